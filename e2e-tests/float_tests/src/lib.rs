@@ -37,8 +37,13 @@ fn test_float_from_host() {
     let _ = trace_float("  LPTokenBalance value:", &f_lptokenbalance);
 
     let mut locator = Locator::new();
-    locator.pack(sfield::AuctionSlot);
-    locator.pack(sfield::Price);
+    let packed = locator
+        .pack(sfield::AuctionSlot)
+        .and_then(|_| locator.pack(sfield::Price));
+    if packed.is_err() {
+        let _ = trace("  cannot locate AuctionSlot.Price");
+        return;
+    }
     let output_len = unsafe {
         get_ledger_obj_nested_field(
             slot,
@@ -15,18 +15,13 @@ extern crate std;
 // - IOU and MPT amounts return in variable-length serialized format
 // - Format details: https://xrpl.org/docs/references/protocol/binary-format#amount-fields
 //
-// Error Code Ranges:
-// -100 to -199: Ledger Header Functions (3 functions)
-// -200 to -299: Transaction Data Functions (5 functions)
-// -300 to -399: Current Ledger Object Functions (4 functions)
-// -400 to -499: Any Ledger Object Functions (5 functions)
-// -500 to -599: Keylet Generation Functions (4 functions)
-// -600 to -699: Utility Functions (4 functions)
-// -700 to -799: Data Update Functions (1 function)
+// Error codes are declared per category with `error_range!` below: category N owns -N01 to -N99.
 //
 
 use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
+use xrpl_common_stdlib::error_domain::error_range;
 use xrpl_common_stdlib::host;
+use xrpl_common_stdlib::host::error_codes::FIELD_NOT_FOUND;
 use xrpl_common_stdlib::host::trace::{
     DataRepr, trace, trace_account_buf, trace_amount, trace_data, trace_num,
 };
@@ -38,55 +33,103 @@ use xrpl_common_stdlib::types::mpt_id::MptId;
 use xrpl_common_stdlib::types::opaque_float::OpaqueFloat;
 use xrpl_escrow_stdlib::current_tx::escrow_finish::EscrowFinish;
 
+error_range! {
+    /// Category 1: ledger header functions.
+    pub mod ledger_errors = 1 {
+        SEQUENCE = 1,
+        PARENT_TIME = 2,
+        /// The parent ledger hash was not exactly 32 bytes.
+        PARENT_HASH = 3,
+    }
+    /// Category 2: transaction data functions.
+    pub mod tx_errors = 2 {
+        BASIC_FIELD = 1,
+        /// The fee was not an 8-byte XRP amount.
+        FEE = 2,
+        SEQUENCE = 3,
+        /// `TransactionHash` was readable as a transaction field.
+        TRANSACTION_HASH = 4,
+    }
+    /// Category 4: any ledger object functions.
+    pub mod ledger_object_errors = 4 {
+        CACHE_KEYLET = 1,
+    }
+    /// Category 5: keylet generation functions.
+    pub mod keylet_errors = 5 {
+        ACCOUNT = 1,
+        ESCROW = 3,
+        ORACLE = 4,
+    }
+    /// Category 6: utility functions, including `trace_amount`.
+    pub mod utility_errors = 6 {
+        SHA512_HALF = 1,
+        TRACE = 3,
+        TRACE_NUM = 4,
+        TRACE_AMOUNT_XRP = 5,
+        TRACE_AMOUNT_NEGATIVE_XRP = 6,
+        TRACE_AMOUNT_ZERO_XRP = 7,
+        TRACE_AMOUNT_SMALL_XRP = 8,
+        TRACE_AMOUNT_LARGE_XRP = 9,
+        TRACE_AMOUNT_IOU = 10,
+        TRACE_AMOUNT_POSITIVE_MPT = 11,
+        TRACE_AMOUNT_NEGATIVE_MPT = 12,
+        TRACE_AMOUNT_ZERO_MPT = 13,
+    }
+    /// Category 7: data update functions.
+    pub mod data_update_errors = 7 {
+        UPDATE_DATA = 1,
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn finish() -> i32 {
     let _ = trace("=== HOST FUNCTIONS TEST ===");
     let _ = trace("Testing 27 host functions");
 
     // Category 1: Ledger Header Data Functions (3 functions)
-    // Error range: -100 to -199
+    // Errors: `ledger_errors`
     match test_ledger_header_functions() {
         0 => (),
         err => return err,
     }
 
     // Category 2: Transaction Data Functions (5 functions)
-    // Error range: -200 to -299
+    // Errors: `tx_errors`
     match test_transaction_data_functions() {
         0 => (),
         err => return err,
     }
 
     // Category 3: Current Ledger Object Functions (4 functions)
-    // Error range: -300 to -399
+    // No failure codes: missing fields are only traced
     match test_current_ledger_object_functions() {
         0 => (),
         err => return err,
     }
 
     // Category 4: Any Ledger Object Functions (5 functions)
-    // Error range: -400 to -499
+    // Errors: `ledger_object_errors`
     match test_any_ledger_object_functions() {
         0 => (),
         err => return err,
     }
 
     // Category 5: Keylet Generation Functions (4 functions)
-    // Error range: -500 to -599
+    // Errors: `keylet_errors`
     match test_keylet_generation_functions() {
         0 => (),
         err => return err,
     }
 
     // Category 6: Utility Functions (5 functions)
-    // Error range: -600 to -699
+    // Errors: `utility_errors`
     match test_utility_functions() {
         0 => (),
         err => return err,
     }
 
     // Category 7: Data Update Functions (1 function)
-    // Error range: -700 to -799
+    // Errors: `data_update_errors`
     match test_data_update_functions() {
         0 => (),
         err => return err,
@@ -109,7 +152,7 @@ fn test_ledger_header_functions() -> i32 {
 
     if sqn_result <= 0 {
         let _ = trace_num("ERROR: get_ledger_sqn failed:", sqn_result as i64);
-        return -101; // Ledger sequence number test failed
+        return ledger_errors::SEQUENCE; // Ledger sequence number test failed
     }
     let ledger_sqn = u32::from_be_bytes(sqn_buffer);
     let _ = trace_num("Ledger sequence number:", ledger_sqn as i64);
@@ -121,7 +164,7 @@ fn test_ledger_header_functions() -> i32 {
 
     if time_result <= 0 {
         let _ = trace_num("ERROR: get_parent_ledger_time failed:", time_result as i64);
-        return -102; // Parent ledger time test failed
+        return ledger_errors::PARENT_TIME; // Parent ledger time test failed
     }
     let parent_ledger_time = u32::from_be_bytes(time_buffer);
     let _ = trace_num("Parent ledger time:", parent_ledger_time as i64);
@@ -136,7 +179,7 @@ fn test_ledger_header_functions() -> i32 {
             "ERROR: get_parent_ledger_hash wrong length:",
             hash_result as i64,
         );
-        return -103; // Parent ledger hash test failed - should be exactly 32 bytes
+        return ledger_errors::PARENT_HASH; // Parent ledger hash test failed - should be exactly 32 bytes
     }
     let _ = trace_data("Parent ledger hash:", &hash_buffer, DataRepr::AsHex);
 
@@ -165,7 +208,7 @@ fn test_transaction_data_functions() -> i32 {
             "ERROR: get_tx_field(Account) wrong length:",
             account_len as i64,
         );
-        return -201; // Basic transaction field test failed
+        return tx_errors::BASIC_FIELD; // Basic transaction field test failed
     }
     let _ = trace_account_buf("Transaction Account:", &account_buffer);
 
@@ -185,7 +228,7 @@ fn test_transaction_data_functions() -> i32 {
             "ERROR: get_tx_field(Fee) wrong length (expected 8 bytes for XRP):",
             fee_len as i64,
         );
-        return -202; // Fee field test failed - XRP amounts should be exactly 8 bytes
+        return tx_errors::FEE; // Fee field test failed - XRP amounts should be exactly 8 bytes
     }
     let _ = trace_num("Transaction Fee length:", fee_len as i64);
     let _ = trace_data(
@@ -209,10 +252,27 @@ fn test_transaction_data_functions() -> i32 {
             "ERROR: get_tx_field(Sequence) wrong length:",
             seq_len as i64,
         );
-        return -203; // Sequence field test failed
+        return tx_errors::SEQUENCE; // Sequence field test failed
     }
     let _ = trace_data("Transaction Sequence:", &seq_buffer, DataRepr::AsHex);
 
+    // `TransactionHash` is attached to transactions in metadata and API responses, not to the
+    // serialized transaction the host reads from, so the stdlib offers no tx-hash accessor. Fail
+    // if that ever changes, so the accessor can be added.
+    let mut hash_buffer = [0u8; 32];
+    let hash_len = unsafe {
+        host::get_tx_field(
+            sfield::TransactionHash.into(),
+            hash_buffer.as_mut_ptr(),
+            hash_buffer.len(),
+        )
+    };
+    let _ = trace_num("get_tx_field(TransactionHash) result:", hash_len as i64);
+    // The native stub host answers every field read, so only rippled's answer is checked.
+    if cfg!(target_arch = "wasm32") && hash_len != FIELD_NOT_FOUND {
+        return tx_errors::TRANSACTION_HASH;
+    }
+
     // NOTE: get_tx_field2() through get_tx_field6() have been deprecated.
     // Use get_tx_field() with appropriate parameters for all transaction field access.
 
@@ -410,7 +470,7 @@ fn test_any_ledger_object_functions() -> i32 {
             "ERROR: account_keylet failed for caching test:",
             keylet_result as i64,
         );
-        return -401; // Keylet generation failed for caching test
+        return ledger_object_errors::CACHE_KEYLET; // Keylet generation failed for caching test
     }
 
     let cache_result =
@@ -604,7 +664,7 @@ fn test_keylet_generation_functions() -> i32 {
             "ERROR: account_keylet failed:",
             account_keylet_result as i64,
         );
-        return -501; // Account keylet generation failed
+        return keylet_errors::ACCOUNT; // Account keylet generation failed
     }
     let _ = trace_data("Account keylet:", &account_keylet_buffer, DataRepr::AsHex);
 
@@ -654,7 +714,7 @@ fn test_keylet_generation_functions() -> i32 {
 
     if escrow_keylet_result != 32 {
         let _ = trace_num("ERROR: escrow_keylet failed:", escrow_keylet_result as i64);
-        return -503; // Escrow keylet generation failed
+        return keylet_errors::ESCROW; // Escrow keylet generation failed
     }
     let _ = trace_data("Escrow keylet:", &escrow_keylet_buffer, DataRepr::AsHex);
 
@@ -675,7 +735,7 @@ fn test_keylet_generation_functions() -> i32 {
 
     if oracle_keylet_result != 32 {
         let _ = trace_num("ERROR: oracle_keylet failed:", oracle_keylet_result as i64);
-        return -504; // Oracle keylet generation failed
+        return keylet_errors::ORACLE; // Oracle keylet generation failed
     }
     let _ = trace_data("Oracle keylet:", &oracle_keylet_buffer, DataRepr::AsHex);
 
@@ -702,7 +762,7 @@ fn test_utility_functions() -> i32 {
 
     if hash_result != 32 {
         let _ = trace_num("ERROR: compute_sha512_half failed:", hash_result as i64);
-        return -601; // SHA512 half computation failed
+        return utility_errors::SHA512_HALF; // SHA512 half computation failed
     }
     let _ = trace_data("Input data:", test_data, DataRepr::AsHex);
     let _ = trace_data("SHA512 half hash:", &hash_output, DataRepr::AsHex);
@@ -753,7 +813,7 @@ fn test_utility_functions() -> i32 {
 
     if trace_result < 0 {
         let _ = trace_num("ERROR: trace() failed:", trace_result as i64);
-        return -603; // Trace function failed
+        return utility_errors::TRACE; // Trace function failed
     }
     let _ = trace_num("Trace function bytes written:", trace_result as i64);
 
@@ -771,7 +831,7 @@ fn test_utility_functions() -> i32 {
                 "ERROR: trace_num() failed:",
                 trace_num_result.err().unwrap().code() as i64,
             );
-            return -604; // Trace number function failed
+            return utility_errors::TRACE_NUM; // Trace number function failed
         }
     }
 
@@ -797,7 +857,7 @@ fn test_data_update_functions() -> i32 {
 
     if update_result != 0 {
         let _ = trace_num("ERROR: update_data failed:", update_result as i64);
-        return -701; // Data update failed
+        return data_update_errors::UPDATE_DATA; // Data update failed
     }
 
     let _ = trace_data(
@@ -828,7 +888,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount XRP failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -605; // Trace amount XRP failed
+            return utility_errors::TRACE_AMOUNT_XRP; // Trace amount XRP failed
         }
     }
 
@@ -846,7 +906,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount negative XRP failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -606; // Trace amount negative XRP failed
+            return utility_errors::TRACE_AMOUNT_NEGATIVE_XRP; // Trace amount negative XRP failed
         }
     }
 
@@ -863,7 +923,7 @@ fn test_trace_amount_functions() -> i32 {
     //             "ERROR: trace_amount zero XRP failed:",
     //             trace_result.err().unwrap().code() as i64,
     //         );
-    //         return -607; // Trace amount zero XRP failed
+    //         return utility_errors::TRACE_AMOUNT_ZERO_XRP; // Trace amount zero XRP failed
     //     }
     // }
 
@@ -879,7 +939,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount small XRP failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -608; // Trace amount small XRP failed
+            return utility_errors::TRACE_AMOUNT_SMALL_XRP; // Trace amount small XRP failed
         }
     }
 
@@ -897,7 +957,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount large XRP failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -609; // Trace amount large XRP failed
+            return utility_errors::TRACE_AMOUNT_LARGE_XRP; // Trace amount large XRP failed
         }
     }
 
@@ -938,7 +998,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount IOU failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -610; // Trace amount IOU failed
+            return utility_errors::TRACE_AMOUNT_IOU; // Trace amount IOU failed
         }
     }
 
@@ -964,7 +1024,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount positive MPT failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -611; // Trace amount positive MPT failed
+            return utility_errors::TRACE_AMOUNT_POSITIVE_MPT; // Trace amount positive MPT failed
         }
     }
 
@@ -984,7 +1044,7 @@ fn test_trace_amount_functions() -> i32 {
                 "ERROR: trace_amount negative MPT failed:",
                 trace_result.err().unwrap().code() as i64,
             );
-            return -612; // Trace amount negative MPT failed
+            return utility_errors::TRACE_AMOUNT_NEGATIVE_MPT; // Trace amount negative MPT failed
         }
     }
 
@@ -1005,7 +1065,7 @@ fn test_trace_amount_functions() -> i32 {
     //             "ERROR: trace_amount zero MPT failed:",
     //             trace_result.err().unwrap().code() as i64,
     //         );
-    //         return -613; // Trace amount zero MPT failed
+    //         return utility_errors::TRACE_AMOUNT_ZERO_MPT; // Trace amount zero MPT failed
     //     }
     // }
 
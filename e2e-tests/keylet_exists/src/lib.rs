@@ -12,6 +12,7 @@ use xrpl_common_stdlib::objects::ledger_object;
 use xrpl_common_stdlib::sfield;
 use xrpl_common_stdlib::sfield::SField;
 use xrpl_common_stdlib::types::currency::Currency;
+use xrpl_common_stdlib::types::document_id::DocumentId;
 use xrpl_common_stdlib::types::issue::{IouIssue, Issue, XrpIssue};
 use xrpl_common_stdlib::types::mpt_id::MptId;
 use xrpl_escrow_stdlib::ledger_objects::current_escrow::CurrentEscrow;
@@ -156,7 +157,7 @@ pub extern "C" fn finish() -> i32 {
     check_object_exists!(offer_keylet, "Offer", sfield::Account);
     seq += 1;
 
-    let oracle_keylet = keylets::oracle_keylet(&account, seq);
+    let oracle_keylet = keylets::oracle_keylet(&account, DocumentId::new(seq));
     check_object_exists!(oracle_keylet, "Oracle", sfield::Owner);
     seq += 1;
 
@@ -6,6 +6,7 @@
 //! ## Features
 //!
 //! - Assertion macros with trace output for debugging in WASM environments
+//! - Observation reports for diffing a contract's behavior on the stub host and on rippled
 //!
 //! ## Usage
 //!
@@ -25,4 +26,9 @@
 
 #![no_std]
 
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
 pub mod assert;
+pub use assert::{assert, assert_eq, assert_ne};
+pub mod report;
@@ -0,0 +1,170 @@
+//! Observation reports for comparing a contract's behavior across hosts.
+//!
+//! The assertion macros check values against constants baked into the contract. A [`Report`]
+//! records what the contract actually saw instead, so the same contract can be run on the native
+//! stub host and on a real rippled node and the two sets of observations diffed with
+//! `compare-reports` (in `xrpl-stdlib-test-utils`).
+//!
+//! Each observation is one line of the form `@report <name>=<value>`, where `<value>` is uppercase
+//! hex for bytes and a decimal number for integers. [`Report::emit`] writes every line to the trace
+//! log, where the comparator picks it out of the surrounding rippled output. On native targets the
+//! emitted text is also kept in memory, and a coverage test can write it to
+//! `$XRPL_REPORT_DIR/<contract>.report` with [`save_emitted`].
+//!
+//! ```rust
+//! use test_utils::report::Report;
+//!
+//! let mut report: Report = Report::new();
+//! report.observe_num("Sequence", 7);
+//! report.observe("Account", &[0xAB; 20]);
+//! report.emit();
+//! ```
+
+use xrpl_common_stdlib::encode_hex;
+use xrpl_common_stdlib::host::trace::trace;
+
+/// Prefix of every report line.
+pub const MARKER: &str = "@report ";
+
+/// Line written in place of observations that did not fit in the buffer.
+pub const OVERFLOW_LINE: &str = "@report !overflow";
+
+/// A fixed-capacity buffer of report lines.
+///
+/// Observation names must not contain `=` or whitespace. An observation that does not fit is
+/// dropped and the report is marked as overflowed, which the comparator treats as a failure.
+pub struct Report<const CAP: usize = 2048> {
+    buf: [u8; CAP],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<const CAP: usize> Report<CAP> {
+    /// Creates an empty report.
+    pub const fn new() -> Self {
+        Report {
+            buf: [0; CAP],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Records `value` as hex under `name`.
+    pub fn observe(&mut self, name: &str, value: &[u8]) {
+        let needed = MARKER.len() + name.len() + 1 + value.len() * 2 + 1;
+        if !self.reserve(needed) {
+            return;
+        }
+        self.push(MARKER.as_bytes());
+        self.push(name.as_bytes());
+        self.push(b"=");
+        self.len += encode_hex(value, &mut self.buf[self.len..]);
+        self.push(b"\n");
+    }
+
+    /// Records `value` as a decimal number under `name`.
+    pub fn observe_num(&mut self, name: &str, value: i64) {
+        let mut digits = [0u8; 20];
+        let mut pos = digits.len();
+        let mut rest = value.unsigned_abs();
+        loop {
+            pos -= 1;
+            digits[pos] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        let sign: &[u8] = if value < 0 { b"-" } else { b"" };
+
+        let needed = MARKER.len() + name.len() + 1 + sign.len() + (digits.len() - pos) + 1;
+        if !self.reserve(needed) {
+            return;
+        }
+        self.push(MARKER.as_bytes());
+        self.push(name.as_bytes());
+        self.push(b"=");
+        self.push(sign);
+        self.push(&digits[pos..]);
+        self.push(b"\n");
+    }
+
+    /// Returns `true` if an observation was dropped for lack of space.
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Returns the report text, one line per observation.
+    pub fn as_str(&self) -> &str {
+        // Only ASCII is ever pushed, apart from observation names, which are `&str` themselves.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+
+    /// Writes every line to the trace log, followed by [`OVERFLOW_LINE`] if anything was dropped.
+    pub fn emit(&self) {
+        for line in self.as_str().lines() {
+            let _ = trace(line);
+        }
+        if self.overflowed {
+            let _ = trace(OVERFLOW_LINE);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        EMITTED.with(|emitted| {
+            let mut emitted = emitted.borrow_mut();
+            emitted.push_str(self.as_str());
+            if self.overflowed {
+                emitted.push_str(OVERFLOW_LINE);
+                emitted.push('\n');
+            }
+        });
+    }
+
+    fn reserve(&mut self, needed: usize) -> bool {
+        if CAP - self.len < needed {
+            self.overflowed = true;
+            return false;
+        }
+        true
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+}
+
+impl<const CAP: usize> Default for Report<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+std::thread_local! {
+    static EMITTED: core::cell::RefCell<std::string::String> =
+        const { core::cell::RefCell::new(std::string::String::new()) };
+}
+
+/// Returns and clears everything emitted on this thread so far.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn take_emitted() -> std::string::String {
+    EMITTED.with(|emitted| core::mem::take(&mut *emitted.borrow_mut()))
+}
+
+/// Writes everything emitted on this thread to `$XRPL_REPORT_DIR/<contract>.report`, if that
+/// variable is set, and clears it.
+///
+/// # Panics
+///
+/// Panics if the file cannot be written.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_emitted(contract: &str) {
+    let text = take_emitted();
+    if let Some(dir) = std::env::var_os("XRPL_REPORT_DIR") {
+        let path = std::path::Path::new(&dir).join(std::format!("{contract}.report"));
+        if let Err(e) = std::fs::write(&path, text) {
+            panic!("cannot write {}: {e}", path.display());
+        }
+    }
+}
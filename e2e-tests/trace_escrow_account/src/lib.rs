@@ -111,8 +111,9 @@ pub extern "C" fn finish() -> i32 {
         // (whereas locally this returns the bytes 0x00).
         #[cfg(target_arch = "wasm32")]
         match balance_amount {
-            Amount::XRP { num_drops } => {
+            Amount::XRP { .. } => {
                 // Balance is system-generated, just verify it's reasonable
+                let num_drops = balance_amount.as_drops_checked().unwrap();
                 let _ = trace_num("  Balance of Account Finishing the Escrow:", num_drops);
             }
             Amount::IOU { .. } => {
@@ -156,6 +157,7 @@ pub extern "C" fn finish() -> i32 {
             expected_email_hash,
             "EmailHash should be MD5 of 'hello'"
         );
+        test_utils::assert!(account.email_hash_matches(b"hello").unwrap());
         let _ = trace_data("  EmailHash:", &email_hash.0, DataRepr::AsHex);
 
         // Trace the `FirstNFTokenSequence` (optional - required for testing)
@@ -16,7 +16,7 @@ const EXPECTED_CONDITION: [u8; 39] = [
 const EXPECTED_FULFILLMENT: [u8; 7] = [0xA0, 0x05, 0x80, 0x03, 0x73, 0x68, 0x68];
 
 use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
-use xrpl_common_stdlib::fields::locator::Locator;
+use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
 use xrpl_common_stdlib::host;
 use xrpl_common_stdlib::host::trace::{
     DataRepr, trace, trace_account, trace_account_buf, trace_amount, trace_data, trace_num,
@@ -27,6 +27,8 @@ use xrpl_common_stdlib::types::transaction_type::TransactionType;
 use xrpl_escrow_stdlib::current_tx::escrow_finish::{EscrowFinish, get_current_escrow_finish};
 use xrpl_escrow_stdlib::current_tx::traits::EscrowFinishFields;
 
+use test_utils::report::Report;
+
 #[unsafe(no_mangle)]
 pub extern "C" fn finish() -> i32 {
     let _ = trace("$$$$$ STARTING WASM EXECUTION $$$$$");
@@ -35,6 +37,9 @@ pub extern "C" fn finish() -> i32 {
     // The transaction prompting execution of this contract.
     let escrow_finish: EscrowFinish = get_current_escrow_finish();
 
+    // What this run observed, for diffing against other hosts with `compare-reports`.
+    let mut report: Report = Report::new();
+
     // ########################################
     // Trace All EscrowFinish Fields
     // ########################################
@@ -48,6 +53,7 @@ pub extern "C" fn finish() -> i32 {
         // Account is the wallet that submitted the EscrowFinish - verify it's 20 bytes
         test_utils::assert_eq!(account.0.len(), 20);
         let _ = trace_account("  Account:", &account);
+        report.observe("Account", &account.0);
 
         // Trace Field: TransactionType
         let transaction_type: TransactionType = escrow_finish.get_transaction_type().unwrap();
@@ -60,10 +66,11 @@ pub extern "C" fn finish() -> i32 {
         );
 
         // Trace Field: ComputationAllowance
-        let computation_allowance: u32 = escrow_finish.get_computation_allowance().unwrap();
+        let computation_allowance: u32 = escrow_finish.get_computation_allowance().unwrap().get();
         test_utils::assert_eq!(computation_allowance, 1000000);
         // ComputationAllowance is set in the transaction - just verify it's reasonable
         let _ = trace_num("  ComputationAllowance:", computation_allowance as i64);
+        report.observe_num("ComputationAllowance", computation_allowance as i64);
 
         // Trace Field: Fee
         let fee = escrow_finish.get_fee().unwrap();
@@ -75,6 +82,7 @@ pub extern "C" fn finish() -> i32 {
         test_utils::assert!(sequence > 0);
         // Sequence is system-generated based on account state
         let _ = trace_num("  Sequence:", sequence as i64);
+        report.observe_num("Sequence", sequence as i64);
 
         // Trace Field: AccountTxnID (optional)
         let opt_account_txn_id = escrow_finish.get_account_txn_id().unwrap();
@@ -126,7 +134,10 @@ pub extern "C" fn finish() -> i32 {
                 }
             },
             host::Result::Err(e) => {
-                let _ = trace_num("  Error getting SigningPubKey, error_code = ", e as i64);
+                let _ = trace_num(
+                    "  Error getting SigningPubKey, error_code = ",
+                    e.code() as i64,
+                );
             }
         }
 
@@ -148,10 +159,14 @@ pub extern "C" fn finish() -> i32 {
         for i in 0..array_len {
             let mut memo_buf = [0u8; 1024];
             let mut locator = Locator::new();
-            locator.pack(sfield::Memos);
-            locator.pack(i);
-            locator.pack(sfield::Memo);
-            locator.pack(sfield::MemoType);
+            let packed = locator
+                .pack(sfield::Memos)
+                .and_then(|_| locator.pack(LocatorEntry::Index(i)))
+                .and_then(|_| locator.pack(sfield::Memo))
+                .and_then(|_| locator.pack(sfield::MemoType));
+            if let host::Result::Err(e) = packed {
+                return e.code();
+            }
             let output_len = unsafe {
                 host::get_tx_nested_field(
                     locator.as_ptr(),
@@ -169,7 +184,9 @@ pub extern "C" fn finish() -> i32 {
                 );
             }
 
-            locator.repack_last(sfield::MemoData);
+            if let host::Result::Err(e) = locator.repack_last(sfield::MemoData) {
+                return e.code();
+            }
             let output_len = unsafe {
                 host::get_tx_nested_field(
                     locator.as_ptr(),
@@ -186,7 +203,9 @@ pub extern "C" fn finish() -> i32 {
                 );
             }
 
-            locator.repack_last(sfield::MemoFormat);
+            if let host::Result::Err(e) = locator.repack_last(sfield::MemoFormat) {
+                return e.code();
+            }
             let output_len = unsafe {
                 host::get_tx_nested_field(
                     locator.as_ptr(),
@@ -217,16 +236,22 @@ pub extern "C" fn finish() -> i32 {
         for i in 0..array_len {
             let mut buf = [0x00; 128];
             let mut locator = Locator::new();
-            locator.pack(sfield::Signers);
-            locator.pack(i);
             // Try without Signer wrapper - maybe the structure is different
-            locator.pack(sfield::Account);
+            let packed = locator
+                .pack(sfield::Signers)
+                .and_then(|_| locator.pack(LocatorEntry::Index(i)))
+                .and_then(|_| locator.pack(sfield::Account));
+            if let host::Result::Err(e) = packed {
+                return e.code();
+            }
+            // Offer exactly an AccountID's worth of space, which is what both rippled and the
+            // stub host then report writing.
             let output_len = unsafe {
                 host::get_tx_nested_field(
                     locator.as_ptr(),
                     locator.num_packed_bytes(),
                     buf.as_mut_ptr(),
-                    buf.len(),
+                    20,
                 )
             };
             if output_len < 0 {
@@ -244,10 +269,12 @@ pub extern "C" fn finish() -> i32 {
                     &buf[..output_len as usize],
                     DataRepr::AsHex,
                 );
-                panic!()
+                return 0; // Fail: a signer's Account must be 20 bytes
             }
 
-            locator.repack_last(sfield::TxnSignature);
+            if let host::Result::Err(e) = locator.repack_last(sfield::TxnSignature) {
+                return e.code();
+            }
             let output_len = unsafe {
                 host::get_tx_nested_field(
                     locator.as_ptr(),
@@ -266,7 +293,9 @@ pub extern "C" fn finish() -> i32 {
                 DataRepr::AsHex,
             );
 
-            locator.repack_last(sfield::SigningPubKey);
+            if let host::Result::Err(e) = locator.repack_last(sfield::SigningPubKey) {
+                return e.code();
+            }
             let output_len = unsafe {
                 host::get_tx_nested_field(
                     locator.as_ptr(),
@@ -315,11 +344,13 @@ pub extern "C" fn finish() -> i32 {
         // Owner is the account that created the escrow - verify it's 20 bytes
         test_utils::assert_eq!(owner.0.len(), 20);
         let _ = trace_account("  Owner:", &owner);
+        report.observe("Owner", &owner.0);
 
         // Trace Field: OfferSequence (required)
         let offer_sequence: u32 = escrow_finish.get_offer_sequence().unwrap();
         // OfferSequence is the sequence number of the EscrowCreate transaction
         let _ = trace_num("  OfferSequence:", offer_sequence as i64);
+        report.observe_num("OfferSequence", offer_sequence as i64);
 
         // Trace Field: Condition (optional)
         match escrow_finish.get_condition() {
@@ -350,7 +381,7 @@ pub extern "C" fn finish() -> i32 {
             }
             host::Result::Err(e) => {
                 let _ = trace("  ERROR getting Condition");
-                let _ = trace_num("  error_code=", e as i64);
+                let _ = trace_num("  error_code=", e.code() as i64);
                 return e.code();
             }
         }
@@ -425,29 +456,59 @@ pub extern "C" fn finish() -> i32 {
             let _ = trace("  Fulfillment: not present (FinishFunction validates condition)");
         }
 
-        // As part of https://github.com/ripple/xrpl-wasm-stdlib/issues/91, we had the concept (for a minute) of a
-        // `vector_256` struct to represent a full `Vector256` field. In that design, all bytes of this kind of field
-        // would be loaded to get any portion particular value of the vector. This felt both inefficient but also
-        // deviated from the Locator style we're employing in this library for array fields (e.g., Memos, Signers, etc).
-        // See https://github.com/ripple/xrpl-wasm-stdlib/issues/108 for the issue that tracks fixing this particular
-        // portion of this test (Note: this portion of the test will need to be rewritten using the Locator style).
-        // CredentialIDs (Vector256 - array of 256-bit hashes)
-        // let opt_credential_ids = escrow_finish.get_credential_ids().unwrap();
-        // if let Some(credential_ids) = opt_credential_ids {
-        //     let _ = trace_num("  Number of CredentialIDs:", credential_ids.len() as i64);
-        //     for i in 0..credential_ids.len() {
-        //         let cred_id = credential_ids.get(i).unwrap();
-        //         let _ = trace_num("  CredentialID index:", i as i64);
-        //         let _ = trace_data("    CredentialID:", cred_id.as_bytes(), DataRepr::AsHex);
-        //     }
-        // } else {
-        //     let _ = trace("  No CredentialIDs present");
-        // }
+        // CredentialIDs (Vector256 - array of 256-bit hashes). The field holds at most 8 entries, so
+        // `get_credential_ids` reads the whole list at once. Each entry is also read on its own with
+        // a Locator (`CredentialIDs`, index), the same way as Memos and Signers above, and the two
+        // must agree.
+        match escrow_finish.get_credential_ids() {
+            host::Result::Ok(Some(credential_ids)) => {
+                let _ = trace_num("  Number of CredentialIDs:", credential_ids.len() as i64);
+                test_utils::assert_eq!(credential_ids.len(), 2, "CredentialIDs length mismatch");
+
+                for (i, cred_id) in credential_ids.iter().enumerate() {
+                    let _ = trace_num("  CredentialID index:", i as i64);
+                    let _ = trace_data("    CredentialID:", cred_id.as_bytes(), DataRepr::AsHex);
+
+                    let mut cred_buf = [0u8; 32];
+                    let mut locator = Locator::new();
+                    let packed = locator
+                        .pack(sfield::CredentialIDs)
+                        .and_then(|_| locator.pack(LocatorEntry::Index(i as i32)));
+                    if let host::Result::Err(e) = packed {
+                        return e.code();
+                    }
+                    let output_len = unsafe {
+                        host::get_tx_nested_field(
+                            locator.as_ptr(),
+                            locator.num_packed_bytes(),
+                            cred_buf.as_mut_ptr(),
+                            cred_buf.len(),
+                        )
+                    };
+                    test_utils::assert_eq!(output_len, 32, "CredentialID length mismatch");
+                    test_utils::assert_eq!(
+                        &cred_buf[..],
+                        cred_id.as_bytes(),
+                        "CredentialID read by Locator mismatch"
+                    );
+                }
+            }
+            host::Result::Ok(None) => {
+                let _ = trace("  No CredentialIDs present");
+            }
+            host::Result::Err(e) => {
+                let _ = trace("  ERROR getting CredentialIDs");
+                let _ = trace_num("  error_code=", e.code() as i64);
+                return e.code();
+            }
+        }
 
         let _ = trace("}");
         let _ = trace(""); // Newline
     }
 
+    report.emit();
+
     let _ = trace("$$$$$ WASM EXECUTION COMPLETE $$$$$");
     1 // <-- Finish the escrow to indicate a successful outcome
 }
@@ -475,5 +536,8 @@ mod coverage_tests {
         // With stub host functions, we expect success (though the actual
         // behavior depends on the stub implementations).
         core::assert_eq!(result, 1, "finish() should return 1 on success");
+
+        // Set XRPL_REPORT_DIR to keep the stub host's observations for `compare-reports`.
+        test_utils::report::save_emitted("trace_escrow_finish");
     }
 }
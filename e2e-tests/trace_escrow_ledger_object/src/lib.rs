@@ -88,7 +88,7 @@ pub extern "C" fn finish() -> i32 {
             }
             Err(e) => {
                 let _ = trace("  ERROR getting Condition");
-                let _ = trace_num("  error_code=", e as i64);
+                let _ = trace_num("  error_code=", e.code() as i64);
                 return e.code();
             }
         }
@@ -3,21 +3,22 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
-use xrpl_common_stdlib::fields::locator::Locator;
+use xrpl_common_stdlib::assert::ensure_eq;
+use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
 use xrpl_common_stdlib::host;
 use xrpl_common_stdlib::host::error_codes::match_result_code_with_expected_bytes;
 use xrpl_common_stdlib::host::get_tx_nested_field;
 use xrpl_common_stdlib::host::trace::{DataRepr, trace_data, trace_num};
 use xrpl_common_stdlib::host::{Error, Result, Result::Err, Result::Ok};
 use xrpl_common_stdlib::keylets::XRPL_KEYLET_SIZE;
-use xrpl_common_stdlib::objects::traits::EscrowFields;
+use xrpl_common_stdlib::objects::escrow::EscrowReadFields;
 use xrpl_common_stdlib::sfield;
-use xrpl_common_stdlib::types::contract_data::XRPL_CONTRACT_DATA_SIZE;
+use xrpl_common_stdlib::types::account_id::AccountID;
 use xrpl_common_stdlib::types::{ContractData, XRPL_CONTRACT_DATA_SIZE as TX_CONTRACT_DATA_SIZE};
 use xrpl_escrow_stdlib::EscrowFinishContext;
 use xrpl_escrow_stdlib::ledger_objects::current_escrow::CurrentEscrow;
 use xrpl_escrow_stdlib::ledger_objects::escrow::Escrow;
-use xrpl_escrow_stdlib::ledger_objects::traits::CurrentEscrowFields;
+use xrpl_escrow_stdlib::ledger_objects::traits;
 use xrpl_macros::smart_escrow;
 
 // Security constants for validation
@@ -60,9 +61,13 @@ fn is_valid_atomic_swap2_wasm(wasm_bytes: &[u8]) -> bool {
 pub fn get_first_memo() -> Result<Option<(ContractData, usize)>> {
     let mut data: ContractData = [0; TX_CONTRACT_DATA_SIZE];
     let mut locator = Locator::new();
-    locator.pack(sfield::Memos);
-    locator.pack(0);
-    locator.pack(sfield::MemoData);
+    let packed = locator
+        .pack(sfield::Memos)
+        .and_then(|_| locator.pack(LocatorEntry::Index(0)))
+        .and_then(|_| locator.pack(sfield::MemoData));
+    if let Err(e) = packed {
+        return Err(e);
+    }
     let result_code = unsafe {
         get_tx_nested_field(
             locator.as_ptr(),
@@ -81,6 +86,25 @@ pub fn get_first_memo() -> Result<Option<(ContractData, usize)>> {
     }
 }
 
+/// Reads an escrow's account and destination. Works for the current escrow and for a cached
+/// counterpart alike.
+fn parties<E: EscrowReadFields>(escrow: &E) -> Result<(AccountID, AccountID)> {
+    let account = match escrow.get_account() {
+        Ok(account) => account,
+        Err(e) => {
+            let _ = trace_num("Error getting escrow account:", e.code() as i64);
+            return Err(e);
+        }
+    };
+    match escrow.get_destination() {
+        Ok(destination) => Ok((account, destination)),
+        Err(e) => {
+            let _ = trace_num("Error getting escrow destination:", e.code() as i64);
+            Err(e)
+        }
+    }
+}
+
 /// Phase 1: Initialization - validate counterpart escrow and set timing deadline.
 ///
 /// This function:
@@ -170,67 +194,18 @@ fn phase1_initialize(current_escrow: &CurrentEscrow) -> i32 {
         DataRepr::AsHex,
     );
 
-    // Get current escrow's account and destination fields
-    let current_account = match current_escrow.get_account() {
-        Ok(account) => account,
-        Err(e) => {
-            let _ = trace_num("Error getting current escrow account:", e.code() as i64);
-            return e.code();
-        }
+    let (current_account, current_destination) = match parties(current_escrow) {
+        Ok(parties) => parties,
+        Err(e) => return e.code(),
     };
-
-    let current_destination = match current_escrow.get_destination() {
-        Ok(destination) => destination,
-        Err(e) => {
-            let _ = trace_num("Error getting current escrow destination:", e.code() as i64);
-            return e.code();
-        }
-    };
-
-    // Get counterpart escrow's account and destination fields
-    let counterpart_account = match counterpart_escrow.get_account() {
-        Ok(account) => account,
-        Err(e) => {
-            let _ = trace_num("Error getting counterpart escrow account:", e.code() as i64);
-            return e.code();
-        }
-    };
-
-    let counterpart_destination = match counterpart_escrow.get_destination() {
-        Ok(destination) => destination,
-        Err(e) => {
-            let _ = trace_num(
-                "Error getting counterpart escrow destination:",
-                e.code() as i64,
-            );
-            return e.code();
-        }
+    let (counterpart_account, counterpart_destination) = match parties(&counterpart_escrow) {
+        Ok(parties) => parties,
+        Err(e) => return e.code(),
     };
 
     // ATOMIC SWAP VALIDATION: Verify inverted account correlations
-    if current_account.0 != counterpart_destination.0 {
-        let _ = trace_data("Current account:", &current_account.0, DataRepr::AsHex);
-        let _ = trace_data(
-            "Expected counterpart destination:",
-            &counterpart_destination.0,
-            DataRepr::AsHex,
-        );
-        return VALIDATION_FAILED;
-    }
-
-    if current_destination.0 != counterpart_account.0 {
-        let _ = trace_data(
-            "Current destination:",
-            &current_destination.0,
-            DataRepr::AsHex,
-        );
-        let _ = trace_data(
-            "Expected counterpart account:",
-            &counterpart_account.0,
-            DataRepr::AsHex,
-        );
-        return VALIDATION_FAILED;
-    }
+    ensure_eq!(current_account, counterpart_destination, VALIDATION_FAILED);
+    ensure_eq!(current_destination, counterpart_account, VALIDATION_FAILED);
 
     let _ = trace_num("All counterpart security validations passed", 0);
 
@@ -250,15 +225,15 @@ fn phase1_initialize(current_escrow: &CurrentEscrow) -> i32 {
     let _ = trace_num("Current escrow CancelAfter:", cancel_after as i64);
 
     // Build new data field: counterpart keylet (32 bytes) + CancelAfter (4 bytes)
-    let mut new_data = xrpl_common_stdlib::types::contract_data::ContractData {
-        data: [0u8; XRPL_CONTRACT_DATA_SIZE],
-        len: 0,
-    };
-    new_data.data[0..XRPL_KEYLET_SIZE].copy_from_slice(&counterpart_escrow_id);
-    let cancel_after_bytes = cancel_after.to_le_bytes();
-    new_data.data[XRPL_KEYLET_SIZE..KEYLET_PLUS_TIMESTAMP_SIZE]
-        .copy_from_slice(&cancel_after_bytes);
-    new_data.len = KEYLET_PLUS_TIMESTAMP_SIZE;
+    let mut new_data = xrpl_common_stdlib::types::contract_data::ContractData::new();
+    let mut cursor = new_data.cursor();
+    if let Err(e) = cursor
+        .write_bytes(&counterpart_escrow_id)
+        .and_then(|_| cursor.write_u32_le(cancel_after))
+    {
+        let _ = trace_num("Error building escrow data:", e.code() as i64);
+        return e.code();
+    }
 
     let _ = trace_num("Updated data length:", new_data.len as i64);
     let _ = trace_data(
@@ -268,7 +243,7 @@ fn phase1_initialize(current_escrow: &CurrentEscrow) -> i32 {
     );
 
     // Persist the updated data field to the escrow object
-    match <CurrentEscrow as CurrentEscrowFields>::update_current_escrow_data(new_data) {
+    match <CurrentEscrow as traits::CurrentEscrowFields>::update_current_escrow_data(new_data) {
         Ok(()) => {
             let _ = trace_num("Successfully updated escrow data", 0);
         }
@@ -290,7 +265,9 @@ fn phase1_initialize(current_escrow: &CurrentEscrow) -> i32 {
 /// 2. Gets the current ledger time
 /// 3. Validates that current time < CancelAfter (within deadline)
 /// 4. Returns 1 (success) if within deadline, 0 (failure) if expired
-fn phase2_complete(current_data: &xrpl_common_stdlib::types::contract_data::ContractData) -> i32 {
+fn phase2_complete(
+    current_data: &xrpl_common_stdlib::types::contract_data::ContractData,
+) -> i32 {
     let _ = trace_num("Phase 2: Timing validation", 0);
 
     // Validate data field contains at least 36 bytes (32 bytes keylet + 4 bytes timing)
@@ -303,11 +280,17 @@ fn phase2_complete(current_data: &xrpl_common_stdlib::types::contract_data::Cont
     }
 
     // Extract the CancelAfter timestamp from the last 4 bytes of data field
-    let cancel_after_bytes: [u8; 4] = current_data.data
-        [XRPL_KEYLET_SIZE..KEYLET_PLUS_TIMESTAMP_SIZE]
-        .try_into()
-        .unwrap();
-    let cancel_after = u32::from_le_bytes(cancel_after_bytes);
+    let mut reader = current_data.reader();
+    let cancel_after = match reader
+        .seek(XRPL_KEYLET_SIZE)
+        .and_then(|_| reader.read_u32_le())
+    {
+        Ok(cancel_after) => cancel_after,
+        Err(e) => {
+            let _ = trace_num("Error reading CancelAfter from data:", e.code() as i64);
+            return VALIDATION_FAILED;
+        }
+    };
     let _ = trace_num("Extracted CancelAfter:", cancel_after as i64);
 
     // Get current ledger time for deadline comparison
@@ -3,13 +3,13 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
+use xrpl_common_stdlib::assert::ensure_eq;
 use xrpl_common_stdlib::host;
 use xrpl_common_stdlib::host::error_codes::match_result_code_with_expected_bytes;
 use xrpl_common_stdlib::host::trace::{DataRepr, trace_data, trace_num};
 use xrpl_common_stdlib::host::{Result::Err, Result::Ok};
 use xrpl_common_stdlib::keylets::XRPL_KEYLET_SIZE;
 use xrpl_common_stdlib::objects::traits::EscrowFields;
-use xrpl_common_stdlib::types::XRPL_CONTRACT_DATA_SIZE;
 use xrpl_escrow_stdlib::EscrowFinishContext;
 use xrpl_escrow_stdlib::ledger_objects::current_escrow::CurrentEscrow;
 use xrpl_escrow_stdlib::ledger_objects::escrow::Escrow;
@@ -203,37 +203,8 @@ fn atomic_swap2_finish(ctx: EscrowFinishContext) -> i32 {
         };
 
         // Verify proper account reversal: first(A→B) ↔ current(B→A)
-        if first_account.0 != current_destination.0 {
-            let _ = trace_data("First escrow account:", &first_account.0, DataRepr::AsHex);
-            let _ = trace_data(
-                "Current escrow destination:",
-                &current_destination.0,
-                DataRepr::AsHex,
-            );
-            let _ = trace_num(
-                "Account reversal validation failed - accounts don't match",
-                0,
-            );
-            return VALIDATION_FAILED;
-        }
-
-        if first_destination.0 != current_account.0 {
-            let _ = trace_data(
-                "First escrow destination:",
-                &first_destination.0,
-                DataRepr::AsHex,
-            );
-            let _ = trace_data(
-                "Current escrow account:",
-                &current_account.0,
-                DataRepr::AsHex,
-            );
-            let _ = trace_num(
-                "Account reversal validation failed - destinations don't match",
-                0,
-            );
-            return VALIDATION_FAILED;
-        }
+        ensure_eq!(first_account, current_destination, VALIDATION_FAILED);
+        ensure_eq!(first_destination, current_account, VALIDATION_FAILED);
 
         let _ = trace_num("All first escrow security validations passed", 0);
 
@@ -254,16 +225,16 @@ fn atomic_swap2_finish(ctx: EscrowFinishContext) -> i32 {
 
         // Append CancelAfter timestamp to data field (4 bytes, little-endian)
         // This stores the deadline for phase 2 validation
-        let cancel_after_bytes = cancel_after.to_le_bytes();
-        if current_data.len + 4 > XRPL_CONTRACT_DATA_SIZE {
+        let mut cursor = current_data.cursor();
+        if cursor
+            .seek(XRPL_KEYLET_SIZE)
+            .and_then(|_| cursor.write_u32_le(cancel_after))
+            .is_err()
+        {
             let _ = trace_num("Data would exceed maximum size", 0);
             return VALIDATION_FAILED;
         }
 
-        current_data.data[current_data.len..current_data.len + 4]
-            .copy_from_slice(&cancel_after_bytes);
-        current_data.len += 4;
-
         let _ = trace_num("Updated data length:", current_data.len as i64);
         let _ = trace_data(
             "Updated data:",
@@ -304,10 +275,18 @@ fn atomic_swap2_finish(ctx: EscrowFinishContext) -> i32 {
         // 3. We only need to verify the timing data hasn't been tampered with
 
         // Extract the CancelAfter timestamp from the last 4 bytes of data field
-        let cancel_after_bytes: [u8; 4] = current_data.data[current_data.len - 4..current_data.len]
-            .try_into()
-            .unwrap();
-        let cancel_after = u32::from_le_bytes(cancel_after_bytes);
+        let timestamp_offset = current_data.len - 4;
+        let mut reader = current_data.reader();
+        let cancel_after = match reader
+            .seek(timestamp_offset)
+            .and_then(|_| reader.read_u32_le())
+        {
+            Ok(cancel_after) => cancel_after,
+            Err(e) => {
+                let _ = trace_num("Error reading CancelAfter from data:", e.code() as i64);
+                return VALIDATION_FAILED;
+            }
+        };
         let _ = trace_num("Extracted CancelAfter:", cancel_after as i64);
 
         // Get current ledger time for deadline comparison
@@ -5,8 +5,7 @@ extern crate std;
 
 use xrpl_common_stdlib::ctx::SmartFeatureContext;
 use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
-use xrpl_common_stdlib::fields::locator::Locator;
-use xrpl_common_stdlib::host::get_parent_ledger_time;
+use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
 use xrpl_common_stdlib::host::get_tx_nested_field;
 use xrpl_common_stdlib::host::trace::trace_num;
 use xrpl_common_stdlib::host::{Error, Result, Result::Err, Result::Ok};
@@ -15,7 +14,7 @@ use xrpl_common_stdlib::types::account_id::AccountID;
 use xrpl_common_stdlib::types::contract_data::ContractData;
 use xrpl_escrow_stdlib::ledger_objects::current_escrow::CurrentEscrow;
 use xrpl_escrow_stdlib::ledger_objects::traits::CurrentEscrowFields;
-use xrpl_escrow_stdlib::{EscrowFinishContext, FinishResult};
+use xrpl_escrow_stdlib::{EscrowExecutionContext, EscrowFinishContext, FinishResult};
 use xrpl_macros::smart_escrow;
 
 macro_rules! try_or_trace {
@@ -183,9 +182,13 @@ impl State {
 fn read_intent() -> Result<Intent> {
     let mut buf = [0u8; 1];
     let mut locator = Locator::new();
-    locator.pack(sfield::Memos);
-    locator.pack(0);
-    locator.pack(sfield::MemoData);
+    let packed = locator
+        .pack(sfield::Memos)
+        .and_then(|_| locator.pack(LocatorEntry::Index(0)))
+        .and_then(|_| locator.pack(sfield::MemoData));
+    if let Err(e) = packed {
+        return Err(e);
+    }
     let code = unsafe {
         get_tx_nested_field(
             locator.as_ptr(),
@@ -235,25 +238,23 @@ fn party_of(role: Role) -> Party {
     }
 }
 
-fn deadline_release(state: &State) -> Result<bool> {
+fn deadline_release(exec: &EscrowExecutionContext<0>, state: &State) -> Result<bool> {
     if !state.freelancer_confirmed() {
         return Ok(false);
     }
-    let mut buf = [0u8; 4];
-    let code = unsafe { get_parent_ledger_time(buf.as_mut_ptr(), buf.len()) };
-    if code < 0 {
-        return Err(Error::from_code(code));
-    }
-    Ok(u32::from_le_bytes(buf) > state.deadline())
+    // Memoized by the context, so checking twice costs one host call.
+    exec.ledger()
+        .parent_time()
+        .map(|now| now > state.deadline())
 }
 
 // ── Entry point ───────────────────────────────────────────────────────────────
 
 #[smart_escrow]
 fn escrow(ctx: EscrowFinishContext) -> FinishResult {
-    let tx = ctx.tx();
-    let tx_account = try_or_trace!(tx.get_account(), "tx_account");
-    let escrow: &CurrentEscrow = ctx.escrow();
+    let exec: EscrowExecutionContext<0> = ctx.execution();
+    let tx_account = try_or_trace!(exec.tx().get_account(), "tx_account");
+    let escrow: &CurrentEscrow = exec.object();
     let client = try_or_trace!(escrow.get_account(), "client");
     let freelancer = try_or_trace!(escrow.get_destination(), "freelancer");
     let mut state = try_or_trace!(State::load(escrow), "state");
@@ -268,7 +269,7 @@ fn escrow(ctx: EscrowFinishContext) -> FinishResult {
     // passed, the next EscrowFinish releases regardless of its intent. Checked here,
     // before any state mutation, so no intent (confirm, deconfirm, or dispute) can
     // clear a condition that is already met.
-    if try_or_trace!(deadline_release(&state), "ledger_time") {
+    if try_or_trace!(deadline_release(&exec, &state), "ledger_time") {
         return FinishResult::succeed();
     }
 
@@ -281,7 +282,7 @@ fn escrow(ctx: EscrowFinishContext) -> FinishResult {
         ) => {
             state.set_confirmation(party_of(role), intent == Intent::Confirm);
             let release = state.client_confirmed() && state.freelancer_confirmed()
-                || try_or_trace!(deadline_release(&state), "ledger_time");
+                || try_or_trace!(deadline_release(&exec, &state), "ledger_time");
             try_or_trace!(state.persist(), "persist");
             (release as i32).into()
         }
@@ -3,6 +3,7 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
+use xrpl_common_stdlib::assert::ensure;
 use xrpl_common_stdlib::host::trace::{DataRepr, trace_data, trace_num};
 use xrpl_common_stdlib::host::{Result::Err, Result::Ok};
 use xrpl_common_stdlib::keylets::credential_keylet;
@@ -28,10 +29,7 @@ fn kyc_finish(ctx: EscrowFinishContext) -> FinishResult {
             let slot = unsafe {
                 xrpl_common_stdlib::host::cache_ledger_obj(keylet.as_ptr(), keylet.len(), 0)
             };
-            if slot < 0 {
-                let _ = trace_num("CACHE ERROR", i64::from(slot));
-                return FinishResult::reject();
-            };
+            ensure!(slot >= 0, FinishResult::reject());
             FinishResult::succeed() // <-- Finish the escrow to indicate a successful outcome
         }
         Err(e) => {
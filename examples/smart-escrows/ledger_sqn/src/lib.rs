@@ -3,27 +3,29 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
-use xrpl_common_stdlib::host;
-use xrpl_common_stdlib::host::error_codes::match_result_code_with_expected_bytes;
+use xrpl_common_stdlib::host::chain::{LedgerBoundError, require_min_ledger};
 use xrpl_common_stdlib::host::trace::trace_num;
 use xrpl_escrow_stdlib::EscrowFinishContext;
 use xrpl_macros::smart_escrow;
 
+/// The first ledger in which the escrow can be finished.
+const MIN_LEDGER_SEQUENCE: u32 = 5;
+
 #[smart_escrow]
 fn check_ledger_sqn(_ctx: EscrowFinishContext) -> i32 {
-    unsafe {
-        let mut ledger_sqn_buffer = [0u8; 4];
-        let result_code =
-            host::get_ledger_sqn(ledger_sqn_buffer.as_mut_ptr(), ledger_sqn_buffer.len());
-
-        match_result_code_with_expected_bytes(result_code, 4, || {
-            Some(result_code) // <-- Move the value into a buffer
-        })
-        .unwrap()
-        .unwrap();
-
-        let ledger_sequence = u32::from_be_bytes(ledger_sqn_buffer);
-        let _ = trace_num("Ledger Sequence", ledger_sequence as i64);
-        (ledger_sequence >= 5) as i32 // Return 1 if true (successful outcome), 0 if false (failed outcome)
+    match require_min_ledger(MIN_LEDGER_SEQUENCE) {
+        Ok(ledger_sequence) => {
+            let _ = trace_num("Ledger Sequence", ledger_sequence as i64);
+            1 // Successful outcome
+        }
+        Err(LedgerBoundError::TooEarly(ledger_sequence))
+        | Err(LedgerBoundError::TooLate(ledger_sequence)) => {
+            let _ = trace_num("Ledger sequence out of range", ledger_sequence as i64);
+            0 // Failed outcome
+        }
+        Err(LedgerBoundError::Host(e)) => {
+            let _ = trace_num("Ledger sequence check failed", e.code() as i64);
+            0 // Failed outcome
+        }
     }
 }
@@ -3,7 +3,7 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
-use xrpl_common_stdlib::fields::locator::Locator;
+use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
 use xrpl_common_stdlib::host::get_tx_nested_field;
 use xrpl_common_stdlib::host::trace::{DataRepr, trace_data, trace_num};
 use xrpl_common_stdlib::host::{Error, Result, Result::Err, Result::Ok};
@@ -19,9 +19,13 @@ use xrpl_macros::smart_escrow;
 pub fn get_first_memo() -> Result<Option<ContractData>> {
     let mut data: ContractData = [0; XRPL_CONTRACT_DATA_SIZE];
     let mut locator = Locator::new();
-    locator.pack(sfield::Memos);
-    locator.pack(0);
-    locator.pack(sfield::MemoData);
+    let packed = locator
+        .pack(sfield::Memos)
+        .and_then(|_| locator.pack(LocatorEntry::Index(0)))
+        .and_then(|_| locator.pack(sfield::MemoData));
+    if let Err(e) = packed {
+        return Err(e);
+    }
     let result_code = unsafe {
         get_tx_nested_field(
             locator.as_ptr(),
@@ -4,6 +4,7 @@
 extern crate std;
 
 // Generic XRPL primitives.
+use xrpl_common_stdlib::contract_metadata;
 use xrpl_common_stdlib::ctx::SmartFeatureContext;
 use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
 use xrpl_common_stdlib::host::trace::trace_num;
@@ -13,6 +14,9 @@ use xrpl_escrow_stdlib::EscrowFinishContext;
 use xrpl_macros::r_address;
 use xrpl_macros::smart_escrow;
 
+// Lets tooling identify this contract from its bytecode (see `xrpl_common_stdlib::metadata`).
+contract_metadata! { name: "notary", version: "0.1.0", abi: 1 }
+
 // The notary account that is authorized to complete escrows
 // Using example notary account for testing
 const NOTARY_ACCOUNT: AccountID = r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
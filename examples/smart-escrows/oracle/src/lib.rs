@@ -3,75 +3,61 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
-use xrpl_common_stdlib::fields::locator::Locator;
-use xrpl_common_stdlib::host::error_codes::match_result_code;
-use xrpl_common_stdlib::host::trace::{DataRepr, trace_data, trace_num};
+use xrpl_common_stdlib::currency;
+use xrpl_common_stdlib::host::trace::trace_num;
 use xrpl_common_stdlib::host::{Result, Result::Err, Result::Ok};
-use xrpl_common_stdlib::keylets::oracle_keylet;
+use xrpl_common_stdlib::objects::oracle::Oracle;
+use xrpl_common_stdlib::objects::traits::OracleFields;
 use xrpl_common_stdlib::r_address;
 use xrpl_common_stdlib::types::account_id::AccountID;
-use xrpl_common_stdlib::{host, sfield};
+use xrpl_common_stdlib::types::currency::Currency;
+use xrpl_common_stdlib::types::document_id::DocumentId;
 use xrpl_escrow_stdlib::{EscrowFinishContext, FinishResult};
 use xrpl_macros::smart_escrow;
 
 const ORACLE_OWNER: AccountID = r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
-const ORACLE_DOCUMENT_ID: u32 = 1;
+const ORACLE_DOCUMENT_ID: DocumentId = DocumentId::new(1);
 
-pub fn get_price_from_oracle(slot: i32) -> Result<u64> {
-    let mut locator = Locator::new();
-    locator.pack(sfield::PriceDataSeries);
-    locator.pack(0);
-    locator.pack(sfield::AssetPrice);
+/// XRP is encoded as the all-zero currency code, which `currency!` does not accept.
+const XRP: Currency = Currency([0; 20]);
+const USD: Currency = currency!("USD");
 
-    let mut data: [u8; 8] = [0; 8];
-    let result_code = unsafe {
-        host::get_ledger_obj_nested_field(
-            slot,
-            locator.as_ptr(),
-            locator.num_packed_bytes(),
-            data.as_mut_ptr(),
-            data.len(),
-        )
-    };
-    let _ = trace_data("get_price_from_oracle: data=", &data, DataRepr::AsHex);
+/// Oracle prices older than this many seconds are ignored.
+const MAX_PRICE_AGE: u32 = 300;
 
-    let asset_price = match match_result_code(result_code, || data) {
-        Ok(asset_bytes) => {
-            let price = u64::from_le_bytes(asset_bytes);
+/// Returns the oracle's XRP/USD `AssetPrice`, or `None` if it is stale or not published.
+pub fn get_price_from_oracle(oracle: &Oracle) -> Result<Option<u64>> {
+    match oracle.latest_fresh_price(&XRP, &USD, MAX_PRICE_AGE) {
+        Ok(Some(entry)) => {
+            let price = entry.asset_price.unwrap_or(0);
             let _ = trace_num("get_price_from_oracle: asset_price=", price as i64);
-            price
+            Ok(Some(price))
+        }
+        Ok(None) => {
+            let _ = trace_num("get_price_from_oracle: no fresh XRP/USD price", 0);
+            Ok(None)
         }
         Err(error) => {
             let _ = trace_num("Error getting asset_price", error.code() as i64);
-            return Err(error); // Must return to short circuit.
+            Err(error)
         }
-    };
-    Ok(asset_price)
+    }
 }
 
 #[smart_escrow]
 fn oracle_finish(_ctx: EscrowFinishContext) -> FinishResult {
-    let oracle_keylet = match oracle_keylet(&ORACLE_OWNER, ORACLE_DOCUMENT_ID) {
-        Ok(keylet) => keylet,
+    let oracle = match Oracle::load(&ORACLE_OWNER, ORACLE_DOCUMENT_ID) {
+        Ok(oracle) => oracle,
         Err(error) => {
-            let _ = trace_num("finish: oracle_keylet error_code=", error.code() as i64);
-            return error.code().into();
+            let _ = trace_num("finish: Oracle::load error_code=", error.code() as i64);
+            return FinishResult::reject();
         }
     };
+    let _ = trace_num("finish: cached oracle slot=", oracle.slot_num as i64);
 
-    let slot: i32;
-    unsafe {
-        slot = host::cache_ledger_obj(oracle_keylet.as_ptr(), oracle_keylet.len(), 0);
-        let _ = trace_num("finish: cache_ledger_obj slot=", slot as i64);
-
-        if slot < 0 {
-            let _ = trace_num("finish: cache_ledger_obj failed, returning 0", 0);
-            return FinishResult::reject();
-        };
-    }
-
-    let price = match get_price_from_oracle(slot) {
-        Ok(v) => v,
+    let price = match get_price_from_oracle(&oracle) {
+        Ok(Some(v)) => v,
+        Ok(None) => return FinishResult::reject(),
         Err(e) => return e.code().into(),
     };
 
@@ -26,4 +26,9 @@ echo "🧪 Running native workspace tests..."
 # Run tests on the native workspace
 cargo test --workspace
 
+echo "🧪 Running feature-gated tests..."
+# Tests behind optional features are not part of the default workspace run
+cargo test -p xrpl-common-stdlib --features sfield-names
+cargo test -p xrpl-common-stdlib --features chunked-trace
+
 echo "✅ Build and test workflow completed successfully!"
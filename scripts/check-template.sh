@@ -0,0 +1,47 @@
+#!/bin/bash
+# Template checking script
+# Renders templates/smart-escrow against this checkout and builds it for wasm32v1-none,
+# so changes to the stdlib that break the template are caught without running cargo-generate.
+
+set -euo pipefail
+
+# Change to the repository root directory (where this script's grandparent directory is located)
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+REPO_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+cd "$REPO_ROOT"
+
+OUT_DIR="$(mktemp -d)"
+trap 'rm -rf "$OUT_DIR"' EXIT
+
+echo "🔧 Rendering templates/smart-escrow into $OUT_DIR"
+cp -r templates/smart-escrow/. "$OUT_DIR"
+rm "$OUT_DIR/cargo-generate.toml"
+
+# Keep the `path` branch of the dependency block and fill in the placeholders.
+render() {
+    perl -0pi -e '
+        s/\{% if stdlib_source == "path" -%\}\n(.*?)\{%- else -%\}.*?\{%- endif %\}\n/$1/s;
+        s/\{\{stdlib_path\}\}/$ENV{REPO_ROOT}/g;
+        s/\{\{project-name\}\}/template-check/g;
+        s/\{\{crate_name\}\}/template_check/g;
+    ' "$1"
+}
+export REPO_ROOT
+for file in Cargo.toml README.md src/lib.rs; do
+    render "$OUT_DIR/$file"
+done
+
+if grep -rq "{{\|{%" "$OUT_DIR" --include=*.rs --include=*.toml; then
+    echo "❌ Unrendered placeholders left in the template"
+    exit 1
+fi
+
+echo "🔧 Building the rendered template"
+(cd "$OUT_DIR" && cargo build --release && cargo clippy -- -D warnings)
+
+test -f "$OUT_DIR/target/wasm32v1-none/release/template_check.wasm" || {
+    echo "❌ Template did not produce template_check.wasm"
+    exit 1
+}
+
+echo "✅ Template check passed!"
@@ -0,0 +1,32 @@
+#!/bin/bash
+# WASM binary size report
+# Lists the size of every release contract built from examples/ and e2e-tests/, largest first.
+#
+# Usage:
+#   ./scripts/build.sh release && ./scripts/wasm-size-report.sh
+#
+# To see the effect of the `opt-size` feature, enable it on the xrpl-common-stdlib dependency of a
+# contract, rebuild, and compare the two reports.
+
+set -euo pipefail
+
+# Change to the repository root directory (where this script's grandparent directory is located)
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+REPO_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+cd "$REPO_ROOT"
+
+shopt -s nullglob
+wasm_files=(
+    examples/target/wasm32v1-none/release/*.wasm
+    e2e-tests/target/wasm32v1-none/release/*.wasm
+)
+
+if [[ ${#wasm_files[@]} -eq 0 ]]; then
+    echo "❌ No release WASM files found. Run ./scripts/build.sh release first."
+    exit 1
+fi
+
+echo "📦 WASM binary sizes (release):"
+for wasm in "${wasm_files[@]}"; do
+    printf "%10d  %s\n" "$(wc -c < "$wasm")" "$wasm"
+done | sort -rn
@@ -0,0 +1,21 @@
+// `no_std` only applies to the WebAssembly build; native builds (e.g. `cargo test --target <host>`)
+// keep the standard library and its panic handler. The WebAssembly panic handler comes from
+// `xrpl-common-stdlib` through `xrpl-escrow-stdlib`.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+use xrpl_escrow_stdlib::host::trace::trace;
+use xrpl_escrow_stdlib::{EscrowFinishContext, FinishResult, smart_escrow};
+
+/// Decides whether the escrow may be finished.
+///
+/// `#[smart_escrow]` exports this as the `finish` function the XRPL host calls. Return
+/// `FinishResult::succeed()` to release the escrow, or `FinishResult::reject()` (or an error
+/// code) to keep it locked.
+#[smart_escrow]
+fn finish_escrow(_ctx: EscrowFinishContext) -> FinishResult {
+    let _ = trace("{{project-name}}: finish called");
+    FinishResult::succeed()
+}
@@ -117,6 +117,7 @@ async function main() {
     UINT160: "Hash160",
     UINT192: "Hash192",
     UINT256: "Hash256",
+    VECTOR256: "StandardVector256",
     AMOUNT: "Amount",
     ACCOUNT: "AccountID",
     VL: "StandardBlob",
@@ -124,6 +125,7 @@ async function main() {
     ISSUE: "Issue",
     ARRAY: "Array",
     OBJECT: "Object",
+    XCHAIN_BRIDGE: "XChainBridge",
   }
 
   // Custom type overrides for specific field names
@@ -131,6 +133,7 @@ async function main() {
   const customFieldTypes = {
     TransactionType: "TransactionType",
     Condition: "ConditionBlob",
+    CredentialType: "CredentialTypeBlob",
     Fulfillment: "FulfillmentBlob",
     FinishFunction: "WasmBlob",
     PublicKey: "PublicKeyBlob",
@@ -221,6 +224,28 @@ async function main() {
     }
   }
 
+  // Reverse lookup for traces and debugging. Behind a feature so production
+  // builds don't carry the name strings.
+  addLine("")
+  addLine(
+    "/// Returns the name of the field with serialized code `code`, or `None` if there is no such field.",
+  )
+  addLine("///")
+  addLine(
+    "/// Only available with the `sfield-names` feature, which adds every field name to the binary.",
+  )
+  addLine('#[cfg(feature = "sfield-names")]')
+  addLine("pub const fn name(code: i32) -> Option<&'static str> {")
+  addLine("    match code {")
+  for (const entry of sfieldEntries) {
+    const fieldCode =
+      parseInt(stypeMap[entry.xrplType]) * 2 ** 16 + entry.ordinal
+    addLine(`        ${fieldCode} => Some("${entry.fieldName}"),`)
+  }
+  addLine("        _ => None,")
+  addLine("    }")
+  addLine("}")
+
   ////////////////////////////////////////////////////////////////////////
   //  Serialized type processing (STI_* type codes)
   ////////////////////////////////////////////////////////////////////////
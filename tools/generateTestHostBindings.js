@@ -4,7 +4,7 @@ if (process.argv.length != 2) {
 }
 
 ////////////////////////////////////////////////////////////////////////
-//  Parse host bindings trait and update the other 3 files to match
+//  Parse the host capability traits and update the other 4 files to match
 ////////////////////////////////////////////////////////////////////////
 const path = require("path")
 const fs = require("fs/promises")
@@ -16,6 +16,7 @@ const FILES = {
   wasm: "host_bindings_wasm.rs",
   empty: "host_bindings_empty.rs",
   test: "host_bindings_test.rs",
+  mock: "host_bindings_trait/mock.rs",
 }
 
 async function readFile(filename) {
@@ -34,9 +35,30 @@ async function writeFile(filename, content) {
 }
 
 /**
- * Parse trait method signatures from host_bindings_trait.rs.
- * Extracts method name, parameters (excluding &self), and return type.
+ * Parse the capability traits (`pub trait LedgerHeader { ... }`, ...) from host_bindings_trait.rs,
+ * in file order. `HostBindings` itself only combines them and has no methods.
  * @param {string} content - The content of host_bindings_trait.rs
+ * @returns {Array<{name: string, methods: Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>}>}
+ */
+function parseCapabilities(content) {
+  const regex = /^pub trait ([A-Za-z0-9_]+) \{\n([\s\S]*?)^\}/gm
+
+  const capabilities = []
+  let match
+  while ((match = regex.exec(content)) !== null) {
+    capabilities.push({ name: match[1], methods: parseTraitMethods(match[2]) })
+  }
+
+  if (capabilities.length === 0) {
+    throw new Error(`No capability traits found in ${FILES.trait}`)
+  }
+  return capabilities
+}
+
+/**
+ * Parse trait method signatures from the body of one capability trait.
+ * Extracts method name, parameters (excluding &self), and return type.
+ * @param {string} content - The body of a trait in host_bindings_trait.rs
  * @returns {Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>}
  */
 function parseTraitMethods(content) {
@@ -136,52 +158,6 @@ function parseExportMacro(content) {
   return functions
 }
 
-/**
- * Parse impl HostBindings for WasmHostBindings methods.
- * Used for verification purposes (not currently called but kept for future use).
- * @param {string} content - The content of host_bindings_wasm.rs
- * @returns {Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>}
- */
-function parseWasmImpl(content) {
-  // Find the impl block
-  const implMatch = content.match(
-    /impl HostBindings for WasmHostBindings\s*\{([\s\S]*?)\n\}/,
-  )
-  if (!implMatch) {
-    return []
-  }
-
-  const implContent = implMatch[1]
-
-  // Match unsafe fn declarations
-  const regex =
-    /unsafe fn ([A-Za-z0-9_]+)\s*\(\s*&self\s*(?:,\s*([^)]*))?\)\s*->\s*([A-Za-z0-9]+)/g
-
-  const methods = []
-  let match
-  while ((match = regex.exec(implContent)) !== null) {
-    const name = match[1]
-    const paramsStr = match[2] || ""
-    const returnType = match[3]
-
-    const params = paramsStr
-      .split(",")
-      .map((p) => p.trim())
-      .filter((p) => p.length > 0)
-      .map((p) => {
-        const parts = p.split(":")
-        return {
-          name: parts[0].trim(),
-          type: parts.slice(1).join(":").trim(),
-        }
-      })
-
-    methods.push({ name, params, returnType })
-  }
-
-  return methods
-}
-
 /**
  * Generate the export_host_functions! macro content for a file.
  * Groups functions by category and formats them with proper indentation.
@@ -304,7 +280,7 @@ function generateExternBlockContent(methods) {
 }
 
 /**
- * Generate the impl HostBindings for WasmHostBindings block content.
+ * Generate the body of one `impl <Capability> for WasmHostBindings` block.
  * Each method calls the corresponding host_defined_functions function.
  * @param {Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>} methods - Parsed methods from trait
  * @returns {string} The formatted impl block content
@@ -362,11 +338,13 @@ function generateImplBlockContent(methods) {
 }
 
 /**
- * Update host_bindings_wasm.rs by replacing the extern block, impl block, and export macro.
- * @param {Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>} methods - Parsed methods from trait
+ * Update host_bindings_wasm.rs by replacing the extern block, the capability impl blocks, and the
+ * export macro.
+ * @param {Array<{name: string, methods: Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>}>} capabilities - Parsed capability traits
  */
-async function updateWasmBindings(methods) {
+async function updateWasmBindings(capabilities) {
   let content = await readFile(FILES.wasm)
+  const methods = capabilities.flatMap((capability) => capability.methods)
 
   // 1. Update extern "C" block
   const externRegex =
@@ -381,20 +359,23 @@ async function updateWasmBindings(methods) {
     `${externMatch[1]}\n${newExternContent}\n    }` +
     content.substring(externMatch.index + externMatch[0].length)
 
-  // 2. Update impl HostBindings for WasmHostBindings block
-  const implRegex =
-    /(\/\/\/ WASM implementation of HostBindings\.\nimpl HostBindings for WasmHostBindings \{)([\s\S]*?)(\n\})/
-  const implMatch = content.match(implRegex)
-  if (!implMatch) {
-    throw new Error(
-      "Could not find impl HostBindings for WasmHostBindings in host_bindings_wasm.rs",
+  // 2. Update each `impl <Capability> for WasmHostBindings` block
+  for (const capability of capabilities) {
+    const implRegex = new RegExp(
+      `(impl ${capability.name} for WasmHostBindings \\{)([\\s\\S]*?)(\\n\\})`,
     )
+    const implMatch = content.match(implRegex)
+    if (!implMatch) {
+      throw new Error(
+        `Could not find impl ${capability.name} for WasmHostBindings in ${FILES.wasm}`,
+      )
+    }
+    const newImplContent = generateImplBlockContent(capability.methods)
+    content =
+      content.substring(0, implMatch.index) +
+      `${implMatch[1]}\n${newImplContent}}` +
+      content.substring(implMatch.index + implMatch[0].length)
   }
-  const newImplContent = generateImplBlockContent(methods)
-  content =
-    content.substring(0, implMatch.index) +
-    `${implMatch[1]}\n${newImplContent}}` +
-    content.substring(implMatch.index + implMatch[0].length)
 
   // 3. Update export_host_functions! macro (reuse existing logic)
   const macroRegex = /(export_host_functions!\s*\{)([\s\S]*?)(\n\})/g
@@ -421,6 +402,60 @@ async function updateWasmBindings(methods) {
   await writeFile(FILES.wasm, content)
 }
 
+/**
+ * Convert a capability trait name to the snake_case name of its ComposedHost field.
+ * @param {string} name - e.g. "LedgerHeader"
+ * @returns {string} e.g. "ledger_header"
+ */
+function snakeCase(name) {
+  return name.replace(/([a-z0-9])([A-Z])/g, "$1_$2").toLowerCase()
+}
+
+/**
+ * Generate the capability list passed to callbacks by the `host_functions!` macro in mock.rs.
+ * @param {Array<{name: string, methods: Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>}>} capabilities - Parsed capability traits
+ * @returns {string} The formatted list
+ */
+function generateHostFunctionsContent(capabilities) {
+  const lines = []
+
+  for (const capability of capabilities) {
+    const field = snakeCase(capability.name)
+    lines.push(`            ${capability.name} via ${field}, with_${field} {`)
+    for (const method of capability.methods) {
+      const params = method.params.map((p) => `${p.name}: ${p.type}`).join(", ")
+      lines.push(
+        `                fn ${method.name}(${params}) -> ${method.returnType};`,
+      )
+    }
+    lines.push(`            }`)
+  }
+
+  return lines.join("\n")
+}
+
+/**
+ * Update the capability list in the `host_functions!` macro of mock.rs, which generates
+ * MockHostBindings, ComposedHost and the recording/replaying hosts.
+ * @param {Array<{name: string, methods: Array<{name: string, params: Array<{name: string, type: string}>, returnType: string}>}>} capabilities - Parsed capability traits
+ */
+async function updateMockHostFunctions(capabilities) {
+  const content = await readFile(FILES.mock)
+
+  const listRegex = /(\$callback! \{\n)([\s\S]*?)(\n        \}\n    \};)/
+  const listMatch = content.match(listRegex)
+  if (!listMatch) {
+    throw new Error(`Could not find the host_functions! list in ${FILES.mock}`)
+  }
+
+  const newContent =
+    content.substring(0, listMatch.index) +
+    `${listMatch[1]}${generateHostFunctionsContent(capabilities)}${listMatch[3]}` +
+    content.substring(listMatch.index + listMatch[0].length)
+
+  await writeFile(FILES.mock, newContent)
+}
+
 /**
  * Update a file by replacing the export_host_functions! macro content.
  * Finds the macro invocation (not definition) and replaces its content with generated signatures.
@@ -473,24 +508,28 @@ async function updateExportMacro(
 
 /**
  * Main entry point.
- * Reads the trait file as source of truth and updates the 3 derived files:
- * - host_bindings_wasm.rs: extern block, impl block, and export macro
+ * Reads the trait file as source of truth and updates the 4 derived files:
+ * - host_bindings_wasm.rs: extern block, capability impl blocks, and export macro
  * - host_bindings_empty.rs: stub implementations with underscore-prefixed params
  * - host_bindings_test.rs: test implementations
+ * - host_bindings_trait/mock.rs: the host_functions! capability list
  */
 async function main() {
   console.log("Reading host bindings trait file (source of truth)...")
 
   const traitContent = await readFile(FILES.trait)
 
-  // Parse trait methods (source of truth)
-  const traitMethods = parseTraitMethods(traitContent)
-  console.log(`  Found ${traitMethods.length} methods in ${FILES.trait}`)
+  // Parse capability traits (source of truth)
+  const capabilities = parseCapabilities(traitContent)
+  const traitMethods = capabilities.flatMap((capability) => capability.methods)
+  console.log(
+    `  Found ${traitMethods.length} methods in ${capabilities.length} capability traits in ${FILES.trait}`,
+  )
 
   console.log("\nUpdating derived files...")
 
-  // Update host_bindings_wasm.rs (extern block, impl block, and export macro)
-  await updateWasmBindings(traitMethods)
+  // Update host_bindings_wasm.rs (extern block, capability impls, and export macro)
+  await updateWasmBindings(capabilities)
 
   // Update host_bindings_empty.rs (with underscore prefix for unused params)
   await updateExportMacro(FILES.empty, traitMethods, true)
@@ -498,8 +537,11 @@ async function main() {
   // Update host_bindings_test.rs (without underscore prefix)
   await updateExportMacro(FILES.test, traitMethods, false)
 
+  // Update host_bindings_trait/mock.rs (capability list)
+  await updateMockHostFunctions(capabilities)
+
   console.log(
-    `\n✅ Successfully updated ${traitMethods.length} function signatures in 3 files.`,
+    `\n✅ Successfully updated ${traitMethods.length} function signatures in 4 files.`,
   )
 }
 
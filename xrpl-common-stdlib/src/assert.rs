@@ -0,0 +1,381 @@
+//! Checks that explain their failures in the trace log.
+//!
+//! A panic in a contract halts it with a bare `unreachable` trap: the panic message is never
+//! shown, and the transaction fails with no hint of which check tripped. This module offers two
+//! kinds of check that both trace the failed expression through the host first.
+//!
+//! [`ensure!`] and [`ensure_eq!`] return early with an error instead of panicking, so the contract
+//! rejects the transaction cleanly and its return code says why. The error is converted with
+//! `From`, so the same macro works in an entry point returning a `FinishResult`, a helper
+//! returning [`host::Result`](crate::host::Result), or a function returning a raw `i32` code:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::assert::{ensure, ensure_eq};
+//! use xrpl_common_stdlib::host::{Error, Result};
+//!
+//! fn check_release(sequence: u32, expected: u32, amount_drops: u64) -> Result<u64> {
+//!     ensure_eq!(sequence, expected, Error::InvalidParams);
+//!     ensure!(amount_drops > 0, Error::InvalidParams);
+//!     Result::Ok(amount_drops)
+//! }
+//! # let _ = check_release(1, 1, 1);
+//! ```
+//!
+//! The failure is traced at [`Level::Error`](crate::log::Level::Error) through the
+//! [`log`](crate::log) machinery, so it respects the `log-*` features and
+//! [`log::set_limit`](crate::log::set_limit).
+//!
+//! With the `assert-macros` feature, [`assert!`], [`assert_eq!`] and [`assert_ne!`] are also
+//! available. They are for invariants that should never fail: they trace the expression and the
+//! values involved, then panic.
+//!
+//! ```no_run
+//! # #[cfg(feature = "assert-macros")]
+//! # {
+//! use xrpl_common_stdlib::assert;
+//!
+//! let expected_sequence = 7u32;
+//! # let sequence = 7u32;
+//! assert::assert_eq!(sequence, expected_sequence);
+//! // On failure the xrpld log shows:
+//! //   Assertion failed: sequence != expected_sequence
+//! //     left:  8
+//! //     right:  7
+//! # }
+//! ```
+//!
+//! Values are traced with [`TraceValue`], so both operands of `ensure_eq!` and of the assertion
+//! macros must implement it as well as `PartialEq`.
+//!
+//! The assertions only check on `wasm32`. Other targets evaluate the expressions, keeping their
+//! side effects, and skip the comparison, so native test builds against stub host data do not
+//! trip over values that only make sense on chain. `ensure!` and `ensure_eq!` are ordinary control
+//! flow and behave the same on every target.
+//!
+//! # Debug-only code
+//!
+//! [`debug_only!`] wraps statements that should run in a debug (simulator) build of a contract
+//! but not in the release WASM that is deployed, such as extra traces or assertions on values a
+//! release build has already checked some other way:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::assert::debug_only;
+//! use xrpl_common_stdlib::host::trace::trace_num;
+//!
+//! # let sequence = 7u32;
+//! debug_only! {
+//!     let _ = trace_num("sequence:", sequence as i64);
+//! }
+//! ```
+//!
+//! The statements run when the contract is built with `debug_assertions`, which is on for the dev
+//! profile and off for release. They are still type-checked in release, then dropped as dead code,
+//! so the host functions they call are not even imported by the release WASM. The
+//! `keep-debug-only` feature keeps them in release builds too, for chasing a problem that only
+//! shows up in an optimized build.
+
+pub use crate::log::TraceValue;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ensure {
+    ($cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            $crate::__log_at!(
+                $crate::log::Level::Error,
+                ::core::concat!("Check failed: ", ::core::stringify!($cond))
+            );
+            return ::core::convert::From::from($err);
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ensure_eq {
+    ($left:expr, $right:expr, $err:expr $(,)?) => {{
+        let left_val = $left;
+        let right_val = $right;
+        if left_val != right_val {
+            $crate::__log_at!(
+                $crate::log::Level::Error,
+                ::core::concat!(
+                    "Check failed: ",
+                    ::core::stringify!($left),
+                    " == ",
+                    ::core::stringify!($right)
+                )
+            );
+            $crate::__log_at!($crate::log::Level::Error, "  left: ", left_val);
+            $crate::__log_at!($crate::log::Level::Error, "  right: ", right_val);
+            return ::core::convert::From::from($err);
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_only {
+    ($($body:tt)*) => {
+        if ::core::cfg!(debug_assertions) || $crate::assert::KEEP_DEBUG_ONLY {
+            $($body)*
+        }
+    };
+}
+
+#[cfg(feature = "assert-macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert {
+    ($cond:expr $(,)?) => {{
+        let cond_val: bool = $cond;
+        #[cfg(target_arch = "wasm32")]
+        {
+            if !cond_val {
+                let _ = $crate::host::trace::trace(::core::concat!(
+                    "Assertion failed: ",
+                    ::core::stringify!($cond)
+                ));
+                ::core::panic!("assertion failed: {}", ::core::stringify!($cond));
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = cond_val;
+    }};
+    ($cond:expr, $($arg:tt)+) => {{
+        let cond_val: bool = $cond;
+        #[cfg(target_arch = "wasm32")]
+        {
+            if !cond_val {
+                let _ = $crate::host::trace::trace(::core::concat!(
+                    "Assertion failed: ",
+                    ::core::stringify!($cond)
+                ));
+                let _ = $crate::host::trace::trace("  message: (see panic message for details)");
+                ::core::panic!(
+                    "assertion failed: {}: {}",
+                    ::core::stringify!($cond),
+                    ::core::format_args!($($arg)+)
+                );
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = cond_val;
+    }};
+}
+
+#[cfg(feature = "assert-macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left_val = $left;
+        let right_val = $right;
+        #[cfg(target_arch = "wasm32")]
+        {
+            if left_val != right_val {
+                let _ = $crate::host::trace::trace(::core::concat!(
+                    "Assertion failed: ",
+                    ::core::stringify!($left),
+                    " != ",
+                    ::core::stringify!($right)
+                ));
+                let _ = $crate::log::TraceValue::trace_value(&left_val, "  left: ");
+                let _ = $crate::log::TraceValue::trace_value(&right_val, "  right: ");
+                ::core::panic!(
+                    "assertion failed: {} != {}",
+                    ::core::stringify!($left),
+                    ::core::stringify!($right)
+                );
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (left_val, right_val);
+    }};
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        let left_val = $left;
+        let right_val = $right;
+        #[cfg(target_arch = "wasm32")]
+        {
+            if left_val != right_val {
+                let _ = $crate::host::trace::trace(::core::concat!(
+                    "Assertion failed: ",
+                    ::core::stringify!($left),
+                    " != ",
+                    ::core::stringify!($right)
+                ));
+                let _ = $crate::log::TraceValue::trace_value(&left_val, "  left: ");
+                let _ = $crate::log::TraceValue::trace_value(&right_val, "  right: ");
+                let _ = $crate::host::trace::trace("  message: (see panic message for details)");
+                ::core::panic!(
+                    "assertion failed: {} != {}: {}",
+                    ::core::stringify!($left),
+                    ::core::stringify!($right),
+                    ::core::format_args!($($arg)+)
+                );
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (left_val, right_val);
+    }};
+}
+
+#[cfg(feature = "assert-macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_ne {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left_val = $left;
+        let right_val = $right;
+        #[cfg(target_arch = "wasm32")]
+        {
+            if left_val == right_val {
+                let _ = $crate::host::trace::trace(::core::concat!(
+                    "Assertion failed: ",
+                    ::core::stringify!($left),
+                    " == ",
+                    ::core::stringify!($right)
+                ));
+                let _ = $crate::log::TraceValue::trace_value(&left_val, "  value: ");
+                ::core::panic!(
+                    "assertion failed: {} == {}",
+                    ::core::stringify!($left),
+                    ::core::stringify!($right)
+                );
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (left_val, right_val);
+    }};
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        let left_val = $left;
+        let right_val = $right;
+        #[cfg(target_arch = "wasm32")]
+        {
+            if left_val == right_val {
+                let _ = $crate::host::trace::trace(::core::concat!(
+                    "Assertion failed: ",
+                    ::core::stringify!($left),
+                    " == ",
+                    ::core::stringify!($right)
+                ));
+                let _ = $crate::log::TraceValue::trace_value(&left_val, "  value: ");
+                let _ = $crate::host::trace::trace("  message: (see panic message for details)");
+                ::core::panic!(
+                    "assertion failed: {} == {}: {}",
+                    ::core::stringify!($left),
+                    ::core::stringify!($right),
+                    ::core::format_args!($($arg)+)
+                );
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (left_val, right_val);
+    }};
+}
+
+/// Returns `From::from(err)` from the enclosing function, after tracing the condition, unless it
+/// holds.
+#[doc(inline)]
+pub use crate::__ensure as ensure;
+
+/// Returns `From::from(err)` from the enclosing function, after tracing both values, unless they
+/// are equal.
+#[doc(inline)]
+pub use crate::__ensure_eq as ensure_eq;
+
+/// Whether [`debug_only!`] blocks run in release builds, i.e. whether the `keep-debug-only`
+/// feature is enabled.
+pub const KEEP_DEBUG_ONLY: bool = cfg!(feature = "keep-debug-only");
+
+/// Runs the enclosed statements only in builds with `debug_assertions`, or with the
+/// `keep-debug-only` feature. See the [module documentation](self#debug-only-code).
+#[doc(inline)]
+pub use crate::__debug_only as debug_only;
+
+/// Asserts that a condition is true, tracing the condition before panicking if it is not.
+#[cfg(feature = "assert-macros")]
+#[doc(inline)]
+pub use crate::__assert as assert;
+
+/// Asserts that two values are equal, tracing both before panicking if they are not.
+#[cfg(feature = "assert-macros")]
+#[doc(inline)]
+pub use crate::__assert_eq as assert_eq;
+
+/// Asserts that two values differ, tracing the value before panicking if they are equal.
+#[cfg(feature = "assert-macros")]
+#[doc(inline)]
+pub use crate::__assert_ne as assert_ne;
+
+#[cfg(test)]
+mod tests {
+    use super::{ensure, ensure_eq};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::host::{Error, Result};
+
+    fn checked_ratio(numerator: u64, denominator: u64, version: u8) -> Result<u64> {
+        ensure_eq!(version, 2u8, Error::InvalidParams);
+        ensure!(denominator != 0, Error::InvalidParams);
+        Result::Ok(numerator / denominator)
+    }
+
+    fn checked_code(value: i32) -> i32 {
+        ensure!(value >= 0, Error::InvalidDecoding);
+        1
+    }
+
+    // The failure traces are not asserted on: the log features may compile them out.
+    fn mock_traces() -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_trace().returning(|_, _, _, _, _| 0);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        mock
+    }
+
+    #[test]
+    fn test_ensure_returns_error_when_check_fails() {
+        let _guard = setup_mock(mock_traces());
+
+        assert_eq!(checked_ratio(10, 5, 2).unwrap(), 2);
+        let result = checked_ratio(10, 0, 2);
+        assert_eq!(result.err().unwrap().code(), Error::InvalidParams.code());
+        let result = checked_ratio(10, 5, 1);
+        assert_eq!(result.err().unwrap().code(), Error::InvalidParams.code());
+    }
+
+    #[test]
+    fn test_ensure_converts_error_to_return_type() {
+        let _guard = setup_mock(mock_traces());
+
+        assert_eq!(checked_code(3), 1);
+        assert_eq!(checked_code(-1), Error::InvalidDecoding.code());
+    }
+
+    #[test]
+    fn test_debug_only_runs_in_debug_builds() {
+        let mut ran = false;
+        super::debug_only! {
+            ran = true;
+        }
+        assert_eq!(ran, cfg!(debug_assertions) || super::KEEP_DEBUG_ONLY);
+    }
+
+    #[cfg(feature = "assert-macros")]
+    #[test]
+    fn test_asserts_evaluate_operands_once_without_checking_natively() {
+        use crate::assert;
+        use crate::types::transaction_type::TransactionType;
+
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+        assert::assert!(next() == 2);
+        assert::assert_eq!(next(), 1, "sequence {}", 1);
+        assert::assert_ne!(TransactionType::EscrowFinish, TransactionType::EscrowFinish);
+        assert_eq!(calls, 2);
+    }
+}
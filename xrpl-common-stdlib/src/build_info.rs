@@ -0,0 +1,64 @@
+//! Build information, for checking which library build a deployed contract was compiled against.
+//!
+//! Only available with the `build_info` feature, so contracts that do not need it pay nothing for
+//! the strings below.
+//!
+//! Pair this with the release profile recommended in the repository `Cargo.toml` (`opt-level = "s"`,
+//! `lto = true`, `codegen-units = 1`, `panic = "abort"`). Contracts that are still too large can
+//! enable the `opt-size` feature (or just `compact-hex`), and `scripts/wasm-size-report.sh` lists the size of every built
+//! contract so the effect of either change can be measured.
+
+use crate::host::Result;
+use crate::host::trace::{trace, trace_num};
+
+/// Version of `xrpl-common-stdlib` the contract was built with.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Whether the `opt-size` feature is enabled.
+pub const OPT_SIZE: bool = cfg!(feature = "opt-size");
+
+/// Whether the `compact-hex` feature is enabled, either directly or through `opt-size`.
+pub const COMPACT_HEX: bool = cfg!(feature = "compact-hex");
+
+/// Whether the library was compiled with debug assertions (i.e. not a release build).
+pub const DEBUG_ASSERTIONS: bool = cfg!(debug_assertions);
+
+/// Writes the library version and build flags to the trace log.
+pub fn trace_build_info() -> Result<i32> {
+    let result = trace(concat!("xrpl-common-stdlib ", env!("CARGO_PKG_VERSION")));
+    if result.is_err() {
+        return result;
+    }
+    let result = trace_num("  opt-size:", OPT_SIZE as i64);
+    if result.is_err() {
+        return result;
+    }
+    let result = trace_num("  compact-hex:", COMPACT_HEX as i64);
+    if result.is_err() {
+        return result;
+    }
+    trace_num("  debug assertions:", DEBUG_ASSERTIONS as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+
+    #[test]
+    fn test_trace_build_info_traces_version_and_flags() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_trace()
+            .times(1)
+            .returning(|msg_ptr, msg_len, _, _, _| {
+                let msg = unsafe { core::slice::from_raw_parts(msg_ptr, msg_len) };
+                assert!(msg.ends_with(VERSION.as_bytes()));
+                msg_len as i32
+            });
+        mock.expect_trace_num().times(3).returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert!(trace_build_info().is_ok());
+    }
+}
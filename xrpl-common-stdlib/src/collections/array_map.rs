@@ -0,0 +1,161 @@
+//! A map with a fixed capacity.
+//!
+//! Lookups scan the entries in order, which for the handful of entries a contract keeps (a few
+//! accounts, a few currencies) is cheaper than hashing would be.
+//!
+//! ```
+//! use xrpl_common_stdlib::collections::ArrayMap;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! let mut shares: ArrayMap<AccountID, u32, 4> = ArrayMap::new();
+//! shares.insert(AccountID([1; 20]), 60).unwrap();
+//! shares.insert(AccountID([2; 20]), 40).unwrap();
+//! assert_eq!(shares.get(&AccountID([1; 20])), Some(&60));
+//! assert_eq!(shares.get(&AccountID([3; 20])), None);
+//! ```
+
+use core::fmt;
+
+use crate::collections::ArrayVec;
+use crate::host::Result;
+
+/// Up to `N` key-value pairs with distinct keys, in insertion order.
+#[derive(Clone, Copy)]
+pub struct ArrayMap<K: Copy + Eq, V: Copy, const N: usize> {
+    entries: ArrayVec<(K, V), N>,
+}
+
+impl<K: Copy + Eq, V: Copy, const N: usize> ArrayMap<K, V, N> {
+    /// Creates an empty map.
+    pub const fn new() -> Self {
+        ArrayMap {
+            entries: ArrayVec::new(),
+        }
+    }
+
+    /// Returns the number of entries.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of entries.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Sets the value for `key`, returning the value it replaces, if any.
+    ///
+    /// # Errors
+    ///
+    /// * `BufferTooSmall` - If `key` is new and the map is full; it is left unchanged
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        if let Some(existing) = self.get_mut(&key) {
+            return Result::Ok(Some(core::mem::replace(existing, value)));
+        }
+        self.entries.push((key, value)).map(|()| None)
+    }
+
+    /// Returns the value for `key`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Returns the value for `key` mutably.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Returns whether the map has a value for `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the entry for `key` and returns its value. Later entries keep their order.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        self.entries.remove(index).map(|(_, v)| v)
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over the entries, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the keys, in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values, in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.entries.iter().map(|(_, v)| v)
+    }
+}
+
+impl<K: Copy + Eq, V: Copy, const N: usize> Default for ArrayMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Copy + Eq + fmt::Debug, V: Copy + fmt::Debug, const N: usize> fmt::Debug
+    for ArrayMap<K, V, N>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::BUFFER_TOO_SMALL;
+
+    #[test]
+    fn test_insert_replaces_existing_keys() {
+        let mut map: ArrayMap<u8, u32, 2> = ArrayMap::new();
+        assert_eq!(map.insert(1, 10).unwrap(), None);
+        assert_eq!(map.insert(1, 11).unwrap(), Some(10));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&1), Some(&11));
+        assert!(!map.contains_key(&2));
+    }
+
+    #[test]
+    fn test_full_map_still_updates_existing_keys() {
+        let mut map: ArrayMap<u8, u32, 2> = ArrayMap::new();
+        map.insert(1, 10).unwrap();
+        map.insert(2, 20).unwrap();
+        assert_eq!(map.insert(3, 30).err().unwrap().code(), BUFFER_TOO_SMALL);
+        assert_eq!(map.insert(2, 21).unwrap(), Some(20));
+        *map.get_mut(&1).unwrap() += 1;
+        assert_eq!(map.values().copied().sum::<u32>(), 32);
+    }
+
+    #[test]
+    fn test_remove_keeps_insertion_order() {
+        let mut map: ArrayMap<u8, char, 4> = ArrayMap::new();
+        for (k, v) in [(3, 'c'), (1, 'a'), (2, 'b')] {
+            map.insert(k, v).unwrap();
+        }
+        assert_eq!(map.remove(&1), Some('a'));
+        assert_eq!(map.remove(&1), None);
+        assert!(map.keys().copied().eq([3, 2]));
+        assert_eq!(std::format!("{map:?}"), "{3: 'c', 2: 'b'}");
+        map.clear();
+        assert!(map.is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+//! A vector with a fixed capacity.
+//!
+//! ```
+//! use xrpl_common_stdlib::collections::ArrayVec;
+//!
+//! let mut prices: ArrayVec<u64, 4> = ArrayVec::new();
+//! prices.push(101).unwrap();
+//! prices.push(99).unwrap();
+//! assert_eq!(prices.as_slice(), &[101, 99]);
+//! assert_eq!(prices.get(2), None);
+//! ```
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::host::{Error, Result};
+
+/// Up to `N` values of `T`, stored inline.
+///
+/// `T` must be `Copy`, which covers the ledger value types (`AccountID`, `Hash256`, `Amount`,
+/// integers) and means no values ever need dropping.
+#[derive(Clone, Copy)]
+pub struct ArrayVec<T: Copy, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> ArrayVec<T, N> {
+    /// Creates an empty vector.
+    pub const fn new() -> Self {
+        ArrayVec {
+            items: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no values.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether no more values fit.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the maximum number of values.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Appends `value`.
+    ///
+    /// # Errors
+    ///
+    /// * `BufferTooSmall` - If the vector is full; it is left unchanged
+    pub fn push(&mut self, value: T) -> Result<()> {
+        if self.is_full() {
+            return Result::Err(Error::BufferTooSmall);
+        }
+        self.items[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+        Result::Ok(())
+    }
+
+    /// Appends every value of `values`.
+    ///
+    /// # Errors
+    ///
+    /// * `BufferTooSmall` - If the values do not all fit; the ones that fit are kept
+    pub fn extend_from_iter<I: IntoIterator<Item = T>>(&mut self, values: I) -> Result<()> {
+        for value in values {
+            if let Result::Err(e) = self.push(value) {
+                return Result::Err(e);
+            }
+        }
+        Result::Ok(())
+    }
+
+    /// Removes and returns the last value.
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        // The slot was below the old length, so it was initialized.
+        Some(unsafe { self.items[self.len].assume_init() })
+    }
+
+    /// Removes and returns the value at `index`, shifting the later values down.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let value = self.get(index).copied()?;
+        self.items.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes every value from `len` on.
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Removes every value.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the value at `index`, or `None` if it is out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns the value at `index` mutably, or `None` if it is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Returns the first value.
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Returns the last value.
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns the values as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // The first `len` slots are initialized, and `MaybeUninit<T>` has the layout of `T`.
+        unsafe { core::slice::from_raw_parts(self.items.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the values as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Returns an iterator over the values.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the values that allows modifying them.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns whether `value` is one of the values.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(value)
+    }
+
+    /// Returns a pointer to the storage of all `N` slots, e.g. for a host function to write into.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.items.as_mut_ptr().cast()
+    }
+
+    /// Sets the length after the slots were written through [`as_mut_ptr`](Self::as_mut_ptr).
+    ///
+    /// # Safety
+    ///
+    /// `len` must be at most `N`, and the first `len` slots must hold valid values of `T`.
+    pub(crate) unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    /// Keeps only the values for which `keep` returns true, in their original order.
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        let mut kept = 0;
+        for i in 0..self.len {
+            let value = unsafe { self.items[i].assume_init() };
+            if keep(&value) {
+                self.items[kept] = MaybeUninit::new(value);
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+}
+
+impl<T: Copy, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Copies `values` into a new vector.
+///
+/// Fails with `BufferTooSmall` if there are more than `N` values.
+impl<T: Copy, const N: usize> TryFrom<&[T]> for ArrayVec<T, N> {
+    type Error = Error;
+
+    fn try_from(values: &[T]) -> core::result::Result<Self, Error> {
+        let mut vec = Self::new();
+        match vec.extend_from_iter(values.iter().copied()) {
+            Result::Ok(()) => core::result::Result::Ok(vec),
+            Result::Err(e) => core::result::Result::Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::BUFFER_TOO_SMALL;
+
+    #[test]
+    fn test_push_stops_at_capacity() {
+        let mut vec: ArrayVec<u32, 2> = ArrayVec::new();
+        assert!(vec.is_empty());
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+        assert!(vec.is_full());
+        assert_eq!(vec.push(3).err().unwrap().code(), BUFFER_TOO_SMALL);
+        assert_eq!(vec.as_slice(), &[1, 2]);
+
+        let mut zero: ArrayVec<u32, 0> = ArrayVec::new();
+        assert!(zero.push(1).is_err());
+        assert_eq!(zero.pop(), None);
+    }
+
+    #[test]
+    fn test_get_is_bounded_by_len_not_capacity() {
+        let mut vec: ArrayVec<u8, 4> = ArrayVec::new();
+        vec.push(7).unwrap();
+        assert_eq!(vec.get(0), Some(&7));
+        assert_eq!(vec.get(1), None);
+        *vec.get_mut(0).unwrap() = 8;
+        assert_eq!(vec.first(), Some(&8));
+        assert_eq!(vec.get_mut(3), None);
+    }
+
+    #[test]
+    fn test_pop_remove_and_retain() {
+        let mut vec = ArrayVec::<u32, 8>::try_from(&[1, 2, 3, 4, 5][..]).unwrap();
+        assert_eq!(vec.pop(), Some(5));
+        assert_eq!(vec.remove(1), Some(2));
+        assert_eq!(vec.remove(3), None);
+        assert_eq!(vec.as_slice(), &[1, 3, 4]);
+
+        vec.retain(|&v| v != 3);
+        assert_eq!(vec.as_slice(), &[1, 4]);
+        assert!(vec.contains(&4));
+
+        vec.truncate(1);
+        assert_eq!(vec.last(), Some(&1));
+        vec.clear();
+        assert_eq!(vec.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_extend_keeps_what_fits() {
+        let mut vec: ArrayVec<u32, 3> = ArrayVec::new();
+        assert!(vec.extend_from_iter(0..5).is_err());
+        assert_eq!(vec.as_slice(), &[0, 1, 2]);
+        assert!(ArrayVec::<u32, 2>::try_from(&[1, 2, 3][..]).is_err());
+    }
+
+    #[test]
+    fn test_equality_ignores_unused_capacity() {
+        let mut a: ArrayVec<u32, 3> = ArrayVec::new();
+        a.push(1).unwrap();
+        a.push(2).unwrap();
+        a.pop();
+        let b = ArrayVec::<u32, 3>::try_from(&[1][..]).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(std::format!("{a:?}"), "[1]");
+    }
+}
@@ -0,0 +1,16 @@
+//! Fixed-capacity collections for contracts without an allocator.
+//!
+//! Contracts run without `alloc`, so anything list- or table-shaped has to live in a fixed-size
+//! array. These types keep the length next to the array and check every access against it:
+//!
+//! - [`ArrayVec`]: A vector of up to `N` values.
+//! - [`ArrayMap`]: A small map of up to `N` entries, searched linearly.
+//!
+//! Adding to a full collection fails with `BufferTooSmall` instead of panicking, and reads past
+//! the length return `None`.
+
+pub mod array_map;
+pub mod array_vec;
+
+pub use array_map::ArrayMap;
+pub use array_vec::ArrayVec;
@@ -1,3 +1,5 @@
+pub mod md5;
+
 use crate::host;
 use crate::host::error_codes::match_result_code_with_expected_bytes;
 use crate::host::{Error, Result};
@@ -14,6 +16,40 @@ pub fn sha512_half(data: &[u8]) -> Result<[u8; 32]> {
     match_result_code_with_expected_bytes(rescode, 32, || out)
 }
 
+/// Longest `Domain` an account can set, in bytes.
+pub const MAX_DOMAIN_LEN: usize = 256;
+
+/// SHA-512Half of `domain` with ASCII letters lowercased, for comparing domains by hash.
+///
+/// Domain names are case-insensitive, so `Example.COM` and `example.com` hash the same. Off-chain,
+/// the same value is the first 32 bytes of SHA-512 over the lowercased domain.
+///
+/// Errors: `InvalidParams` if `domain` is longer than [`MAX_DOMAIN_LEN`].
+pub fn domain_hash(domain: &[u8]) -> Result<[u8; 32]> {
+    if domain.len() > MAX_DOMAIN_LEN {
+        return Result::Err(Error::InvalidParams);
+    }
+    let mut lowered = [0u8; MAX_DOMAIN_LEN];
+    for (out, byte) in lowered.iter_mut().zip(domain) {
+        *out = byte.to_ascii_lowercase();
+    }
+    sha512_half(&lowered[..domain.len()])
+}
+
+/// Constant-time byte comparison, for checking secrets such as preimages or fulfillments.
+///
+/// Unlike `==` on slices, this does not return early at the first differing byte, so the running
+/// time does not reveal how much of a guess was correct. Lengths are not treated as secret:
+/// slices of different lengths compare unequal immediately.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    // Keep the optimizer from turning the fold back into an early-exit comparison.
+    core::hint::black_box(diff) == 0
+}
+
 /// Verify `sig` over `msg` for public key `key`.
 ///
 /// `&PublicKey` (33 bytes) enforces the size constraint at the call site.
@@ -59,6 +95,38 @@ mod tests {
         }
     }
 
+    // ---- ct_eq ----
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"secret", b"secret"));
+        assert!(!ct_eq(b"secret", b"secreT"));
+        assert!(!ct_eq(b"secret", b"Secret"));
+        assert!(!ct_eq(b"secret", b"secret!"));
+    }
+
+    // ---- domain_hash ----
+
+    #[test]
+    fn test_domain_hash_lowercases_before_hashing() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_compute_sha512_half()
+            .withf(|data, len, _, _| unsafe {
+                core::slice::from_raw_parts(*data, *len) == b"example.com"
+            })
+            .times(1)
+            .returning(|_, _, out_ptr, _| {
+                write_digest(out_ptr, 0xDD);
+                32
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(domain_hash(b"Example.COM").unwrap(), [0xDD; 32]);
+        let too_long = [b'a'; MAX_DOMAIN_LEN + 1];
+        assert_eq!(domain_hash(&too_long).err().unwrap().code(), INVALID_PARAMS);
+    }
+
     // ---- sha512_half ----
 
     #[test]
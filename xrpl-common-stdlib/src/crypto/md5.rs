@@ -0,0 +1,181 @@
+//! MD5, computed in the contract.
+//!
+//! The host has no MD5 call, and MD5 is only here because the ledger uses it: an account's
+//! `EmailHash` is the MD5 of an email address, as Gravatar expects. Do not use it for anything
+//! that needs collision resistance.
+//!
+//! ```
+//! use xrpl_common_stdlib::crypto::md5::{email_hash, md5};
+//!
+//! assert_eq!(md5(b"abc")[..4], [0x90, 0x01, 0x50, 0x98]);
+//! assert_eq!(email_hash(b" User@Example.com "), md5(b"user@example.com"));
+//! ```
+
+/// Size of an MD5 digest in bytes.
+pub const MD5_SIZE: usize = 16;
+
+const BLOCK_SIZE: usize = 64;
+
+const SHIFTS: [u32; 16] = [7, 12, 17, 22, 5, 9, 14, 20, 4, 11, 16, 23, 6, 10, 15, 21];
+
+// floor(abs(sin(i + 1)) * 2^32), from RFC 1321.
+const K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Incremental MD5, for input that is produced in pieces.
+#[derive(Debug, Clone)]
+pub struct Md5 {
+    state: [u32; 4],
+    buffer: [u8; BLOCK_SIZE],
+    buffered: usize,
+    length: u64,
+}
+
+impl Md5 {
+    pub const fn new() -> Self {
+        Md5 {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            buffer: [0; BLOCK_SIZE],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    /// Feeds `data` into the hash.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        while !data.is_empty() {
+            let take = (BLOCK_SIZE - self.buffered).min(data.len());
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+            self.buffered += take;
+            data = &data[take..];
+            if self.buffered == BLOCK_SIZE {
+                let block = self.buffer;
+                self.compress(&block);
+                self.buffered = 0;
+            }
+        }
+    }
+
+    /// Pads the input and returns the digest.
+    pub fn finalize(mut self) -> [u8; MD5_SIZE] {
+        let bit_length = self.length.wrapping_mul(8);
+        self.update(&[0x80]);
+        while self.buffered != BLOCK_SIZE - 8 {
+            self.update(&[0]);
+        }
+        self.update(&bit_length.to_le_bytes());
+
+        let mut digest = [0u8; MD5_SIZE];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+
+    fn compress(&mut self, block: &[u8; BLOCK_SIZE]) {
+        let mut words = [0u32; 16];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        let [mut a, mut b, mut c, mut d] = self.state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(K[i])
+                .wrapping_add(words[g])
+                .rotate_left(SHIFTS[(i / 16) * 4 + i % 4]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(rotated);
+        }
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the MD5 digest of `data`.
+pub fn md5(data: &[u8]) -> [u8; MD5_SIZE] {
+    let mut hasher = Md5::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// Returns the `EmailHash` an account would set for `email`: the MD5 of the address with
+/// surrounding whitespace removed and ASCII letters lowercased, as Gravatar computes it.
+pub fn email_hash(email: &[u8]) -> [u8; MD5_SIZE] {
+    let mut hasher = Md5::new();
+    for chunk in email.trim_ascii().chunks(BLOCK_SIZE) {
+        let mut lowered = [0u8; BLOCK_SIZE];
+        for (out, byte) in lowered.iter_mut().zip(chunk) {
+            *out = byte.to_ascii_lowercase();
+        }
+        hasher.update(&lowered[..chunk.len()]);
+    }
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(digest: [u8; MD5_SIZE]) -> std::string::String {
+        digest.iter().map(|b| std::format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_rfc_1321_vectors() {
+        assert_eq!(hex(md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex(md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex(md5(b"abcdefghijklmnopqrstuvwxyz")),
+            "c3fcd3d76192e4007dfb496cca67e13b"
+        );
+        assert_eq!(
+            hex(md5(
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            )),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn test_incremental_matches_one_shot() {
+        let data = [0x5Au8; 200];
+        let mut hasher = Md5::new();
+        for piece in data.chunks(7) {
+            hasher.update(piece);
+        }
+        assert_eq!(hasher.finalize(), md5(&data));
+    }
+
+    #[test]
+    fn test_email_hash_normalizes() {
+        assert_eq!(
+            hex(email_hash(b"  MyEmailAddress@example.com ")),
+            "0bc83cb571cd1c50ba6f3e8a78ef1346"
+        );
+    }
+}
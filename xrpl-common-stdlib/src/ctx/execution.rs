@@ -0,0 +1,241 @@
+//! One handle for everything a contract invocation works with.
+//!
+//! Helpers in a contract typically need some mix of the current transaction, the ledger object
+//! the contract is attached to, ledger header values, and cache slots. Passing these separately
+//! gets unwieldy and makes every helper signature different; an [`ExecutionContext`] bundles them
+//! so it can be created once at the top of the entry point and passed around by reference.
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::ctx::{ExecutionContext, SmartFeatureContext};
+//! use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
+//! use xrpl_common_stdlib::host::Result;
+//! # #[derive(Clone, Copy)] struct Tx;
+//! # impl TransactionCommonFields for Tx {}
+//! # #[derive(Clone, Copy)] struct Escrow;
+//!
+//! fn still_open(ctx: &ExecutionContext<Tx, Escrow>) -> Result<bool> {
+//!     // Both reads go through the same memoized ledger info.
+//!     ctx.ledger().sequence().map(|seq| seq < 1_000_000)
+//! }
+//!
+//! let ctx: ExecutionContext<Tx, Escrow> = ExecutionContext::new(Tx, Escrow);
+//! let _ = still_open(&ctx);
+//! ```
+//!
+//! Tests build one with [`ExecutionContext::with_ledger`] and a [`LedgerInfo::fixed`], which
+//! never reads ledger header values from the host.
+
+use crate::ctx::SmartFeatureContext;
+use crate::current_tx::traits::TransactionCommonFields;
+use crate::host::Result;
+use crate::host::chain::{
+    base_fee, is_simulation, ledger_sqn, parent_ledger_hash, parent_ledger_time,
+};
+use crate::objects::slot::SlotPool;
+use crate::patterns::memoized::Memoized;
+
+/// Ledger header values, each read from the host at most once.
+#[derive(Debug, Default)]
+pub struct LedgerInfo {
+    sequence: Memoized<u32>,
+    parent_time: Memoized<u32>,
+    parent_hash: Memoized<[u8; 32]>,
+    base_fee: Memoized<u32>,
+}
+
+impl LedgerInfo {
+    /// Creates ledger info that reads each value from the host on first use.
+    pub const fn new() -> Self {
+        LedgerInfo {
+            sequence: Memoized::new(),
+            parent_time: Memoized::new(),
+            parent_hash: Memoized::new(),
+            base_fee: Memoized::new(),
+        }
+    }
+
+    /// Creates ledger info with fixed values that are never read from the host.
+    pub fn fixed(sequence: u32, parent_time: u32, parent_hash: [u8; 32], base_fee: u32) -> Self {
+        LedgerInfo {
+            sequence: Memoized::with_value(sequence),
+            parent_time: Memoized::with_value(parent_time),
+            parent_hash: Memoized::with_value(parent_hash),
+            base_fee: Memoized::with_value(base_fee),
+        }
+    }
+
+    /// Sequence number of the ledger being built.
+    pub fn sequence(&self) -> Result<u32> {
+        self.sequence.get_or_read(ledger_sqn).map(|seq| *seq)
+    }
+
+    /// Close time of the parent ledger, in seconds since the Ripple epoch.
+    pub fn parent_time(&self) -> Result<u32> {
+        self.parent_time
+            .get_or_read(parent_ledger_time)
+            .map(|time| *time)
+    }
+
+    /// Hash of the parent ledger.
+    pub fn parent_hash(&self) -> Result<[u8; 32]> {
+        self.parent_hash
+            .get_or_read(parent_ledger_hash)
+            .map(|hash| *hash)
+    }
+
+    /// Base transaction fee of the ledger, in drops.
+    pub fn base_fee(&self) -> Result<u32> {
+        self.base_fee.get_or_read(base_fee).map(|fee| *fee)
+    }
+}
+
+/// The current transaction, the current ledger object, ledger info and a slot pool.
+///
+/// `Tx` and `Obj` are the feature's transaction and ledger object types, e.g. `EscrowFinish` and
+/// `CurrentEscrow`. `SLOTS` is the capacity of the [`SlotPool`] helpers share for caching other
+/// ledger objects.
+#[derive(Debug)]
+pub struct ExecutionContext<Tx, Obj, const SLOTS: usize = 8> {
+    tx: Tx,
+    object: Obj,
+    ledger: LedgerInfo,
+    slots: SlotPool<SLOTS>,
+    simulation: Memoized<bool>,
+}
+
+impl<Tx, Obj, const SLOTS: usize> ExecutionContext<Tx, Obj, SLOTS> {
+    /// Creates a context that reads ledger info from the host on first use.
+    pub fn new(tx: Tx, object: Obj) -> Self {
+        Self::with_ledger(tx, object, LedgerInfo::new())
+    }
+
+    /// Creates a context with the given ledger info, e.g. [`LedgerInfo::fixed`] in tests.
+    pub fn with_ledger(tx: Tx, object: Obj, ledger: LedgerInfo) -> Self {
+        ExecutionContext {
+            tx,
+            object,
+            ledger,
+            slots: SlotPool::new(),
+            simulation: Memoized::new(),
+        }
+    }
+
+    /// Marks the context as simulated or not instead of asking the host, e.g. in tests.
+    pub fn with_simulation(mut self, simulation: bool) -> Self {
+        self.simulation = Memoized::with_value(simulation);
+        self
+    }
+
+    /// The ledger object the contract is attached to.
+    pub fn object(&self) -> &Obj {
+        &self.object
+    }
+
+    /// Ledger header values.
+    pub fn ledger(&self) -> &LedgerInfo {
+        &self.ledger
+    }
+
+    /// The shared slot pool, for caching other ledger objects.
+    pub fn slots(&mut self) -> &mut SlotPool<SLOTS> {
+        &mut self.slots
+    }
+
+    /// Whether this invocation is a dry run, e.g. from rippled's `simulate` method, whose results
+    /// are discarded.
+    ///
+    /// Contracts can use this to skip state updates such as `update_data`. See
+    /// [`is_simulation`](crate::host::chain::is_simulation) for how the host signals it. If the
+    /// host cannot be asked, this returns `false`, so the contract behaves as it would under
+    /// consensus.
+    pub fn is_simulation(&self) -> bool {
+        self.simulation
+            .get_or_read(is_simulation)
+            .map(|simulation| *simulation)
+            .unwrap_or(false)
+    }
+}
+
+impl<Tx: TransactionCommonFields, Obj, const SLOTS: usize> SmartFeatureContext
+    for ExecutionContext<Tx, Obj, SLOTS>
+{
+    type Tx = Tx;
+    fn tx(&self) -> &Tx {
+        &self.tx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+
+    struct Tx;
+    impl TransactionCommonFields for Tx {}
+    struct Object;
+
+    #[test]
+    fn test_fixed_ledger_info_needs_no_host() {
+        let _guard = setup_mock(MockHostBindings::new());
+        let ctx: ExecutionContext<Tx, Object> =
+            ExecutionContext::with_ledger(Tx, Object, LedgerInfo::fixed(7, 100, [9; 32], 10));
+
+        assert_eq!(ctx.ledger().sequence().unwrap(), 7);
+        assert_eq!(ctx.ledger().parent_time().unwrap(), 100);
+        assert_eq!(ctx.ledger().parent_hash().unwrap(), [9; 32]);
+        assert_eq!(ctx.ledger().base_fee().unwrap(), 10);
+    }
+
+    #[test]
+    fn test_ledger_info_reads_each_value_once() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_sqn().times(1).returning(|ptr, _| {
+            unsafe { ptr.copy_from_nonoverlapping(42u32.to_le_bytes().as_ptr(), 4) };
+            4
+        });
+        let _guard = setup_mock(mock);
+
+        let mut ctx: ExecutionContext<Tx, Object, 2> = ExecutionContext::new(Tx, Object);
+        assert_eq!(ctx.ledger().sequence().unwrap(), 42);
+        assert_eq!(ctx.ledger().sequence().unwrap(), 42);
+        assert_eq!(ctx.slots().allocated(), 0);
+    }
+
+    #[test]
+    fn test_is_simulation_is_read_once() {
+        let mut mock = MockHostBindings::new();
+        // An unsigned, non-inner transaction: no signature, no signers, no flags.
+        mock.expect_get_tx_field()
+            .times(2)
+            .returning(|_, _, _| crate::host::error_codes::FIELD_NOT_FOUND);
+        mock.expect_get_tx_array_len()
+            .times(1)
+            .returning(|_| crate::host::error_codes::FIELD_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let ctx: ExecutionContext<Tx, Object> = ExecutionContext::new(Tx, Object);
+        assert!(ctx.is_simulation());
+        assert!(ctx.is_simulation());
+    }
+
+    #[test]
+    fn test_is_simulation_defaults_to_false_on_host_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .returning(|_, _, _| crate::host::error_codes::INTERNAL_ERROR);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        let ctx: ExecutionContext<Tx, Object> = ExecutionContext::new(Tx, Object);
+        assert!(!ctx.is_simulation());
+    }
+
+    #[test]
+    fn test_with_simulation_needs_no_host() {
+        let _guard = setup_mock(MockHostBindings::new());
+        let ctx: ExecutionContext<Tx, Object> =
+            ExecutionContext::new(Tx, Object).with_simulation(true);
+        assert!(ctx.is_simulation());
+    }
+}
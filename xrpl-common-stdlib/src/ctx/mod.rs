@@ -1,7 +1,13 @@
 //! Smart Feature context primitives shared by all entry points.
 //!
 //! This module exposes the [`SmartFeatureContext`] trait — the narrow context
-//! type implemented by feature-specific contexts (e.g., `EscrowFinishContext`)
+//! type implemented by feature-specific contexts (e.g., `EscrowFinishContext`) —
+//! and [`EntryContext`], which ties a context to the export that constructs it.
+//! [`ExecutionContext`] bundles the state helpers share during one invocation.
+
+mod execution;
+
+pub use execution::{ExecutionContext, LedgerInfo};
 
 use crate::current_tx::traits::TransactionCommonFields;
 
@@ -16,3 +22,50 @@ pub trait SmartFeatureContext {
     type Tx: TransactionCommonFields;
     fn tx(&self) -> &Self::Tx;
 }
+
+/// A context type that an entry point is constructed with.
+///
+/// `#[entry_point(<export>)]` builds the context with [`Default`] and passes it to the annotated
+/// function. [`ENTRY_POINT`](EntryContext::ENTRY_POINT) names the export the context belongs to,
+/// and the macro refuses to compile if it differs from the attribute's, so a context written for
+/// one entry point cannot silently be used for another. When a host adds an entry point the
+/// library does not know about yet, a contract can define its own context and implement this
+/// trait for it.
+pub trait EntryContext: Default {
+    /// Name of the `extern "C"` export the host calls for this entry point (e.g. `"finish"`).
+    const ENTRY_POINT: &'static str;
+}
+
+/// Compile-time check used by `#[entry_point]`: panics (a compile error in a `const`) if the
+/// context's entry point is not the exported one.
+#[doc(hidden)]
+pub const fn check_entry_point(context_entry_point: &str, export: &str) {
+    let (a, b) = (context_entry_point.as_bytes(), export.as_bytes());
+    let mut same = a.len() == b.len();
+    let mut i = 0;
+    while same && i < a.len() {
+        same = a[i] == b[i];
+        i += 1;
+    }
+    if !same {
+        panic!(
+            "the context type's EntryContext::ENTRY_POINT does not match the exported entry point"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_entry_point_accepts_matching_names() {
+        check_entry_point("finish", "finish");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match")]
+    fn test_check_entry_point_rejects_other_names() {
+        check_entry_point("finish", "cancel");
+    }
+}
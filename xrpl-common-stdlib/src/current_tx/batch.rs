@@ -0,0 +1,341 @@
+//! Inner transactions of a `Batch`.
+//!
+//! A `Batch` transaction carries the transactions it applies in its `RawTransactions` array.
+//! [`inner_transactions`] iterates over them, and each [`InnerTransaction`] reads its fields
+//! through a [`Locator`] into that array, so a contract can check a condition across the whole
+//! batch rather than only the transaction that triggered it.
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::current_tx::batch::inner_transactions;
+//! use xrpl_common_stdlib::host::Result;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! // Every transaction in the batch must come from `owner`.
+//! fn all_from(owner: &AccountID) -> Result<bool> {
+//!     inner_transactions().and_then(|txs| {
+//!         txs.try_all(|tx| tx.get_account().map(|account| &account == owner))
+//!     })
+//! }
+//! # let _ = all_from(&AccountID([0; 20]));
+//! ```
+//!
+//! A transaction that is itself applied as part of a batch has the `tfInnerBatchTxn` flag set;
+//! [`is_inner_transaction`] checks for it.
+
+use crate::current_tx::{array_len, get_field, get_field_optional};
+use crate::fields::decoder::{FromCurrentTx, decode_field};
+use crate::fields::locator::{Locator, LocatorEntry};
+use crate::host::error_codes::match_result_code;
+use crate::host::{Error, Result, get_tx_nested_field};
+use crate::sfield;
+use crate::tx_flags::tfInnerBatchTxn;
+use crate::types::account_id::AccountID;
+use crate::types::transaction_type::TransactionType;
+
+/// The most inner transactions a `Batch` may carry.
+pub const MAX_INNER_TRANSACTIONS: usize = 8;
+
+/// Returns whether the current transaction is a `Batch`.
+pub fn is_batch() -> Result<bool> {
+    get_field(sfield::TransactionType).map(|tx_type| tx_type == TransactionType::Batch)
+}
+
+/// Returns whether the current transaction is being applied as part of a `Batch`.
+pub fn is_inner_transaction() -> Result<bool> {
+    get_field_optional(sfield::Flags).map(|flags| flags.unwrap_or(0) & tfInnerBatchTxn != 0)
+}
+
+/// Returns an iterator over the inner transactions of the current transaction.
+///
+/// The iterator is empty if the current transaction has no `RawTransactions`, i.e. is not a
+/// `Batch`. It yields at most [`MAX_INNER_TRANSACTIONS`] entries whatever length the host reports.
+pub fn inner_transactions() -> Result<InnerTransactions> {
+    match array_len(sfield::RawTransactions) {
+        Result::Ok(len) => Result::Ok(InnerTransactions {
+            next: 0,
+            len: len.min(MAX_INNER_TRANSACTIONS),
+        }),
+        Result::Err(Error::FieldNotFound) => Result::Ok(InnerTransactions { next: 0, len: 0 }),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Iterator over the entries of the current transaction's `RawTransactions` array.
+///
+/// ## Derived Traits
+///
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone)]
+pub struct InnerTransactions {
+    next: usize,
+    len: usize,
+}
+
+impl InnerTransactions {
+    /// Returns whether `check` holds for every remaining inner transaction.
+    ///
+    /// Stops at the first transaction for which `check` returns `Ok(false)` or an error, and
+    /// returns that result.
+    pub fn try_all<F>(self, mut check: F) -> Result<bool>
+    where
+        F: FnMut(&InnerTransaction) -> Result<bool>,
+    {
+        for tx in self {
+            match check(&tx) {
+                Result::Ok(true) => {}
+                other => return other,
+            }
+        }
+        Result::Ok(true)
+    }
+}
+
+impl Iterator for InnerTransactions {
+    type Item = InnerTransaction;
+
+    fn next(&mut self) -> Option<InnerTransaction> {
+        (self.next < self.len).then(|| {
+            self.next += 1;
+            InnerTransaction {
+                index: self.next - 1,
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for InnerTransactions {}
+
+/// One entry of the current transaction's `RawTransactions` array.
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this single-index struct, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InnerTransaction {
+    index: usize,
+}
+
+impl InnerTransaction {
+    /// Position of this transaction in `RawTransactions`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Locator for `field` of this inner transaction.
+    pub fn locator(&self, field: impl Into<i32>) -> Result<Locator> {
+        let mut locator = Locator::new();
+        locator
+            .pack(sfield::RawTransactions)
+            .and_then(|_| locator.pack(LocatorEntry::Index(self.index as i32)))
+            .and_then(|_| locator.pack(field.into()))
+            .map(|_| locator)
+    }
+
+    /// Reads the raw bytes of `field` into `buf`, returning how many bytes were written.
+    pub fn get_field_raw(&self, field: impl Into<i32>, buf: &mut [u8]) -> Result<usize> {
+        let locator = match self.locator(field) {
+            Result::Ok(locator) => locator,
+            Result::Err(e) => return Result::Err(e),
+        };
+        let result_code = unsafe {
+            get_tx_nested_field(locator.as_ptr(), locator.len(), buf.as_mut_ptr(), buf.len())
+        };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Reads `field` and decodes it as `T`.
+    pub fn get_field_typed<T: FromCurrentTx>(&self, field: impl Into<i32>) -> Result<T> {
+        let mut buf = T::Buffer::default();
+        self.get_field_raw(field, buf.as_mut())
+            .and_then(|len| decode_field(&buf.as_mut()[..len]))
+    }
+
+    /// Like [`get_field_typed`](Self::get_field_typed), but returns `None` if the field is
+    /// absent.
+    pub fn get_field_typed_optional<T: FromCurrentTx>(
+        &self,
+        field: impl Into<i32>,
+    ) -> Result<Option<T>> {
+        match self.get_field_typed(field) {
+            Result::Ok(value) => Result::Ok(Some(value)),
+            Result::Err(Error::FieldNotFound) => Result::Ok(None),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// The type of this inner transaction.
+    pub fn get_transaction_type(&self) -> Result<TransactionType> {
+        let mut buf = [0u8; 2];
+        self.get_field_raw(sfield::TransactionType, &mut buf)
+            .and_then(|len| match len {
+                2 => Result::Ok(TransactionType::from(buf)),
+                _ => Result::Err(Error::InvalidDecoding),
+            })
+    }
+
+    /// The account that sends this inner transaction.
+    pub fn get_account(&self) -> Result<AccountID> {
+        self.get_field_typed(sfield::Account)
+    }
+
+    /// The sequence number of this inner transaction; zero if it uses a ticket.
+    pub fn get_sequence(&self) -> Result<u32> {
+        self.get_field_typed(sfield::Sequence)
+    }
+
+    /// The flags of this inner transaction, which always include `tfInnerBatchTxn`.
+    pub fn get_flags(&self) -> Result<Option<u32>> {
+        self.get_field_typed_optional(sfield::Flags)
+    }
+
+    /// The destination of this inner transaction, for transaction types that have one.
+    pub fn get_destination(&self) -> Result<Option<AccountID>> {
+        self.get_field_typed_optional(sfield::Destination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::eq;
+
+    /// Decodes the `[RawTransactions, index, field]` path of a locator.
+    fn path(locator_ptr: *const u8, locator_len: usize) -> [i32; 3] {
+        assert_eq!(locator_len, 12);
+        let bytes = unsafe { core::slice::from_raw_parts(locator_ptr, locator_len) };
+        core::array::from_fn(|i| i32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap()))
+    }
+
+    fn expect_batch_len(mock: &mut MockHostBindings, result: i32) {
+        mock.expect_get_tx_array_len()
+            .with(eq(i32::from(sfield::RawTransactions)))
+            .times(1)
+            .returning(move |_| result);
+    }
+
+    /// Answers `Account` reads with `[index; 20]`, so each inner transaction has its own sender.
+    fn expect_accounts(mock: &mut MockHostBindings, times: usize) {
+        mock.expect_get_tx_nested_field()
+            .times(times)
+            .returning(|ptr, len, out, out_len| {
+                let [array, index, field] = path(ptr, len);
+                assert_eq!(array, i32::from(sfield::RawTransactions));
+                assert_eq!(field, i32::from(sfield::Account));
+                assert_eq!(out_len, 20);
+                unsafe { core::ptr::write_bytes(out, index as u8, 20) };
+                20
+            });
+    }
+
+    #[test]
+    fn test_iterates_over_raw_transactions() {
+        let mut mock = MockHostBindings::new();
+        expect_batch_len(&mut mock, 3);
+        expect_accounts(&mut mock, 3);
+        let _guard = setup_mock(mock);
+
+        let txs = inner_transactions().unwrap();
+        assert_eq!(txs.len(), 3);
+        for (i, tx) in txs.enumerate() {
+            assert_eq!(tx.index(), i);
+            assert_eq!(tx.get_account().unwrap(), AccountID([i as u8; 20]));
+        }
+    }
+
+    #[test]
+    fn test_not_a_batch_has_no_inner_transactions() {
+        let mut mock = MockHostBindings::new();
+        expect_batch_len(&mut mock, FIELD_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(inner_transactions().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_length_is_capped_at_batch_limit() {
+        let mut mock = MockHostBindings::new();
+        expect_batch_len(&mut mock, 1_000);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(inner_transactions().unwrap().len(), MAX_INNER_TRANSACTIONS);
+    }
+
+    #[test]
+    fn test_try_all_stops_at_first_failure() {
+        let mut mock = MockHostBindings::new();
+        expect_batch_len(&mut mock, 3);
+        // The second transaction fails the check, so the third is never read.
+        expect_accounts(&mut mock, 2);
+        let _guard = setup_mock(mock);
+
+        let all_first = inner_transactions()
+            .unwrap()
+            .try_all(|tx| tx.get_account().map(|a| a == AccountID([0; 20])));
+        assert!(!all_first.unwrap());
+    }
+
+    #[test]
+    fn test_try_all_propagates_errors() {
+        let mut mock = MockHostBindings::new();
+        expect_batch_len(&mut mock, 2);
+        mock.expect_get_tx_nested_field()
+            .times(1)
+            .returning(|_, _, _, _| INTERNAL_ERROR);
+        let _guard = setup_mock(mock);
+
+        let result = inner_transactions()
+            .unwrap()
+            .try_all(|tx| tx.get_sequence().map(|seq| seq > 0));
+        assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+    }
+
+    #[test]
+    fn test_inner_transaction_type_and_optional_fields() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_nested_field()
+            .times(2)
+            .returning(|ptr, len, out, _| match path(ptr, len) {
+                [_, 1, field] if field == i32::from(sfield::TransactionType) => {
+                    let bytes: [u8; 2] = TransactionType::Payment.into();
+                    unsafe { out.copy_from_nonoverlapping(bytes.as_ptr(), 2) };
+                    2
+                }
+                _ => FIELD_NOT_FOUND,
+            });
+        let _guard = setup_mock(mock);
+
+        let tx = InnerTransaction { index: 1 };
+        assert_eq!(tx.get_transaction_type().unwrap(), TransactionType::Payment);
+        assert_eq!(tx.get_destination().unwrap(), None);
+    }
+
+    #[test]
+    fn test_is_inner_transaction_checks_flag() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(
+                eq(i32::from(sfield::Flags)),
+                mockall::predicate::always(),
+                eq(4),
+            )
+            .times(1)
+            .returning(|_, out, _| {
+                let flags = tfInnerBatchTxn.to_le_bytes();
+                unsafe { out.copy_from_nonoverlapping(flags.as_ptr(), 4) };
+                4
+            });
+        let _guard = setup_mock(mock);
+
+        assert!(is_inner_transaction().unwrap());
+    }
+}
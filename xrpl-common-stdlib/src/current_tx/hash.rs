@@ -0,0 +1,332 @@
+//! Signing hash and transaction ID of the current transaction, rebuilt from its fields.
+//!
+//! The host has no call returning the hash of the transaction being processed, and
+//! `TransactionHash` is not part of the serialized transaction, so neither can be read directly.
+//! Both can be recomputed instead: rippled hashes the transaction's canonical binary
+//! serialization with SHA-512Half, behind a 4-byte prefix. The transaction ID uses `TXN\0` over
+//! every field; the single-signing hash uses `STX\0` and leaves out `TxnSignature` and `Signers`.
+//!
+//! The host cannot list a transaction's fields either, so the caller names every field its
+//! transaction type may carry. Absent ones are skipped, and the rest are read one by one and
+//! serialized in canonical order:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::current_tx::hash::transaction_id;
+//! use xrpl_common_stdlib::sfield;
+//!
+//! let fields = [
+//!     sfield::TransactionType.into(),
+//!     sfield::Flags.into(),
+//!     sfield::Sequence.into(),
+//!     sfield::OfferSequence.into(),
+//!     sfield::Fee.into(),
+//!     sfield::SigningPubKey.into(),
+//!     sfield::TxnSignature.into(),
+//!     sfield::Account.into(),
+//!     sfield::Owner.into(),
+//!     sfield::Memos.into(),
+//! ];
+//! let id = transaction_id(&fields).unwrap();
+//! # let _ = id;
+//! ```
+//!
+//! Only leaf fields can be read back as bytes. A transaction that carries an object or array
+//! field, such as `Memos` or `Signers`, cannot be hashed this way and yields `InvalidParams`; list
+//! those fields anyway, so that their presence is caught instead of silently producing a wrong
+//! hash. A field missing from the list is not detected.
+
+use crate::crypto::sha512_half;
+use crate::host::error_codes::FIELD_NOT_FOUND;
+use crate::host::{Error, Result, get_tx_field};
+use crate::sfield;
+use crate::type_codes::*;
+use crate::types::uint::Hash256;
+
+/// Prefix of the data hashed into a transaction ID (`TXN\0`).
+pub const TRANSACTION_ID_PREFIX: [u8; 4] = *b"TXN\0";
+
+/// Prefix of the data hashed into a single-signing hash (`STX\0`).
+pub const SIGNING_PREFIX: [u8; 4] = *b"STX\0";
+
+/// The most serialized bytes, prefix included, that can be hashed: the host's SHA-512Half input
+/// limit.
+pub const MAX_HASHED_TX_SIZE: usize = 1024;
+
+/// The most fields [`signing_hash`] and [`transaction_id`] accept.
+pub const MAX_TX_FIELDS: usize = 64;
+
+/// Computes the hash the transaction's single signer signed.
+///
+/// # Errors
+///
+/// * `InvalidParams` - If `fields` has more than [`MAX_TX_FIELDS`] entries, or a listed object
+///   or array field other than `Signers` is present
+/// * `DataFieldTooLarge` - If the serialized transaction exceeds [`MAX_HASHED_TX_SIZE`]
+/// * Any error from reading a field or hashing
+pub fn signing_hash(fields: &[i32]) -> Result<Hash256> {
+    hash_fields(SIGNING_PREFIX, fields, |code| {
+        code != i32::from(sfield::TxnSignature) && code != i32::from(sfield::Signers)
+    })
+}
+
+/// Computes the transaction's ID, the hash it is known by on the ledger.
+///
+/// # Errors
+///
+/// * `InvalidParams` - If `fields` has more than [`MAX_TX_FIELDS`] entries, or a listed object
+///   or array field is present
+/// * `DataFieldTooLarge` - If the serialized transaction exceeds [`MAX_HASHED_TX_SIZE`]
+/// * Any error from reading a field or hashing
+pub fn transaction_id(fields: &[i32]) -> Result<Hash256> {
+    hash_fields(TRANSACTION_ID_PREFIX, fields, |_| true)
+}
+
+fn hash_fields(prefix: [u8; 4], fields: &[i32], included: impl Fn(i32) -> bool) -> Result<Hash256> {
+    if fields.len() > MAX_TX_FIELDS {
+        return Result::Err(Error::InvalidParams);
+    }
+    let mut sorted = [0i32; MAX_TX_FIELDS];
+    let sorted = &mut sorted[..fields.len()];
+    sorted.copy_from_slice(fields);
+    // Sfield codes are `(type_code << 16) | field_code`, so they sort in canonical order.
+    sorted.sort_unstable();
+
+    let mut buf = [0u8; MAX_HASHED_TX_SIZE];
+    buf[..4].copy_from_slice(&prefix);
+    let mut len = 4;
+    for &code in sorted.iter().filter(|&&code| included(code)) {
+        len = match append_field(&mut buf, len, code) {
+            Result::Ok(len) => len,
+            Result::Err(e) => return Result::Err(e),
+        };
+    }
+    sha512_half(&buf[..len]).map(Hash256::from)
+}
+
+/// How a field's value is serialized, given the bytes the host returns for it.
+enum Encoding {
+    /// Written as the host returns it.
+    Raw,
+    /// An integer the host returns little-endian; serialized big-endian.
+    Integer,
+    /// Preceded by its length.
+    LengthPrefixed,
+}
+
+fn encoding_of(type_code: i32) -> Option<Encoding> {
+    match u8::try_from(type_code).ok()? {
+        STI_UINT8 | STI_UINT16 | STI_UINT32 | STI_UINT64 | STI_INT32 | STI_INT64 => {
+            Some(Encoding::Integer)
+        }
+        STI_UINT96 | STI_UINT128 | STI_UINT160 | STI_UINT192 | STI_UINT256 | STI_UINT384
+        | STI_UINT512 | STI_AMOUNT | STI_NUMBER | STI_ISSUE | STI_CURRENCY => Some(Encoding::Raw),
+        STI_VL | STI_ACCOUNT | STI_VECTOR256 => Some(Encoding::LengthPrefixed),
+        _ => None,
+    }
+}
+
+/// Serializes field `code` into `buf` at `len`, if the transaction has it, and returns the new
+/// length.
+fn append_field(buf: &mut [u8; MAX_HASHED_TX_SIZE], len: usize, code: i32) -> Result<usize> {
+    let type_code = code >> 16;
+    let field_code = code & 0xFFFF;
+    let header = field_header(type_code, field_code);
+    let header = &header.0[..header.1];
+    // Leave room for the longest length prefix between the header and the value.
+    let value_start = len + header.len() + 3;
+    if value_start >= buf.len() {
+        return Result::Err(Error::DataFieldTooLarge);
+    }
+    let value = &mut buf[value_start..];
+    let result_code = unsafe { get_tx_field(code, value.as_mut_ptr(), value.len()) };
+    if result_code == FIELD_NOT_FOUND {
+        return Result::Ok(len);
+    }
+    let encoding = match encoding_of(type_code) {
+        Some(encoding) => encoding,
+        None => return Result::Err(Error::InvalidParams),
+    };
+    if result_code < 0 {
+        return Result::Err(match Error::from_code(result_code) {
+            Error::BufferTooSmall => Error::DataFieldTooLarge,
+            e => e,
+        });
+    }
+    let value_len = result_code as usize;
+
+    buf[len..len + header.len()].copy_from_slice(header);
+    let mut end = len + header.len();
+    match encoding {
+        Encoding::Raw => {}
+        Encoding::Integer => buf[value_start..value_start + value_len].reverse(),
+        Encoding::LengthPrefixed => {
+            let prefix = length_prefix(value_len);
+            let prefix = &prefix.0[..prefix.1];
+            buf[end..end + prefix.len()].copy_from_slice(prefix);
+            end += prefix.len();
+        }
+    }
+    buf.copy_within(value_start..value_start + value_len, end);
+    Result::Ok(end + value_len)
+}
+
+/// The field ID that precedes a field's value: type and field code, each in a nibble when it
+/// fits or in a byte of its own when it does not.
+fn field_header(type_code: i32, field_code: i32) -> ([u8; 3], usize) {
+    let (t, f) = (type_code as u8, field_code as u8);
+    match (type_code < 16, field_code < 16) {
+        (true, true) => ([(t << 4) | f, 0, 0], 1),
+        (true, false) => ([t << 4, f, 0], 2),
+        (false, true) => ([f, t, 0], 2),
+        (false, false) => ([0, t, f], 3),
+    }
+}
+
+/// The length prefix of a variable-length value of `len` bytes.
+fn length_prefix(len: usize) -> ([u8; 3], usize) {
+    if len <= 192 {
+        ([len as u8, 0, 0], 1)
+    } else if len <= 12_480 {
+        let len = len - 193;
+        ([193 + (len >> 8) as u8, len as u8, 0], 2)
+    } else {
+        let len = len - 12_481;
+        ([241 + (len >> 16) as u8, (len >> 8) as u8, len as u8], 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use sha2::{Digest, Sha512};
+
+    // A signed Payment of 1 USD from rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh, from the xrpl.org
+    // `sign` method example, and its transaction ID.
+    const TX_BLOB: &str = "1200002280000000240000000361D4838D7EA4C6800000000000000000000000000055534400\
+        000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000A732103AB40A0490F9B7ED8DF\
+        29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100D184EB4AE5956FF600E7536EE45934\
+        5C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF21\
+        74AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6\
+        724EF53754";
+    const TX_ID: &str = "82230B9D489370504B39BC2CE46216176CAC9E752E5C1774A8CBEC9FBB819208";
+
+    fn hex(s: &str) -> Vec<u8> {
+        let digits: Vec<u8> = s.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+        digits
+            .chunks(2)
+            .map(|pair| u8::from_str_radix(core::str::from_utf8(pair).unwrap(), 16).unwrap())
+            .collect()
+    }
+
+    /// The Payment's fields as the host returns them: integers little-endian, accounts and
+    /// blobs without a length prefix.
+    fn payment_field(code: i32) -> Option<Vec<u8>> {
+        let blob = hex(TX_BLOB);
+        let value = if code == i32::from(sfield::TransactionType) {
+            vec![0, 0]
+        } else if code == i32::from(sfield::Flags) {
+            0x8000_0000u32.to_le_bytes().to_vec()
+        } else if code == i32::from(sfield::Sequence) {
+            3u32.to_le_bytes().to_vec()
+        } else if code == i32::from(sfield::Amount) {
+            blob[14..62].to_vec()
+        } else if code == i32::from(sfield::Fee) {
+            blob[63..71].to_vec()
+        } else if code == i32::from(sfield::SigningPubKey) {
+            blob[73..106].to_vec()
+        } else if code == i32::from(sfield::TxnSignature) {
+            blob[108..179].to_vec()
+        } else if code == i32::from(sfield::Account) {
+            blob[181..201].to_vec()
+        } else if code == i32::from(sfield::Destination) {
+            blob[203..223].to_vec()
+        } else {
+            return None;
+        };
+        Some(value)
+    }
+
+    fn payment_host() -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .returning(|code, out, out_len| match payment_field(code) {
+                Some(value) if value.len() <= out_len => {
+                    unsafe { core::ptr::copy_nonoverlapping(value.as_ptr(), out, value.len()) };
+                    value.len() as i32
+                }
+                Some(_) => crate::host::error_codes::BUFFER_TOO_SMALL,
+                None => FIELD_NOT_FOUND,
+            });
+        mock.expect_compute_sha512_half()
+            .returning(|data, data_len, out, _| {
+                let data = unsafe { core::slice::from_raw_parts(data, data_len) };
+                let digest = Sha512::digest(data);
+                unsafe { core::ptr::copy_nonoverlapping(digest.as_ptr(), out, 32) };
+                32
+            });
+        mock
+    }
+
+    fn payment_fields() -> [i32; 11] {
+        // Deliberately out of canonical order, and with fields the Payment does not have.
+        [
+            sfield::Destination.into(),
+            sfield::Account.into(),
+            sfield::TxnSignature.into(),
+            sfield::SigningPubKey.into(),
+            sfield::Fee.into(),
+            sfield::Amount.into(),
+            sfield::DestinationTag.into(),
+            sfield::Sequence.into(),
+            sfield::Flags.into(),
+            sfield::TransactionType.into(),
+            sfield::Memos.into(),
+        ]
+    }
+
+    #[test]
+    fn test_transaction_id_matches_known_hash() {
+        let _guard = setup_mock(payment_host());
+
+        let id = transaction_id(&payment_fields()).unwrap();
+        assert_eq!(id.as_bytes(), hex(TX_ID).as_slice());
+    }
+
+    #[test]
+    fn test_signing_hash_leaves_out_the_signature() {
+        let _guard = setup_mock(payment_host());
+
+        let blob = hex(TX_BLOB);
+        let mut signed = SIGNING_PREFIX.to_vec();
+        signed.extend_from_slice(&blob[..106]);
+        signed.extend_from_slice(&blob[179..]);
+        let expected = Sha512::digest(&signed);
+
+        let hash = signing_hash(&payment_fields()).unwrap();
+        assert_eq!(hash.as_bytes(), &expected[..32]);
+    }
+
+    #[test]
+    fn test_present_array_field_is_invalid_params() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field().returning(|_, _, _| 64);
+        let _guard = setup_mock(mock);
+
+        let result = transaction_id(&[sfield::Memos.into()]);
+        assert!(matches!(result.err(), Some(Error::InvalidParams)));
+    }
+
+    #[test]
+    fn test_field_headers_and_length_prefixes() {
+        assert_eq!(field_header(1, 2), ([0x12, 0, 0], 1));
+        assert_eq!(field_header(2, 27), ([0x20, 27, 0], 2));
+        assert_eq!(field_header(16, 1), ([0x01, 16, 0], 2));
+        assert_eq!(field_header(16, 16), ([0, 16, 16], 3));
+        assert_eq!(length_prefix(192), ([192, 0, 0], 1));
+        assert_eq!(length_prefix(193), ([193, 0, 0], 2));
+        assert_eq!(length_prefix(12_480), ([240, 255, 0], 2));
+        assert_eq!(length_prefix(12_481), ([241, 0, 0], 3));
+    }
+}
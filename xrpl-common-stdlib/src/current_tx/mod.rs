@@ -49,12 +49,20 @@
 //! Concrete transaction wrappers (e.g., `EscrowFinish`) live in their respective
 //! companion crates (`xrpl-escrow-stdlib` for escrow flows).
 
+pub mod batch;
+pub mod hash;
 pub mod traits;
 
+use crate::fields::decoder::{FromCurrentTx, decode_field};
+use crate::fields::locator::Locator;
 use crate::host::error_codes::{
-    match_result_code_with_expected_bytes, match_result_code_with_expected_bytes_optional,
+    match_result_code, match_result_code_with_expected_bytes,
+    match_result_code_with_expected_bytes_optional,
 };
-use crate::host::{Result, get_tx_field};
+use crate::host::{
+    Result, get_tx_array_len, get_tx_field, get_tx_nested_array_len, get_tx_nested_field,
+};
+use crate::objects::array_object::Array;
 use crate::sfield::SField;
 
 /// Trait for types that can be retrieved from current transaction fields.
@@ -274,9 +282,84 @@ pub fn get_field_optional<T: CurrentTxFieldGetter, const CODE: i32>(
     T::get_from_current_tx_optional(field)
 }
 
+/// Returns the number of elements in an array field of the current transaction.
+///
+/// # Arguments
+///
+/// * `field` - An array SField constant (e.g., `sfield::Memos`)
+///
+/// # Returns
+///
+/// Returns a `Result<usize>` where:
+/// * `Ok(usize)` - The number of elements in the array
+/// * `Err(Error)` - If the field is missing or is not an array
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use xrpl_common_stdlib::current_tx::array_len;
+/// use xrpl_common_stdlib::sfield;
+///
+/// let num_memos = array_len(sfield::Memos).unwrap_or(0);
+/// ```
+#[inline]
+pub fn array_len<const CODE: i32>(field: SField<Array, CODE>) -> Result<usize> {
+    let result_code = unsafe { get_tx_array_len(field.into()) };
+    match_result_code(result_code, || result_code as usize)
+}
+
+/// Returns the number of elements in a nested array of the current transaction.
+///
+/// # Arguments
+///
+/// * `locator` - A [`Locator`] whose path ends at an array field
+///
+/// # Returns
+///
+/// Returns a `Result<usize>` where:
+/// * `Ok(usize)` - The number of elements in the array
+/// * `Err(Error)` - If the locator is malformed or does not point at an array
+#[inline]
+pub fn nested_array_len(locator: &Locator) -> Result<usize> {
+    let result_code = unsafe { get_tx_nested_array_len(locator.as_ptr(), locator.len()) };
+    match_result_code(result_code, || result_code as usize)
+}
+
+/// Retrieves a nested field of the current transaction into `out`.
+///
+/// # Returns
+///
+/// Returns a `Result<usize>` where:
+/// * `Ok(usize)` - The number of bytes written to `out`
+/// * `Err(Error)` - If the locator is malformed, the field is missing, or `out` is too small
+#[inline]
+pub fn nested_field(locator: &Locator, out: &mut [u8]) -> Result<usize> {
+    let result_code = unsafe {
+        get_tx_nested_field(locator.as_ptr(), locator.len(), out.as_mut_ptr(), out.len())
+    };
+    match_result_code(result_code, || result_code as usize)
+}
+
+/// Retrieves a nested field of the current transaction as a `T`.
+///
+/// See [`fields::decoder`](crate::fields::decoder#nested-fields) for an example.
+///
+/// # Returns
+///
+/// Returns a `Result<T>` where:
+/// * `Ok(T)` - The field value at `locator`
+/// * `Err(Error::InvalidDecoding)` - If the field does not decode as a `T`
+/// * `Err(Error)` - If the locator is malformed or the field cannot be retrieved
+#[inline]
+pub fn get_nested_field<T: FromCurrentTx>(locator: &Locator) -> Result<T> {
+    let mut buf = T::Buffer::default();
+    nested_field(locator, buf.as_mut()).and_then(|len| decode_field(&buf.as_mut()[..len]))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{CurrentTxFieldGetter, get_field, get_field_optional};
+    use super::{CurrentTxFieldGetter, array_len, get_field, get_field_optional, nested_array_len};
+    use crate::fields::locator::{Locator, LocatorEntry};
     use crate::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR};
     use crate::host::host_bindings_trait::MockHostBindings;
     use crate::host::setup_mock;
@@ -495,4 +578,54 @@ mod tests {
         let amount = Amount::get_from_current_tx(sfield::Amount).unwrap();
         assert!(matches!(amount, Amount::XRP { num_drops: 1000 }));
     }
+
+    #[test]
+    fn test_array_len_returns_host_count() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_array_len()
+            .with(eq::<i32>(sfield::Memos.into()))
+            .times(1)
+            .returning(|_| 3);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(array_len(sfield::Memos).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_array_len_translates_negative_code() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_array_len()
+            .times(1)
+            .returning(|_| FIELD_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let result = array_len(sfield::Signers);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_nested_array_len_passes_locator() {
+        let mut locator = Locator::new();
+        locator.pack(sfield::Signers).unwrap();
+        locator.pack(LocatorEntry::Index(0)).unwrap();
+        locator.pack(sfield::SignerEntries).unwrap();
+
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_nested_array_len()
+            .with(always(), eq(12))
+            .times(1)
+            .returning(|_, _| 2);
+        mock.expect_get_tx_nested_array_len()
+            .with(always(), eq(0))
+            .times(1)
+            .returning(|_, _| INTERNAL_ERROR);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(nested_array_len(&locator).unwrap(), 2);
+        assert_eq!(
+            nested_array_len(&Locator::new()).err().unwrap().code(),
+            INTERNAL_ERROR
+        );
+    }
 }
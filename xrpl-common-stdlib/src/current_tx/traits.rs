@@ -38,16 +38,83 @@
 //! - **PublicKey**: 33-byte compressed public keys
 //! - **TransactionType**: Enumerated transaction type identifiers
 
-use crate::current_tx::{get_field, get_field_optional};
-use crate::host::Result;
-use crate::sfield;
+use crate::current_tx::{array_len, get_field, get_field_optional};
+use crate::fields::decoder::{FromCurrentTx, decode_field};
+use crate::host::error_codes::match_result_code;
+use crate::host::{Error, Result, get_tx_field};
+use crate::objects::array_object::Array;
+use crate::sfield::{self, SField};
 use crate::types::account_id::AccountID;
 use crate::types::amount::Amount;
 use crate::types::blob::SignatureBlob;
+use crate::types::gas::Gas;
 use crate::types::public_key::PublicKey;
 use crate::types::transaction_type::TransactionType;
 use crate::types::uint::Hash256;
 
+/// The accounts behind the current transaction, as returned by
+/// [`TransactionCommonFields::effective_sender`].
+///
+/// With delegated sending (the `Delegate` field), an account can let another account sign and
+/// submit certain transactions for it. `Account` is then still the account the transaction acts
+/// for, whose funds move and whose objects change, while `Delegate` is the account that actually
+/// signed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sender {
+    /// The transaction's `Account`.
+    pub account: AccountID,
+    /// The transaction's `Delegate`, if it was sent by a delegate.
+    pub delegate: Option<AccountID>,
+}
+
+impl Sender {
+    /// Returns the account the transaction acts for. Conditions about whose funds or objects
+    /// are involved should check this account.
+    pub fn principal(&self) -> &AccountID {
+        &self.account
+    }
+
+    /// Returns the account that signed the transaction: the delegate if there is one, otherwise
+    /// the principal.
+    pub fn signer(&self) -> &AccountID {
+        self.delegate.as_ref().unwrap_or(&self.account)
+    }
+
+    /// Returns whether the transaction was sent by a delegate.
+    pub fn is_delegated(&self) -> bool {
+        self.delegate.is_some()
+    }
+}
+
+/// The sequence number a transaction consumed, as returned by
+/// [`TransactionCommonFields::effective_sequence`].
+///
+/// A transaction either uses the account's next `Sequence`, or sets `Sequence` to 0 and uses a
+/// Ticket, identified by `TicketSequence`. Objects the transaction creates (an escrow, an offer, a
+/// check) are keyed by whichever of the two it used, so that is the number later transactions
+/// pass as `OfferSequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectiveSequence {
+    /// The transaction used the account's `Sequence`.
+    Sequence(u32),
+    /// The transaction used a Ticket; the value is its `TicketSequence`.
+    Ticket(u32),
+}
+
+impl EffectiveSequence {
+    /// Returns the sequence number, whichever field it came from.
+    pub const fn value(self) -> u32 {
+        match self {
+            EffectiveSequence::Sequence(value) | EffectiveSequence::Ticket(value) => value,
+        }
+    }
+
+    /// Returns whether the transaction used a Ticket.
+    pub const fn is_ticket(self) -> bool {
+        matches!(self, EffectiveSequence::Ticket(_))
+    }
+}
+
 /// Trait providing access to common fields present in all XRPL transactions.
 ///
 /// ## Implementation Requirements
@@ -70,6 +137,37 @@ pub trait TransactionCommonFields {
         get_field(sfield::Account)
     }
 
+    /// Retrieves the delegate from the current transaction.
+    ///
+    /// This optional field names the account that signed and submitted the transaction on
+    /// behalf of `Account`, using permissions `Account` granted it with a `DelegateSet`
+    /// transaction (Added by the PermissionDelegation amendment).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<AccountID>>` where:
+    /// * `Ok(Some(AccountID))` - The delegate that sent the transaction
+    /// * `Ok(None)` - If `Account` sent the transaction itself
+    /// * `Err(Error)` - If an error occurred during field retrieval
+    fn get_delegate(&self) -> Result<Option<AccountID>> {
+        get_field_optional(sfield::Delegate)
+    }
+
+    /// Reads `Account` and `Delegate` together, so a check can tell the account the
+    /// transaction acts for from the account that signed it. See [`Sender`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading `Account` or `Delegate`.
+    fn effective_sender(&self) -> Result<Sender> {
+        let account = match self.get_account() {
+            Result::Ok(account) => account,
+            Result::Err(e) => return Result::Err(e),
+        };
+        self.get_delegate()
+            .map(|delegate| Sender { account, delegate })
+    }
+
     /// Retrieves the transaction type from the current transaction.
     ///
     /// This field specifies the type of transaction. Valid transaction types include:
@@ -89,15 +187,16 @@ pub trait TransactionCommonFields {
     ///
     /// This field specifies the maximum computational resources that the transaction is
     /// allowed to consume during execution in the XRPL Programmability environment.
-    /// It helps prevent runaway computations and ensures network stability.
+    /// It helps prevent runaway computations and ensures network stability. Every
+    /// programmability trigger carries it, so it is available on all transaction types.
     ///
     /// # Returns
     ///
-    /// Returns a `Result<u32>` where:
-    /// * `Ok(u32)` - The computation allowance value in platform-defined units
+    /// Returns a `Result<Gas>` where:
+    /// * `Ok(Gas)` - The maximum gas the host will let the contract use
     /// * `Err(Error)` - If the field cannot be retrieved or has an unexpected size
-    fn get_computation_allowance(&self) -> Result<u32> {
-        get_field(sfield::ComputationAllowance)
+    fn get_computation_allowance(&self) -> Result<Gas> {
+        get_field(sfield::ComputationAllowance).map(Gas)
     }
 
     /// Retrieves the fee amount from the current transaction.
@@ -137,11 +236,32 @@ pub trait TransactionCommonFields {
     /// # Note
     ///
     /// If the transaction uses tickets instead of sequence numbers, this field may not
-    /// be present. In such cases, use `get_ticket_sequence()` instead.
+    /// be present. In such cases, use `get_ticket_sequence()` instead, or
+    /// [`effective_sequence`](Self::effective_sequence) to get whichever one was used.
     fn get_sequence(&self) -> Result<u32> {
         get_field(sfield::Sequence)
     }
 
+    /// Returns the sequence number the transaction consumed: `Sequence` if it is non-zero,
+    /// otherwise `TicketSequence`. See [`EffectiveSequence`].
+    ///
+    /// # Errors
+    ///
+    /// * `FieldNotFound` - `Sequence` is 0 and there is no `TicketSequence`
+    /// * Any error from reading `Sequence` or `TicketSequence`
+    fn effective_sequence(&self) -> Result<EffectiveSequence> {
+        match self.get_sequence() {
+            Result::Ok(0) => {}
+            Result::Ok(sequence) => return Result::Ok(EffectiveSequence::Sequence(sequence)),
+            Result::Err(e) => return Result::Err(e),
+        }
+        match self.get_ticket_sequence() {
+            Result::Ok(Some(ticket)) => Result::Ok(EffectiveSequence::Ticket(ticket)),
+            Result::Ok(None) => Result::Err(Error::FieldNotFound),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
     /// Retrieves the account transaction ID from the current transaction.
     ///
     /// This optional field contains the hash value identifying another transaction. If provided,
@@ -205,6 +325,22 @@ pub trait TransactionCommonFields {
         get_field_optional(sfield::NetworkID)
     }
 
+    /// Retrieves the operation limit from the current transaction.
+    ///
+    /// This optional field caps how much work the transaction may make the server do, such as
+    /// how many offers a payment may cross. It is reserved for future use and rippled does not
+    /// enforce it yet, but transactions may carry it.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<u32>>` where:
+    /// * `Ok(Some(u32))` - The operation limit
+    /// * `Ok(None)` - If no limit is specified
+    /// * `Err(Error)` - If an error occurred during field retrieval
+    fn get_operation_limit(&self) -> Result<Option<u32>> {
+        get_field_optional(sfield::OperationLimit)
+    }
+
     /// Retrieves the source tag from the current transaction.
     ///
     /// This optional field is an arbitrary integer used to identify the reason for this payment, or
@@ -221,6 +357,22 @@ pub trait TransactionCommonFields {
         get_field_optional(sfield::SourceTag)
     }
 
+    /// Checks that the transaction carries the source tag `expected`.
+    ///
+    /// # Errors
+    ///
+    /// * `FieldNotFound` - The transaction has no source tag
+    /// * `InvalidParams` - The transaction has a different source tag
+    /// * Any error returned while reading `SourceTag`
+    fn require_source_tag(&self, expected: u32) -> Result<()> {
+        match self.get_source_tag() {
+            Result::Ok(Some(tag)) if tag == expected => Result::Ok(()),
+            Result::Ok(Some(_)) => Result::Err(Error::InvalidParams),
+            Result::Ok(None) => Result::Err(Error::FieldNotFound),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
     /// Retrieves the signing public key from the current transaction.
     ///
     /// This field contains the hex representation of the public key that corresponds to the
@@ -254,6 +406,38 @@ pub trait TransactionCommonFields {
         })
     }
 
+    /// Returns the number of entries in the transaction's `Signers` array.
+    ///
+    /// A multi-signed transaction has an empty `SigningPubKey` and one `Signer` entry per
+    /// signature. Individual entries can be read with
+    /// [`get_nested_field`](crate::current_tx::get_nested_field).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<usize>>` where:
+    /// * `Ok(Some(usize))` - The number of signers of a multi-signed transaction
+    /// * `Ok(None)` - If the transaction is single-signed
+    /// * `Err(Error)` - If an error occurred during field retrieval
+    fn get_signers_count(&self) -> Result<Option<usize>> {
+        array_len_optional(sfield::Signers)
+    }
+
+    /// Returns the number of entries in the transaction's `Memos` array.
+    ///
+    /// Individual memos can be read with
+    /// [`get_nested_field`](crate::current_tx::get_nested_field), or parsed as commands with
+    /// [`memo_protocol`](crate::memo_protocol).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<usize>>` where:
+    /// * `Ok(Some(usize))` - The number of memos
+    /// * `Ok(None)` - If the transaction has no memos
+    /// * `Err(Error)` - If an error occurred during field retrieval
+    fn get_memos_count(&self) -> Result<Option<usize>> {
+        array_len_optional(sfield::Memos)
+    }
+
     /// Retrieves the ticket sequence from the current transaction.
     ///
     /// This optional field provides the sequence number of the ticket to use in place of a
@@ -297,6 +481,40 @@ pub trait TransactionCommonFields {
     fn get_txn_signature(&self) -> Result<SignatureBlob> {
         get_field(sfield::TxnSignature)
     }
+
+    /// Reads any field of the current transaction into `buf` without interpreting it.
+    ///
+    /// Use this for fields that have no typed getter yet. `field_code` is usually an `sfield`
+    /// constant, but a raw field code works too.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of bytes written to `buf`
+    /// * `Err(Error)` - `FieldNotFound` if the transaction does not have the field, or another
+    ///   host error
+    fn get_field_raw(&self, field_code: impl Into<i32>, buf: &mut [u8]) -> Result<usize> {
+        let result_code = unsafe { get_tx_field(field_code.into(), buf.as_mut_ptr(), buf.len()) };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Reads any field of the current transaction and decodes it as `T`.
+    ///
+    /// The field code is not tied to `T` at compile time, so a mismatch between the field and
+    /// `T` surfaces as `InvalidDecoding` rather than a type error.
+    fn get_field_typed<T: FromCurrentTx>(&self, field_code: impl Into<i32>) -> Result<T> {
+        let mut buf = T::Buffer::default();
+        self.get_field_raw(field_code, buf.as_mut())
+            .and_then(|len| decode_field(&buf.as_mut()[..len]))
+    }
+}
+
+fn array_len_optional<const CODE: i32>(field: SField<Array, CODE>) -> Result<Option<usize>> {
+    match array_len(field) {
+        Result::Ok(len) => Result::Ok(Some(len)),
+        Result::Err(Error::FieldNotFound) => Result::Ok(None),
+        Result::Err(e) => Result::Err(e),
+    }
 }
 
 #[cfg(test)]
@@ -351,6 +569,8 @@ mod tests {
                 expect_tx_field(&mut mock, sfield::LastLedgerSequence, 4, 1);
                 // get_network_id
                 expect_tx_field(&mut mock, sfield::NetworkID, 4, 1);
+                // get_operation_limit
+                expect_tx_field(&mut mock, sfield::OperationLimit, 4, 1);
                 // get_source_tag
                 expect_tx_field(&mut mock, sfield::SourceTag, 4, 1);
                 // get_ticket_sequence
@@ -365,6 +585,7 @@ mod tests {
                 assert!(tx.get_flags().unwrap().is_some());
                 assert!(tx.get_last_ledger_sequence().unwrap().is_some());
                 assert!(tx.get_network_id().unwrap().is_some());
+                assert!(tx.get_operation_limit().unwrap().is_some());
                 assert!(tx.get_source_tag().unwrap().is_some());
                 assert!(tx.get_ticket_sequence().unwrap().is_some());
             }
@@ -393,6 +614,11 @@ mod tests {
                     .with(eq(sfield::NetworkID), always(), eq(4))
                     .times(1)
                     .returning(|_, _, _| FIELD_NOT_FOUND);
+                // get_operation_limit
+                mock.expect_get_tx_field()
+                    .with(eq(sfield::OperationLimit), always(), eq(4))
+                    .times(1)
+                    .returning(|_, _, _| FIELD_NOT_FOUND);
                 // get_source_tag
                 mock.expect_get_tx_field()
                     .with(eq(sfield::SourceTag), always(), eq(4))
@@ -413,6 +639,7 @@ mod tests {
                 assert!(tx.get_flags().unwrap().is_none());
                 assert!(tx.get_last_ledger_sequence().unwrap().is_none());
                 assert!(tx.get_network_id().unwrap().is_none());
+                assert!(tx.get_operation_limit().unwrap().is_none());
                 assert!(tx.get_source_tag().unwrap().is_none());
                 assert!(tx.get_ticket_sequence().unwrap().is_none());
             }
@@ -975,4 +1202,287 @@ mod tests {
             }
         }
     }
+
+    mod source_tag {
+        use crate::current_tx::traits::TransactionCommonFields;
+        use crate::current_tx::traits::tests::TestTransaction;
+        use crate::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR, INVALID_PARAMS};
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use mockall::predicate::{always, eq};
+
+        fn mock_source_tag(code_or_tag: Result<u32, i32>) -> MockHostBindings {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .with(eq(sfield::SourceTag), always(), eq(4))
+                .times(1)
+                .returning(move |_, ptr, _| match code_or_tag {
+                    Ok(tag) => {
+                        unsafe { ptr.copy_from_nonoverlapping(tag.to_le_bytes().as_ptr(), 4) };
+                        4
+                    }
+                    Err(code) => code,
+                });
+            mock
+        }
+
+        #[test]
+        fn test_require_source_tag_matches() {
+            let _guard = setup_mock(mock_source_tag(Ok(7)));
+            assert!(TestTransaction.require_source_tag(7).is_ok());
+        }
+
+        #[test]
+        fn test_require_source_tag_rejects_other_tag() {
+            let _guard = setup_mock(mock_source_tag(Ok(8)));
+            let result = TestTransaction.require_source_tag(7);
+            assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+        }
+
+        #[test]
+        fn test_require_source_tag_rejects_missing_tag() {
+            let _guard = setup_mock(mock_source_tag(Err(FIELD_NOT_FOUND)));
+            let result = TestTransaction.require_source_tag(7);
+            assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+        }
+
+        #[test]
+        fn test_require_source_tag_propagates_host_errors() {
+            let mut mock = mock_source_tag(Err(INTERNAL_ERROR));
+            mock.expect_trace_num().returning(|_, _, _| 0);
+            let _guard = setup_mock(mock);
+            let result = TestTransaction.require_source_tag(7);
+            assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+        }
+    }
+
+    mod array_counts {
+        use crate::current_tx::traits::TransactionCommonFields;
+        use crate::current_tx::traits::tests::TestTransaction;
+        use crate::host::error_codes::{FIELD_NOT_FOUND, INVALID_FIELD};
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use mockall::predicate::eq;
+
+        #[test]
+        fn test_counts_of_present_arrays() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_array_len()
+                .with(eq(i32::from(sfield::Memos)))
+                .returning(|_| 2);
+            mock.expect_get_tx_array_len()
+                .with(eq(i32::from(sfield::Signers)))
+                .returning(|_| 3);
+            let _guard = setup_mock(mock);
+
+            assert_eq!(TestTransaction.get_memos_count().unwrap(), Some(2));
+            assert_eq!(TestTransaction.get_signers_count().unwrap(), Some(3));
+        }
+
+        #[test]
+        fn test_missing_arrays_are_none() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_array_len()
+                .returning(|_| FIELD_NOT_FOUND);
+            let _guard = setup_mock(mock);
+
+            assert_eq!(TestTransaction.get_memos_count().unwrap(), None);
+            assert_eq!(TestTransaction.get_signers_count().unwrap(), None);
+        }
+
+        #[test]
+        fn test_other_errors_propagate() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_array_len().returning(|_| INVALID_FIELD);
+            let _guard = setup_mock(mock);
+
+            let result = TestTransaction.get_memos_count();
+            assert_eq!(result.err().unwrap().code(), INVALID_FIELD);
+        }
+    }
+
+    mod delegate {
+        use crate::current_tx::traits::TransactionCommonFields;
+        use crate::current_tx::traits::tests::TestTransaction;
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use crate::types::account_id::AccountID;
+        use mockall::predicate::{always, eq};
+
+        fn mock_sender(delegate: Option<u8>) -> MockHostBindings {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .with(eq(sfield::Account), always(), eq(20))
+                .times(1)
+                .returning(|_, ptr, len| {
+                    unsafe { core::ptr::write_bytes(ptr, 0xAA, len) };
+                    20
+                });
+            mock.expect_get_tx_field()
+                .with(eq(sfield::Delegate), always(), eq(20))
+                .times(1)
+                .returning(move |_, ptr, len| match delegate {
+                    Some(byte) => {
+                        unsafe { core::ptr::write_bytes(ptr, byte, len) };
+                        20
+                    }
+                    None => FIELD_NOT_FOUND,
+                });
+            mock
+        }
+
+        #[test]
+        fn test_effective_sender_without_delegate() {
+            let _guard = setup_mock(mock_sender(None));
+            let sender = TestTransaction.effective_sender().unwrap();
+            assert!(!sender.is_delegated());
+            assert_eq!(sender.principal(), &AccountID([0xAA; 20]));
+            assert_eq!(sender.signer(), &AccountID([0xAA; 20]));
+        }
+
+        #[test]
+        fn test_effective_sender_with_delegate() {
+            let _guard = setup_mock(mock_sender(Some(0xDD)));
+            let sender = TestTransaction.effective_sender().unwrap();
+            assert!(sender.is_delegated());
+            assert_eq!(sender.principal(), &AccountID([0xAA; 20]));
+            assert_eq!(sender.signer(), &AccountID([0xDD; 20]));
+        }
+    }
+
+    mod effective_sequence {
+        use crate::current_tx::traits::tests::TestTransaction;
+        use crate::current_tx::traits::{EffectiveSequence, TransactionCommonFields};
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use mockall::predicate::{always, eq};
+
+        fn write_u32(ptr: *mut u8, value: u32) -> i32 {
+            unsafe { ptr.copy_from_nonoverlapping(value.to_le_bytes().as_ptr(), 4) };
+            4
+        }
+
+        fn mock_sequences(sequence: u32, ticket: Option<u32>) -> MockHostBindings {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .with(eq(sfield::Sequence), always(), eq(4))
+                .times(1)
+                .returning(move |_, ptr, _| write_u32(ptr, sequence));
+            mock.expect_get_tx_field()
+                .with(eq(sfield::TicketSequence), always(), eq(4))
+                .returning(move |_, ptr, _| match ticket {
+                    Some(ticket) => write_u32(ptr, ticket),
+                    None => FIELD_NOT_FOUND,
+                });
+            mock
+        }
+
+        #[test]
+        fn test_non_zero_sequence_wins() {
+            let _guard = setup_mock(mock_sequences(12, Some(40)));
+            let sequence = TestTransaction.effective_sequence().unwrap();
+            assert_eq!(sequence, EffectiveSequence::Sequence(12));
+            assert!(!sequence.is_ticket());
+        }
+
+        #[test]
+        fn test_zero_sequence_uses_ticket() {
+            let _guard = setup_mock(mock_sequences(0, Some(40)));
+            let sequence = TestTransaction.effective_sequence().unwrap();
+            assert_eq!(sequence, EffectiveSequence::Ticket(40));
+            assert_eq!(sequence.value(), 40);
+        }
+
+        #[test]
+        fn test_zero_sequence_without_ticket_is_an_error() {
+            let _guard = setup_mock(mock_sequences(0, None));
+            let result = TestTransaction.effective_sequence();
+            assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+        }
+    }
+
+    mod raw_fields {
+        use crate::current_tx::traits::TransactionCommonFields;
+        use crate::current_tx::traits::tests::TestTransaction;
+        use crate::host::error_codes::{FIELD_NOT_FOUND, INVALID_DECODING};
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use crate::types::account_id::AccountID;
+        use mockall::predicate::{always, eq};
+
+        #[test]
+        fn test_get_field_raw_returns_bytes_written() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .with(eq(sfield::Destination), always(), eq(64))
+                .times(1)
+                .returning(|_, ptr, _| {
+                    unsafe { core::ptr::write_bytes(ptr, 0xAB, 20) };
+                    20
+                });
+            let _guard = setup_mock(mock);
+
+            let mut buf = [0u8; 64];
+            let len = TestTransaction
+                .get_field_raw(sfield::Destination, &mut buf)
+                .unwrap();
+
+            assert_eq!(len, 20);
+            assert_eq!(&buf[..len], &[0xAB; 20]);
+        }
+
+        #[test]
+        fn test_get_field_raw_returns_error_when_field_not_found() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .times(1)
+                .returning(|_, _, _| FIELD_NOT_FOUND);
+            let _guard = setup_mock(mock);
+
+            let mut buf = [0u8; 4];
+            let result = TestTransaction.get_field_raw(0x0002_0099, &mut buf);
+
+            assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+        }
+
+        #[test]
+        fn test_get_field_typed_decodes_value() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .with(eq(sfield::Destination), always(), eq(20))
+                .times(1)
+                .returning(|_, ptr, _| {
+                    unsafe { core::ptr::write_bytes(ptr, 0xCD, 20) };
+                    20
+                });
+            let _guard = setup_mock(mock);
+
+            let destination = TestTransaction
+                .get_field_typed::<AccountID>(sfield::Destination)
+                .unwrap();
+
+            assert_eq!(destination, AccountID([0xCD; 20]));
+        }
+
+        #[test]
+        fn test_get_field_typed_rejects_short_value() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_field()
+                .with(eq(sfield::Sequence), always(), eq(8))
+                .times(1)
+                .returning(|_, _, _| 4);
+            let _guard = setup_mock(mock);
+
+            let result = TestTransaction.get_field_typed::<u64>(sfield::Sequence);
+
+            assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+        }
+    }
 }
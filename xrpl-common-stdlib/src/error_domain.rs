@@ -0,0 +1,204 @@
+//! Namespaced error codes for contract-defined failures.
+//!
+//! A contract that rejects with a negative code can tell its callers exactly which check failed,
+//! but only if the codes stay unique as the contract grows. This module splits the negative range
+//! into domains of [`CODES_PER_DOMAIN`] codes: code `c` in domain `d` is `-(d * 100 + c)`, so
+//! domain 3 owns `-301` to `-399`. Domain 0 (`-1` to `-99`) is left to the host's own
+//! [`error_codes`](crate::host::error_codes).
+//!
+//! [`error_range!`] declares a set of domains, each as a module of named codes, and checks at
+//! compile time that no two domains share an id and no two codes in a domain share a number:
+//!
+//! ```
+//! use xrpl_common_stdlib::error_domain::{self, error_range};
+//!
+//! error_range! {
+//!     /// Checks on the finishing transaction.
+//!     pub mod tx_errors = 1 {
+//!         WRONG_ACCOUNT = 1,
+//!         MISSING_MEMO = 2,
+//!     }
+//!     /// Checks on the oracle the escrow reads.
+//!     pub mod oracle_errors = 2 {
+//!         STALE_PRICE = 1,
+//!     }
+//! }
+//!
+//! assert_eq!(tx_errors::MISSING_MEMO, -102);
+//! assert_eq!(oracle_errors::STALE_PRICE, -201);
+//! assert!(oracle_errors::DOMAIN.contains(oracle_errors::STALE_PRICE));
+//! assert_eq!(error_domain::decode(-102), Some((1, 2)));
+//! ```
+//!
+//! The codes are plain `i32` constants, so they can be returned directly from a raw `finish`, or
+//! passed to `FinishResult::reject_with` or `FinishResult::from`. Collisions are only detected
+//! within one `error_range!` invocation, so declare all of a contract's domains together.
+
+/// Number of codes reserved for each domain, including the unused code 0.
+pub const CODES_PER_DOMAIN: i32 = 100;
+
+/// A block of [`CODES_PER_DOMAIN`] negative error codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorDomain(u16);
+
+impl ErrorDomain {
+    /// Creates domain `id`.
+    ///
+    /// # Panics
+    ///
+    /// If `id` is 0, which is reserved for host error codes. In a `const` this is a compile
+    /// error.
+    pub const fn new(id: u16) -> Self {
+        assert!(id != 0, "error domain 0 is reserved for host error codes");
+        ErrorDomain(id)
+    }
+
+    /// The domain's id.
+    pub const fn id(self) -> u16 {
+        self.0
+    }
+
+    /// Returns code `code` of this domain, `-(id * 100 + code)`.
+    ///
+    /// # Panics
+    ///
+    /// If `code` is not between 1 and 99. In a `const` this is a compile error.
+    pub const fn code(self, code: u8) -> i32 {
+        assert!(
+            code >= 1 && (code as i32) < CODES_PER_DOMAIN,
+            "error code must be between 1 and 99"
+        );
+        -(self.0 as i32 * CODES_PER_DOMAIN + code as i32)
+    }
+
+    /// Returns whether `code` belongs to this domain.
+    pub const fn contains(self, code: i32) -> bool {
+        match decode(code) {
+            Some((id, _)) => id == self.0,
+            None => false,
+        }
+    }
+}
+
+/// Splits a code produced by [`ErrorDomain::code`] into its domain id and code number.
+///
+/// Returns `None` for non-negative values, host error codes (domain 0), the unused code 0 of a
+/// domain, and values whose domain would not fit in a `u16`.
+pub const fn decode(code: i32) -> Option<(u16, u8)> {
+    if code >= 0 {
+        return None;
+    }
+    let magnitude = code.unsigned_abs();
+    let id = magnitude / CODES_PER_DOMAIN as u32;
+    let number = magnitude % CODES_PER_DOMAIN as u32;
+    if id == 0 || id > u16::MAX as u32 || number == 0 {
+        return None;
+    }
+    Some((id as u16, number as u8))
+}
+
+#[doc(hidden)]
+pub const fn __assert_distinct_domains(ids: &[u16]) {
+    let mut i = 0;
+    while i < ids.len() {
+        let mut j = i + 1;
+        while j < ids.len() {
+            assert!(ids[i] != ids[j], "two error domains share the same id");
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+#[doc(hidden)]
+pub const fn __assert_distinct_codes(codes: &[u8]) {
+    let mut i = 0;
+    while i < codes.len() {
+        let mut j = i + 1;
+        while j < codes.len() {
+            assert!(
+                codes[i] != codes[j],
+                "two error codes in a domain share the same number"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __error_range {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis mod $domain:ident = $id:literal {
+            $($(#[$code_meta:meta])* $code:ident = $number:literal),* $(,)?
+        }
+    )+) => {
+        $(
+            $(#[$meta])*
+            $vis mod $domain {
+                /// The domain these codes belong to.
+                pub const DOMAIN: $crate::error_domain::ErrorDomain =
+                    $crate::error_domain::ErrorDomain::new($id);
+                $(
+                    $(#[$code_meta])*
+                    pub const $code: i32 = DOMAIN.code($number);
+                )*
+                const _: () = $crate::error_domain::__assert_distinct_codes(&[$($number),*]);
+            }
+        )+
+        const _: () = $crate::error_domain::__assert_distinct_domains(&[$($id),+]);
+    };
+}
+
+/// Declares error domains as modules of named `i32` codes, rejecting duplicate domain ids and
+/// duplicate codes within a domain at compile time. See the [module documentation](self).
+#[doc(inline)]
+pub use crate::__error_range as error_range;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    error_range! {
+        mod ledger = 1 {
+            SEQUENCE = 1,
+            PARENT_HASH = 3,
+        }
+        mod utility = 6 {
+            /// Documented codes keep their docs.
+            TRACE = 99,
+        }
+        mod empty = 7 {}
+    }
+
+    #[test]
+    fn test_codes_are_negative_and_namespaced() {
+        assert_eq!(ledger::SEQUENCE, -101);
+        assert_eq!(ledger::PARENT_HASH, -103);
+        assert_eq!(utility::TRACE, -699);
+        assert_eq!(utility::DOMAIN.id(), 6);
+        assert_eq!(empty::DOMAIN, ErrorDomain::new(7));
+    }
+
+    #[test]
+    fn test_decode_round_trips_domain_codes_only() {
+        assert_eq!(decode(ledger::PARENT_HASH), Some((1, 3)));
+        assert_eq!(
+            decode(ErrorDomain::new(u16::MAX).code(99)),
+            Some((u16::MAX, 99))
+        );
+        for not_a_domain_code in [1, 0, -1, -99, -100, -600, i32::MIN] {
+            assert_eq!(decode(not_a_domain_code), None, "{not_a_domain_code}");
+        }
+        assert!(ledger::DOMAIN.contains(-150));
+        assert!(!ledger::DOMAIN.contains(utility::TRACE));
+    }
+
+    #[test]
+    #[should_panic(expected = "between 1 and 99")]
+    fn test_code_out_of_range_panics() {
+        let _ = ErrorDomain::new(1).code(100);
+    }
+}
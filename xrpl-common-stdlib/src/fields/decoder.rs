@@ -33,8 +33,39 @@
 //!
 //! assert!(Flag::decode(&[2]).is_err());
 //! ```
+//!
+//! # Nested fields
+//!
+//! The `sfield`-based getters infer their type from an `SField`. A nested path has no single
+//! `SField` to carry the type, so nested reads take any [`FieldDecoder`] the caller names
+//! instead, and a field that does not decode as that type is `InvalidDecoding`:
+//!
+//! ```rust,no_run
+//! use xrpl_common_stdlib::current_tx;
+//! use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
+//! use xrpl_common_stdlib::sfield;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! let mut locator = Locator::new();
+//! locator.pack(sfield::Signers).unwrap();
+//! locator.pack(LocatorEntry::Index(0)).unwrap();
+//! locator.pack(sfield::Account).unwrap();
+//! let first_signer: AccountID = current_tx::get_nested_field(&locator).unwrap();
+//! ```
+//!
+//! The same reads are available for the current ledger object
+//! ([`current_ledger_object::get_nested_field`](crate::current_ledger_object::get_nested_field))
+//! and cached ledger objects
+//! ([`ledger_object::get_nested_field`](crate::objects::ledger_object::get_nested_field)).
 
 use crate::host::Error;
+use crate::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::types::amount::{AMOUNT_SIZE, Amount};
+use crate::types::blob::Blob;
+use crate::types::currency::{CURRENCY_SIZE, Currency};
+use crate::types::uint::{
+    HASH128_SIZE, HASH160_SIZE, HASH192_SIZE, HASH256_SIZE, Hash128, Hash160, Hash192, Hash256,
+};
 
 /// Decodes a fixed-format XRPL field from its raw byte representation.
 pub trait FieldDecoder: Sized {
@@ -52,6 +83,106 @@ pub trait FromCurrentTx: FieldDecoder {}
 /// Marker trait for fields that can be decoded from a ledger object.
 pub trait FromLedger: FieldDecoder {}
 
+/// A zeroed stack buffer of `N` bytes, for fields whose buffer is too large for `[u8; N]` to
+/// implement `Default`.
+pub struct FieldBuffer<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for FieldBuffer<N> {
+    fn default() -> Self {
+        FieldBuffer([0; N])
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for FieldBuffer<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Decodes raw field bytes read by a generic getter, converting the error into a host result.
+pub(crate) fn decode_field<T: FieldDecoder>(bytes: &[u8]) -> crate::host::Result<T> {
+    match T::decode(bytes) {
+        Ok(value) => crate::host::Result::Ok(value),
+        Err(e) => crate::host::Result::Err(e),
+    }
+}
+
+/// Unsigned integers are stored in the host's native byte order, matching the typed getters.
+macro_rules! impl_uint_decoder {
+    ($($ty:ty),*) => {$(
+        impl FieldDecoder for $ty {
+            type Buffer = [u8; core::mem::size_of::<$ty>()];
+
+            fn decode(bytes: &[u8]) -> Result<Self, Error> {
+                bytes
+                    .try_into()
+                    .map(<$ty>::from_ne_bytes)
+                    .map_err(|_| Error::InvalidDecoding)
+            }
+        }
+        impl FromCurrentTx for $ty {}
+        impl FromLedger for $ty {}
+    )*};
+}
+
+impl_uint_decoder!(u8, u16, u32, u64);
+
+/// Fixed-width byte types decode from exactly their own size.
+macro_rules! impl_bytes_decoder {
+    ($($ty:ty => $size:expr),*) => {$(
+        impl FieldDecoder for $ty {
+            type Buffer = [u8; $size];
+
+            fn decode(bytes: &[u8]) -> Result<Self, Error> {
+                <[u8; $size]>::try_from(bytes)
+                    .map(<$ty>::from)
+                    .map_err(|_| Error::InvalidDecoding)
+            }
+        }
+        impl FromCurrentTx for $ty {}
+        impl FromLedger for $ty {}
+    )*};
+}
+
+impl_bytes_decoder!(
+    AccountID => ACCOUNT_ID_SIZE,
+    Currency => CURRENCY_SIZE,
+    Hash128 => HASH128_SIZE,
+    Hash160 => HASH160_SIZE,
+    Hash192 => HASH192_SIZE,
+    Hash256 => HASH256_SIZE
+);
+
+/// Amounts are 8 (XRP), 33 (MPT) or 48 (IOU) bytes long; see [`Amount::from_host_bytes`].
+impl FieldDecoder for Amount {
+    type Buffer = FieldBuffer<AMOUNT_SIZE>;
+
+    fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() > AMOUNT_SIZE {
+            return Err(Error::InvalidDecoding);
+        }
+        let mut buffer = [0u8; AMOUNT_SIZE];
+        buffer[..bytes.len()].copy_from_slice(bytes);
+        Ok(Amount::from_host_bytes(buffer, bytes.len()))
+    }
+}
+impl FromCurrentTx for Amount {}
+impl FromLedger for Amount {}
+
+/// Blobs take any length up to their capacity.
+impl<const N: usize> FieldDecoder for Blob<N> {
+    type Buffer = FieldBuffer<N>;
+
+    fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() > N {
+            return Err(Error::InvalidDecoding);
+        }
+        Ok(Blob::from_slice(bytes))
+    }
+}
+impl<const N: usize> FromCurrentTx for Blob<N> {}
+impl<const N: usize> FromLedger for Blob<N> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,6 +263,32 @@ mod tests {
         assert_eq!(decoded, TxOnly(42));
     }
 
+    #[test]
+    fn builtin_types_implement_both() {
+        assert_from_current_tx::<u32>();
+        assert_from_ledger::<u64>();
+        assert_from_current_tx::<AccountID>();
+        assert_from_ledger::<Hash256>();
+    }
+
+    #[test]
+    fn uint_decode_requires_exact_width() {
+        assert_eq!(u32::decode(&7u32.to_ne_bytes()).unwrap(), 7);
+        assert_eq!(
+            u32::decode(&[1, 2]).unwrap_err().code(),
+            Error::InvalidDecoding.code()
+        );
+    }
+
+    #[test]
+    fn bytes_decode_requires_exact_width() {
+        assert_eq!(AccountID::decode(&[9; 20]).unwrap(), AccountID([9; 20]));
+        assert_eq!(
+            Hash256::decode(&[0; 31]).unwrap_err().code(),
+            Error::InvalidDecoding.code()
+        );
+    }
+
     #[test]
     fn decode_returns_error_on_empty_input() {
         let result = TxOnly::decode(&[]);
@@ -144,4 +301,130 @@ mod tests {
         let mut buffer = <TxOnly as FieldDecoder>::Buffer::default();
         assert_eq!(buffer.as_mut().len(), 1);
     }
+
+    mod nested {
+        use super::*;
+        use crate::current_ledger_object;
+        use crate::current_tx;
+        use crate::fields::locator::{Locator, LocatorEntry};
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+        use crate::host::error_codes::INVALID_DECODING;
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::objects::ledger_object;
+        use crate::sfield;
+        use crate::types::account_id::ACCOUNT_ID_SIZE;
+        use crate::types::blob::StandardBlob;
+        use mockall::predicate::{always, eq};
+
+        fn signer_account_locator() -> Locator {
+            let mut locator = Locator::new();
+            locator.pack(sfield::Signers).unwrap();
+            locator.pack(LocatorEntry::Index(1)).unwrap();
+            locator.pack(sfield::Account).unwrap();
+            locator
+        }
+
+        #[test]
+        fn test_current_tx_nested_fixed_size() {
+            let locator = signer_account_locator();
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_nested_field()
+                .with(always(), eq(locator.len()), always(), eq(ACCOUNT_ID_SIZE))
+                .times(1)
+                .returning(|_, _, out, len| {
+                    unsafe { core::ptr::write_bytes(out, 0x0A, len) };
+                    len as i32
+                });
+            mock.expect_get_tx_nested_field()
+                .with(always(), always(), always(), eq(4))
+                .times(1)
+                .returning(|_, _, out, _| {
+                    unsafe { core::ptr::copy_nonoverlapping(7u32.to_ne_bytes().as_ptr(), out, 4) };
+                    4
+                });
+            let _guard = setup_mock(mock);
+
+            let account: AccountID = current_tx::get_nested_field(&locator).unwrap();
+            assert_eq!(account, AccountID([0x0A; ACCOUNT_ID_SIZE]));
+            let sequence: u32 = current_tx::get_nested_field(&locator).unwrap();
+            assert_eq!(sequence, 7);
+        }
+
+        #[test]
+        fn test_current_ledger_object_nested_variable_size() {
+            let locator = signer_account_locator();
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_nested_field()
+                .times(1)
+                .returning(|_, _, out, _| {
+                    unsafe { core::ptr::write_bytes(out, 0xB0, 5) };
+                    5
+                });
+            let _guard = setup_mock(mock);
+
+            let memo: StandardBlob = current_ledger_object::get_nested_field(&locator).unwrap();
+            assert_eq!(memo.as_slice(), &[0xB0; 5]);
+        }
+
+        #[test]
+        fn test_ledger_object_nested_uses_slot_and_propagates_errors() {
+            let locator = signer_account_locator();
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_ledger_obj_nested_field()
+                .with(eq(3), always(), always(), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _, out, len| {
+                    unsafe { core::ptr::write_bytes(out, 0xCC, len) };
+                    len as i32
+                });
+            mock.expect_get_ledger_obj_nested_field()
+                .with(eq(4), always(), always(), always(), always())
+                .times(1)
+                .returning(|_, _, _, _, _| FIELD_NOT_FOUND);
+            mock.expect_trace_num().returning(|_, _, _| 0);
+            let _guard = setup_mock(mock);
+
+            let hash: Hash256 = ledger_object::get_nested_field(3, &locator).unwrap();
+            assert_eq!(hash, Hash256::from([0xCC; 32]));
+            let result = ledger_object::get_nested_field::<Hash256>(4, &locator);
+            assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+        }
+
+        #[test]
+        fn test_nested_size_mismatch_is_invalid_decoding() {
+            let locator = signer_account_locator();
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_tx_nested_field()
+                .times(1)
+                .returning(|_, _, _, _| 19);
+            mock.expect_get_ledger_obj_nested_field()
+                .times(1)
+                .returning(|_, _, _, _, _| 2);
+            let _guard = setup_mock(mock);
+
+            let account = current_tx::get_nested_field::<AccountID>(&locator);
+            assert_eq!(account.err().unwrap().code(), INVALID_DECODING);
+            let sequence = ledger_object::get_nested_field::<u32>(1, &locator);
+            assert_eq!(sequence.err().unwrap().code(), INVALID_DECODING);
+        }
+
+        #[test]
+        fn test_nested_amount_keeps_its_length() {
+            let locator = signer_account_locator();
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_nested_field()
+                .with(always(), always(), always(), eq(AMOUNT_SIZE))
+                .times(1)
+                .returning(|_, _, out, _| {
+                    let drops = (0x4000_0000_0000_0000u64 | 25).to_be_bytes();
+                    unsafe { core::ptr::copy_nonoverlapping(drops.as_ptr(), out, 8) };
+                    8
+                });
+            let _guard = setup_mock(mock);
+
+            let amount: Amount = current_ledger_object::get_nested_field(&locator).unwrap();
+            assert_eq!(amount, Amount::XRP { num_drops: 25 });
+        }
+    }
 }
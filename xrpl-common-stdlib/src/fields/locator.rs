@@ -5,18 +5,65 @@
 //!
 //! Example
 //! ```no_run
-//! use xrpl_common_stdlib::fields::locator::Locator;
+//! use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
 //! use xrpl_common_stdlib::sfield;
 //! let mut l = Locator::new();
-//! l.pack(sfield::Memos);
-//! l.pack(0);
-//! l.pack(sfield::MemoType);
-//! # let _ = (l.len() >= 3);
+//! let packed = l
+//!     .pack(sfield::Memos)
+//!     .and_then(|_| l.pack(LocatorEntry::Index(0)))
+//!     .and_then(|_| l.pack(sfield::MemoType));
+//! # let _ = (packed.is_ok(), l.len() >= 3);
 //! ```
+//!
+//! In debug builds, each entry is checked against the path so far, so that e.g. an index under a
+//! non-array field is refused by [`Locator::pack`] rather than by the host. Release builds only
+//! check the maximum depth.
+//!
+//! A locator can be stored, e.g. in the `Data` field between two invocations, with
+//! [`Locator::as_bytes`] and restored with [`Locator::from_bytes`].
+
+use crate::host::{Error, Result};
+use crate::sfield::SField;
+#[cfg(debug_assertions)]
+use crate::type_codes::STI_VECTOR256;
 
 /// The size of the buffer, in bytes, to use for any new locator
 const LOCATOR_BUFFER_SIZE: usize = 64; // max depth: 64/4 = 16
 
+/// One entry of a locator path.
+///
+/// Sfield codes and array indices share the same 4-byte encoding, so the kind of each entry is
+/// carried explicitly rather than guessed from its value. [`SField`] constants and raw `i32`
+/// sfield codes convert into [`Field`](Self::Field); an array index has to be wrapped in
+/// `LocatorEntry::Index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocatorEntry {
+    /// An sfield code, `(type_code << 16) | field_code`.
+    Field(i32),
+    /// A position in the preceding array field.
+    Index(i32),
+}
+
+impl LocatorEntry {
+    const fn value(self) -> i32 {
+        match self {
+            LocatorEntry::Field(code) | LocatorEntry::Index(code) => code,
+        }
+    }
+}
+
+impl<T, const CODE: i32> From<SField<T, CODE>> for LocatorEntry {
+    fn from(_field: SField<T, CODE>) -> Self {
+        LocatorEntry::Field(CODE)
+    }
+}
+
+impl From<i32> for LocatorEntry {
+    fn from(code: i32) -> Self {
+        LocatorEntry::Field(code)
+    }
+}
+
 /// A Locator encodes a path to a nested field as a sequence of 4-byte packed values
 /// (sfield codes or array indices) in a compact binary format understood by the host.
 ///
@@ -26,7 +73,8 @@ const LOCATOR_BUFFER_SIZE: usize = 64; // max depth: 64/4 = 16
 /// - `Clone`: Reasonable for this 72-byte struct when explicit copying is needed
 /// - `Eq, PartialEq`: Enable comparisons between locators
 ///
-/// Note: `Copy` is intentionally not derived due to the struct's size (72 bytes).
+/// Note: `Copy` is intentionally not derived due to the struct's size (72 bytes, plus the entry
+/// kinds tracked in debug builds).
 /// Large `Copy` types can lead to accidental expensive copies and poor performance.
 /// Use `.clone()` when you need to duplicate a locator.
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -36,6 +84,10 @@ pub struct Locator {
 
     /// An index into `buffer` where the next packing operation can be stored.
     cur_buffer_index: usize,
+
+    /// Bit `i` is set if entry `i` is an array index rather than an sfield code.
+    #[cfg(debug_assertions)]
+    index_entries: u16,
 }
 
 impl Default for Locator {
@@ -50,19 +102,29 @@ impl Locator {
         Self {
             buffer: [0; LOCATOR_BUFFER_SIZE],
             cur_buffer_index: 0,
+            #[cfg(debug_assertions)]
+            index_entries: 0,
         }
     }
 
-    pub fn pack(&mut self, sfield_or_index: impl Into<i32>) -> bool {
-        if self.cur_buffer_index + 4 > LOCATOR_BUFFER_SIZE {
-            return false;
+    /// Appends an sfield or array index to the path.
+    ///
+    /// # Errors
+    ///
+    /// Leaves the locator unchanged and returns `LocatorMalformed` if it is already at its
+    /// maximum depth of 16 or, in debug builds, if the entry cannot legally follow the path so
+    /// far: the path must start with a field, an array field must be followed by an index, an
+    /// index or object field by a field, and nothing may follow a leaf field or an element of an
+    /// `STVector256`. Entries following an sfield with an unrecognized type code are not checked.
+    pub fn pack(&mut self, entry: impl Into<LocatorEntry>) -> Result<()> {
+        let entry = entry.into();
+        let count = self.count();
+        if count == LOCATOR_BUFFER_SIZE / 4 || !self.may_follow(count, entry) {
+            return Result::Err(Error::LocatorMalformed);
         }
-
-        let value_bytes: [u8; 4] = sfield_or_index.into().to_le_bytes();
-        self.buffer[self.cur_buffer_index..self.cur_buffer_index + 4].copy_from_slice(&value_bytes);
+        self.write_entry(count, entry);
         self.cur_buffer_index += 4;
-
-        true
+        Result::Ok(())
     }
 
     pub fn as_ptr(&self) -> *const u8 {
@@ -81,24 +143,192 @@ impl Locator {
         self.cur_buffer_index == 0
     }
 
-    pub fn repack_last(&mut self, sfield_or_index: impl Into<i32>) -> bool {
-        if self.cur_buffer_index < 4 {
-            return false;
+    /// The packed path, as passed to the host: 4 little-endian bytes per entry.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.cur_buffer_index]
+    }
+
+    /// Restores a locator from bytes produced by [`as_bytes`](Self::as_bytes).
+    ///
+    /// In debug builds the entries are checked as [`pack`](Self::pack) would. The bytes do not
+    /// say which entries are indices, so each entry is classified by the entry before it: the
+    /// first is a field, and an array field is followed by an index.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If `bytes` is not a whole number of entries or is longer than the
+    ///   maximum depth of 16. In debug builds, also if it contains an entry that `pack` would
+    ///   refuse, or an entry after an sfield with an unrecognized type code (which leaves the
+    ///   kind of that entry unknown)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Locator> {
+        if bytes.len() % 4 != 0 || bytes.len() > LOCATOR_BUFFER_SIZE {
+            return Result::Err(Error::InvalidDecoding);
+        }
+        let mut locator = Locator::new();
+        for chunk in bytes.chunks_exact(4) {
+            let value = i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let packed = match locator.decoded_entry(value) {
+                Some(entry) => locator.pack(entry),
+                None => Result::Err(Error::InvalidDecoding),
+            };
+            if packed.is_err() {
+                return Result::Err(Error::InvalidDecoding);
+            }
+        }
+        Result::Ok(locator)
+    }
+
+    /// Replaces the last entry of the path, e.g. to read a sibling field of the same array
+    /// element.
+    ///
+    /// # Errors
+    ///
+    /// Leaves the locator unchanged and returns `LocatorMalformed` if it is empty or, in debug
+    /// builds, if `entry` cannot follow the entries before the last one (see
+    /// [`pack`](Self::pack)).
+    pub fn repack_last(&mut self, entry: impl Into<LocatorEntry>) -> Result<()> {
+        let entry = entry.into();
+        let count = self.count();
+        if count == 0 || !self.may_follow(count - 1, entry) {
+            return Result::Err(Error::LocatorMalformed);
         }
+        self.write_entry(count - 1, entry);
+        Result::Ok(())
+    }
 
-        self.cur_buffer_index -= 4;
+    fn count(&self) -> usize {
+        self.cur_buffer_index / 4
+    }
 
-        let value_bytes: [u8; 4] = sfield_or_index.into().to_le_bytes();
-        self.buffer[self.cur_buffer_index..self.cur_buffer_index + 4].copy_from_slice(&value_bytes);
-        self.cur_buffer_index += 4;
+    #[cfg(debug_assertions)]
+    fn entry(&self, position: usize) -> LocatorEntry {
+        let start = position * 4;
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&self.buffer[start..start + 4]);
+        let value = i32::from_le_bytes(bytes);
+        if self.index_entries & (1 << position) != 0 {
+            LocatorEntry::Index(value)
+        } else {
+            LocatorEntry::Field(value)
+        }
+    }
+
+    fn write_entry(&mut self, position: usize, entry: LocatorEntry) {
+        let start = position * 4;
+        self.buffer[start..start + 4].copy_from_slice(&entry.value().to_le_bytes());
+        #[cfg(debug_assertions)]
+        match entry {
+            LocatorEntry::Field(_) => self.index_entries &= !(1 << position),
+            LocatorEntry::Index(_) => self.index_entries |= 1 << position,
+        }
+    }
+
+    /// Classifies the next entry of a path being decoded, or returns `None` if no entry may
+    /// follow.
+    #[cfg(debug_assertions)]
+    fn decoded_entry(&self, value: i32) -> Option<LocatorEntry> {
+        match self.expected(self.count()) {
+            Expected::Field => Some(LocatorEntry::Field(value)),
+            Expected::Index => Some(LocatorEntry::Index(value)),
+            Expected::Nothing | Expected::Any => None,
+        }
+    }
+
+    /// Release builds do not track entry kinds, so every decoded entry is stored as is.
+    #[cfg(not(debug_assertions))]
+    fn decoded_entry(&self, value: i32) -> Option<LocatorEntry> {
+        Some(LocatorEntry::Field(value))
+    }
+
+    /// Returns what may be stored at `position`, given the entries before it.
+    #[cfg(debug_assertions)]
+    fn expected(&self, position: usize) -> Expected {
+        if position == 0 {
+            return Expected::Field;
+        }
+        match self.entry(position - 1) {
+            LocatorEntry::Field(code) => match nesting_of(code) {
+                Some(Nesting::Indexed) => Expected::Index,
+                Some(Nesting::Object) => Expected::Field,
+                Some(Nesting::Leaf) => Expected::Nothing,
+                None => Expected::Any,
+            },
+            LocatorEntry::Index(_) => match self.entry(position - 2) {
+                LocatorEntry::Field(code) if type_code_of(code) == Some(STI_VECTOR256) => {
+                    Expected::Nothing
+                }
+                _ => Expected::Field,
+            },
+        }
+    }
 
+    #[cfg(debug_assertions)]
+    fn may_follow(&self, position: usize, entry: LocatorEntry) -> bool {
+        if entry.value() < 0 {
+            return false;
+        }
+        matches!(
+            (self.expected(position), entry),
+            (Expected::Any, _)
+                | (Expected::Field, LocatorEntry::Field(_))
+                | (Expected::Index, LocatorEntry::Index(_))
+        )
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn may_follow(&self, _position: usize, _entry: LocatorEntry) -> bool {
         true
     }
 }
 
+/// What the next entry of a locator path may be.
+#[cfg(debug_assertions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expected {
+    Field,
+    Index,
+    Nothing,
+    /// The previous sfield has an unrecognized type code, so nothing is checked.
+    Any,
+}
+
+/// Classifies an sfield by the kind of entry that may follow it in a locator path.
+#[cfg(debug_assertions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nesting {
+    /// Must be followed by an array index (`STArray`, `STVector256`).
+    Indexed,
+    /// May be followed by another sfield (`STObject`).
+    Object,
+    /// Nothing may follow (all scalar and blob types).
+    Leaf,
+}
+
+#[cfg(debug_assertions)]
+fn type_code_of(code: i32) -> Option<u8> {
+    u8::try_from(code >> 16).ok()
+}
+
+/// Returns how the sfield `code` may be nested, or `None` if its type code is unknown.
+#[cfg(debug_assertions)]
+fn nesting_of(code: i32) -> Option<Nesting> {
+    use crate::type_codes::*;
+
+    match type_code_of(code)? {
+        STI_ARRAY | STI_VECTOR256 => Some(Nesting::Indexed),
+        STI_OBJECT => Some(Nesting::Object),
+        STI_UINT16 | STI_UINT32 | STI_UINT64 | STI_UINT128 | STI_UINT256 | STI_AMOUNT | STI_VL
+        | STI_ACCOUNT | STI_NUMBER | STI_INT32 | STI_INT64 | STI_UINT8 | STI_UINT160
+        | STI_UINT96 | STI_UINT192 | STI_UINT384 | STI_UINT512 | STI_ISSUE | STI_CURRENCY
+        | STI_DATA | STI_DATATYPE => Some(Nesting::Leaf),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::host::error_codes::LOCATOR_MALFORMED;
     use crate::sfield;
 
     #[test]
@@ -107,35 +337,46 @@ mod tests {
         let mut locator = Locator::new();
 
         // Pack SField constants directly without .into()
-        assert!(locator.pack(sfield::Memos));
-        assert!(locator.pack(0));
-        assert!(locator.pack(sfield::MemoData));
+        assert!(locator.pack(sfield::Memos).is_ok());
+        assert!(locator.pack(LocatorEntry::Index(0)).is_ok());
+        assert!(locator.pack(sfield::MemoData).is_ok());
 
         assert_eq!(locator.len(), 12); // 3 packed values * 4 bytes each
     }
 
     #[test]
-    fn test_pack_with_i32_still_works() {
-        // This test verifies that i32 values still work as before
+    fn test_pack_with_raw_field_codes() {
         let mut locator = Locator::new();
 
-        assert!(locator.pack(123i32));
-        assert!(locator.pack(456i32));
+        assert!(locator.pack(LocatorEntry::Field(123)).is_ok());
+        assert!(locator.pack(LocatorEntry::Field(456)).is_ok());
 
         assert_eq!(locator.len(), 8); // 2 packed values * 4 bytes each
     }
 
+    #[test]
+    fn test_raw_i32_is_a_field_code() {
+        let mut locator = Locator::new();
+        assert!(locator.pack(i32::from(sfield::Memos)).is_ok());
+        assert_eq!(LocatorEntry::from(0), LocatorEntry::Field(0));
+
+        assert!(locator.pack(LocatorEntry::Index(0)).is_ok());
+        assert!(locator.pack(i32::from(sfield::MemoData)).is_ok());
+        assert_eq!(locator.len(), 12);
+    }
+
     #[test]
     fn test_repack_last_with_sfield() {
         let mut locator = Locator::new();
 
-        locator.pack(sfield::Memos);
-        locator.pack(0);
+        locator.pack(sfield::Memos).unwrap();
+        locator.pack(LocatorEntry::Index(0)).unwrap();
+        locator.pack(sfield::MemoType).unwrap();
 
         // Repack the last value with a different SField
-        assert!(locator.repack_last(sfield::MemoData));
+        assert!(locator.repack_last(sfield::MemoData).is_ok());
 
-        assert_eq!(locator.len(), 8); // Still 2 packed values
+        assert_eq!(locator.len(), 12); // Still 3 packed values
     }
 
     #[test]
@@ -153,7 +394,7 @@ mod tests {
     #[test]
     fn test_pack_writes_correct_bytes() {
         let mut locator = Locator::new();
-        assert!(locator.pack(0x12345678i32));
+        assert!(locator.pack(LocatorEntry::Field(0x12345678)).is_ok());
         assert_eq!(locator.len(), 4);
 
         let bytes = unsafe { core::slice::from_raw_parts(locator.as_ptr(), 4) };
@@ -161,17 +402,18 @@ mod tests {
     }
 
     #[test]
-    fn test_pack_returns_false_when_buffer_full() {
+    fn test_pack_fails_when_buffer_full() {
         let mut locator = Locator::new();
 
         // Fill all 16 slots (64 bytes / 4 bytes per pack)
         for i in 0..16 {
-            assert!(locator.pack(i));
+            assert!(locator.pack(LocatorEntry::Field(i)).is_ok());
         }
         assert_eq!(locator.len(), 64);
 
         // 17th pack should fail
-        assert!(!locator.pack(999i32));
+        let result = locator.pack(LocatorEntry::Field(999));
+        assert_eq!(result.err().unwrap().code(), LOCATOR_MALFORMED);
         assert_eq!(locator.len(), 64);
     }
 
@@ -180,7 +422,7 @@ mod tests {
         let mut locator = Locator::new();
         assert!(locator.is_empty());
 
-        locator.pack(sfield::Memos);
+        locator.pack(sfield::Memos).unwrap();
         assert!(!locator.is_empty());
         assert_eq!(locator.len(), 4);
     }
@@ -190,30 +432,30 @@ mod tests {
         let mut locator = Locator::new();
         assert_eq!(locator.num_packed_bytes(), locator.len());
 
-        locator.pack(sfield::Memos);
+        locator.pack(sfield::Memos).unwrap();
         assert_eq!(locator.num_packed_bytes(), locator.len());
         assert_eq!(locator.num_packed_bytes(), 4);
 
-        locator.pack(0);
+        locator.pack(LocatorEntry::Index(0)).unwrap();
         assert_eq!(locator.num_packed_bytes(), locator.len());
         assert_eq!(locator.num_packed_bytes(), 8);
     }
 
     #[test]
-    fn test_repack_last_on_empty_returns_false() {
+    fn test_repack_last_on_empty_fails() {
         let mut locator = Locator::new();
-        assert!(!locator.repack_last(sfield::Memos));
+        assert!(locator.repack_last(sfield::Memos).is_err());
         assert_eq!(locator.len(), 0);
     }
 
     #[test]
     fn test_repack_last_overwrites_correct_bytes() {
         let mut locator = Locator::new();
-        locator.pack(0x11111111i32);
-        locator.pack(0x22222222i32);
+        locator.pack(LocatorEntry::Field(0x11111111)).unwrap();
+        locator.pack(LocatorEntry::Field(0x22222222)).unwrap();
         assert_eq!(locator.len(), 8);
 
-        assert!(locator.repack_last(0x33333333i32));
+        assert!(locator.repack_last(LocatorEntry::Field(0x33333333)).is_ok());
         assert_eq!(locator.len(), 8);
 
         let bytes = unsafe { core::slice::from_raw_parts(locator.as_ptr(), 8) };
@@ -222,4 +464,158 @@ mod tests {
         // Second value replaced
         assert_eq!(&bytes[4..8], &0x33333333i32.to_le_bytes());
     }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut locator = Locator::new();
+        locator.pack(sfield::Memos).unwrap();
+        locator.pack(LocatorEntry::Index(2)).unwrap();
+        locator.pack(sfield::MemoData).unwrap();
+
+        assert_eq!(locator.as_bytes().len(), 12);
+        assert_eq!(&locator.as_bytes()[4..8], &2i32.to_le_bytes());
+        assert_eq!(Locator::from_bytes(locator.as_bytes()).unwrap(), locator);
+        assert_eq!(Locator::from_bytes(&[]).unwrap(), Locator::new());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_malformed_input() {
+        use crate::host::error_codes::INVALID_DECODING;
+
+        let mut memo_data = Locator::new();
+        memo_data.pack(sfield::Memos).unwrap();
+        memo_data.pack(LocatorEntry::Index(0)).unwrap();
+        memo_data.pack(sfield::MemoData).unwrap();
+        let bytes = memo_data.as_bytes();
+
+        // Partial entry, too deep.
+        let too_deep = [0u8; LOCATOR_BUFFER_SIZE + 4];
+        for malformed in [&bytes[..10], &too_deep[..]] {
+            let result = Locator::from_bytes(malformed);
+            assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    mod nesting {
+        use super::*;
+
+        fn path(entries: &[LocatorEntry]) -> Option<Locator> {
+            let mut locator = Locator::new();
+            entries
+                .iter()
+                .all(|entry| locator.pack(*entry).is_ok())
+                .then_some(locator)
+        }
+
+        #[test]
+        fn test_pack_accepts_valid_paths() {
+            let memo_type = [
+                sfield::Memos.into(),
+                LocatorEntry::Index(0),
+                sfield::MemoType.into(),
+            ];
+            assert!(path(&memo_type).is_some());
+            assert!(path(&[sfield::Account.into()]).is_some());
+            assert!(path(&[sfield::Amendments.into(), LocatorEntry::Index(3)]).is_some());
+        }
+
+        #[test]
+        fn test_pack_accepts_indices_above_u16() {
+            let locator = path(&[
+                sfield::Memos.into(),
+                LocatorEntry::Index(70_000),
+                sfield::Memo.into(),
+            ]);
+            assert_eq!(locator.unwrap().len(), 12);
+        }
+
+        #[test]
+        fn test_pack_refuses_invalid_paths() {
+            let invalid: [&[LocatorEntry]; 7] = [
+                &[LocatorEntry::Index(0)],
+                &[sfield::Account.into(), LocatorEntry::Index(0)],
+                &[sfield::Memo.into(), LocatorEntry::Index(1)],
+                &[sfield::MemoData.into(), sfield::Account.into()],
+                &[sfield::Memos.into(), sfield::MemoData.into()],
+                &[
+                    sfield::Memos.into(),
+                    LocatorEntry::Index(0),
+                    LocatorEntry::Index(1),
+                ],
+                &[
+                    sfield::Amendments.into(),
+                    LocatorEntry::Index(0),
+                    sfield::Account.into(),
+                ],
+            ];
+            for entries in invalid {
+                assert!(path(entries).is_none(), "{entries:?}");
+            }
+            assert!(path(&[sfield::Memos.into(), LocatorEntry::Index(-1)]).is_none());
+        }
+
+        #[test]
+        fn test_refused_pack_leaves_locator_unchanged() {
+            let mut locator = Locator::new();
+            locator.pack(sfield::Account).unwrap();
+            assert!(locator.pack(LocatorEntry::Index(0)).is_err());
+            assert_eq!(
+                locator.as_bytes(),
+                &i32::from(sfield::Account).to_le_bytes()
+            );
+        }
+
+        #[test]
+        fn test_repack_last_checks_the_new_entry() {
+            let mut locator = Locator::new();
+            locator.pack(sfield::Memos).unwrap();
+            locator.pack(LocatorEntry::Index(0)).unwrap();
+            assert!(locator.repack_last(sfield::Signers).is_err());
+            assert!(locator.repack_last(LocatorEntry::Index(1)).is_ok());
+            assert!(locator.pack(sfield::MemoType).is_ok());
+            assert!(locator.repack_last(LocatorEntry::Index(2)).is_err());
+            assert!(locator.repack_last(sfield::MemoData).is_ok());
+        }
+
+        #[test]
+        fn test_unknown_type_codes_are_not_checked() {
+            assert!(path(&[LocatorEntry::Field(0x11111111), LocatorEntry::Index(0)]).is_some());
+            assert!(
+                path(&[
+                    LocatorEntry::Field(0x11111111),
+                    LocatorEntry::Field(0x22222222)
+                ])
+                .is_some()
+            );
+        }
+
+        #[test]
+        fn test_from_bytes_rejects_invalid_paths() {
+            use crate::host::error_codes::INVALID_DECODING;
+
+            // Negative entry, entry under a leaf field. A field directly under an array can't be
+            // detected: it reads as a (large) index.
+            let negative = (-1i32).to_le_bytes();
+            let memo_data = i32::from(sfield::MemoData).to_le_bytes();
+            let mut under_leaf = [0u8; 8];
+            under_leaf[..4].copy_from_slice(&memo_data);
+            under_leaf[4..].copy_from_slice(&memo_data);
+            for malformed in [&negative[..], &under_leaf[..]] {
+                let result = Locator::from_bytes(malformed);
+                assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+            }
+        }
+
+        #[test]
+        fn test_from_bytes_classifies_large_indices() {
+            let locator = path(&[
+                sfield::Memos.into(),
+                LocatorEntry::Index(70_000),
+                sfield::Memo.into(),
+            ]);
+            let locator = locator.unwrap();
+            assert_eq!(Locator::from_bytes(locator.as_bytes()).unwrap(), locator);
+        }
+    }
 }
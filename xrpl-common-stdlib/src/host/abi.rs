@@ -0,0 +1,213 @@
+//! Detecting what the host supports at runtime.
+//!
+//! The host a contract runs on changes over time: amendments switch on new ledger features, and
+//! devnets pick up new host behavior before it reaches Mainnet. Rather than build one binary per
+//! network, a contract can ask:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::{Feature, host_supports};
+//!
+//! if host_supports(Feature::TokenEscrow) {
+//!     // IOU and MPT escrows exist on this network.
+//! }
+//! ```
+//!
+//! Features gated by an amendment are checked with one `amendment_enabled` call, using the
+//! amendment ID rippled derives from its name (the SHA-512Half of the name), hashed at compile
+//! time by [`amendment_id!`](crate::amendment_id). Host behavior that no amendment
+//! covers is probed by calling the host function in question; [`Feature`] documents the cost of
+//! each check. Nothing is cached, so store the answer if it is needed more than once.
+//!
+//! [`abi_version`] condenses the host-behavior features into one number that only grows.
+
+use crate::amendment_id;
+use crate::host::chain::amendment_enabled;
+use crate::host::trace::trace_amount;
+use crate::host::{Error, Result};
+use crate::types::amount::Amount;
+
+/// Something a host may or may not support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Feature {
+    /// Smart Escrows: the `FinishFunction` field and the WASM host functions. Amendment
+    /// `SmartEscrow`.
+    SmartEscrow,
+    /// Escrows holding IOUs and MPTs rather than only XRP. Amendment `TokenEscrow`.
+    TokenEscrow,
+    /// Multi-purpose tokens. Amendment `MPTokensV1`.
+    MPTokens,
+    /// On-ledger credentials. Amendment `Credentials`.
+    Credentials,
+    /// Permissioned domains. Amendment `PermissionedDomains`.
+    PermissionedDomains,
+    /// `Batch` transactions. Amendment `Batch`.
+    Batch,
+    /// `trace_amount` accepts an XRP amount of zero drops. Probed with one `trace_amount` call,
+    /// which writes an empty line to the trace log.
+    ZeroXrpTraceAmount,
+}
+
+impl Feature {
+    /// The name of the amendment that enables this feature, or `None` if it is host behavior
+    /// that has to be probed.
+    pub const fn amendment_name(self) -> Option<&'static str> {
+        match self {
+            Feature::SmartEscrow => Some("SmartEscrow"),
+            Feature::TokenEscrow => Some("TokenEscrow"),
+            Feature::MPTokens => Some("MPTokensV1"),
+            Feature::Credentials => Some("Credentials"),
+            Feature::PermissionedDomains => Some("PermissionedDomains"),
+            Feature::Batch => Some("Batch"),
+            Feature::ZeroXrpTraceAmount => None,
+        }
+    }
+
+    /// The ID of the amendment named by [`amendment_name`](Self::amendment_name), or `None` if
+    /// this feature is host behavior that has to be probed.
+    pub const fn amendment_id(self) -> Option<[u8; 32]> {
+        match self {
+            Feature::SmartEscrow => Some(amendment_id!("SmartEscrow")),
+            Feature::TokenEscrow => Some(amendment_id!("TokenEscrow")),
+            Feature::MPTokens => Some(amendment_id!("MPTokensV1")),
+            Feature::Credentials => Some(amendment_id!("Credentials")),
+            Feature::PermissionedDomains => Some(amendment_id!("PermissionedDomains")),
+            Feature::Batch => Some(amendment_id!("Batch")),
+            Feature::ZeroXrpTraceAmount => None,
+        }
+    }
+}
+
+/// The host behaviors [`abi_version`] counts, in the order hosts gained them. ABI version `n`
+/// means the host supports the first `n`.
+pub const ABI_REVISIONS: [Feature; 2] = [Feature::SmartEscrow, Feature::ZeroXrpTraceAmount];
+
+/// Returns whether the host supports `feature`.
+///
+/// # Errors
+///
+/// Returns any error from the underlying check. A failed probe is not an error: it is the answer.
+pub fn supports(feature: Feature) -> Result<bool> {
+    match feature.amendment_id() {
+        Some(id) => amendment_enabled(&id),
+        None => Result::Ok(probe(feature)),
+    }
+}
+
+/// Like [`supports`], but treats a failed check as "not supported", so the contract takes the
+/// conservative path.
+pub fn host_supports(feature: Feature) -> bool {
+    matches!(supports(feature), Result::Ok(true))
+}
+
+/// Returns how many of [`ABI_REVISIONS`] the host supports, counting from the first and stopping
+/// at the first it lacks. 0 means the host does not support Smart Escrows at all, which in
+/// practice only happens in a simulator.
+///
+/// # Errors
+///
+/// Returns any error from the underlying checks.
+pub fn abi_version() -> Result<u32> {
+    let mut version = 0;
+    for feature in ABI_REVISIONS {
+        match supports(feature) {
+            Result::Ok(true) => version += 1,
+            Result::Ok(false) => break,
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    Result::Ok(version)
+}
+
+/// Returns `Err(InvalidParams)` unless the host supports `feature`, for contracts that cannot
+/// work without it.
+pub fn require(feature: Feature) -> Result<()> {
+    if host_supports(feature) {
+        Result::Ok(())
+    } else {
+        Result::Err(Error::InvalidParams)
+    }
+}
+
+fn probe(feature: Feature) -> bool {
+    match feature {
+        Feature::ZeroXrpTraceAmount => trace_amount("", &Amount::XRP { num_drops: 0 }).is_ok(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{INTERNAL_ERROR, INVALID_PARAMS};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::{always, eq};
+
+    /// Enables the amendments of the `enabled` features.
+    fn mock_amendments(enabled: &'static [Feature]) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_amendment_enabled()
+            .withf(|_, len| *len == 32)
+            .returning(move |id, _| {
+                let id = unsafe { &*(id as *const [u8; 32]) };
+                i32::from(enabled.iter().any(|f| f.amendment_id() == Some(*id)))
+            });
+        mock
+    }
+
+    #[test]
+    fn test_amendment_ids_match_names() {
+        // The ID rippled lists for `TokenEscrow`.
+        assert_eq!(
+            Feature::TokenEscrow.amendment_id().unwrap(),
+            [
+                0x13, 0x8B, 0x96, 0x8F, 0x25, 0x82, 0x2E, 0xFB, 0xF5, 0x4C, 0x00, 0xF9, 0x70, 0x31,
+                0x22, 0x1C, 0x47, 0xB1, 0xEA, 0xB8, 0x32, 0x1D, 0x93, 0xC7, 0xC2, 0xAE, 0xAF, 0x85,
+                0xF0, 0x4E, 0xC5, 0xDF,
+            ]
+        );
+        assert_eq!(Feature::ZeroXrpTraceAmount.amendment_id(), None);
+    }
+
+    #[test]
+    fn test_supports_checks_amendment_id_of_name() {
+        let _guard = setup_mock(mock_amendments(&[Feature::TokenEscrow]));
+
+        assert!(host_supports(Feature::TokenEscrow));
+        assert!(!host_supports(Feature::SmartEscrow));
+        assert!(require(Feature::TokenEscrow).is_ok());
+        assert_eq!(
+            require(Feature::Batch).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_failed_probe_is_unsupported() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_trace_amount()
+            .with(always(), eq(0), always(), always())
+            .times(1)
+            .returning(|_, _, _, _| INTERNAL_ERROR);
+        let _guard = setup_mock(mock);
+
+        assert!(!supports(Feature::ZeroXrpTraceAmount).unwrap());
+    }
+
+    #[test]
+    fn test_abi_version_stops_at_first_missing_revision() {
+        {
+            let mut mock = mock_amendments(&[Feature::SmartEscrow]);
+            mock.expect_trace_amount()
+                .times(1)
+                .returning(|_, _, _, _| 0);
+            let _guard = setup_mock(mock);
+            assert_eq!(abi_version().unwrap(), 2);
+        }
+        {
+            let _guard = setup_mock(mock_amendments(&[]));
+            assert_eq!(abi_version().unwrap(), 0);
+        }
+    }
+}
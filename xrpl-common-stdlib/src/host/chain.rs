@@ -1,8 +1,11 @@
+use crate::current_tx::{array_len, get_field, get_field_optional};
 use crate::host::{
     Error, Result, amendment_enabled as host_amendment_enabled,
     error_codes::match_result_code_with_expected_bytes, get_base_fee, get_ledger_sqn,
     get_parent_ledger_hash, get_parent_ledger_time,
 };
+use crate::sfield;
+use crate::tx_flags::tfInnerBatchTxn;
 
 pub fn ledger_sqn() -> Result<u32> {
     let mut uint_bytes = [0u8; 4];
@@ -16,6 +19,10 @@ pub fn parent_ledger_time() -> Result<u32> {
     match_result_code_with_expected_bytes(rescode, 4, || u32::from_le_bytes(uint_bytes))
 }
 
+/// Seconds from the Unix epoch to the Ripple Epoch (2000-01-01T00:00:00Z), in which ledger close
+/// times such as [`parent_ledger_time`] are expressed.
+pub const RIPPLE_EPOCH_UNIX_OFFSET: u32 = 946_684_800;
+
 pub fn parent_ledger_hash() -> Result<[u8; 32]> {
     let mut bytes = [0u8; 32];
     let rescode = unsafe { get_parent_ledger_hash(bytes.as_mut_ptr(), 32) };
@@ -37,12 +44,155 @@ pub fn amendment_enabled(hash: &[u8; 32]) -> Result<bool> {
     }
 }
 
+/// Highest network ID whose transactions omit the `NetworkID` field (Mainnet, Testnet, Devnet and
+/// other early networks). Chains above this ID require it on every transaction.
+pub const LEGACY_NETWORK_ID_MAX: u32 = 1024;
+
+/// Why [`require_network`] refused to continue.
+#[derive(Debug, Clone, Copy)]
+pub enum NetworkError {
+    /// The transaction was submitted on another network. Holds its `NetworkID`, or `None` if it
+    /// has none, i.e. it was submitted on a legacy network.
+    WrongNetwork(Option<u32>),
+    /// The transaction has no `NetworkID` and the expected network is a legacy one: the
+    /// transaction was submitted on *a* legacy network, but not necessarily the expected one.
+    Ambiguous,
+    /// The `NetworkID` field could not be read.
+    Host(Error),
+}
+
+/// Result of a network check, or why it failed.
+pub type NetworkResult = core::result::Result<(), NetworkError>;
+
+impl From<Error> for NetworkError {
+    fn from(error: Error) -> Self {
+        NetworkError::Host(error)
+    }
+}
+
+/// Refuses to continue unless the current transaction was submitted on network `network_id`.
+///
+/// The check is based on the transaction's `NetworkID` field, which rippled validates against the
+/// server's own network before the transaction is applied. Transactions on networks with an ID of
+/// [`LEGACY_NETWORK_ID_MAX`] or lower omit the field, so for those networks the check cannot
+/// tell Mainnet from Testnet or Devnet and fails with `Ambiguous`. Contracts that accept any
+/// legacy network can treat `Ambiguous` as success; those that must tell them apart need another
+/// signal, such as an amendment that is only enabled on one of them.
+///
+/// # Errors
+///
+/// * `WrongNetwork` - The transaction targets a different network
+/// * `Ambiguous` - `network_id` is a legacy network, which the transaction cannot confirm
+/// * `Host` - Any error returned while reading `NetworkID`
+pub fn require_network(network_id: u32) -> NetworkResult {
+    match get_field_optional(sfield::NetworkID) {
+        Result::Ok(Some(tx_network_id)) if tx_network_id == network_id => Ok(()),
+        Result::Ok(None) if network_id <= LEGACY_NETWORK_ID_MAX => Err(NetworkError::Ambiguous),
+        Result::Ok(tx_network_id) => Err(NetworkError::WrongNetwork(tx_network_id)),
+        Result::Err(e) => Err(e.into()),
+    }
+}
+
+/// Why [`require_min_ledger`] or [`require_before_ledger`] refused to continue.
+///
+/// A ledger that is too early may still pass the check later, while one that is too late never
+/// will, so the two are kept apart. Each holds the current ledger sequence.
+#[derive(Debug, Clone, Copy)]
+pub enum LedgerBoundError {
+    /// The current ledger is before the lower bound.
+    TooEarly(u32),
+    /// The current ledger is at or past the upper bound.
+    TooLate(u32),
+    /// The ledger sequence could not be read.
+    Host(Error),
+}
+
+/// Result of a ledger bound check: the current ledger sequence, or why the check failed.
+pub type LedgerBoundResult = core::result::Result<u32, LedgerBoundError>;
+
+impl From<Error> for LedgerBoundError {
+    fn from(error: Error) -> Self {
+        LedgerBoundError::Host(error)
+    }
+}
+
+fn check_ledger_sqn(check: impl FnOnce(u32) -> LedgerBoundResult) -> LedgerBoundResult {
+    match ledger_sqn() {
+        Result::Ok(seq) => check(seq),
+        Result::Err(e) => Err(e.into()),
+    }
+}
+
+/// Refuses to continue before ledger `min_seq`, and returns the current ledger sequence.
+///
+/// # Errors
+///
+/// * `TooEarly` - The current ledger sequence is lower than `min_seq`
+/// * `Host` - Any error returned while reading the ledger sequence
+pub fn require_min_ledger(min_seq: u32) -> LedgerBoundResult {
+    check_ledger_sqn(|seq| {
+        if seq >= min_seq {
+            Ok(seq)
+        } else {
+            Err(LedgerBoundError::TooEarly(seq))
+        }
+    })
+}
+
+/// Refuses to continue from ledger `seq` onwards, and returns the current ledger sequence.
+///
+/// The bound is exclusive, whereas rippled still applies a transaction in the ledger named by its
+/// `LastLedgerSequence`. To expire together with the transaction, pass its
+/// `LastLedgerSequence + 1`; a lower bound lets a contract give up earlier than its caller would.
+///
+/// # Errors
+///
+/// * `TooLate` - The current ledger sequence is `seq` or higher
+/// * `Host` - Any error returned while reading the ledger sequence
+pub fn require_before_ledger(seq: u32) -> LedgerBoundResult {
+    check_ledger_sqn(|current| {
+        if current < seq {
+            Ok(current)
+        } else {
+            Err(LedgerBoundError::TooLate(current))
+        }
+    })
+}
+
+/// Returns whether the current transaction is being simulated rather than applied by consensus.
+///
+/// rippled's `simulate` method runs transactions that have not been signed, so the host signals a
+/// dry run by handing the contract a transaction with no `TxnSignature` and no `Signers`. Inner
+/// transactions of a `Batch` are unsigned too, but carry the `tfInnerBatchTxn` flag and are
+/// reported as consensus execution.
+///
+/// A simulated multi-signed transaction carries `Signers` and is reported as consensus execution
+/// as well. Since this can only err towards `false`, contracts may rely on it to skip work such as
+/// `update_data` that only matters when the result is kept, never to relax a check.
+pub fn is_simulation() -> Result<bool> {
+    let signed = match get_field(sfield::TxnSignature) {
+        Result::Ok(signature) => signature.len > 0,
+        Result::Err(Error::FieldNotFound) => false,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let multi_signed = match array_len(sfield::Signers) {
+        Result::Ok(len) => len > 0,
+        Result::Err(Error::FieldNotFound) => false,
+        Result::Err(e) => return Result::Err(e),
+    };
+    if signed || multi_signed {
+        return Result::Ok(false);
+    }
+    get_field_optional(sfield::Flags).map(|flags| flags.unwrap_or(0) & tfInnerBatchTxn == 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::host::error_codes::INTERNAL_ERROR;
+    use crate::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR};
     use crate::host::host_bindings_trait::MockHostBindings;
     use crate::host::setup_mock;
+    use mockall::predicate::{always, eq};
 
     fn write_u32(ptr: *mut u8, value: u32) {
         let bytes = value.to_le_bytes();
@@ -203,4 +353,171 @@ mod tests {
 
         assert!(amendment_enabled(&[0u8; 32]).is_err());
     }
+
+    // ---- require_network ----
+
+    fn mock_network_id(network_id: Option<u32>) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::NetworkID)), always(), eq(4))
+            .times(1)
+            .returning(move |_, ptr, _| match network_id {
+                Some(id) => {
+                    write_u32(ptr, id);
+                    4
+                }
+                None => FIELD_NOT_FOUND,
+            });
+        mock
+    }
+
+    #[test]
+    fn test_require_network_matches_tx_network_id() {
+        let _guard = setup_mock(mock_network_id(Some(21_337)));
+        assert!(require_network(21_337).is_ok());
+    }
+
+    #[test]
+    fn test_require_network_rejects_other_network_id() {
+        let _guard = setup_mock(mock_network_id(Some(21_338)));
+        let result = require_network(21_337);
+        assert!(matches!(
+            result,
+            Err(NetworkError::WrongNetwork(Some(21_338)))
+        ));
+    }
+
+    #[test]
+    fn test_require_network_without_network_id_is_ambiguous_for_legacy_networks() {
+        {
+            let _guard = setup_mock(mock_network_id(None));
+            assert!(matches!(require_network(0), Err(NetworkError::Ambiguous)));
+        }
+        let _guard = setup_mock(mock_network_id(None));
+        assert!(matches!(
+            require_network(LEGACY_NETWORK_ID_MAX + 1),
+            Err(NetworkError::WrongNetwork(None))
+        ));
+    }
+
+    #[test]
+    fn test_require_network_legacy_id_in_field_is_not_ambiguous() {
+        let _guard = setup_mock(mock_network_id(Some(1)));
+        assert!(matches!(
+            require_network(0),
+            Err(NetworkError::WrongNetwork(Some(1)))
+        ));
+    }
+
+    #[test]
+    fn test_require_network_propagates_read_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .times(1)
+            .returning(|_, _, _| INTERNAL_ERROR);
+        // The field helper traces the error code before returning it.
+        mock.expect_trace_num().times(1).returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert!(matches!(
+            require_network(1),
+            Err(NetworkError::Host(Error::InternalError))
+        ));
+    }
+
+    // ---- require_min_ledger / require_before_ledger ----
+
+    fn mock_ledger_sqn(seq: u32) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_sqn().returning(move |ptr, _| {
+            write_u32(ptr, seq);
+            4
+        });
+        mock
+    }
+
+    #[test]
+    fn test_require_min_ledger_is_inclusive() {
+        let _guard = setup_mock(mock_ledger_sqn(100));
+        assert_eq!(require_min_ledger(99).unwrap(), 100);
+        assert_eq!(require_min_ledger(100).unwrap(), 100);
+        assert!(matches!(
+            require_min_ledger(101),
+            Err(LedgerBoundError::TooEarly(100))
+        ));
+    }
+
+    #[test]
+    fn test_require_before_ledger_is_exclusive() {
+        let _guard = setup_mock(mock_ledger_sqn(100));
+        assert_eq!(require_before_ledger(101).unwrap(), 100);
+        assert!(matches!(
+            require_before_ledger(100),
+            Err(LedgerBoundError::TooLate(100))
+        ));
+    }
+
+    #[test]
+    fn test_ledger_bounds_propagate_read_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_sqn()
+            .times(2)
+            .returning(|_, _| INTERNAL_ERROR);
+        let _guard = setup_mock(mock);
+
+        assert!(matches!(
+            require_min_ledger(0),
+            Err(LedgerBoundError::Host(Error::InternalError))
+        ));
+        assert!(matches!(
+            require_before_ledger(u32::MAX),
+            Err(LedgerBoundError::Host(Error::InternalError))
+        ));
+    }
+
+    // ---- is_simulation ----
+
+    fn mock_signing(signature_len: Option<usize>, signers: i32, flags: u32) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::TxnSignature)), always(), always())
+            .returning(move |_, _, _| match signature_len {
+                Some(len) => len as i32,
+                None => FIELD_NOT_FOUND,
+            });
+        mock.expect_get_tx_array_len()
+            .with(eq(i32::from(sfield::Signers)))
+            .returning(move |_| signers);
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Flags)), always(), eq(4))
+            .returning(move |_, ptr, _| {
+                write_u32(ptr, flags);
+                4
+            });
+        mock
+    }
+
+    #[test]
+    fn test_is_simulation_for_unsigned_transaction() {
+        {
+            let _guard = setup_mock(mock_signing(None, FIELD_NOT_FOUND, 0));
+            assert!(is_simulation().unwrap());
+        }
+        let _guard = setup_mock(mock_signing(Some(0), FIELD_NOT_FOUND, 0));
+        assert!(is_simulation().unwrap());
+    }
+
+    #[test]
+    fn test_is_not_simulation_for_signed_or_inner_transaction() {
+        {
+            let _guard = setup_mock(mock_signing(Some(71), FIELD_NOT_FOUND, 0));
+            assert!(!is_simulation().unwrap());
+        }
+        {
+            let _guard = setup_mock(mock_signing(None, 2, 0));
+            assert!(!is_simulation().unwrap());
+        }
+        let _guard = setup_mock(mock_signing(None, FIELD_NOT_FOUND, tfInnerBatchTxn));
+        assert!(!is_simulation().unwrap());
+    }
 }
@@ -0,0 +1,193 @@
+//! Attaching contract-defined codes to host errors.
+//!
+//! A host error says what went wrong (`FieldNotFound`), not where: the same code can come from
+//! any of a dozen reads in a contract. [`ResultContext::context`] attaches a contract-defined
+//! code to an error as it leaves a helper, and [`ResultContext::tag`] derives one from an
+//! [`ErrorDomain`], so the code the contract finally returns says which step failed while the
+//! original host error stays available for tracing:
+//!
+//! ```
+//! use xrpl_common_stdlib::error_domain::{ErrorDomain, error_range};
+//! use xrpl_common_stdlib::host::context::{ContextResult, ResultContext};
+//! use xrpl_common_stdlib::host::{Error, Result};
+//!
+//! error_range! {
+//!     pub mod oracle_errors = 3 {
+//!         MISSING_PRICE = 1,
+//!     }
+//! }
+//!
+//! fn read_price(raw: Result<u64>) -> ContextResult<u64> {
+//!     let price = raw.context(oracle_errors::MISSING_PRICE)?;
+//!     Ok(price * 2)
+//! }
+//!
+//! let error = read_price(Result::Err(Error::FieldNotFound)).unwrap_err();
+//! assert_eq!(error.code(), -301);
+//! assert_eq!(error.source().code(), -2);
+//!
+//! // A tag keeps the host code's number inside the domain: FieldNotFound (-2) becomes -302.
+//! let tagged = Result::<u64>::Err(Error::FieldNotFound).tag(oracle_errors::DOMAIN);
+//! assert_eq!(i32::from(tagged.unwrap_err()), -302);
+//! ```
+//!
+//! [`ContextResult`] is a `core::result::Result`, so `?` works inside helpers, and a plain
+//! [`Error`] converts into a [`ContextError`] without context. When an error passes through
+//! several helpers, the innermost context is kept, since it names the step that failed.
+
+use crate::error_domain::{CODES_PER_DOMAIN, ErrorDomain};
+use crate::host::{Error, Result};
+
+/// A host error, optionally with a contract-defined code describing where it happened.
+#[derive(Debug, Clone, Copy)]
+pub struct ContextError {
+    context: Option<i32>,
+    source: Error,
+}
+
+/// Result of code that attaches context to host errors.
+pub type ContextResult<T> = core::result::Result<T, ContextError>;
+
+impl ContextError {
+    /// Wraps `source` with the contract-defined code `code`.
+    pub const fn new(code: i32, source: Error) -> Self {
+        ContextError {
+            context: Some(code),
+            source,
+        }
+    }
+
+    /// The contract-defined code if one was attached, otherwise the host error's code.
+    pub fn code(self) -> i32 {
+        match self.context {
+            Some(code) => code,
+            None => self.source.code(),
+        }
+    }
+
+    /// The contract-defined code, if one was attached.
+    pub const fn context(self) -> Option<i32> {
+        self.context
+    }
+
+    /// The host error that caused the failure.
+    pub const fn source(self) -> Error {
+        self.source
+    }
+
+    fn or_context(self, code: i32) -> Self {
+        match self.context {
+            Some(_) => self,
+            None => ContextError::new(code, self.source),
+        }
+    }
+}
+
+impl From<Error> for ContextError {
+    fn from(source: Error) -> Self {
+        ContextError {
+            context: None,
+            source,
+        }
+    }
+}
+
+impl From<ContextError> for i32 {
+    fn from(error: ContextError) -> Self {
+        error.code()
+    }
+}
+
+/// Returns the code `domain` assigns to `error`: the host code's number within the domain, or
+/// the domain's last code (99) for errors whose code does not fit, such as [`Error::Unknown`].
+pub fn domain_code(domain: ErrorDomain, error: Error) -> i32 {
+    let number = error.code().unsigned_abs();
+    if number >= 1 && number < CODES_PER_DOMAIN as u32 {
+        domain.code(number as u8)
+    } else {
+        domain.code((CODES_PER_DOMAIN - 1) as u8)
+    }
+}
+
+/// Adds context to the error of a [`host::Result`](Result) or [`ContextResult`].
+pub trait ResultContext<T> {
+    /// Attaches the contract-defined `code` to an error that has no context yet.
+    fn context(self, code: i32) -> ContextResult<T>;
+
+    /// Attaches the code `domain` assigns to the host error (see [`domain_code`]) to an error
+    /// that has no context yet.
+    fn tag(self, domain: ErrorDomain) -> ContextResult<T>;
+}
+
+impl<T> ResultContext<T> for Result<T> {
+    fn context(self, code: i32) -> ContextResult<T> {
+        match self {
+            Result::Ok(value) => Ok(value),
+            Result::Err(e) => Err(ContextError::new(code, e)),
+        }
+    }
+
+    fn tag(self, domain: ErrorDomain) -> ContextResult<T> {
+        match self {
+            Result::Ok(value) => Ok(value),
+            Result::Err(e) => Err(ContextError::new(domain_code(domain, e), e)),
+        }
+    }
+}
+
+impl<T> ResultContext<T> for ContextResult<T> {
+    fn context(self, code: i32) -> ContextResult<T> {
+        self.map_err(|e| e.or_context(code))
+    }
+
+    fn tag(self, domain: ErrorDomain) -> ContextResult<T> {
+        self.map_err(|e| e.or_context(domain_code(domain, e.source)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HELPER: ErrorDomain = ErrorDomain::new(4);
+
+    fn helper(fail: bool) -> ContextResult<u32> {
+        let value = if fail {
+            Result::Err(Error::LedgerObjNotFound)
+        } else {
+            Result::Ok(7)
+        };
+        value.tag(HELPER)
+    }
+
+    #[test]
+    fn test_innermost_context_is_kept() {
+        let error = helper(true).context(-999).unwrap_err();
+        assert_eq!(error.code(), -410);
+        assert_eq!(error.context(), Some(-410));
+        assert_eq!(error.source().code(), -10);
+        assert_eq!(helper(false).context(-999).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_plain_errors_convert_without_context() {
+        let outer = || -> ContextResult<u32> {
+            let value: ContextResult<u32> = Err(Error::InvalidParams.into());
+            value.context(-205)
+        };
+        let error = outer().unwrap_err();
+        assert_eq!(error.code(), -205);
+        assert_eq!(error.source().code(), -15);
+
+        let untagged = ContextError::from(Error::SlotsFull);
+        assert_eq!(untagged.context(), None);
+        assert_eq!(i32::from(untagged), -8);
+    }
+
+    #[test]
+    fn test_domain_code_saturates_for_unknown_codes() {
+        assert_eq!(domain_code(HELPER, Error::InternalError), -401);
+        assert_eq!(domain_code(HELPER, Error::Unknown(-150)), -499);
+        assert_eq!(domain_code(HELPER, Error::Unknown(0)), -499);
+    }
+}
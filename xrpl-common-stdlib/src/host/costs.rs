@@ -0,0 +1,112 @@
+//! Estimated gas cost of each host function.
+//!
+//! rippled charges a fixed amount of gas for every host function call, on top of the gas for the
+//! WASM instructions the contract executes itself. The constants below are those fixed charges,
+//! grouped by host function family. They let policy code (and
+//! [`GasBudget`](crate::patterns::budget::GasBudget)) estimate the cost of a code path before
+//! running it.
+//!
+//! The values are estimates: they do not include the instructions needed to prepare arguments or
+//! decode results, and the host may change its schedule. When the host's schedule changes, update
+//! these constants and confirm them with the `gas_benchmark` contract (`scripts/benchmark-gas.sh`).
+
+use crate::types::gas::Gas;
+
+/// `get_ledger_sqn`, `get_parent_ledger_time`, `get_parent_ledger_hash`, `get_base_fee`.
+pub const LEDGER_HEADER: Gas = Gas(60);
+/// `amendment_enabled`.
+pub const AMENDMENT_ENABLED: Gas = Gas(60);
+/// `cache_ledger_obj`.
+pub const CACHE_LEDGER_OBJ: Gas = Gas(5_000);
+/// `get_tx_field`, `get_current_ledger_obj_field`, `get_ledger_obj_field`.
+pub const GET_FIELD: Gas = Gas(70);
+/// `get_tx_nested_field` and its ledger object counterparts.
+pub const GET_NESTED_FIELD: Gas = Gas(110);
+/// `get_tx_array_len`, `get_tx_nested_array_len` and their ledger object counterparts.
+pub const ARRAY_LEN: Gas = Gas(40);
+/// `update_data`.
+pub const UPDATE_DATA: Gas = Gas(1_000);
+/// `compute_sha512_half`.
+pub const SHA512_HALF: Gas = Gas(2_000);
+/// `check_sig`.
+pub const CHECK_SIG: Gas = Gas(2_000);
+/// Any of the `*_keylet` functions.
+pub const KEYLET: Gas = Gas(350);
+/// `get_nft`.
+pub const GET_NFT: Gas = Gas(1_000);
+/// `get_nft_issuer`, `get_nft_taxon`, `get_nft_flags`, `get_nft_transfer_fee`, `get_nft_serial`.
+pub const NFT_ATTRIBUTE: Gas = Gas(60);
+/// Any of the `float_*` functions.
+pub const FLOAT_OP: Gas = Gas(1_000);
+/// Any of the `trace*` functions.
+pub const TRACE: Gas = Gas(500);
+
+// The point of caching a ledger object is that reading fields from the slot afterwards is cheap;
+// keep the table consistent with that.
+const _: () = assert!(CACHE_LEDGER_OBJ.0 > GET_FIELD.0 * 10 && GET_NESTED_FIELD.0 > GET_FIELD.0);
+
+/// A family of host functions that share a cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostCall {
+    /// See [`LEDGER_HEADER`].
+    LedgerHeader,
+    /// See [`AMENDMENT_ENABLED`].
+    AmendmentEnabled,
+    /// See [`CACHE_LEDGER_OBJ`].
+    CacheLedgerObj,
+    /// See [`GET_FIELD`].
+    GetField,
+    /// See [`GET_NESTED_FIELD`].
+    GetNestedField,
+    /// See [`ARRAY_LEN`].
+    ArrayLen,
+    /// See [`UPDATE_DATA`].
+    UpdateData,
+    /// See [`SHA512_HALF`].
+    Sha512Half,
+    /// See [`CHECK_SIG`].
+    CheckSig,
+    /// See [`KEYLET`].
+    Keylet,
+    /// See [`GET_NFT`].
+    GetNft,
+    /// See [`NFT_ATTRIBUTE`].
+    NftAttribute,
+    /// See [`FLOAT_OP`].
+    FloatOp,
+    /// See [`TRACE`].
+    Trace,
+}
+
+/// Returns the estimated gas charged by the host for one call of `call`.
+#[inline]
+pub const fn estimated_cost(call: HostCall) -> Gas {
+    match call {
+        HostCall::LedgerHeader => LEDGER_HEADER,
+        HostCall::AmendmentEnabled => AMENDMENT_ENABLED,
+        HostCall::CacheLedgerObj => CACHE_LEDGER_OBJ,
+        HostCall::GetField => GET_FIELD,
+        HostCall::GetNestedField => GET_NESTED_FIELD,
+        HostCall::ArrayLen => ARRAY_LEN,
+        HostCall::UpdateData => UPDATE_DATA,
+        HostCall::Sha512Half => SHA512_HALF,
+        HostCall::CheckSig => CHECK_SIG,
+        HostCall::Keylet => KEYLET,
+        HostCall::GetNft => GET_NFT,
+        HostCall::NftAttribute => NFT_ATTRIBUTE,
+        HostCall::FloatOp => FLOAT_OP,
+        HostCall::Trace => TRACE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimated_cost_matches_constants() {
+        assert_eq!(estimated_cost(HostCall::GetField), GET_FIELD);
+        assert_eq!(estimated_cost(HostCall::CacheLedgerObj), CACHE_LEDGER_OBJ);
+        assert_eq!(estimated_cost(HostCall::Trace), TRACE);
+    }
+}
@@ -510,6 +510,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_from_unknown_code_is_preserved() {
+        for code in [-21, -1000, i32::MIN, 0, 7] {
+            let error = Error::from_code(code);
+            assert!(matches!(error, Error::Unknown(c) if c == code), "{code}");
+            assert_eq!(error.code(), code);
+            assert_eq!(i64::from(error), code as i64);
+        }
+    }
+
     #[test]
     fn test_closure_execution_count() {
         let mut execution_count = 0;
@@ -1,7 +1,7 @@
 use crate::host::Result;
 use crate::host::error_codes::{
-    match_result_code, match_result_code_optional, match_result_code_with_expected_bytes,
-    match_result_code_with_expected_bytes_optional,
+    BUFFER_TOO_SMALL, match_result_code, match_result_code_optional,
+    match_result_code_with_expected_bytes, match_result_code_with_expected_bytes_optional,
 };
 
 /// Helper function for retrieving fixed-size fields with exact byte validation.
@@ -194,3 +194,130 @@ where
         Some((unsafe { buffer.assume_init() }, result_code as usize))
     })
 }
+
+/// Reads a field of unknown length by retrying with larger prefixes of `buf`.
+///
+/// The host has no call that reports a field's length up front; it only fails with
+/// `BufferTooSmall` when the buffer cannot hold the field. This helper calls `host_fn` with
+/// `buf[..size]` for each entry of `fallback_sizes` in turn (sizes larger than `buf` are clamped),
+/// and finally with all of `buf`, stopping at the first attempt that does not fail with
+/// `BufferTooSmall`.
+///
+/// Trying a small size first is worthwhile when most values are short and the caller wants to
+/// keep the common path's copy small, e.g. `fallback_sizes = &[32, 256]` with a 1024-byte `buf`.
+///
+/// # Arguments
+///
+/// * `field_code` - The field code identifying which field to retrieve
+/// * `buf` - The largest buffer the caller is prepared to use
+/// * `fallback_sizes` - Buffer sizes to try, in increasing order, before using all of `buf`
+/// * `host_fn` - A closure that calls the appropriate host function
+///   - Takes: (field_code: i32, buffer_ptr: *mut u8, buffer_size: usize) -> i32
+///   - Returns: result code (number of bytes written or error code)
+///
+/// # Returns
+///
+/// Returns `Result<usize>` with the number of bytes written to the start of `buf`, or the first
+/// error other than `BufferTooSmall` (or `BufferTooSmall` if even all of `buf` is too small).
+///
+/// # Example
+///
+/// ```ignore
+/// let mut buf = [0u8; 1024];
+/// let len = read_growable(sfield::URI, &mut buf, &[64, 256], |fc, ptr, size| unsafe {
+///     get_tx_field(fc, ptr, size)
+/// })?;
+/// ```
+pub fn read_growable<F>(
+    field_code: impl Into<i32>,
+    buf: &mut [u8],
+    fallback_sizes: &[usize],
+    mut host_fn: F,
+) -> Result<usize>
+where
+    F: FnMut(i32, *mut u8, usize) -> i32,
+{
+    let field_code = field_code.into();
+    let full = buf.len();
+    let mut result_code = BUFFER_TOO_SMALL;
+    for size in fallback_sizes
+        .iter()
+        .map(|&size| size.min(full))
+        .chain(core::iter::once(full))
+    {
+        result_code = host_fn(field_code, buf.as_mut_ptr(), size);
+        if result_code != BUFFER_TOO_SMALL || size == full {
+            break;
+        }
+    }
+    match_result_code(result_code, || result_code as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    /// Simulates a host field of `len` bytes: fails with `BufferTooSmall` for smaller buffers and
+    /// records every buffer size it was offered.
+    fn field_of_len(len: usize, sizes: &mut Vec<usize>) -> impl FnMut(i32, *mut u8, usize) -> i32 {
+        move |_, ptr, size| {
+            sizes.push(size);
+            if size < len {
+                return BUFFER_TOO_SMALL;
+            }
+            unsafe { core::ptr::write_bytes(ptr, 0xEE, len) };
+            len as i32
+        }
+    }
+
+    #[test]
+    fn test_read_growable_stops_at_first_size_that_fits() {
+        let mut buf = [0u8; 1024];
+        let mut sizes = Vec::new();
+
+        let len = read_growable(1, &mut buf, &[32, 256], field_of_len(100, &mut sizes)).unwrap();
+
+        assert_eq!(len, 100);
+        assert_eq!(sizes, [32, 256]);
+        assert!(buf[..100].iter().all(|&b| b == 0xEE));
+    }
+
+    #[test]
+    fn test_read_growable_falls_back_to_whole_buffer() {
+        let mut buf = [0u8; 512];
+        let mut sizes = Vec::new();
+
+        let len = read_growable(1, &mut buf, &[32, 4096], field_of_len(300, &mut sizes)).unwrap();
+
+        assert_eq!(len, 300);
+        // 4096 is clamped to the buffer, so the final full-buffer attempt is not repeated.
+        assert_eq!(sizes, [32, 512]);
+    }
+
+    #[test]
+    fn test_read_growable_reports_buffer_too_small_when_nothing_fits() {
+        let mut buf = [0u8; 16];
+        let mut sizes = Vec::new();
+
+        let result = read_growable(1, &mut buf, &[8], field_of_len(17, &mut sizes));
+
+        assert_eq!(result.err().unwrap().code(), BUFFER_TOO_SMALL);
+        assert_eq!(sizes, [8, 16]);
+    }
+
+    #[test]
+    fn test_read_growable_returns_other_errors_immediately() {
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+
+        let mut buf = [0u8; 64];
+        let mut calls = 0;
+        let result = read_growable(1, &mut buf, &[8, 16], |_, _, _| {
+            calls += 1;
+            FIELD_NOT_FOUND
+        });
+
+        assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+        assert_eq!(calls, 1);
+    }
+}
@@ -13,7 +13,7 @@ impl Drop for MockGuard {
 }
 
 #[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
-pub fn setup_mock(mock: MockHostBindings) -> MockGuard {
+pub fn setup_mock(mock: impl HostBindings + 'static) -> MockGuard {
     set_mock_host_bindings(mock);
     MockGuard
 }
@@ -187,14 +187,14 @@ pub fn apply_default_expectations(mock: &mut MockHostBindings) {
 // #[cfg(test)]
 #[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
 thread_local! {
-    static MOCK_STATE: RefCell<Option<MockHostBindings>> = RefCell::new(Some(create_default_mock()));
+    static MOCK_STATE: RefCell<Option<Box<dyn HostBindings>>> = RefCell::new(Some(Box::new(create_default_mock())));
 }
 
 // Helper functions to manage the mock state
 #[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
-pub fn set_mock_host_bindings(mock: MockHostBindings) {
+pub fn set_mock_host_bindings(mock: impl HostBindings + 'static) {
     MOCK_STATE.with(|state| {
-        *state.borrow_mut() = Some(mock);
+        *state.borrow_mut() = Some(Box::new(mock));
     });
 }
 
@@ -207,7 +207,7 @@ pub fn clear_mock_host_bindings() {
 
 #[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
 // Macro to generate stub functions for non-WASM targets
-// These functions delegate to the MockHostBindings in MOCK_STATE
+// These functions delegate to the host bindings in MOCK_STATE
 macro_rules! export_host_functions {
     ($(
         $(#[$attr:meta])*
@@ -242,17 +242,17 @@ export_host_functions! {
     fn get_parent_ledger_hash(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_base_fee(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn amendment_enabled(amendment_ptr: *const u8, amendment_len: usize) -> i32;
-    fn cache_ledger_obj(keylet_ptr: *const u8, keylet_len: usize, cache_num: i32) -> i32;
     fn get_tx_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+    fn get_tx_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+    fn get_tx_array_len(field: i32) -> i32;
+    fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
+    fn cache_ledger_obj(keylet_ptr: *const u8, keylet_len: usize, cache_num: i32) -> i32;
     fn get_current_ledger_obj_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_ledger_obj_field(cache_num: i32, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
-    fn get_tx_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_current_ledger_obj_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_ledger_obj_nested_field(cache_num: i32, locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
-    fn get_tx_array_len(field: i32) -> i32;
     fn get_current_ledger_obj_array_len(field: i32) -> i32;
     fn get_ledger_obj_array_len(cache_num: i32, field: i32) -> i32;
-    fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
     fn get_current_ledger_obj_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
     fn get_ledger_obj_nested_array_len(cache_num: i32, locator_ptr: *const u8, locator_len: usize) -> i32;
 
@@ -318,6 +318,10 @@ export_host_functions! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::host::host_bindings_trait::{
+        ComposedHost, Keylets, LedgerHeader, MockLedgerHeader, MockTrace, Trace,
+    };
+    use mockall::predicate::{always, eq};
 
     #[test]
     fn test_ledger_functions_with_mock() {
@@ -501,4 +505,38 @@ mod tests {
         assert_eq!(time, 888);
         assert_eq!(fee, 777);
     }
+
+    #[test]
+    fn test_composed_host_mocks_one_capability() {
+        let mut trace = MockTrace::new();
+        trace
+            .expect_trace_num()
+            .with(always(), eq(5), eq(42))
+            .times(1)
+            .returning(|_, _, _| 0);
+
+        let _guard = setup_mock(ComposedHost::new().with_trace(trace));
+
+        let message = b"count";
+        let mut buffer = [0u8; 4];
+        unsafe {
+            assert_eq!(trace_num(message.as_ptr(), message.len(), 42), 0);
+            // Capabilities that were not replaced answer with the default stubs.
+            assert_eq!(get_ledger_sqn(buffer.as_mut_ptr(), buffer.len()), 4);
+        }
+    }
+
+    #[test]
+    fn test_capability_bound_accepts_capability_mock() {
+        fn base_fee<H: LedgerHeader>(host: &H) -> i32 {
+            let mut buffer = [0u8; 4];
+            unsafe { host.get_base_fee(buffer.as_mut_ptr(), buffer.len()) }
+        }
+
+        let mut ledger = MockLedgerHeader::new();
+        ledger.expect_get_base_fee().returning(|_, _| 10);
+
+        assert_eq!(base_fee(&ledger), 10);
+        assert_eq!(base_fee(&ComposedHost::new().with_ledger_header(ledger)), 10);
+    }
 }
@@ -1,16 +1,24 @@
 /// Trait defining all host functions available to WASM smart contracts.
 ///
-/// This trait serves as the source of truth for host function signatures and is implemented by:
+/// The host functions are grouped into capability traits ([`LedgerHeader`], [`TxFields`],
+/// [`LedgerObjects`], [`Crypto`], [`Keylets`], [`Nft`], [`Float`], [`Trace`]); `HostBindings` is
+/// implemented for every type that implements all of them. Together they serve as the source of
+/// truth for host function signatures and are implemented by:
 /// - `WasmHostBindings`: The production implementation that calls actual host functions via FFI
 /// - `EmptyHostBindings`: Stub implementations for non-WASM builds (panics if called)
-/// - `MockHostBindings`: Generated by `mockall` for unit testing (via `#[automock]`)
+/// - `MockHostBindings`: A `mockall` mock of all capabilities at once, for unit testing
+/// - `ComposedHost`: Combines per-capability implementations (e.g. a `MockTrace` generated by
+///   `#[automock]`) so a test only has to mock the capability it exercises
+///
+/// Code that only needs some host functions can bound on the capability traits instead, so that
+/// alternate hosts only have to implement that subset.
 ///
 /// # Example
 ///
 /// ```rust,ignore
-/// use xrpl_common_stdlib::host::{HostBindings, WasmHostBindings};
+/// use xrpl_common_stdlib::host::host_bindings_trait::LedgerHeader;
 ///
-/// fn my_function<H: HostBindings>(host: &H) {
+/// fn my_function<H: LedgerHeader>(host: &H) {
 ///     unsafe {
 ///         let mut buffer = [0u8; 8];
 ///         let result = host.get_ledger_sqn(buffer.as_mut_ptr(), buffer.len());
@@ -22,16 +30,36 @@
 /// let host = WasmHostBindings;
 /// my_function(&host);
 /// ```
+pub trait HostBindings:
+    LedgerHeader + TxFields + LedgerObjects + Crypto + Keylets + Nft + Float + Trace
+{
+}
+
+impl<T> HostBindings for T where
+    T: LedgerHeader + TxFields + LedgerObjects + Crypto + Keylets + Nft + Float + Trace
+{
+}
+
+#[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
+mod mock;
+#[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
+pub use mock::{ComposedHost, MockHostBindings};
+#[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
+pub mod count;
+#[cfg(all(any(test, feature = "fixture-host"), not(target_arch = "wasm32")))]
+pub mod fixture;
+#[cfg(all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")))]
+pub mod record;
+
+/// Ledger header queries: sequence, parent close time and hash, base fee, and amendment status.
+///
+/// Part of [`HostBindings`].
 #[allow(unused)] // To remove warn when compiled for non-WASM targets
 #[cfg_attr(
     all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
     mockall::automock
 )]
-pub trait HostBindings {
-    // ###############################
-    // Host Function Category: getters
-    // ###############################
-
+pub trait LedgerHeader {
     /// Retrieves the current ledger sequence number.
     ///
     /// This function populates a provided buffer with the ledger sequence number.
@@ -128,7 +156,101 @@ pub trait HostBindings {
     /// # Safety
     /// Caller must ensure all pointer parameters point to valid memory
     unsafe fn amendment_enabled(&self, amendment_ptr: *const u8, amendment_len: usize) -> i32;
+}
+
+/// Field reads from the transaction being executed.
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait TxFields {
+    /// Retrieves a specific transaction field and writes it into the provided output buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `field` - An integer value representing the specific transaction field to retrieve.
+    /// * `out_buff_ptr` - A mutable pointer to a buffer where the output data will be written.
+    /// * `out_buff_len` - The size (in bytes) of the buffer pointed to by `out_buff_ptr`.
+    ///
+    /// # Returns
+    ///
+    /// - Returns a positive number of bytes wrote to an output buffer on success
+    /// - Returns a negative error code on failure. The list of error codes is defined in
+    ///   `../core/error_codes.rs`
+    ///
+    /// # Safety
+    /// Caller must ensure all pointer parameters point to valid memory
+    unsafe fn get_tx_field(&self, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+
+    /// Retrieves a nested field from the current ledger object and writes it into the provided buffer.
+    ///
+    /// # Parameters
+    /// - `locator_ptr`: A pointer to a byte array containing the locator for the nested field.
+    /// - `locator_len`: The length of the locator data in bytes.
+    /// - `out_buff_ptr`: A pointer to a mutable byte array where the resulting field data will be written.
+    /// - `out_buff_len`: The size of the output buffer in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - Returns a positive number of bytes wrote to an output buffer on success
+    /// - Returns a negative error code on failure. The list of error codes is defined in
+    ///   `../core/error_codes.rs`
+    ///
+    /// # Safety
+    /// Caller must ensure all pointer parameters point to valid memory
+    unsafe fn get_tx_nested_field(
+        &self,
+        locator_ptr: *const u8,
+        locator_len: usize,
+        out_buff_ptr: *mut u8,
+        out_buff_len: usize,
+    ) -> i32;
+
+    /// Retrieves the length of an array based on the provided field value.
+    ///
+    /// # Parameters
+    /// - `field` (i32): The integer identifier for the desired field.
+    ///
+    /// # Returns
+    ///
+    /// - Returns a positive number of array length on success
+    /// - Returns a negative error code on failure. The list of error codes is defined in
+    ///   ../core/error_codes.rs
+    ///
+    /// # Safety
+    /// This function is safe to call from WASM context
+    unsafe fn get_tx_array_len(&self, field: i32) -> i32;
+
+    /// Retrieves the length of an array based on the provided locator.
+    ///
+    /// # Parameters
+    /// - `locator_ptr`: A pointer to a byte array containing the locator for the nested field.
+    /// - `locator_len`: The length of the locator data in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - Returns a positive number of array length on success
+    /// - Returns a negative error code on failure. The list of error codes is defined in
+    ///   ../core/error_codes.rs
+    ///
+    /// # Safety
+    /// Caller must ensure all pointer parameters point to valid memory
+    unsafe fn get_tx_nested_array_len(&self, locator_ptr: *const u8, locator_len: usize) -> i32;
+}
 
+/// Ledger object caching, field reads from cached objects and the current ledger entry, and
+/// updates to the current ledger entry.
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait LedgerObjects {
     /// Fetch a ledger entry pointed by the given keylet.
     ///
     /// This function uses the keylet to locate a ledger entry. If found, add it to the
@@ -158,24 +280,6 @@ pub trait HostBindings {
         cache_num: i32,
     ) -> i32;
 
-    /// Retrieves a specific transaction field and writes it into the provided output buffer.
-    ///
-    /// # Parameters
-    ///
-    /// * `field` - An integer value representing the specific transaction field to retrieve.
-    /// * `out_buff_ptr` - A mutable pointer to a buffer where the output data will be written.
-    /// * `out_buff_len` - The size (in bytes) of the buffer pointed to by `out_buff_ptr`.
-    ///
-    /// # Returns
-    ///
-    /// - Returns a positive number of bytes wrote to an output buffer on success
-    /// - Returns a negative error code on failure. The list of error codes is defined in
-    ///   `../core/error_codes.rs`
-    ///
-    /// # Safety
-    /// Caller must ensure all pointer parameters point to valid memory
-    unsafe fn get_tx_field(&self, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
-
     /// Retrieves a specific field from the current ledger object and writes it into the provided buffer.
     ///
     /// # Parameters
@@ -224,30 +328,6 @@ pub trait HostBindings {
         out_buff_len: usize,
     ) -> i32;
 
-    /// Retrieves a nested field from the current ledger object and writes it into the provided buffer.
-    ///
-    /// # Parameters
-    /// - `locator_ptr`: A pointer to a byte array containing the locator for the nested field.
-    /// - `locator_len`: The length of the locator data in bytes.
-    /// - `out_buff_ptr`: A pointer to a mutable byte array where the resulting field data will be written.
-    /// - `out_buff_len`: The size of the output buffer in bytes.
-    ///
-    /// # Returns
-    ///
-    /// - Returns a positive number of bytes wrote to an output buffer on success
-    /// - Returns a negative error code on failure. The list of error codes is defined in
-    ///   `../core/error_codes.rs`
-    ///
-    /// # Safety
-    /// Caller must ensure all pointer parameters point to valid memory
-    unsafe fn get_tx_nested_field(
-        &self,
-        locator_ptr: *const u8,
-        locator_len: usize,
-        out_buff_ptr: *mut u8,
-        out_buff_len: usize,
-    ) -> i32;
-
     /// Retrieves a specific nested field from the current ledger object.
     ///
     /// This function is designed to access a nested field within the ledger object
@@ -304,21 +384,6 @@ pub trait HostBindings {
         out_buff_len: usize,
     ) -> i32;
 
-    /// Retrieves the length of an array based on the provided field value.
-    ///
-    /// # Parameters
-    /// - `field` (i32): The integer identifier for the desired field.
-    ///
-    /// # Returns
-    ///
-    /// - Returns a positive number of array length on success
-    /// - Returns a negative error code on failure. The list of error codes is defined in
-    ///   ../core/error_codes.rs
-    ///
-    /// # Safety
-    /// This function is safe to call from WASM context
-    unsafe fn get_tx_array_len(&self, field: i32) -> i32;
-
     /// Retrieves the length of an array based on the provided field value.
     ///
     /// # Parameters
@@ -350,22 +415,6 @@ pub trait HostBindings {
     /// This function is safe to call from WASM context
     unsafe fn get_ledger_obj_array_len(&self, cache_num: i32, field: i32) -> i32;
 
-    /// Retrieves the length of an array based on the provided locator.
-    ///
-    /// # Parameters
-    /// - `locator_ptr`: A pointer to a byte array containing the locator for the nested field.
-    /// - `locator_len`: The length of the locator data in bytes.
-    ///
-    /// # Returns
-    ///
-    /// - Returns a positive number of array length on success
-    /// - Returns a negative error code on failure. The list of error codes is defined in
-    ///   ../core/error_codes.rs
-    ///
-    /// # Safety
-    /// Caller must ensure all pointer parameters point to valid memory
-    unsafe fn get_tx_nested_array_len(&self, locator_ptr: *const u8, locator_len: usize) -> i32;
-
     /// Retrieves the length of an array based on the provided locator.
     ///
     /// # Parameters
@@ -408,10 +457,6 @@ pub trait HostBindings {
         locator_len: usize,
     ) -> i32;
 
-    // ###################################################
-    // Host Function Category: update current ledger entry
-    // ###################################################
-
     /// Updates a data field of the current ledger entry
     ///
     /// # Parameters
@@ -427,11 +472,17 @@ pub trait HostBindings {
     /// # Safety
     /// Caller must ensure all pointer parameters point to valid memory
     unsafe fn update_data(&self, data_ptr: *const u8, data_len: usize) -> i32;
+}
 
-    // ###################################################
-    // Host Function Category: hash and keylet computation
-    // ###################################################
-
+/// Hashing and signature verification.
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait Crypto {
     /// Computes the first 32 bytes (half) of the SHA-512 hash for the given input data.
     ///
     /// # Parameters
@@ -485,7 +536,17 @@ pub trait HostBindings {
         pubkey_ptr: *const u8,
         pubkey_len: usize,
     ) -> i32;
+}
 
+/// Keylet computation, one function per ledger entry type.
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait Keylets {
     /// Generates the keylet (key identifier) for a specific account.
     ///
     /// This function is used to calculate the account keylet in a cryptographic or
@@ -1048,11 +1109,17 @@ pub trait HostBindings {
         out_buff_ptr: *mut u8,
         out_buff_len: usize,
     ) -> i32;
+}
 
-    // #############################
-    // Host Function Category: NFT
-    // #############################
-
+/// Lookups of NFTs held by an account.
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait Nft {
     /// Retrieves the URI details of a specific NFT (Non-Fungible Token) associated with a given account.
     ///
     /// # Parameters
@@ -1194,11 +1261,17 @@ pub trait HostBindings {
         out_buff_ptr: *mut u8,
         out_buff_len: usize,
     ) -> i32;
+}
 
-    // #############################
-    // Host Function Category: FLOAT
-    // #############################
-
+/// Arithmetic on the host's opaque float representation (rippled's `Number`).
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait Float {
     /// Converts a signed 64-bit integer to an opaque float representation
     /// # Parameters
     /// * `in_int` - The input integer to convert
@@ -1514,11 +1587,17 @@ pub trait HostBindings {
         out_buff_len: usize,
         rounding_mode: i32,
     ) -> i32;
+}
 
-    // #############################
-    // Host Function Category: TRACE
-    // #############################
-
+/// Writing to the rippled trace log.
+///
+/// Part of [`HostBindings`].
+#[allow(unused)] // To remove warn when compiled for non-WASM targets
+#[cfg_attr(
+    all(any(test, feature = "test-host-bindings"), not(target_arch = "wasm32")),
+    mockall::automock
+)]
+pub trait Trace {
     /// Print to the trace log on XRPLd. Any XRPLd instance set to \"trace\" log level will see this.
     ///
     /// # Parameters
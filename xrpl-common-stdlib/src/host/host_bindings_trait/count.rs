@@ -0,0 +1,156 @@
+//! Counting host calls, for gas regression tests.
+//!
+//! Every host call crosses the WASM boundary and costs gas, so a refactor that adds a call to a
+//! hot path makes contracts more expensive without changing what they compute. [`CountingHost`]
+//! wraps another host and tallies the calls it forwards in a [`HostCallStats`], which a test can
+//! pin down:
+//!
+//! ```ignore
+//! let (balance, stats) = count_calls(ComposedHost::new(), || balance_of(&account, &xrp));
+//! assert!(balance.is_ok());
+//! assert_eq!(stats.total(), 3);
+//! assert_eq!(stats.count("get_ledger_obj_field"), 1);
+//! ```
+//!
+//! The counts say nothing about the arguments; use a [`RecordingHost`](super::record::RecordingHost)
+//! to see those.
+
+use super::mock::host_functions;
+use super::{
+    Crypto, Float, HostBindings, Keylets, LedgerHeader, LedgerObjects, Nft, Trace, TxFields,
+};
+use crate::host::setup_mock;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::vec::Vec;
+
+/// Number of calls made to each host function.
+///
+/// Cloning a `HostCallStats` yields another handle to the same counters, so a test can keep one
+/// handle while the [`CountingHost`] holding the other is installed with `setup_mock`.
+#[derive(Debug, Clone, Default)]
+pub struct HostCallStats {
+    counts: Rc<RefCell<BTreeMap<&'static str, u32>>>,
+}
+
+impl HostCallStats {
+    /// Creates stats with every count at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how many times the host function named `function` was called.
+    pub fn count(&self, function: &str) -> u32 {
+        self.counts.borrow().get(function).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of host calls.
+    pub fn total(&self) -> u32 {
+        self.counts.borrow().values().sum()
+    }
+
+    /// Returns the functions that were called at least once with their counts, sorted by name.
+    pub fn calls(&self) -> Vec<(&'static str, u32)> {
+        self.counts
+            .borrow()
+            .iter()
+            .map(|(function, count)| (*function, *count))
+            .collect()
+    }
+
+    /// Sets every count back to zero.
+    pub fn reset(&self) {
+        self.counts.borrow_mut().clear();
+    }
+
+    fn increment(&self, function: &'static str) {
+        *self.counts.borrow_mut().entry(function).or_insert(0) += 1;
+    }
+}
+
+/// A host that forwards every call to `inner` and counts it in a [`HostCallStats`].
+pub struct CountingHost<H> {
+    inner: H,
+    stats: HostCallStats,
+}
+
+impl<H: HostBindings> CountingHost<H> {
+    /// Wraps `inner`, counting into new stats.
+    pub fn new(inner: H) -> Self {
+        CountingHost {
+            inner,
+            stats: HostCallStats::new(),
+        }
+    }
+
+    /// Returns a handle to the stats this host counts into.
+    pub fn stats(&self) -> HostCallStats {
+        self.stats.clone()
+    }
+}
+
+/// Runs `f` with `host` installed behind a [`CountingHost`], and returns its result along with
+/// the host calls it made.
+pub fn count_calls<H, R>(host: H, f: impl FnOnce() -> R) -> (R, HostCallStats)
+where
+    H: HostBindings + 'static,
+{
+    let counting = CountingHost::new(host);
+    let stats = counting.stats();
+    let _guard = setup_mock(counting);
+    (f(), stats)
+}
+
+macro_rules! count {
+    ($(
+        $capability:ident via $field:ident, $with:ident {
+            $(fn $name:ident($($param:ident: $(*$ptr:ident)? $param_ty:ident),*) -> $ret:ident;)*
+        }
+    )*) => {
+        $(
+            impl<H: HostBindings> $capability for CountingHost<H> {
+                $(
+                    unsafe fn $name(&self, $($param: $(*$ptr)? $param_ty),*) -> $ret {
+                        self.stats.increment(stringify!($name));
+                        unsafe { self.inner.$name($($param),*) }
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+host_functions!(count);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::host_bindings_trait::ComposedHost;
+    use crate::objects::balances::balance_of;
+    use crate::types::account_id::AccountID;
+    use crate::types::issue::{Issue, XrpIssue};
+
+    #[test]
+    fn test_counts_each_host_function() {
+        let account = AccountID([0x2B; 20]);
+        let (balance, stats) = count_calls(ComposedHost::new(), || {
+            balance_of(&account, &Issue::XRP(XrpIssue {}))
+        });
+
+        assert!(balance.is_ok());
+        assert_eq!(
+            stats.calls(),
+            [
+                ("account_keylet", 1),
+                ("cache_ledger_obj", 1),
+                ("get_ledger_obj_field", 1),
+            ]
+        );
+        assert_eq!(stats.total(), 3);
+        assert_eq!(stats.count("trace"), 0);
+
+        stats.reset();
+        assert_eq!(stats.total(), 0);
+    }
+}
@@ -0,0 +1,577 @@
+//! A host that serves random, structurally valid field bytes, for property tests.
+//!
+//! [`FixtureBytes`] generates the bytes the host would return for a field of a given type — an
+//! `Amount` with a valid type/sign prefix and in-range value, a `Blob` no longer than its
+//! capacity, and so on — from a seeded [`FixtureRng`]. [`FixtureHost`] serves those bytes to the
+//! crate's field getters. Because everything derives from a single `u64` seed, the harness plugs
+//! into `proptest` or `quickcheck` by generating the seed, and a failing case can be reproduced
+//! from the seed alone:
+//!
+//! ```ignore
+//! proptest! {
+//!     #[test]
+//!     fn amount_round_trips(seed: u64) {
+//!         let mut rng = FixtureRng::new(seed);
+//!         let fixture = FixtureHost::new().with_random(sfield::Amount, &mut rng);
+//!         let expected = fixture.bytes(sfield::Amount.into()).unwrap().to_vec();
+//!         let _guard = fixture.install();
+//!
+//!         let amount = current_tx::get_field(sfield::Amount).unwrap();
+//!         prop_assert_eq!(&amount.to_stamount_bytes().0[..expected.len()], expected);
+//!     }
+//! }
+//! ```
+//!
+//! Requires the `fixture-host` feature outside this crate's own tests.
+
+use super::{ComposedHost, LedgerHeader, LedgerObjects, TxFields};
+use crate::host::error_codes::{BUFFER_TOO_SMALL, FIELD_NOT_FOUND, LEDGER_OBJ_NOT_FOUND};
+use crate::host::{MockGuard, setup_mock};
+use crate::sfield::SField;
+use crate::types::account_id::AccountID;
+use crate::types::amount::{AMOUNT_SIZE, Amount};
+use crate::types::blob::Blob;
+use crate::types::constants::MAX_DROPS;
+use crate::types::currency::{CURRENCY_SIZE, Currency};
+use crate::types::mpt_id::MPT_ID_SIZE;
+use crate::types::uint::UInt;
+use std::collections::BTreeMap;
+use std::vec::Vec;
+
+/// A small deterministic pseudo-random generator (SplitMix64).
+///
+/// Not suitable for anything but test data.
+#[derive(Debug, Clone)]
+pub struct FixtureRng {
+    state: u64,
+}
+
+impl FixtureRng {
+    /// Creates a generator from `seed`. Equal seeds produce equal sequences.
+    pub const fn new(seed: u64) -> Self {
+        FixtureRng { state: seed }
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..=max`.
+    pub fn up_to(&mut self, max: u64) -> u64 {
+        match max.checked_add(1) {
+            Some(bound) => self.next_u64() % bound,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Fills `bytes` with random bytes.
+    pub fn fill(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(8) {
+            let random = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&random[..chunk.len()]);
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        let mut bytes = std::vec![0; len];
+        self.fill(&mut bytes);
+        bytes
+    }
+}
+
+/// Types whose host representation can be generated at random.
+///
+/// Implementations only produce bytes the real host could return for a field of that type, so a
+/// getter that fails on them has a bug.
+pub trait FixtureBytes {
+    /// Generates the bytes the host would write for a field of this type.
+    fn fixture_bytes(rng: &mut FixtureRng) -> Vec<u8>;
+}
+
+macro_rules! random_bytes_fixture {
+    ($($ty:ty => $len:expr),* $(,)?) => {
+        $(
+            impl FixtureBytes for $ty {
+                fn fixture_bytes(rng: &mut FixtureRng) -> Vec<u8> {
+                    rng.bytes($len)
+                }
+            }
+        )*
+    };
+}
+
+random_bytes_fixture! {
+    u8 => 1,
+    u16 => 2,
+    u32 => 4,
+    u64 => 8,
+    AccountID => 20,
+}
+
+impl<const N: usize> FixtureBytes for UInt<N> {
+    fn fixture_bytes(rng: &mut FixtureRng) -> Vec<u8> {
+        rng.bytes(N)
+    }
+}
+
+/// Either a standard three-letter code (bytes 12..15, everything else zero) or a non-standard
+/// code, whose first byte is never zero.
+impl FixtureBytes for Currency {
+    fn fixture_bytes(rng: &mut FixtureRng) -> Vec<u8> {
+        let mut bytes = std::vec![0; CURRENCY_SIZE];
+        if rng.next_u64() % 2 == 0 {
+            for byte in &mut bytes[12..15] {
+                *byte = b'A' + (rng.up_to(25) as u8);
+            }
+        } else {
+            rng.fill(&mut bytes);
+            bytes[0] |= 0x01;
+        }
+        bytes
+    }
+}
+
+/// One of the three `STAmount` layouts, at the length the host writes it: 8 bytes of XRP with at
+/// most [`MAX_DROPS`], 33 bytes of MPT amount that fits in an `i64`, or 48 bytes of IOU with a
+/// normalized mantissa.
+impl FixtureBytes for Amount {
+    fn fixture_bytes(rng: &mut FixtureRng) -> Vec<u8> {
+        let mut bytes = std::vec![0; AMOUNT_SIZE];
+        let positive = rng.next_u64() % 4 != 0;
+        let sign = if positive { 0x40 } else { 0x00 };
+        match rng.up_to(2) {
+            0 => {
+                let drops = rng.up_to(MAX_DROPS);
+                bytes[..8].copy_from_slice(&drops.to_be_bytes());
+                bytes[0] |= sign;
+                bytes.truncate(8);
+            }
+            1 => {
+                bytes[0] = 0x20 | sign;
+                bytes[1..9].copy_from_slice(&rng.up_to(i64::MAX as u64).to_be_bytes());
+                rng.fill(&mut bytes[9..9 + MPT_ID_SIZE]);
+                bytes.truncate(9 + MPT_ID_SIZE);
+            }
+            _ => {
+                let float = if rng.next_u64() % 8 == 0 {
+                    0x8000_0000_0000_0000
+                } else {
+                    const MIN_MANTISSA: u64 = 1_000_000_000_000_000;
+                    let mantissa = MIN_MANTISSA + rng.up_to(9 * MIN_MANTISSA - 1);
+                    let exponent = rng.up_to(176); // -96..=80, biased by 97
+                    0x8000_0000_0000_0000
+                        | (u64::from(positive) << 62)
+                        | ((exponent + 1) << 54)
+                        | mantissa
+                };
+                bytes[..8].copy_from_slice(&float.to_be_bytes());
+                bytes[8..28].copy_from_slice(&Currency::fixture_bytes(rng));
+                rng.fill(&mut bytes[28..48]);
+            }
+        }
+        bytes
+    }
+}
+
+impl<const N: usize> FixtureBytes for Blob<N> {
+    fn fixture_bytes(rng: &mut FixtureRng) -> Vec<u8> {
+        let len = rng.up_to(N as u64) as usize;
+        rng.bytes(len)
+    }
+}
+
+/// A host that answers field reads from a fixed table of field bytes.
+///
+/// The same table serves transaction fields, the current ledger object and every cached ledger
+/// object. Fields that aren't in the table report `FieldNotFound`, as do nested and array reads.
+/// The ledger header reports the sequence and close time set with
+/// [`with_ledger_sqn`](Self::with_ledger_sqn) and
+/// [`with_parent_ledger_time`](Self::with_parent_ledger_time) (zero by default), a zero parent
+/// hash and base fee, and no enabled amendments. Every keylet is found in the ledger unless
+/// [`with_keylet_misses`](Self::with_keylet_misses) is set. [`install`](Self::install) fills
+/// every other capability with the default stubs.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureHost {
+    fields: BTreeMap<i32, Vec<u8>>,
+    ledger_sqn: u32,
+    parent_ledger_time: u32,
+    keylet_misses: bool,
+}
+
+impl FixtureHost {
+    /// Creates a host with no fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `bytes` for `field_code`.
+    pub fn with_bytes(mut self, field_code: i32, bytes: impl Into<Vec<u8>>) -> Self {
+        self.fields.insert(field_code, bytes.into());
+        self
+    }
+
+    /// Serves freshly generated bytes of type `T` for `field`.
+    pub fn with_random<T: FixtureBytes, const CODE: i32>(
+        self,
+        _field: SField<T, CODE>,
+        rng: &mut FixtureRng,
+    ) -> Self {
+        self.with_bytes(CODE, T::fixture_bytes(rng))
+    }
+
+    /// Reports `ledger_sqn` as the current ledger sequence.
+    pub fn with_ledger_sqn(mut self, ledger_sqn: u32) -> Self {
+        self.ledger_sqn = ledger_sqn;
+        self
+    }
+
+    /// Reports `parent_ledger_time` (seconds since the Ripple Epoch) as the parent ledger's close
+    /// time.
+    pub fn with_parent_ledger_time(mut self, parent_ledger_time: u32) -> Self {
+        self.parent_ledger_time = parent_ledger_time;
+        self
+    }
+
+    /// Reports every keylet as missing from the ledger: `cache_ledger_obj` returns
+    /// `LedgerObjNotFound`.
+    ///
+    /// The default keylet stubs don't compute real keylets, so misses can't be told apart by
+    /// keylet; they apply to every lookup.
+    pub fn with_keylet_misses(mut self) -> Self {
+        self.keylet_misses = true;
+        self
+    }
+
+    /// Returns the bytes served for `field_code`, if any.
+    pub fn bytes(&self, field_code: i32) -> Option<&[u8]> {
+        self.fields.get(&field_code).map(Vec::as_slice)
+    }
+
+    /// Installs this host as the thread-local host bindings.
+    pub fn install(self) -> MockGuard {
+        setup_mock(
+            ComposedHost::new()
+                .with_ledger_header(self.clone())
+                .with_tx_fields(self.clone())
+                .with_ledger_objects(self),
+        )
+    }
+
+    fn serve(&self, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        match self.fields.get(&field) {
+            Some(bytes) if bytes.len() > out_buff_len => BUFFER_TOO_SMALL,
+            Some(bytes) => {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(bytes.as_ptr(), out_buff_ptr, bytes.len())
+                };
+                bytes.len() as i32
+            }
+            None => FIELD_NOT_FOUND,
+        }
+    }
+}
+
+/// Writes `bytes` if they fit in the output buffer.
+fn serve_header(bytes: &[u8], out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+    if bytes.len() > out_buff_len {
+        return BUFFER_TOO_SMALL;
+    }
+    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out_buff_ptr, bytes.len()) };
+    bytes.len() as i32
+}
+
+impl LedgerHeader for FixtureHost {
+    unsafe fn get_ledger_sqn(&self, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        serve_header(&self.ledger_sqn.to_le_bytes(), out_buff_ptr, out_buff_len)
+    }
+
+    unsafe fn get_parent_ledger_time(&self, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        serve_header(
+            &self.parent_ledger_time.to_le_bytes(),
+            out_buff_ptr,
+            out_buff_len,
+        )
+    }
+
+    unsafe fn get_parent_ledger_hash(&self, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        serve_header(&[0; 32], out_buff_ptr, out_buff_len)
+    }
+
+    unsafe fn get_base_fee(&self, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        serve_header(&[0; 4], out_buff_ptr, out_buff_len)
+    }
+
+    unsafe fn amendment_enabled(&self, _amendment_ptr: *const u8, _amendment_len: usize) -> i32 {
+        0
+    }
+}
+
+impl TxFields for FixtureHost {
+    unsafe fn get_tx_field(&self, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        self.serve(field, out_buff_ptr, out_buff_len)
+    }
+
+    unsafe fn get_tx_nested_field(
+        &self,
+        _locator_ptr: *const u8,
+        _locator_len: usize,
+        _out_buff_ptr: *mut u8,
+        _out_buff_len: usize,
+    ) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_tx_array_len(&self, _field: i32) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_tx_nested_array_len(&self, _locator_ptr: *const u8, _locator_len: usize) -> i32 {
+        FIELD_NOT_FOUND
+    }
+}
+
+impl LedgerObjects for FixtureHost {
+    unsafe fn cache_ledger_obj(
+        &self,
+        _keylet_ptr: *const u8,
+        _keylet_len: usize,
+        cache_num: i32,
+    ) -> i32 {
+        if self.keylet_misses {
+            return LEDGER_OBJ_NOT_FOUND;
+        }
+        cache_num.max(1)
+    }
+
+    unsafe fn get_current_ledger_obj_field(
+        &self,
+        field: i32,
+        out_buff_ptr: *mut u8,
+        out_buff_len: usize,
+    ) -> i32 {
+        self.serve(field, out_buff_ptr, out_buff_len)
+    }
+
+    unsafe fn get_ledger_obj_field(
+        &self,
+        _cache_num: i32,
+        field: i32,
+        out_buff_ptr: *mut u8,
+        out_buff_len: usize,
+    ) -> i32 {
+        self.serve(field, out_buff_ptr, out_buff_len)
+    }
+
+    unsafe fn get_current_ledger_obj_nested_field(
+        &self,
+        _locator_ptr: *const u8,
+        _locator_len: usize,
+        _out_buff_ptr: *mut u8,
+        _out_buff_len: usize,
+    ) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_ledger_obj_nested_field(
+        &self,
+        _cache_num: i32,
+        _locator_ptr: *const u8,
+        _locator_len: usize,
+        _out_buff_ptr: *mut u8,
+        _out_buff_len: usize,
+    ) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_current_ledger_obj_array_len(&self, _field: i32) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_ledger_obj_array_len(&self, _cache_num: i32, _field: i32) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_current_ledger_obj_nested_array_len(
+        &self,
+        _locator_ptr: *const u8,
+        _locator_len: usize,
+    ) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn get_ledger_obj_nested_array_len(
+        &self,
+        _cache_num: i32,
+        _locator_ptr: *const u8,
+        _locator_len: usize,
+    ) -> i32 {
+        FIELD_NOT_FOUND
+    }
+
+    unsafe fn update_data(&self, _data_ptr: *const u8, _data_len: usize) -> i32 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::current_tx;
+    use crate::objects::{current_ledger_object, ledger_object};
+    use crate::sfield;
+
+    const CASES: u64 = 256;
+
+    fn for_each_seed(mut property: impl FnMut(&mut FixtureRng)) {
+        for seed in 0..CASES {
+            property(&mut FixtureRng::new(seed));
+        }
+    }
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = FixtureRng::new(7);
+        let mut b = FixtureRng::new(7);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_ne!(a.next_u64(), FixtureRng::new(8).next_u64());
+        assert!((0..100).all(|_| a.up_to(3) <= 3));
+    }
+
+    #[test]
+    fn prop_amount_round_trips() {
+        for_each_seed(|rng| {
+            let fixture = FixtureHost::new()
+                .with_random(sfield::Amount, rng)
+                .with_random(sfield::Balance, rng);
+            let amount_bytes = fixture.bytes(sfield::Amount.into()).unwrap().to_vec();
+            let balance_bytes = fixture.bytes(sfield::Balance.into()).unwrap().to_vec();
+            let _guard = fixture.install();
+
+            let amount = current_tx::get_field(sfield::Amount).unwrap();
+            assert_eq!(
+                &amount.to_stamount_bytes().0[..amount_bytes.len()],
+                amount_bytes
+            );
+
+            let balance = ledger_object::get_field(1, sfield::Balance).unwrap();
+            assert_eq!(
+                &balance.to_stamount_bytes().0[..balance_bytes.len()],
+                balance_bytes
+            );
+            if let Amount::XRP { num_drops } = balance {
+                assert!(balance.as_drops_checked().is_ok(), "{num_drops}");
+            }
+        });
+    }
+
+    #[test]
+    fn prop_fixed_size_fields_round_trip() {
+        for_each_seed(|rng| {
+            let fixture = FixtureHost::new()
+                .with_random(sfield::TickSize, rng)
+                .with_random(sfield::LedgerEntryType, rng)
+                .with_random(sfield::Sequence, rng)
+                .with_random(sfield::AssetPrice, rng)
+                .with_random(sfield::Account, rng)
+                .with_random(sfield::EmailHash, rng)
+                .with_random(sfield::MPTokenIssuanceID, rng)
+                .with_random(sfield::PreviousTxnID, rng)
+                .with_random(sfield::BaseAsset, rng);
+            let bytes = |code: i32| fixture.bytes(code).unwrap().to_vec();
+            let expected = (
+                bytes(sfield::TickSize.into()),
+                bytes(sfield::LedgerEntryType.into()),
+                bytes(sfield::Sequence.into()),
+                bytes(sfield::AssetPrice.into()),
+                bytes(sfield::Account.into()),
+                bytes(sfield::EmailHash.into()),
+                bytes(sfield::MPTokenIssuanceID.into()),
+                bytes(sfield::PreviousTxnID.into()),
+                bytes(sfield::BaseAsset.into()),
+            );
+            let _guard = fixture.install();
+
+            let tick_size = current_ledger_object::get_field(sfield::TickSize).unwrap();
+            assert_eq!(tick_size.to_ne_bytes().as_slice(), expected.0);
+            let entry_type = current_ledger_object::get_field(sfield::LedgerEntryType).unwrap();
+            assert_eq!(entry_type.to_ne_bytes().as_slice(), expected.1);
+            let sequence: u32 = current_tx::get_field(sfield::Sequence).unwrap();
+            assert_eq!(sequence.to_ne_bytes().as_slice(), expected.2);
+            let price = current_ledger_object::get_field(sfield::AssetPrice).unwrap();
+            assert_eq!(price.to_ne_bytes().as_slice(), expected.3);
+            let account = current_tx::get_field(sfield::Account).unwrap();
+            assert_eq!(account.0.as_slice(), expected.4);
+            let email = current_ledger_object::get_field(sfield::EmailHash).unwrap();
+            assert_eq!(email.as_bytes().as_slice(), expected.5);
+            let issuance = current_ledger_object::get_field(sfield::MPTokenIssuanceID).unwrap();
+            assert_eq!(issuance.as_bytes().as_slice(), expected.6);
+            let previous = current_tx::get_field(sfield::PreviousTxnID).unwrap();
+            assert_eq!(previous.as_bytes().as_slice(), expected.7);
+            let currency = current_ledger_object::get_field(sfield::BaseAsset).unwrap();
+            assert_eq!(currency.as_bytes().as_slice(), expected.8);
+        });
+    }
+
+    #[test]
+    fn prop_blobs_keep_their_length() {
+        for_each_seed(|rng| {
+            let fixture = FixtureHost::new()
+                .with_random(sfield::URI, rng)
+                .with_random(sfield::PublicKey, rng);
+            let uri_bytes = fixture.bytes(sfield::URI.into()).unwrap().to_vec();
+            let key_bytes = fixture.bytes(sfield::PublicKey.into()).unwrap().to_vec();
+            let _guard = fixture.install();
+
+            let uri = current_ledger_object::get_field(sfield::URI).unwrap();
+            assert_eq!(uri.as_slice(), uri_bytes);
+            let key = current_tx::get_field(sfield::PublicKey).unwrap();
+            assert_eq!(key.as_slice(), key_bytes);
+        });
+    }
+
+    #[test]
+    fn test_ledger_header_reports_configured_values() {
+        use crate::host::chain::{amendment_enabled, ledger_sqn, parent_ledger_time};
+
+        let _guard = FixtureHost::new()
+            .with_ledger_sqn(1_234)
+            .with_parent_ledger_time(800_000_000)
+            .install();
+
+        assert_eq!(ledger_sqn().unwrap(), 1_234);
+        assert_eq!(parent_ledger_time().unwrap(), 800_000_000);
+        assert!(!amendment_enabled(&[0xAA; 32]).unwrap());
+    }
+
+    #[test]
+    fn test_keylet_misses_are_not_found() {
+        use crate::host::cache_ledger_obj;
+
+        let keylet = [0xAB; 32];
+        {
+            let _guard = FixtureHost::new().install();
+            assert_eq!(
+                unsafe { cache_ledger_obj(keylet.as_ptr(), keylet.len(), 0) },
+                1
+            );
+        }
+        let _guard = FixtureHost::new().with_keylet_misses().install();
+        assert_eq!(
+            unsafe { cache_ledger_obj(keylet.as_ptr(), keylet.len(), 0) },
+            LEDGER_OBJ_NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn test_missing_field_is_not_found() {
+        let _guard = FixtureHost::new().install();
+
+        let sequence = current_tx::get_field_optional(sfield::Sequence).unwrap();
+        assert_eq!(sequence, None::<u32>);
+    }
+}
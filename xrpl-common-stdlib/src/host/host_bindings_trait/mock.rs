@@ -0,0 +1,178 @@
+//! Test doubles for [`HostBindings`](super::HostBindings).
+//!
+//! `#[automock]` on each capability trait generates a per-capability mock (`MockTrace`,
+//! `MockKeylets`, ...). This module adds the two types built on top of them:
+//!
+//! - [`MockHostBindings`], a single mock implementing every capability, which is what most tests
+//!   hand to `setup_mock`.
+//! - [`ComposedHost`], which starts from the default stubs for every capability and swaps in only
+//!   the implementations a test provides:
+//!
+//! ```ignore
+//! let mut trace = MockTrace::new();
+//! trace.expect_trace_num().times(1).returning(|_, _, _| 0);
+//! let _guard = setup_mock(ComposedHost::new().with_trace(trace));
+//! ```
+//!
+//! Both are generated from the capability list in [`host_functions`]. The list itself is generated
+//! from the traits in the parent module by `tools/generateTestHostBindings.js`; the compiler rejects
+//! any mismatch left by a hand edit.
+
+use super::{Crypto, Float, Keylets, LedgerHeader, LedgerObjects, Nft, Trace, TxFields};
+use crate::host::create_default_mock;
+
+/// Invokes `$callback!` with every host function, grouped by capability trait.
+///
+/// Each group also names the [`ComposedHost`] field and builder method for that capability. The
+/// parameter types are spelled so callbacks can match them token by token
+/// (`$(*$ptr:ident)? $param_ty:ident`) rather than as `ty` fragments, because `mockall::mock!`
+/// cannot parse types that arrive wrapped in a macro fragment.
+macro_rules! host_functions {
+    ($callback:ident) => {
+        $callback! {
+            LedgerHeader via ledger_header, with_ledger_header {
+                fn get_ledger_sqn(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_parent_ledger_time(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_parent_ledger_hash(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_base_fee(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn amendment_enabled(amendment_ptr: *const u8, amendment_len: usize) -> i32;
+            }
+            TxFields via tx_fields, with_tx_fields {
+                fn get_tx_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_tx_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_tx_array_len(field: i32) -> i32;
+                fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
+            }
+            LedgerObjects via ledger_objects, with_ledger_objects {
+                fn cache_ledger_obj(keylet_ptr: *const u8, keylet_len: usize, cache_num: i32) -> i32;
+                fn get_current_ledger_obj_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_ledger_obj_field(cache_num: i32, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_current_ledger_obj_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_ledger_obj_nested_field(cache_num: i32, locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_current_ledger_obj_array_len(field: i32) -> i32;
+                fn get_ledger_obj_array_len(cache_num: i32, field: i32) -> i32;
+                fn get_current_ledger_obj_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
+                fn get_ledger_obj_nested_array_len(cache_num: i32, locator_ptr: *const u8, locator_len: usize) -> i32;
+                fn update_data(data_ptr: *const u8, data_len: usize) -> i32;
+            }
+            Crypto via crypto, with_crypto {
+                fn compute_sha512_half(data_ptr: *const u8, data_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn check_sig(message_ptr: *const u8, message_len: usize, signature_ptr: *const u8, signature_len: usize, pubkey_ptr: *const u8, pubkey_len: usize) -> i32;
+            }
+            Keylets via keylets, with_keylets {
+                fn account_keylet(account_ptr: *const u8, account_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn amm_keylet(issue1_ptr: *const u8, issue1_len: usize, issue2_ptr: *const u8, issue2_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn check_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn credential_keylet(subject_ptr: *const u8, subject_len: usize, issuer_ptr: *const u8, issuer_len: usize, cred_type_ptr: *const u8, cred_type_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn delegate_keylet(account_ptr: *const u8, account_len: usize, authorize_ptr: *const u8, authorize_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn deposit_preauth_keylet(account_ptr: *const u8, account_len: usize, authorize_ptr: *const u8, authorize_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn did_keylet(account_ptr: *const u8, account_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn escrow_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn line_keylet(account1_ptr: *const u8, account1_len: usize, account2_ptr: *const u8, account2_len: usize, currency_ptr: *const u8, currency_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn mpt_issuance_keylet(issuer_ptr: *const u8, issuer_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn mptoken_keylet(mptid_ptr: *const u8, mptid_len: usize, holder_ptr: *const u8, holder_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn nft_offer_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn offer_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn oracle_keylet(account_ptr: *const u8, account_len: usize, document_id_ptr: *const u8, document_id_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn paychan_keylet(account_ptr: *const u8, account_len: usize, destination_ptr: *const u8, destination_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn permissioned_domain_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn signers_keylet(account_ptr: *const u8, account_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn ticket_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn vault_keylet(account_ptr: *const u8, account_len: usize, sequence_ptr: *const u8, sequence_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+            }
+            Nft via nft, with_nft {
+                fn get_nft(account_ptr: *const u8, account_len: usize, nft_id_ptr: *const u8, nft_id_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_nft_issuer(nft_id_ptr: *const u8, nft_id_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_nft_taxon(nft_id_ptr: *const u8, nft_id_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+                fn get_nft_flags(nft_id_ptr: *const u8, nft_id_len: usize) -> i32;
+                fn get_nft_transfer_fee(nft_id_ptr: *const u8, nft_id_len: usize) -> i32;
+                fn get_nft_serial(nft_id_ptr: *const u8, nft_id_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+            }
+            Float via float, with_float {
+                fn float_from_int(in_int: i64, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_from_uint(in_uint_ptr: *const u8, in_uint_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_from_mant_exp(mantissa: i64, exponent: i32, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_from_stamount(in_buff: *const u8, in_buff_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_from_stnumber(in_buff: *const u8, in_buff_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_to_int(in_buff: *const u8, in_buff_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_to_mant_exp(in_buff: *const u8, in_buff_len: usize, mant_buff: *mut u8, mant_buff_len: usize, exp_buff: *mut u8, exp_buff_len: usize) -> i32;
+                fn float_compare(in_buff1: *const u8, in_buff1_len: usize, in_buff2: *const u8, in_buff2_len: usize) -> i32;
+                fn float_add(in_buff1: *const u8, in_buff1_len: usize, in_buff2: *const u8, in_buff2_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_subtract(in_buff1: *const u8, in_buff1_len: usize, in_buff2: *const u8, in_buff2_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_multiply(in_buff1: *const u8, in_buff1_len: usize, in_buff2: *const u8, in_buff2_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_divide(in_buff1: *const u8, in_buff1_len: usize, in_buff2: *const u8, in_buff2_len: usize, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_pow(in_buff: *const u8, in_buff_len: usize, pow: i32, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+                fn float_root(in_buff: *const u8, in_buff_len: usize, root: i32, out_buff: *mut u8, out_buff_len: usize, rounding_mode: i32) -> i32;
+            }
+            Trace via trace, with_trace {
+                fn trace(msg_read_ptr: *const u8, msg_read_len: usize, data_read_ptr: *const u8, data_read_len: usize, as_hex: i32) -> i32;
+                fn trace_num(msg_read_ptr: *const u8, msg_read_len: usize, number: i64) -> i32;
+                fn trace_account(msg_read_ptr: *const u8, msg_read_len: usize, account_ptr: *const u8, account_len: usize) -> i32;
+                fn trace_opaque_float(msg_read_ptr: *const u8, msg_read_len: usize, opaque_float_ptr: *const u8, opaque_float_len: usize) -> i32;
+                fn trace_amount(msg_read_ptr: *const u8, msg_read_len: usize, amount_ptr: *const u8, amount_len: usize) -> i32;
+            }
+        }
+    };
+}
+pub(super) use host_functions;
+
+macro_rules! host_capabilities {
+    ($(
+        $capability:ident via $field:ident, $with:ident {
+            $(fn $name:ident($($param:ident: $(*$ptr:ident)? $param_ty:ident),*) -> $ret:ident;)*
+        }
+    )*) => {
+        mockall::mock! {
+            /// A mock implementing every host capability.
+            pub HostBindings {}
+            $(
+                impl $capability for HostBindings {
+                    $(unsafe fn $name(&self, $($param: $(*$ptr)? $param_ty),*) -> $ret;)*
+                }
+            )*
+        }
+
+        /// A host assembled from one implementation per capability.
+        ///
+        /// [`ComposedHost::new`] backs every capability with the default stubs used by
+        /// `create_default_mock`; the `with_*` methods replace one capability at a time.
+        pub struct ComposedHost {
+            $($field: Box<dyn $capability>,)*
+        }
+
+        impl ComposedHost {
+            /// Creates a host that answers every call with the default stubs.
+            pub fn new() -> Self {
+                ComposedHost {
+                    $($field: Box::new(create_default_mock()),)*
+                }
+            }
+
+            $(
+                #[doc = concat!("Serves [`", stringify!($capability), "`] calls from `host`.")]
+                pub fn $with(mut self, host: impl $capability + 'static) -> Self {
+                    self.$field = Box::new(host);
+                    self
+                }
+            )*
+        }
+
+        $(
+            impl $capability for ComposedHost {
+                $(
+                    unsafe fn $name(&self, $($param: $(*$ptr)? $param_ty),*) -> $ret {
+                        unsafe { self.$field.$name($($param),*) }
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+impl Default for ComposedHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+host_functions!(host_capabilities);
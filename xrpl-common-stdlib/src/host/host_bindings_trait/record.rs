@@ -0,0 +1,519 @@
+//! Recording and replaying host calls, for golden tests.
+//!
+//! [`RecordingHost`] wraps another host and appends every call it forwards — function name,
+//! scalar arguments, the bytes behind each input pointer, the bytes written to each output buffer,
+//! and the result code — to a [`CallLog`]. [`ReplayHost`] serves a recorded log back: each call
+//! must match the next record, and gets the recorded output bytes and result.
+//!
+//! A golden test records once against a host that behaves like the network (for example a mock
+//! filled from a devnet transaction), saves [`CallLog::to_text`] next to the test, and then
+//! replays it in CI:
+//!
+//! ```ignore
+//! const GOLDEN: &str = include_str!("golden/finish.log");
+//!
+//! let replay = ReplayHost::from_text(GOLDEN).unwrap();
+//! let _guard = setup_mock(replay.clone());
+//! assert_eq!(finish(), 1);
+//! assert!(replay.is_exhausted());
+//! ```
+//!
+//! # Text format
+//!
+//! One call per line, with five `|`-separated columns: function name, result code, scalar
+//! arguments (comma-separated, pointer lengths included), input buffers and output buffers (each
+//! comma-separated lowercase hex). Empty lines are ignored.
+//!
+//! ```text
+//! get_tx_field|20|524289,20||b5f762798a53d543a014caf8b297cff8f2f937e8
+//! ```
+
+use super::mock::host_functions;
+use super::{
+    Crypto, Float, HostBindings, Keylets, LedgerHeader, LedgerObjects, Nft, Trace, TxFields,
+};
+use std::cell::{Cell, RefCell};
+use std::fmt::Write;
+use std::rc::Rc;
+use std::string::String;
+use std::vec::Vec;
+
+/// One host call, as seen by [`RecordingHost`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostCallRecord {
+    /// Name of the host function.
+    pub function: String,
+    /// The integer arguments, in order, including the length of every buffer.
+    pub args: Vec<i64>,
+    /// The contents of each input (`*const u8`) buffer at the time of the call.
+    pub inputs: Vec<Vec<u8>>,
+    /// The bytes the host reported writing to each output (`*mut u8`) buffer: the first `result`
+    /// bytes, or none if the call failed. The rest of the buffer may be uninitialized and is
+    /// never read.
+    pub outputs: Vec<Vec<u8>>,
+    /// The value returned by the host.
+    pub result: i32,
+}
+
+impl HostCallRecord {
+    fn to_line(&self, out: &mut String) {
+        let args: Vec<String> = self.args.iter().map(|arg| arg.to_string()).collect();
+        let _ = writeln!(
+            out,
+            "{}|{}|{}|{}|{}",
+            self.function,
+            self.result,
+            args.join(","),
+            hex_list(&self.inputs),
+            hex_list(&self.outputs),
+        );
+    }
+
+    fn parse_line(line: &str) -> Result<Self, String> {
+        let columns: Vec<&str> = line.split('|').collect();
+        let [function, result, args, inputs, outputs] = columns[..] else {
+            return Err(format!("expected 5 columns: {line}"));
+        };
+        let result = result
+            .parse()
+            .map_err(|_| format!("invalid result code: {line}"))?;
+        let args = split_list(args)
+            .map(|arg| arg.parse().map_err(|_| format!("invalid argument: {line}")))
+            .collect::<Result<_, _>>()?;
+        Ok(HostCallRecord {
+            function: function.into(),
+            args,
+            inputs: parse_hex_list(inputs).ok_or_else(|| format!("invalid input hex: {line}"))?,
+            outputs: parse_hex_list(outputs)
+                .ok_or_else(|| format!("invalid output hex: {line}"))?,
+            result,
+        })
+    }
+}
+
+/// A shared, growable list of [`HostCallRecord`]s.
+///
+/// Cloning a `CallLog` yields another handle to the same list, so a test can keep one handle
+/// while the [`RecordingHost`] holding the other is installed with `setup_mock`.
+#[derive(Debug, Clone, Default)]
+pub struct CallLog {
+    records: Rc<RefCell<Vec<HostCallRecord>>>,
+}
+
+impl CallLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the records so far.
+    pub fn records(&self) -> Vec<HostCallRecord> {
+        self.records.borrow().clone()
+    }
+
+    /// Returns the number of records so far.
+    pub fn len(&self) -> usize {
+        self.records.borrow().len()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.records.borrow().is_empty()
+    }
+
+    /// Renders the log in the text format described in the [module docs](self).
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        for record in self.records.borrow().iter() {
+            record.to_line(&mut out);
+        }
+        out
+    }
+
+    fn push(&self, record: HostCallRecord) {
+        self.records.borrow_mut().push(record);
+    }
+}
+
+/// A host that forwards every call to `inner` and records it in a [`CallLog`].
+pub struct RecordingHost<H> {
+    inner: H,
+    log: CallLog,
+}
+
+impl<H: HostBindings> RecordingHost<H> {
+    /// Wraps `inner`, recording into a new, empty log.
+    pub fn new(inner: H) -> Self {
+        RecordingHost {
+            inner,
+            log: CallLog::new(),
+        }
+    }
+
+    /// Returns a handle to the log this host records into.
+    pub fn log(&self) -> CallLog {
+        self.log.clone()
+    }
+}
+
+/// A host that answers calls from a recorded log, in order.
+///
+/// Every call must match the next record's function name, scalar arguments and input bytes;
+/// otherwise the call panics, failing the test with a description of the divergence. Calls past
+/// the end of the log panic too. Clones share their position in the log.
+#[derive(Debug, Clone)]
+pub struct ReplayHost {
+    records: Rc<Vec<HostCallRecord>>,
+    next: Rc<Cell<usize>>,
+}
+
+impl ReplayHost {
+    /// Creates a host that replays `records`.
+    pub fn new(records: Vec<HostCallRecord>) -> Self {
+        ReplayHost {
+            records: Rc::new(records),
+            next: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Parses a log in the text format described in the [module docs](self).
+    pub fn from_text(text: &str) -> Result<Self, String> {
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| HostCallRecord::parse_line(line.trim()))
+            .collect::<Result<_, _>>()
+            .map(Self::new)
+    }
+
+    /// Returns the number of records not yet replayed.
+    pub fn remaining(&self) -> usize {
+        self.records.len() - self.next.get()
+    }
+
+    /// Returns `true` once every record has been replayed.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    fn next_record(&self, function: &str, args: &[i64], inputs: &[Vec<u8>]) -> &HostCallRecord {
+        let index = self.next.get();
+        let Some(record) = self.records.get(index) else {
+            panic!("replay: unexpected call #{index} to {function}; the log has ended");
+        };
+        assert_eq!(
+            record.function, function,
+            "replay: call #{index} diverged from the log"
+        );
+        assert_eq!(
+            record.args, args,
+            "replay: call #{index} ({function}) has different arguments"
+        );
+        assert_eq!(
+            record.inputs, inputs,
+            "replay: call #{index} ({function}) has different input bytes"
+        );
+        self.next.set(index + 1);
+        record
+    }
+}
+
+/// A host function argument, before buffers are paired with their lengths.
+#[derive(Clone, Copy)]
+enum Arg {
+    Int(i64),
+    In(*const u8),
+    Out(*mut u8),
+}
+
+impl From<i32> for Arg {
+    fn from(value: i32) -> Self {
+        Arg::Int(value.into())
+    }
+}
+
+impl From<i64> for Arg {
+    fn from(value: i64) -> Self {
+        Arg::Int(value)
+    }
+}
+
+impl From<usize> for Arg {
+    fn from(value: usize) -> Self {
+        Arg::Int(value as i64)
+    }
+}
+
+impl From<*const u8> for Arg {
+    fn from(value: *const u8) -> Self {
+        Arg::In(value)
+    }
+}
+
+impl From<*mut u8> for Arg {
+    fn from(value: *mut u8) -> Self {
+        Arg::Out(value)
+    }
+}
+
+fn scalars(args: &[Arg]) -> Vec<i64> {
+    args.iter()
+        .filter_map(|arg| match arg {
+            Arg::Int(value) => Some(*value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every buffer pointer in the host ABI is followed by its length.
+fn buffer_len(args: &[Arg], index: usize) -> usize {
+    match args.get(index + 1) {
+        Some(Arg::Int(len)) => *len as usize,
+        _ => panic!("host function buffer argument #{index} is not followed by a length"),
+    }
+}
+
+/// # Safety
+/// Every input pointer in `args` must be valid for reads of its length.
+unsafe fn read_inputs(args: &[Arg]) -> Vec<Vec<u8>> {
+    let mut inputs = Vec::new();
+    for (index, arg) in args.iter().enumerate() {
+        if let Arg::In(ptr) = *arg {
+            let len = buffer_len(args, index);
+            inputs.push(unsafe { read_buffer(ptr, len) });
+        }
+    }
+    inputs
+}
+
+/// Returns how many bytes of a `len`-byte output buffer a call returning `result` wrote.
+fn written_len(len: usize, result: i32) -> usize {
+    len.min(result.max(0) as usize)
+}
+
+/// # Safety
+/// The first `result` bytes of every output pointer in `args` (bounded by its length) must have
+/// been written by the host.
+unsafe fn read_outputs(args: &[Arg], result: i32) -> Vec<Vec<u8>> {
+    let mut outputs = Vec::new();
+    for (index, arg) in args.iter().enumerate() {
+        if let Arg::Out(ptr) = *arg {
+            let len = written_len(buffer_len(args, index), result);
+            outputs.push(unsafe { read_buffer(ptr, len) });
+        }
+    }
+    outputs
+}
+
+/// Writes back what [`read_outputs`] recorded for a call returning `result`, leaving the rest of
+/// each buffer untouched.
+///
+/// # Safety
+/// Every output pointer in `args` must be valid for writes of its length.
+unsafe fn write_outputs(args: &[Arg], outputs: &[Vec<u8>], result: i32) {
+    let buffers = args
+        .iter()
+        .enumerate()
+        .filter_map(|(index, arg)| match *arg {
+            Arg::Out(ptr) => Some((ptr, buffer_len(args, index))),
+            _ => None,
+        });
+    for ((ptr, len), bytes) in buffers.zip(outputs) {
+        let count = written_len(len, result).min(bytes.len());
+        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, count) };
+    }
+}
+
+unsafe fn read_buffer(ptr: *const u8, len: usize) -> Vec<u8> {
+    if ptr.is_null() || len == 0 {
+        return Vec::new();
+    }
+    unsafe { core::slice::from_raw_parts(ptr, len) }.to_vec()
+}
+
+fn hex_list(buffers: &[Vec<u8>]) -> String {
+    let mut out = String::new();
+    for (index, buffer) in buffers.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        for byte in buffer {
+            let _ = write!(out, "{byte:02x}");
+        }
+    }
+    out
+}
+
+/// Splits a comma-separated column, treating an empty column as an empty list.
+fn split_list(column: &str) -> impl Iterator<Item = &str> {
+    column.split(',').filter(move |_| !column.is_empty())
+}
+
+fn parse_hex_list(column: &str) -> Option<Vec<Vec<u8>>> {
+    split_list(column)
+        .map(|hex| {
+            if hex.len() % 2 != 0 {
+                return None;
+            }
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+                .collect()
+        })
+        .collect()
+}
+
+macro_rules! record_and_replay {
+    ($(
+        $capability:ident via $field:ident, $with:ident {
+            $(fn $name:ident($($param:ident: $(*$ptr:ident)? $param_ty:ident),*) -> $ret:ident;)*
+        }
+    )*) => {
+        $(
+            impl<H: HostBindings> $capability for RecordingHost<H> {
+                $(
+                    unsafe fn $name(&self, $($param: $(*$ptr)? $param_ty),*) -> $ret {
+                        let args = [$(Arg::from($param)),*];
+                        let inputs = unsafe { read_inputs(&args) };
+                        let result = unsafe { self.inner.$name($($param),*) };
+                        self.log.push(HostCallRecord {
+                            function: stringify!($name).into(),
+                            args: scalars(&args),
+                            inputs,
+                            outputs: unsafe { read_outputs(&args, result) },
+                            result,
+                        });
+                        result
+                    }
+                )*
+            }
+
+            impl $capability for ReplayHost {
+                $(
+                    unsafe fn $name(&self, $($param: $(*$ptr)? $param_ty),*) -> $ret {
+                        let args = [$(Arg::from($param)),*];
+                        let inputs = unsafe { read_inputs(&args) };
+                        let record = self.next_record(stringify!($name), &scalars(&args), &inputs);
+                        unsafe { write_outputs(&args, &record.outputs, record.result) };
+                        record.result
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+host_functions!(record_and_replay);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::current_tx::get_field;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::{apply_default_expectations, setup_mock};
+    use crate::sfield;
+    use crate::types::account_id::AccountID;
+
+    const ACCOUNT: [u8; 20] = [0xB5; 20];
+
+    fn account_host() -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field().returning(|_, ptr, len| {
+            unsafe { core::ptr::copy_nonoverlapping(ACCOUNT.as_ptr(), ptr, 20) };
+            len as i32
+        });
+        mock.expect_trace()
+            .returning(|_, msg_len, _, _, _| msg_len as i32);
+        apply_default_expectations(&mut mock);
+        mock
+    }
+
+    fn record_session() -> CallLog {
+        let recording = RecordingHost::new(account_host());
+        let log = recording.log();
+        let _guard = setup_mock(recording);
+
+        let account: AccountID = get_field(sfield::Account).unwrap();
+        assert_eq!(account.0, ACCOUNT);
+        let _ = crate::host::trace::trace("done");
+        log
+    }
+
+    #[test]
+    fn test_recording_captures_arguments_and_outputs() {
+        let records = record_session().records();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].function, "get_tx_field");
+        assert_eq!(records[0].args, [i64::from(i32::from(sfield::Account)), 20]);
+        assert_eq!(records[0].outputs, [ACCOUNT.to_vec()]);
+        assert_eq!(records[0].result, 20);
+        assert_eq!(records[1].function, "trace");
+        assert_eq!(records[1].inputs[0], b"done");
+    }
+
+    #[test]
+    fn test_recording_keeps_only_written_bytes() {
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+        use crate::host::get_tx_field;
+        use mockall::predicate::{always, eq};
+
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Account)), always(), always())
+            .returning(|_, ptr, _| {
+                unsafe { core::ptr::copy_nonoverlapping(ACCOUNT.as_ptr(), ptr, 3) };
+                3
+            });
+        mock.expect_get_tx_field()
+            .returning(|_, _, _| FIELD_NOT_FOUND);
+        let recording = RecordingHost::new(mock);
+        let log = recording.log();
+        let _guard = setup_mock(recording);
+
+        let mut buffer = [0u8; 20];
+        let short = unsafe { get_tx_field(sfield::Account.into(), buffer.as_mut_ptr(), 20) };
+        let failed = unsafe { get_tx_field(sfield::Destination.into(), buffer.as_mut_ptr(), 20) };
+
+        assert_eq!((short, failed), (3, FIELD_NOT_FOUND));
+        let records = log.records();
+        assert_eq!(records[0].outputs, [ACCOUNT[..3].to_vec()]);
+        assert_eq!(records[1].outputs, [Vec::new()]);
+    }
+
+    #[test]
+    fn test_replay_serves_recorded_session() {
+        let text = record_session().to_text();
+        let replay = ReplayHost::from_text(&text).unwrap();
+        let _guard = setup_mock(replay.clone());
+
+        let account: AccountID = get_field(sfield::Account).unwrap();
+        assert_eq!(account.0, ACCOUNT);
+        assert_eq!(replay.remaining(), 1);
+        let _ = crate::host::trace::trace("done");
+        assert!(replay.is_exhausted());
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let log = record_session();
+        let replay = ReplayHost::from_text(&log.to_text()).unwrap();
+        assert_eq!(*replay.records, log.records());
+    }
+
+    #[test]
+    #[should_panic(expected = "different arguments")]
+    fn test_replay_rejects_divergent_call() {
+        let replay = ReplayHost::from_text(&record_session().to_text()).unwrap();
+        let _guard = setup_mock(replay);
+
+        let _ = get_field(sfield::Destination);
+    }
+
+    #[test]
+    fn test_from_text_rejects_malformed_lines() {
+        assert!(ReplayHost::from_text("get_tx_field|20|1,20|").is_err());
+        assert!(ReplayHost::from_text("get_tx_field|x|1,20||").is_err());
+        assert!(ReplayHost::from_text("get_tx_field|20|1,20||abc").is_err());
+        assert!(ReplayHost::from_text("\nget_tx_field|20|1,20||\n").is_ok());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::host::host_bindings_trait::HostBindings;
+use crate::host::host_bindings_trait::{
+    Crypto, Float, Keylets, LedgerHeader, LedgerObjects, Nft, Trace, TxFields,
+};
 
 /// This module hides the actual host functions from outside callers so that the correct
 /// implementations are called, regardless of target.
@@ -17,12 +19,20 @@ mod host_defined_functions {
         pub(super) fn get_parent_ledger_hash(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
         pub(super) fn get_base_fee(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
         pub(super) fn amendment_enabled(amendment_ptr: *const u8, amendment_len: usize) -> i32;
+        pub(super) fn get_tx_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+        pub(super) fn get_tx_nested_field(
+            locator_ptr: *const u8,
+            locator_len: usize,
+            out_buff_ptr: *mut u8,
+            out_buff_len: usize,
+        ) -> i32;
+        pub(super) fn get_tx_array_len(field: i32) -> i32;
+        pub(super) fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
         pub(super) fn cache_ledger_obj(
             keylet_ptr: *const u8,
             keylet_len: usize,
             cache_num: i32,
         ) -> i32;
-        pub(super) fn get_tx_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
         pub(super) fn get_current_ledger_obj_field(
             field: i32,
             out_buff_ptr: *mut u8,
@@ -34,12 +44,6 @@ mod host_defined_functions {
             out_buff_ptr: *mut u8,
             out_buff_len: usize,
         ) -> i32;
-        pub(super) fn get_tx_nested_field(
-            locator_ptr: *const u8,
-            locator_len: usize,
-            out_buff_ptr: *mut u8,
-            out_buff_len: usize,
-        ) -> i32;
         pub(super) fn get_current_ledger_obj_nested_field(
             locator_ptr: *const u8,
             locator_len: usize,
@@ -53,10 +57,8 @@ mod host_defined_functions {
             out_buff_ptr: *mut u8,
             out_buff_len: usize,
         ) -> i32;
-        pub(super) fn get_tx_array_len(field: i32) -> i32;
         pub(super) fn get_current_ledger_obj_array_len(field: i32) -> i32;
         pub(super) fn get_ledger_obj_array_len(cache_num: i32, field: i32) -> i32;
-        pub(super) fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
         pub(super) fn get_current_ledger_obj_nested_array_len(
             locator_ptr: *const u8,
             locator_len: usize,
@@ -400,8 +402,8 @@ mod host_defined_functions {
 /// Implementation of host bindings for WASM targets.
 pub struct WasmHostBindings;
 
-/// WASM implementation of HostBindings.
-impl HostBindings for WasmHostBindings {
+// WASM implementation of HostBindings, one capability at a time.
+impl LedgerHeader for WasmHostBindings {
     unsafe fn get_ledger_sqn(&self, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
         unsafe { host_defined_functions::get_ledger_sqn(out_buff_ptr, out_buff_len) }
     }
@@ -421,7 +423,40 @@ impl HostBindings for WasmHostBindings {
     unsafe fn amendment_enabled(&self, amendment_ptr: *const u8, amendment_len: usize) -> i32 {
         unsafe { host_defined_functions::amendment_enabled(amendment_ptr, amendment_len) }
     }
+}
+
+impl TxFields for WasmHostBindings {
+    unsafe fn get_tx_field(&self, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
+        unsafe { host_defined_functions::get_tx_field(field, out_buff_ptr, out_buff_len) }
+    }
 
+    unsafe fn get_tx_nested_field(
+        &self,
+        locator_ptr: *const u8,
+        locator_len: usize,
+        out_buff_ptr: *mut u8,
+        out_buff_len: usize,
+    ) -> i32 {
+        unsafe {
+            host_defined_functions::get_tx_nested_field(
+                locator_ptr,
+                locator_len,
+                out_buff_ptr,
+                out_buff_len,
+            )
+        }
+    }
+
+    unsafe fn get_tx_array_len(&self, field: i32) -> i32 {
+        unsafe { host_defined_functions::get_tx_array_len(field) }
+    }
+
+    unsafe fn get_tx_nested_array_len(&self, locator_ptr: *const u8, locator_len: usize) -> i32 {
+        unsafe { host_defined_functions::get_tx_nested_array_len(locator_ptr, locator_len) }
+    }
+}
+
+impl LedgerObjects for WasmHostBindings {
     unsafe fn cache_ledger_obj(
         &self,
         keylet_ptr: *const u8,
@@ -431,10 +466,6 @@ impl HostBindings for WasmHostBindings {
         unsafe { host_defined_functions::cache_ledger_obj(keylet_ptr, keylet_len, cache_num) }
     }
 
-    unsafe fn get_tx_field(&self, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32 {
-        unsafe { host_defined_functions::get_tx_field(field, out_buff_ptr, out_buff_len) }
-    }
-
     unsafe fn get_current_ledger_obj_field(
         &self,
         field: i32,
@@ -463,23 +494,6 @@ impl HostBindings for WasmHostBindings {
         }
     }
 
-    unsafe fn get_tx_nested_field(
-        &self,
-        locator_ptr: *const u8,
-        locator_len: usize,
-        out_buff_ptr: *mut u8,
-        out_buff_len: usize,
-    ) -> i32 {
-        unsafe {
-            host_defined_functions::get_tx_nested_field(
-                locator_ptr,
-                locator_len,
-                out_buff_ptr,
-                out_buff_len,
-            )
-        }
-    }
-
     unsafe fn get_current_ledger_obj_nested_field(
         &self,
         locator_ptr: *const u8,
@@ -516,10 +530,6 @@ impl HostBindings for WasmHostBindings {
         }
     }
 
-    unsafe fn get_tx_array_len(&self, field: i32) -> i32 {
-        unsafe { host_defined_functions::get_tx_array_len(field) }
-    }
-
     unsafe fn get_current_ledger_obj_array_len(&self, field: i32) -> i32 {
         unsafe { host_defined_functions::get_current_ledger_obj_array_len(field) }
     }
@@ -528,10 +538,6 @@ impl HostBindings for WasmHostBindings {
         unsafe { host_defined_functions::get_ledger_obj_array_len(cache_num, field) }
     }
 
-    unsafe fn get_tx_nested_array_len(&self, locator_ptr: *const u8, locator_len: usize) -> i32 {
-        unsafe { host_defined_functions::get_tx_nested_array_len(locator_ptr, locator_len) }
-    }
-
     unsafe fn get_current_ledger_obj_nested_array_len(
         &self,
         locator_ptr: *const u8,
@@ -563,7 +569,9 @@ impl HostBindings for WasmHostBindings {
     unsafe fn update_data(&self, data_ptr: *const u8, data_len: usize) -> i32 {
         unsafe { host_defined_functions::update_data(data_ptr, data_len) }
     }
+}
 
+impl Crypto for WasmHostBindings {
     unsafe fn compute_sha512_half(
         &self,
         data_ptr: *const u8,
@@ -601,7 +609,9 @@ impl HostBindings for WasmHostBindings {
             )
         }
     }
+}
 
+impl Keylets for WasmHostBindings {
     unsafe fn account_keylet(
         &self,
         account_ptr: *const u8,
@@ -995,7 +1005,9 @@ impl HostBindings for WasmHostBindings {
             )
         }
     }
+}
 
+impl Nft for WasmHostBindings {
     unsafe fn get_nft(
         &self,
         account_ptr: *const u8,
@@ -1075,7 +1087,9 @@ impl HostBindings for WasmHostBindings {
             )
         }
     }
+}
 
+impl Float for WasmHostBindings {
     unsafe fn float_from_int(
         &self,
         in_int: i64,
@@ -1349,7 +1363,9 @@ impl HostBindings for WasmHostBindings {
             )
         }
     }
+}
 
+impl Trace for WasmHostBindings {
     unsafe fn trace(
         &self,
         msg_read_ptr: *const u8,
@@ -1446,17 +1462,17 @@ export_host_functions! {
     fn get_parent_ledger_hash(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_base_fee(out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn amendment_enabled(amendment_ptr: *const u8, amendment_len: usize) -> i32;
-    fn cache_ledger_obj(keylet_ptr: *const u8, keylet_len: usize, cache_num: i32) -> i32;
     fn get_tx_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+    fn get_tx_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
+    fn get_tx_array_len(field: i32) -> i32;
+    fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
+    fn cache_ledger_obj(keylet_ptr: *const u8, keylet_len: usize, cache_num: i32) -> i32;
     fn get_current_ledger_obj_field(field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_ledger_obj_field(cache_num: i32, field: i32, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
-    fn get_tx_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_current_ledger_obj_nested_field(locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
     fn get_ledger_obj_nested_field(cache_num: i32, locator_ptr: *const u8, locator_len: usize, out_buff_ptr: *mut u8, out_buff_len: usize) -> i32;
-    fn get_tx_array_len(field: i32) -> i32;
     fn get_current_ledger_obj_array_len(field: i32) -> i32;
     fn get_ledger_obj_array_len(cache_num: i32, field: i32) -> i32;
-    fn get_tx_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
     fn get_current_ledger_obj_nested_array_len(locator_ptr: *const u8, locator_len: usize) -> i32;
     fn get_ledger_obj_nested_array_len(cache_num: i32, locator_ptr: *const u8, locator_len: usize) -> i32;
 
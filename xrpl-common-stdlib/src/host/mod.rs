@@ -20,11 +20,16 @@
 //!
 //! See the host_bindings documentation for detailed function signatures.
 
+pub mod abi;
 pub mod chain;
+pub mod context;
+pub mod costs;
 pub mod error_codes;
 pub mod field_helpers;
 pub mod trace;
 
+pub use abi::{Feature, abi_version, host_supports};
+
 // Float rounding mode constants (same as in host_bindings.rs)
 #[allow(unused)]
 pub const FLOAT_ROUNDING_MODES_TO_NEAREST: i32 = 0;
@@ -209,6 +214,13 @@ impl From<i64> for Result<u64> {
     }
 }
 
+impl<T> From<Error> for Result<T> {
+    #[inline(always)]
+    fn from(error: Error) -> Self {
+        Result::Err(error)
+    }
+}
+
 /// Transposes an `Option` of a `Result` into a `Result` of an `Option`.
 ///
 /// `None` will be mapped to `Ok(None)`.
@@ -234,107 +246,151 @@ pub fn transpose_option<T>(opt: Option<Result<T>>) -> Result<Option<T>> {
 ///
 /// Errors are global across all Programmability APIs.
 #[derive(Clone, Copy, Debug)]
-#[repr(i32)]
 pub enum Error {
     /// Reserved for internal invariant trips, generally unrelated to inputs.
     /// These should be reported with an issue.
-    InternalError = error_codes::INTERNAL_ERROR,
+    InternalError,
 
     /// The requested serialized field could not be found in the specified object.
     /// This error is returned when attempting to access a field that doesn't exist
     /// in the current transaction or ledger object.
-    FieldNotFound = error_codes::FIELD_NOT_FOUND,
+    FieldNotFound,
 
     /// The provided buffer is too small to hold the requested data.
     /// Increase the buffer size and retry the operation.
-    BufferTooSmall = error_codes::BUFFER_TOO_SMALL,
+    BufferTooSmall,
 
     /// The API was asked to assume the object under analysis is an STArray but it was not.
     /// This error occurs when trying to perform array operations on non-array objects.
-    NoArray = error_codes::NO_ARRAY,
+    NoArray,
 
     /// The specified field is not a leaf field and cannot be accessed directly.
     /// Leaf fields are primitive types that contain actual data values.
-    NotLeafField = error_codes::NOT_LEAF_FIELD,
+    NotLeafField,
 
     /// The provided locator string is malformed or invalid.
     /// Locators must follow the proper format for field identification.
-    LocatorMalformed = error_codes::LOCATOR_MALFORMED,
+    LocatorMalformed,
 
     /// The specified slot number is outside the valid range.
     /// Slot numbers must be within the allowed bounds for the current context.
-    SlotOutRange = error_codes::SLOT_OUT_RANGE,
+    SlotOutRange,
 
     /// No free slots are available for allocation.
     /// All available slots are currently in use. Consider reusing existing slots.
-    SlotsFull = error_codes::SLOTS_FULL,
+    SlotsFull,
 
     /// The specified slot did not contain any slotted data (i.e., is empty).
     /// This error occurs when trying to access a slot that hasn't been allocated
     /// or has been freed.
-    EmptySlot = error_codes::EMPTY_SLOT,
+    EmptySlot,
 
     /// The requested ledger object could not be found.
     /// This may occur if the object doesn't exist or the keylet is invalid.
-    LedgerObjNotFound = error_codes::LEDGER_OBJ_NOT_FOUND,
+    LedgerObjNotFound,
 
     /// An error occurred while decoding serialized data.
     /// This typically indicates corrupted or invalidly formatted data.
-    InvalidDecoding = error_codes::INVALID_DECODING,
+    InvalidDecoding,
 
     /// The data field is too large to be processed.
     /// Consider reducing the size of the data or splitting it into smaller chunks.
-    DataFieldTooLarge = error_codes::DATA_FIELD_TOO_LARGE,
+    DataFieldTooLarge,
 
     /// A pointer or buffer length provided as a parameter described memory outside the allowed memory region.
     /// This error indicates a memory access violation.
-    PointerOutOfBounds = error_codes::POINTER_OUT_OF_BOUNDS,
+    PointerOutOfBounds,
 
     /// No memory has been exported by the WebAssembly module.
     /// The module must export its memory for host functions to access it.
-    NoMemoryExported = error_codes::NO_MEM_EXPORTED,
+    NoMemoryExported,
 
     /// One or more of the parameters provided to the API are invalid.
     /// Check the API documentation for valid parameter ranges and formats.
-    InvalidParams = error_codes::INVALID_PARAMS,
+    InvalidParams,
 
     /// The provided account identifier is invalid.
     /// Account IDs must be valid 20-byte addresses in the proper format.
-    InvalidAccount = error_codes::INVALID_ACCOUNT,
+    InvalidAccount,
 
     /// The specified field identifier is invalid or not recognized.
     /// Field IDs must correspond to valid XRPL serialization fields.
-    InvalidField = error_codes::INVALID_FIELD,
+    InvalidField,
 
     /// The specified index is outside the valid bounds of the array or collection.
     /// Ensure the index is within the valid range for the target object.
-    IndexOutOfBounds = error_codes::INDEX_OUT_OF_BOUNDS,
+    IndexOutOfBounds,
 
     /// The input provided for floating-point parsing is malformed.
     /// Floating-point values must be in the correct format for XFL operations.
-    InvalidFloatInput = error_codes::INVALID_FLOAT_INPUT,
+    InvalidFloatInput,
 
     /// An error occurred during floating-point computation.
     /// This may indicate overflow, underflow, or other arithmetic errors.
-    InvalidFloatComputation = error_codes::INVALID_FLOAT_COMPUTATION,
+    InvalidFloatComputation,
+
+    /// A code this version of the library does not recognize, such as one introduced by a newer
+    /// host. Holds the code as returned.
+    Unknown(i32),
 }
 
-impl Error {
-    // TODO: Use Trait instead?
-    #[inline(always)] // <-- Inline because this function is very small
-    pub fn from_code(code: i32) -> Self {
-        unsafe { core::mem::transmute(code) }
-    }
+/// Maps each known variant to its code, in both directions.
+macro_rules! error_codes_table {
+    ($($variant:ident = $code:path),* $(,)?) => {
+        impl Error {
+            /// Converts a host result code into an `Error`. Codes without a variant, including
+            /// non-negative ones, become [`Error::Unknown`].
+            #[inline]
+            pub fn from_code(code: i32) -> Self {
+                match code {
+                    $($code => Error::$variant,)*
+                    _ => Error::Unknown(code),
+                }
+            }
 
-    /// Error code
-    #[inline(always)] // <-- Inline because this function is very small
-    pub fn code(self) -> i32 {
-        self as _
+            /// Error code
+            #[inline]
+            pub fn code(self) -> i32 {
+                match self {
+                    $(Error::$variant => $code,)*
+                    Error::Unknown(code) => code,
+                }
+            }
+        }
+    };
+}
+
+error_codes_table! {
+    InternalError = error_codes::INTERNAL_ERROR,
+    FieldNotFound = error_codes::FIELD_NOT_FOUND,
+    BufferTooSmall = error_codes::BUFFER_TOO_SMALL,
+    NoArray = error_codes::NO_ARRAY,
+    NotLeafField = error_codes::NOT_LEAF_FIELD,
+    LocatorMalformed = error_codes::LOCATOR_MALFORMED,
+    SlotOutRange = error_codes::SLOT_OUT_RANGE,
+    SlotsFull = error_codes::SLOTS_FULL,
+    EmptySlot = error_codes::EMPTY_SLOT,
+    LedgerObjNotFound = error_codes::LEDGER_OBJ_NOT_FOUND,
+    InvalidDecoding = error_codes::INVALID_DECODING,
+    DataFieldTooLarge = error_codes::DATA_FIELD_TOO_LARGE,
+    PointerOutOfBounds = error_codes::POINTER_OUT_OF_BOUNDS,
+    NoMemoryExported = error_codes::NO_MEM_EXPORTED,
+    InvalidParams = error_codes::INVALID_PARAMS,
+    InvalidAccount = error_codes::INVALID_ACCOUNT,
+    InvalidField = error_codes::INVALID_FIELD,
+    IndexOutOfBounds = error_codes::INDEX_OUT_OF_BOUNDS,
+    InvalidFloatInput = error_codes::INVALID_FLOAT_INPUT,
+    InvalidFloatComputation = error_codes::INVALID_FLOAT_COMPUTATION,
+}
+
+impl From<Error> for i32 {
+    fn from(val: Error) -> Self {
+        val.code()
     }
 }
 
 impl From<Error> for i64 {
     fn from(val: Error) -> Self {
-        val as i64
+        val.code() as i64
     }
 }
@@ -1,9 +1,14 @@
 use crate::host::error_codes::match_result_code;
 
+use crate::encode_hex;
 use crate::host;
 use crate::host::Result;
+use crate::objects::ledger_object;
+use crate::sfield::SField;
 use crate::types::account_id::AccountID;
 use crate::types::amount::Amount;
+use crate::types::currency::{CURRENCY_SIZE, Currency};
+use crate::types::opaque_float::FLOAT_DECIMAL_MAX_LEN;
 
 /// Data representation
 #[derive(Clone, Copy)]
@@ -53,8 +58,22 @@ pub fn trace(msg: &str) -> Result<i32> {
 /// Returns an integer representing the result of the operation. A value of `0` or higher signifies
 /// the number of message bytes that were written to the trace function. Non-zero values indicate
 /// an error (e.g., incorrect buffer sizes).
+///
+/// With the `chunked-trace` feature, `data` longer than [`TRACE_DATA_CHUNK_SIZE`] is written as
+/// several trace lines of at most that many bytes, each labelled `msg [i/n]`, and the return value
+/// is the sum over all of them. Without it, oversized data is passed to the host in one call,
+/// which may truncate or reject it.
 #[inline(always)] // <-- Inline because this function is very small
 pub fn trace_data(msg: &str, data: &[u8], data_repr: DataRepr) -> Result<i32> {
+    #[cfg(feature = "chunked-trace")]
+    if data.len() > TRACE_DATA_CHUNK_SIZE {
+        return trace_data_chunked(msg, data, data_repr);
+    }
+    trace_data_raw(msg.as_bytes(), data, data_repr)
+}
+
+#[inline(always)]
+fn trace_data_raw(msg: &[u8], data: &[u8], data_repr: DataRepr) -> Result<i32> {
     let result_code = unsafe {
         let data_ptr = data.as_ptr();
         let data_len = data.len();
@@ -64,6 +83,70 @@ pub fn trace_data(msg: &str, data: &[u8], data_repr: DataRepr) -> Result<i32> {
     match_result_code(result_code, || result_code)
 }
 
+/// Largest number of data bytes [`trace_data`] passes to the host in one call when the
+/// `chunked-trace` feature is enabled.
+pub const TRACE_DATA_CHUNK_SIZE: usize = 512;
+
+/// Size of the buffer chunk labels are built in. Longer messages are cut short to leave room for
+/// the ` [i/n]` marker.
+#[cfg(feature = "chunked-trace")]
+const CHUNK_LABEL_SIZE: usize = 128;
+
+#[cfg(feature = "chunked-trace")]
+#[inline(never)]
+fn trace_data_chunked(msg: &str, data: &[u8], data_repr: DataRepr) -> Result<i32> {
+    let total = data.len().div_ceil(TRACE_DATA_CHUNK_SIZE);
+    let mut label = [0u8; CHUNK_LABEL_SIZE];
+    let mut written: i32 = 0;
+    for (index, chunk) in data.chunks(TRACE_DATA_CHUNK_SIZE).enumerate() {
+        let label_len = chunk_label(&mut label, msg, index + 1, total);
+        match trace_data_raw(&label[..label_len], chunk, data_repr) {
+            Result::Ok(n) => written = written.saturating_add(n),
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    Result::Ok(written)
+}
+
+/// Writes `msg [index/total]` into `buf`, shortening `msg` at a character boundary if needed, and
+/// returns the label's length.
+#[cfg(feature = "chunked-trace")]
+fn chunk_label(buf: &mut [u8; CHUNK_LABEL_SIZE], msg: &str, index: usize, total: usize) -> usize {
+    // " [" + two numbers of up to 20 digits + "/" + "]"
+    const MARKER_MAX: usize = 44;
+    let mut msg_len = msg.len().min(CHUNK_LABEL_SIZE - MARKER_MAX);
+    while !msg.is_char_boundary(msg_len) {
+        msg_len -= 1;
+    }
+    buf[..msg_len].copy_from_slice(&msg.as_bytes()[..msg_len]);
+    buf[msg_len..msg_len + 2].copy_from_slice(b" [");
+    let mut len = msg_len + 2;
+    len += write_decimal(&mut buf[len..], index as u64);
+    buf[len] = b'/';
+    len += 1;
+    len += write_decimal(&mut buf[len..], total as u64);
+    buf[len] = b']';
+    len + 1
+}
+
+/// Writes `value` in decimal at the start of `buf` and returns the number of digits.
+fn write_decimal(buf: &mut [u8], mut value: u64) -> usize {
+    let mut digits = [0u8; 20];
+    let mut count = 0;
+    loop {
+        digits[count] = b'0' + (value % 10) as u8;
+        count += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    for (dst, src) in buf.iter_mut().zip(digits[..count].iter().rev()) {
+        *dst = *src;
+    }
+    count
+}
+
 /// Write the contents of a message, and a number, to the xrpld trace log.
 ///
 /// # Parameters
@@ -107,8 +190,84 @@ pub fn trace_account(msg: &str, account_id: &AccountID) -> Result<i32> {
     match_result_code(result_code, || result_code)
 }
 
-#[inline(always)]
+/// Reads an `AccountID` field from a cached ledger object and writes it to the xrpld trace log.
+///
+/// Saves reading the field into a scratch buffer just to pass it to [`trace_account`].
+///
+/// # Parameters
+/// * `msg`: A str ref pointing to an array of bytes containing UTF-8 characters.
+/// * `slot`: The cache slot holding the ledger object (as returned by `cache_ledger_obj`).
+/// * `field`: The `AccountID` field to read, e.g. `sfield::Owner`.
+///
+/// # Returns
+///
+/// Returns the trace result, or the error from reading the field if it could not be read.
+#[inline]
+pub fn trace_account_from<const CODE: i32>(
+    msg: &str,
+    slot: i32,
+    field: SField<AccountID, CODE>,
+) -> Result<i32> {
+    match ledger_object::get_field(slot, field) {
+        Result::Ok(account_id) => trace_account(msg, &account_id),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Write a message and an amount to the xrpld trace log, in units a reader can check directly.
+///
+/// XRP amounts are traced with the host's `trace_amount`. IOU amounts are written as
+/// `msg <decimal> <currency> issuer:` followed by the issuer's account ID in hex, e.g.
+/// `payout 1250.5 USD issuer: 0A1B…`, and MPT amounts as `msg <units> MPT:` followed by the
+/// issuance ID in hex. Non-standard currency codes are written as 40 hex digits. A message
+/// too long to fit alongside the amount is cut short.
+///
+/// Use [`trace_amount_serialized`] to have the host render any amount from its serialized form.
 pub fn trace_amount(msg: &str, amount: &Amount) -> Result<i32> {
+    match amount {
+        Amount::XRP { .. } => trace_amount_serialized(msg, amount),
+        Amount::IOU {
+            amount,
+            issuer,
+            currency,
+        } => {
+            let mut label = AmountLabel::new(msg);
+            let mut decimal = [0u8; FLOAT_DECIMAL_MAX_LEN];
+            let len = amount.write_decimal(&mut decimal);
+            label.push(b" ");
+            label.push(&decimal[..len]);
+            label.push(b" ");
+            match standard_currency_code(currency) {
+                Some(code) => label.push(code),
+                None => {
+                    let mut hex = [0u8; CURRENCY_SIZE * 2];
+                    let len = encode_hex(currency.as_bytes(), &mut hex);
+                    label.push(&hex[..len]);
+                }
+            }
+            label.push(b" issuer:");
+            trace_data_raw(label.as_bytes(), &issuer.0, DataRepr::AsHex)
+        }
+        Amount::MPT {
+            num_units,
+            is_positive,
+            mpt_id,
+        } => {
+            let mut label = AmountLabel::new(msg);
+            label.push(if *is_positive { b" " } else { b" -" });
+            let mut units = [0u8; 20];
+            let len = write_decimal(&mut units, *num_units);
+            label.push(&units[..len]);
+            label.push(b" MPT:");
+            trace_data_raw(label.as_bytes(), mpt_id.as_bytes(), DataRepr::AsHex)
+        }
+    }
+}
+
+/// Write a message and an amount to the xrpld trace log, passing the amount to the host's
+/// `trace_amount` in its serialized (STAmount) form.
+#[inline(always)]
+pub fn trace_amount_serialized(msg: &str, amount: &Amount) -> Result<i32> {
     // Convert Amount to the STAmount format expected by the host trace function
     let (amount_bytes, len) = amount.to_stamount_bytes();
 
@@ -118,6 +277,53 @@ pub fn trace_amount(msg: &str, amount: &Amount) -> Result<i32> {
     match_result_code(result_code, || result_code)
 }
 
+/// Size of the buffer [`trace_amount`] builds its message in.
+const AMOUNT_LABEL_SIZE: usize = 160;
+
+/// Room kept after the message for the rendered amount: a decimal, a 40-digit currency code
+/// and the separators.
+const AMOUNT_SUFFIX_MAX: usize = 96;
+
+/// A trace message with a rendered amount appended.
+struct AmountLabel {
+    buf: [u8; AMOUNT_LABEL_SIZE],
+    len: usize,
+}
+
+impl AmountLabel {
+    /// Starts the label with `msg`, shortened at a character boundary if needed.
+    fn new(msg: &str) -> Self {
+        let mut msg_len = msg.len().min(AMOUNT_LABEL_SIZE - AMOUNT_SUFFIX_MAX);
+        while !msg.is_char_boundary(msg_len) {
+            msg_len -= 1;
+        }
+        let mut label = AmountLabel {
+            buf: [0; AMOUNT_LABEL_SIZE],
+            len: 0,
+        };
+        label.push(&msg.as_bytes()[..msg_len]);
+        label
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+/// Returns the three-letter code of a standard currency, which is stored in bytes 12 to 14 with
+/// every other byte zero.
+fn standard_currency_code(currency: &Currency) -> Option<&[u8]> {
+    let bytes = currency.as_bytes();
+    let code = &bytes[12..15];
+    let rest_zero = bytes[..12].iter().chain(&bytes[15..]).all(|&b| b == 0);
+    (rest_zero && code.iter().all(|b| b.is_ascii_graphic())).then_some(code)
+}
+
 /// Write a float to the XRPLD trace log
 #[inline(always)]
 pub fn trace_float(msg: &str, f: &[u8; 8]) -> Result<i32> {
@@ -160,7 +366,7 @@ mod tests {
     }
 
     #[test]
-    fn test_trace_amount_mpt() {
+    fn test_trace_amount_serialized_mpt() {
         let mut mock = MockHostBindings::new();
 
         let message = "Test MPT amount";
@@ -189,7 +395,7 @@ mod tests {
         };
 
         // Call trace_amount function
-        let result = trace_amount(message, &amount);
+        let result = trace_amount_serialized(message, &amount);
 
         // Should return Ok with the message length
         assert!(result.is_ok());
@@ -197,7 +403,7 @@ mod tests {
     }
 
     #[test]
-    fn test_trace_amount_iou() {
+    fn test_trace_amount_serialized_iou() {
         let mut mock = MockHostBindings::new();
 
         let message = "Test IOU amount";
@@ -229,13 +435,101 @@ mod tests {
         };
 
         // Call trace_amount function
-        let result = trace_amount(message, &amount);
+        let result = trace_amount_serialized(message, &amount);
 
         // Should return Ok with the message length
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), message.len() as i32);
     }
 
+    /// The message and data passed to `trace`.
+    type Captured = std::sync::Arc<std::sync::Mutex<(std::string::String, std::vec::Vec<u8>)>>;
+
+    /// Captures the message and hex data of a single `trace` call.
+    fn mock_trace_capture() -> (MockHostBindings, Captured) {
+        let captured = std::sync::Arc::new(std::sync::Mutex::new(Default::default()));
+        let sink = captured.clone();
+        let mut mock = MockHostBindings::new();
+        mock.expect_trace_amount().never();
+        mock.expect_trace()
+            .withf(|_, _, _, _, as_hex| *as_hex == DataRepr::AsHex as i32)
+            .times(1)
+            .returning(move |msg, msg_len, data, data_len, _| {
+                let (msg, data) = unsafe {
+                    (
+                        core::slice::from_raw_parts(msg, msg_len),
+                        core::slice::from_raw_parts(data, data_len),
+                    )
+                };
+                *sink.lock().unwrap() = (
+                    std::string::String::from_utf8(msg.to_vec()).unwrap(),
+                    data.to_vec(),
+                );
+                msg_len as i32
+            });
+        (mock, captured)
+    }
+
+    #[test]
+    fn test_trace_amount_renders_iou_as_decimal() {
+        use crate::types::account_id::AccountID;
+        use crate::types::currency::Currency;
+        use crate::types::opaque_float::OpaqueFloat;
+
+        let (mock, captured) = mock_trace_capture();
+        let _guard = setup_mock(mock);
+
+        // 1250.5 = 1250500000000000 * 10^-12
+        let raw: u64 = (1 << 63) | (1 << 62) | (((97 - 12) as u64) << 54) | 1_250_500_000_000_000;
+        let amount = Amount::IOU {
+            amount: OpaqueFloat(raw.to_be_bytes()),
+            issuer: AccountID([3; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert!(trace_amount("payout", &amount).is_ok());
+
+        let (msg, data) = captured.lock().unwrap().clone();
+        assert_eq!(msg, "payout 1250.5 USD issuer:");
+        assert_eq!(data, [3; 20]);
+    }
+
+    #[test]
+    fn test_trace_amount_renders_mpt_units_and_non_standard_currency() {
+        use crate::types::account_id::AccountID;
+        use crate::types::currency::Currency;
+        use crate::types::mpt_id::MptId;
+        use crate::types::opaque_float::{FLOAT_NEGATIVE_ONE, OpaqueFloat};
+
+        let (mock, captured) = mock_trace_capture();
+        let _guard = setup_mock(mock);
+        let mpt_id = MptId::new(7, AccountID([1; 20]));
+        let amount = Amount::MPT {
+            num_units: u64::MAX,
+            is_positive: false,
+            mpt_id,
+        };
+        assert!(trace_amount("held", &amount).is_ok());
+        let (msg, data) = captured.lock().unwrap().clone();
+        assert_eq!(msg, "held -18446744073709551615 MPT:");
+        assert_eq!(data, mpt_id.as_bytes());
+        drop(_guard);
+
+        let (mock, captured) = mock_trace_capture();
+        let _guard = setup_mock(mock);
+        let long_msg = "é".repeat(60);
+        let amount = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_NEGATIVE_ONE),
+            issuer: AccountID([3; 20]),
+            currency: Currency::from([0xAB; 20]),
+        };
+        assert!(trace_amount(&long_msg, &amount).is_ok());
+        let (msg, _) = captured.lock().unwrap().clone();
+        let expected_suffix = std::format!(" -1 {} issuer:", "AB".repeat(20));
+        assert!(msg.ends_with(&expected_suffix));
+        assert!(msg.len() <= AMOUNT_LABEL_SIZE);
+        assert!(long_msg.starts_with(&msg[..msg.len() - expected_suffix.len()]));
+    }
+
     #[test]
     fn test_trace_amount_negative_xrp() {
         let mut mock = MockHostBindings::new();
@@ -262,6 +556,49 @@ mod tests {
         assert_eq!(result.unwrap(), message.len() as i32);
     }
 
+    #[test]
+    fn test_trace_account_from_reads_slot_field() {
+        use crate::sfield;
+        use mockall::predicate::eq;
+
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(3), eq(i32::from(sfield::Owner)), always(), always())
+            .times(1)
+            .returning(|_, _, ptr, _| {
+                unsafe { core::ptr::write_bytes(ptr, 0xAB, 20) };
+                20
+            });
+        mock.expect_trace_account()
+            .withf(|_, _, ptr, len| {
+                *len == 20 && unsafe { core::slice::from_raw_parts(*ptr, 20) } == [0xAB; 20]
+            })
+            .times(1)
+            .returning(|_, msg_len, _, _| msg_len as i32);
+        let _guard = setup_mock(mock);
+
+        let message = "Owner:";
+        assert_eq!(
+            trace_account_from(message, 3, sfield::Owner).unwrap(),
+            message.len() as i32
+        );
+    }
+
+    #[test]
+    fn test_trace_account_from_propagates_read_error() {
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+        use crate::sfield;
+
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .returning(|_, _, _, _| FIELD_NOT_FOUND);
+        mock.expect_trace_account().never();
+        let _guard = setup_mock(mock);
+
+        let result = trace_account_from("Owner:", 1, sfield::Owner);
+        assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+    }
+
     #[test]
     fn test_trace_bytes_format() {
         // Test XRP format
@@ -318,4 +655,56 @@ mod tests {
         assert_eq!(bytes[0], 0b_0110_0000); // Positive MPT prefix
         assert_eq!(&bytes[1..9], &VALUE.to_be_bytes()); // Amount bytes
     }
+
+    #[test]
+    #[cfg(feature = "chunked-trace")]
+    fn test_trace_data_chunks_large_payloads() {
+        let mut mock = MockHostBindings::new();
+        let mut seq = mockall::Sequence::new();
+        for (label, chunk_len) in [
+            ("Data [1/3]", TRACE_DATA_CHUNK_SIZE),
+            ("Data [2/3]", TRACE_DATA_CHUNK_SIZE),
+            ("Data [3/3]", 10),
+        ] {
+            mock.expect_trace()
+                .withf(move |msg, msg_len, _, data_len, repr| unsafe {
+                    core::slice::from_raw_parts(*msg, *msg_len) == label.as_bytes()
+                        && *data_len == chunk_len
+                        && *repr == DataRepr::AsHex as i32
+                })
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(|_, _, _, data_len, _| data_len as i32);
+        }
+        let _guard = setup_mock(mock);
+
+        let data = [0xAB; 2 * TRACE_DATA_CHUNK_SIZE + 10];
+        assert_eq!(
+            trace_data("Data", &data, DataRepr::AsHex).unwrap(),
+            data.len() as i32
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chunked-trace")]
+    fn test_chunk_label_shortens_long_messages() {
+        let mut buf = [0u8; CHUNK_LABEL_SIZE];
+        let msg = "é".repeat(CHUNK_LABEL_SIZE);
+        let len = chunk_label(&mut buf, &msg, 12, 345);
+        let label = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(label.starts_with("éé"));
+        assert!(label.ends_with(" [12/345]"));
+    }
+
+    #[test]
+    fn test_trace_data_small_payload_is_one_call() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_trace()
+            .withf(|_, msg_len, _, data_len, _| *msg_len == 4 && *data_len == 16)
+            .times(1)
+            .returning(|_, _, _, _, _| 36);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(trace_data("Data", &[0; 16], DataRepr::AsHex).unwrap(), 36);
+    }
 }
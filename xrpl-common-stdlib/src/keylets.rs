@@ -1,10 +1,13 @@
+use crate::crypto::sha512_half;
 use crate::host;
-use crate::host::Result;
 use crate::host::error_codes::match_result_code_with_expected_bytes;
+use crate::host::{Error, Result};
 use crate::types::account_id::AccountID;
 use crate::types::currency::Currency;
+use crate::types::document_id::DocumentId;
 use crate::types::issue::Issue;
 use crate::types::mpt_id::MptId;
+use crate::types::xchain_bridge::{ChainType, XChainBridge};
 
 pub const XRPL_KEYLET_SIZE: usize = 32;
 // Type aliases for specific keylets, all currently using the same underlying array type.
@@ -759,7 +762,7 @@ pub fn offer_keylet(owner: &AccountID, seq: u32) -> Result<KeyletBytes> {
 /// # Arguments
 ///
 /// * `owner` - Reference to an `AccountID` representing the oracle owner's account
-/// * `document_id` - An integer identifier for the oracle document
+/// * `document_id` - Which of the owner's oracles to reference
 ///
 /// # Returns
 ///
@@ -778,11 +781,12 @@ pub fn offer_keylet(owner: &AccountID, seq: u32) -> Result<KeyletBytes> {
 /// use xrpl_common_stdlib::types::account_id::AccountID;
 /// use xrpl_common_stdlib::keylets::oracle_keylet;
 /// use xrpl_common_stdlib::host::trace::{DataRepr, trace_data, trace_num};
+/// use xrpl_common_stdlib::types::document_id::DocumentId;
 ///
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///   let owner: AccountID =
 ///       AccountID::from(*b"\xd5\xb9\x84VP\x9f \xb5'\x9d\x1eJ.\xe8\xb2\xaa\x82\xaec\xe3");
-///   let document_id = 12345;
+///   let document_id = DocumentId::new(12345);
 ///   match oracle_keylet(&owner, document_id) {
 ///     xrpl_common_stdlib::host::Result::Ok(keylet) => {
 ///       let _ = trace_data("Generated keylet", &keylet, DataRepr::AsHex);
@@ -794,7 +798,7 @@ pub fn offer_keylet(owner: &AccountID, seq: u32) -> Result<KeyletBytes> {
 ///   Ok(())
 ///}
 /// ```
-pub fn oracle_keylet(owner: &AccountID, document_id: u32) -> Result<KeyletBytes> {
+pub fn oracle_keylet(owner: &AccountID, document_id: DocumentId) -> Result<KeyletBytes> {
     let document_id_bytes = document_id.to_le_bytes();
     create_keylet_from_host_call(|keylet_buffer_ptr, keylet_buffer_len| unsafe {
         host::oracle_keylet(
@@ -1098,6 +1102,72 @@ pub fn vault_keylet(account: &AccountID, seq: u32) -> Result<KeyletBytes> {
     })
 }
 
+/// Generates the keylet of the `Bridge` ledger object on one side of a bridge.
+///
+/// The host has no keylet function for bridges, so this hashes the door account and the bridged
+/// currency under rippled's `BRIDGE` ledger namespace with [`sha512_half`].
+///
+/// # Arguments
+///
+/// * `bridge` - The bridge, e.g. read from the `XChainBridge` field of an XChain transaction
+/// * `chain` - Which side of the bridge the current ledger is on
+///
+/// # Returns
+///
+/// * `Result<KeyletBytes>` - On success, returns a 32-byte bridge keylet.
+///   On failure, returns an `Error` with the corresponding error code.
+///   `InvalidParams` is returned if the bridged issue is an MPT, which bridges do not support.
+pub fn bridge_keylet(bridge: &XChainBridge, chain: ChainType) -> Result<KeyletBytes> {
+    let mut data = [0u8; 2 + 20 + 20];
+    data[..2].copy_from_slice(&NAMESPACE_BRIDGE.to_be_bytes());
+    data[2..22].copy_from_slice(&bridge.door(chain).0);
+    match bridge.issue(chain) {
+        Issue::XRP(_) => {}
+        Issue::IOU(iou) => data[22..].copy_from_slice(&iou.as_bytes()[..20]),
+        Issue::MPT(_) => return Result::Err(Error::InvalidParams),
+    }
+    sha512_half(&data)
+}
+
+/// Generates the keylet of the `XChainOwnedClaimID` ledger object for a claim ID on a bridge.
+///
+/// The host has no keylet function for claim IDs, so this hashes both doors, both issues and
+/// the claim ID under rippled's `XCHAIN_CLAIM_ID` ledger namespace with [`sha512_half`].
+///
+/// # Arguments
+///
+/// * `bridge` - The bridge the claim ID was created on
+/// * `claim_id` - The claim ID, i.e. the `XChainClaimID` field
+///
+/// # Returns
+///
+/// * `Result<KeyletBytes>` - On success, returns a 32-byte claim ID keylet.
+///   On failure, returns an `Error` with the corresponding error code.
+///   `InvalidParams` is returned if either bridged issue is an MPT.
+pub fn xchain_owned_claim_id_keylet(bridge: &XChainBridge, claim_id: u64) -> Result<KeyletBytes> {
+    let mut data = [0u8; 2 + 2 * (20 + 40) + 8];
+    data[..2].copy_from_slice(&NAMESPACE_XCHAIN_CLAIM_ID.to_be_bytes());
+    let mut offset = 2;
+    for chain in [ChainType::Locking, ChainType::Issuing] {
+        data[offset..offset + 20].copy_from_slice(&bridge.door(chain).0);
+        offset += 20;
+        // An issue hashes as its currency and issuer; XRP's are both all zeros.
+        match bridge.issue(chain) {
+            Issue::XRP(_) => {}
+            Issue::IOU(iou) => data[offset..offset + 40].copy_from_slice(iou.as_bytes()),
+            Issue::MPT(_) => return Result::Err(Error::InvalidParams),
+        }
+        offset += 40;
+    }
+    data[offset..].copy_from_slice(&claim_id.to_be_bytes());
+    sha512_half(&data)
+}
+
+/// rippled's `LedgerNameSpace::BRIDGE`.
+const NAMESPACE_BRIDGE: u16 = b'H' as u16;
+/// rippled's `LedgerNameSpace::XCHAIN_CLAIM_ID`.
+const NAMESPACE_XCHAIN_CLAIM_ID: u16 = b'Q' as u16;
+
 /// Generic helper function to create a keylet by calling a host function.
 ///
 /// This function handles the common tasks of:
@@ -1306,7 +1376,7 @@ mod tests {
 
     keylet_test!(oracle_keylet_tests, expect_oracle_keylet, 4, 6, {
         let owner = AccountID::from([0xBB; 20]);
-        oracle_keylet(&owner, 12345)
+        oracle_keylet(&owner, DocumentId::new(12345))
     });
 
     keylet_test!(paychan_keylet_tests, expect_paychan_keylet, 6, 8, {
@@ -1341,6 +1411,87 @@ mod tests {
         vault_keylet(&account, 12345)
     });
 
+    mod xchain_keylet_tests {
+        use super::*;
+        use crate::types::issue::{IouIssue, XrpIssue};
+        use crate::types::mpt_id::MptId;
+        use crate::types::xchain_bridge::{ChainType, XChainBridge};
+
+        fn bridge() -> XChainBridge {
+            XChainBridge {
+                locking_chain_door: AccountID::from([0x11; 20]),
+                locking_chain_issue: Issue::XRP(XrpIssue {}),
+                issuing_chain_door: AccountID::from([0x22; 20]),
+                issuing_chain_issue: Issue::IOU(IouIssue::new(
+                    AccountID::from([0x33; 20]),
+                    Currency::from([0x44; 20]),
+                )),
+            }
+        }
+
+        /// Expects one `compute_sha512_half` call over exactly `expected` and answers `0xCC`s.
+        fn expect_hash_of(expected: std::vec::Vec<u8>) -> MockHostBindings {
+            let mut mock = MockHostBindings::new();
+            mock.expect_compute_sha512_half().times(1).returning(
+                move |data_ptr, data_len, out_ptr, out_len| {
+                    let data = unsafe { core::slice::from_raw_parts(data_ptr, data_len) };
+                    assert_eq!(data, expected.as_slice());
+                    write_keylet_to_buffer(out_ptr, out_len)
+                },
+            );
+            mock
+        }
+
+        #[test]
+        fn test_bridge_keylet_hashes_door_and_currency() {
+            let mut expected = std::vec![0x00, b'H'];
+            expected.extend_from_slice(&[0x22; 20]);
+            expected.extend_from_slice(&[0x44; 20]);
+            let _guard = setup_mock(expect_hash_of(expected));
+
+            let keylet = bridge_keylet(&bridge(), ChainType::Issuing).unwrap();
+            assert_eq!(keylet, EXPECTED_KEYLET);
+        }
+
+        #[test]
+        fn test_bridge_keylet_uses_zero_currency_for_xrp() {
+            let mut expected = std::vec![0x00, b'H'];
+            expected.extend_from_slice(&[0x11; 20]);
+            expected.extend_from_slice(&[0x00; 20]);
+            let _guard = setup_mock(expect_hash_of(expected));
+
+            assert!(bridge_keylet(&bridge(), ChainType::Locking).is_ok());
+        }
+
+        #[test]
+        fn test_claim_id_keylet_hashes_both_sides_and_claim_id() {
+            let mut expected = std::vec![0x00, b'Q'];
+            expected.extend_from_slice(&[0x11; 20]);
+            expected.extend_from_slice(&[0x00; 40]);
+            expected.extend_from_slice(&[0x22; 20]);
+            expected.extend_from_slice(&[0x44; 20]);
+            expected.extend_from_slice(&[0x33; 20]);
+            expected.extend_from_slice(&7u64.to_be_bytes());
+            let _guard = setup_mock(expect_hash_of(expected));
+
+            let keylet = xchain_owned_claim_id_keylet(&bridge(), 7).unwrap();
+            assert_eq!(keylet, EXPECTED_KEYLET);
+        }
+
+        #[test]
+        fn test_mpt_bridges_are_rejected_without_hashing() {
+            let _guard = setup_mock(MockHostBindings::new());
+            let mut bridge = bridge();
+            bridge.issuing_chain_issue = Issue::MPT(crate::types::issue::MptIssue::new(
+                MptId::new(1, AccountID::from([0x55; 20])),
+            ));
+
+            let result = bridge_keylet(&bridge, ChainType::Issuing);
+            assert_eq!(result.err().unwrap().code(), Error::InvalidParams.code());
+            assert!(xchain_owned_claim_id_keylet(&bridge, 1).is_err());
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_wrong_size_panics() {
@@ -0,0 +1,295 @@
+//! Binding contract state to the ledger it was recorded in.
+//!
+//! A contract that runs in several phases (e.g. one transaction commits, a later one reveals)
+//! can record which ledger the first phase ran in and check, in later phases, whether they run in
+//! that same ledger or a later one. The first phase saves the current [`LedgerBinding`] (the
+//! parent ledger hash and the current ledger sequence) in the `Data` field, and
+//! [`check_continuity`] compares the saved binding with the ledger a later phase runs in.
+//!
+//! Only the same-ledger case is compared exactly. A contract can read the hash of its parent
+//! ledger but of no older ledger, so a later phase cannot tell whether its ledger descends from
+//! the recorded one: [`LedgerContext::Later`] means only that the ledger sequence has grown.
+//!
+//! [`bind_to_ledger`] covers the single-phase case: a transaction that carries the hash of the
+//! ledger it was prepared against (e.g. in a memo) only succeeds if it is applied directly on top
+//! of that ledger.
+//!
+//! # Data layout
+//!
+//! An `Option<LedgerBinding>` is a [`DataSection`], so it is loaded from and saved to `Data`
+//! together with the rest of the contract's state (in an escrow, with
+//! `EscrowFinishContext::load_state` and `save_state`):
+//!
+//! ```text
+//! ┌─────────────────┬──────────────────────────┬─────────────────────────┐
+//! │ present (u8)    │ parent hash (32 bytes)   │ ledger seq (u32, LE)    │
+//! └─────────────────┴──────────────────────────┴─────────────────────────┘
+//! ```
+//!
+//! `present` is `0` for `None`, in which case nothing follows it.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::Result;
+//! use xrpl_common_stdlib::ledger::{LedgerBinding, LedgerContext, check_continuity};
+//!
+//! # fn load_binding() -> Option<LedgerBinding> { None }
+//! // Loaded from `Data`, e.g. with `EscrowFinishContext::load_state`.
+//! let stored = load_binding();
+//! match check_continuity(stored.as_ref()) {
+//!     // Phase 1: remember where we started by saving `Some(binding)` to `Data`.
+//!     Result::Ok(LedgerContext::Unbound) => {
+//!         let _binding = LedgerBinding::current();
+//!     }
+//!     // Phase 2 must run in a later ledger. Whether that ledger descends from the recorded
+//!     // one is not checked.
+//!     Result::Ok(LedgerContext::Later { .. }) => { /* ... */ }
+//!     _ => { /* same ledger, fork or rollback: refuse */ }
+//! }
+//! ```
+
+use crate::host::chain::{ledger_sqn, parent_ledger_hash};
+use crate::host::{Error, Result};
+use crate::types::data_cursor::{DataCursor, DataSection};
+use crate::types::uint::{HASH256_SIZE, Hash256};
+
+/// Size in bytes of a serialized [`LedgerBinding`].
+pub const LEDGER_BINDING_SIZE: usize = HASH256_SIZE + 4;
+
+/// The ledger a contract phase ran in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerBinding {
+    /// The hash of the parent of the ledger the phase ran in.
+    pub parent_hash: Hash256,
+    /// The sequence of the ledger the phase ran in.
+    pub ledger_seq: u32,
+}
+
+impl LedgerBinding {
+    /// Reads the binding for the ledger the contract is running in.
+    pub fn current() -> Result<Self> {
+        let parent_hash = match parent_ledger_hash() {
+            Result::Ok(hash) => Hash256::from(hash),
+            Result::Err(e) => return Result::Err(e),
+        };
+        ledger_sqn().map(|ledger_seq| LedgerBinding {
+            parent_hash,
+            ledger_seq,
+        })
+    }
+
+    /// Writes the binding at the cursor's position.
+    pub fn write(&self, cursor: &mut DataCursor) -> Result<()> {
+        cursor
+            .write_hash256(&self.parent_hash)
+            .and_then(|()| cursor.write_u32_le(self.ledger_seq))
+    }
+
+    /// Reads a binding at the cursor's position.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If fewer than [`LEDGER_BINDING_SIZE`] bytes remain
+    pub fn read(cursor: &mut DataCursor) -> Result<Self> {
+        if cursor.remaining() < LEDGER_BINDING_SIZE {
+            return Result::Err(Error::InvalidDecoding);
+        }
+        let parent_hash = match cursor.read_hash256() {
+            Result::Ok(hash) => hash,
+            Result::Err(e) => return Result::Err(e),
+        };
+        cursor.read_u32_le().map(|ledger_seq| LedgerBinding {
+            parent_hash,
+            ledger_seq,
+        })
+    }
+
+    /// Compares this binding, recorded by an earlier phase, with `current`.
+    ///
+    /// A later sequence is reported as [`LedgerContext::Later`] whatever its parent hash, which
+    /// cannot be related to the recorded one.
+    pub fn context_of(&self, current: &LedgerBinding) -> LedgerContext {
+        if current.ledger_seq == self.ledger_seq && current.parent_hash == self.parent_hash {
+            LedgerContext::SameLedger
+        } else if current.ledger_seq > self.ledger_seq {
+            LedgerContext::Later {
+                ledgers_elapsed: current.ledger_seq - self.ledger_seq,
+            }
+        } else {
+            LedgerContext::Unexpected
+        }
+    }
+}
+
+impl DataSection for Option<LedgerBinding> {
+    /// Reads a binding stored in the format described in the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If the presence byte is neither `0` nor `1`, or the binding is
+    ///   truncated
+    fn read_section(cursor: &mut DataCursor) -> Result<Self> {
+        match cursor.read_u8() {
+            Result::Ok(0) => Result::Ok(None),
+            Result::Ok(1) => LedgerBinding::read(cursor).map(Some),
+            Result::Ok(_) => Result::Err(Error::InvalidDecoding),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    fn write_section(&self, cursor: &mut DataCursor) -> Result<()> {
+        match self {
+            Some(binding) => cursor.write_u8(1).and_then(|()| binding.write(cursor)),
+            None => cursor.write_u8(0),
+        }
+    }
+}
+
+/// Where a later phase runs relative to the ledger recorded by an earlier one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerContext {
+    /// Nothing has been recorded yet: this is the first phase.
+    Unbound,
+    /// The same ledger as the recorded phase, e.g. a second transaction in that ledger.
+    SameLedger,
+    /// A ledger with a higher sequence. This says nothing about ancestry: the ledger may be on
+    /// another fork or network than the recorded one.
+    Later { ledgers_elapsed: u32 },
+    /// The same ledger sequence with a different parent, or an earlier sequence: the recorded
+    /// phase ran on another fork or network, or the ledger was rolled back.
+    Unexpected,
+}
+
+/// Checks that the contract is running directly on top of the ledger with hash `hash`.
+///
+/// # Errors
+///
+/// * `InvalidParams` - If the parent ledger hash is not `hash`
+/// * Any error from reading the parent ledger hash
+pub fn bind_to_ledger(hash: &Hash256) -> Result<()> {
+    parent_ledger_hash().and_then(|parent| {
+        if &Hash256::from(parent) == hash {
+            Result::Ok(())
+        } else {
+            Result::Err(Error::InvalidParams)
+        }
+    })
+}
+
+/// Compares the ledger the contract is running in with `stored`, the binding saved by an
+/// earlier phase (`None` if no phase has saved one yet).
+pub fn check_continuity(stored: Option<&LedgerBinding>) -> Result<LedgerContext> {
+    match stored {
+        Some(stored) => LedgerBinding::current().map(|current| stored.context_of(&current)),
+        None => Result::Ok(LedgerContext::Unbound),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{INVALID_DECODING, INVALID_PARAMS};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::types::contract_data::ContractData;
+
+    fn binding(byte: u8, ledger_seq: u32) -> LedgerBinding {
+        LedgerBinding {
+            parent_hash: Hash256::from([byte; 32]),
+            ledger_seq,
+        }
+    }
+
+    /// Wires the ledger header reads to `current`.
+    fn mock_ledger(current: LedgerBinding) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        let parent_hash = current.parent_hash.0;
+        mock.expect_get_parent_ledger_hash()
+            .returning(move |out, _| {
+                unsafe { core::ptr::copy_nonoverlapping(parent_hash.as_ptr(), out, 32) };
+                32
+            });
+        mock.expect_get_ledger_sqn().returning(move |out, _| {
+            unsafe {
+                core::ptr::copy_nonoverlapping(current.ledger_seq.to_le_bytes().as_ptr(), out, 4)
+            };
+            4
+        });
+        mock
+    }
+
+    fn encoded(stored: Option<LedgerBinding>) -> ContractData {
+        let mut data = ContractData::new();
+        DataCursor::new(&mut data).write_section(&stored).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_bind_to_ledger() {
+        let _guard = setup_mock(mock_ledger(binding(0xAA, 10)));
+
+        assert!(bind_to_ledger(&Hash256::from([0xAA; 32])).is_ok());
+        assert_eq!(
+            bind_to_ledger(&Hash256::from([0xBB; 32]))
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_current_reads_the_ledger_header() {
+        let _guard = setup_mock(mock_ledger(binding(0xAA, 10)));
+
+        assert_eq!(LedgerBinding::current().unwrap(), binding(0xAA, 10));
+    }
+
+    #[test]
+    fn test_check_continuity() {
+        let stored = binding(0xAA, 10);
+        let cases = [
+            (binding(0xAA, 10), LedgerContext::SameLedger),
+            (
+                binding(0xCC, 13),
+                LedgerContext::Later { ledgers_elapsed: 3 },
+            ),
+            (binding(0xCC, 10), LedgerContext::Unexpected),
+            (binding(0xAA, 9), LedgerContext::Unexpected),
+        ];
+        for (current, expected) in cases {
+            let _guard = setup_mock(mock_ledger(current));
+            assert_eq!(check_continuity(Some(&stored)).unwrap(), expected);
+        }
+
+        let _guard = setup_mock(MockHostBindings::new());
+        assert_eq!(check_continuity(None).unwrap(), LedgerContext::Unbound);
+    }
+
+    #[test]
+    fn test_section_round_trip() {
+        let mut data = encoded(Some(binding(0xAA, 10)));
+        assert_eq!(data.len, 1 + LEDGER_BINDING_SIZE);
+        let decoded: Option<LedgerBinding> = DataCursor::new(&mut data).read_section().unwrap();
+        assert_eq!(decoded, Some(binding(0xAA, 10)));
+
+        let mut data = encoded(None);
+        assert_eq!(data.as_slice(), &[0]);
+        let decoded: Option<LedgerBinding> = DataCursor::new(&mut data).read_section().unwrap();
+        assert_eq!(decoded, None);
+    }
+
+    #[test]
+    fn test_malformed_binding_is_rejected() {
+        let mut truncated = encoded(Some(binding(0xAA, 10)));
+        truncated.len -= 1;
+        let result = DataCursor::new(&mut truncated).read_section::<Option<LedgerBinding>>();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+
+        let mut bad_flag = encoded(Some(binding(0xAA, 10)));
+        bad_flag.data[0] = 2;
+        let result = DataCursor::new(&mut bad_flag).read_section::<Option<LedgerBinding>>();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+    }
+}
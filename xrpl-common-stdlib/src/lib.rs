@@ -5,24 +5,43 @@
 extern crate std;
 
 // Re-export macros for convenient access
+pub use xrpl_macros::amendment_id;
 pub use xrpl_macros::blob;
+pub use xrpl_macros::contract_metadata;
 pub use xrpl_macros::currency;
+pub use xrpl_macros::entry_point;
 pub use xrpl_macros::hash256;
 pub use xrpl_macros::pubkey;
 pub use xrpl_macros::r_address;
 pub use xrpl_macros::smart_contract;
 pub use xrpl_macros::smart_escrow;
+pub use xrpl_macros::xrp;
+pub mod assert;
+#[cfg(feature = "build_info")]
+pub mod build_info;
+pub mod collections;
 pub mod crypto;
 pub mod ctx;
 pub mod current_tx;
+pub mod error_domain;
 pub mod fields;
 pub mod host;
 pub mod keylets;
+pub mod ledger;
+pub mod log;
+pub mod math;
+pub mod memo_protocol;
+pub mod metadata;
 pub mod objects;
+pub mod patterns;
+pub mod rand;
 pub mod sfield;
 pub(crate) mod tx_flags;
+pub mod type_codes;
 pub mod types;
 
+pub use objects::current_ledger_object;
+
 /// Complete Developer Guide
 ///
 /// This comprehensive guide covers everything you need to develop smart escrows using
@@ -72,6 +91,52 @@ fn hex_char_to_nibble(c: u8) -> Option<u8> {
     }
 }
 
+/// Decode a hex string of `H` characters into an array of `N = H / 2` bytes.
+///
+/// Both lengths are usually inferred, so one function covers every fixed-size value:
+///
+/// ```
+/// # use xrpl_common_stdlib::decode_hex;
+/// let email_hash: [u8; 16] = decode_hex(b"98b4375e1d753e5b91627516f6d70977").unwrap();
+/// let public_key: [u8; 33] =
+///     decode_hex(b"ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A").unwrap();
+/// # let _ = (email_hash, public_key);
+/// ```
+///
+/// Returns `None` if any character is not a valid hex digit (lower- or upper-case). Passing an
+/// input whose length is not exactly `2 * N` fails to compile.
+///
+/// This is a plain loop; [`decode_hex_20`] and [`decode_hex_32`] are unrolled for speed unless the
+/// `compact-hex` feature is enabled.
+#[inline]
+pub fn decode_hex<const N: usize, const H: usize>(hex: &[u8; H]) -> Option<[u8; N]> {
+    const {
+        assert!(
+            H == N * 2,
+            "hex input must be exactly two characters per output byte"
+        )
+    };
+    let mut out = [0u8; N];
+    for (byte, pair) in out.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (hex_char_to_nibble(pair[0])? << 4) | hex_char_to_nibble(pair[1])?;
+    }
+    Some(out)
+}
+
+/// Expands to an unrolled decode of the listed byte indices, returning `None` from the enclosing
+/// function on an invalid digit. Unrolling eliminates the loop counter overhead in WASM.
+#[cfg(not(feature = "compact-hex"))]
+macro_rules! decode_hex_unrolled {
+    ($hex:expr, $len:literal; $($i:literal)*) => {{
+        let hex = $hex;
+        let mut out = [0u8; $len];
+        $(
+            out[$i] = (hex_char_to_nibble(hex[$i * 2])? << 4) | hex_char_to_nibble(hex[$i * 2 + 1])?;
+        )*
+        Some(out)
+    }};
+}
+
 /// Decode a 64-hex-character string into a 32-byte array.
 ///
 /// The input must be exactly 64 hexadecimal ASCII bytes (lower- or upper-case).
@@ -86,51 +151,16 @@ fn hex_char_to_nibble(c: u8) -> Option<u8> {
 /// ```
 #[inline(always)]
 pub fn decode_hex_32(hex: &[u8; 64]) -> Option<[u8; 32]> {
-    let mut out = [0u8; 32];
-
-    // Unrolled loop for better WASM performance - eliminates loop counter overhead
-    macro_rules! decode_byte {
-        ($i:expr) => {{
-            let high = hex_char_to_nibble(hex[$i * 2])?;
-            let low = hex_char_to_nibble(hex[$i * 2 + 1])?;
-            out[$i] = (high << 4) | low;
-        }};
+    #[cfg(feature = "compact-hex")]
+    {
+        decode_hex(hex)
+    }
+    #[cfg(not(feature = "compact-hex"))]
+    {
+        decode_hex_unrolled!(hex, 32;
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31)
     }
-
-    decode_byte!(0);
-    decode_byte!(1);
-    decode_byte!(2);
-    decode_byte!(3);
-    decode_byte!(4);
-    decode_byte!(5);
-    decode_byte!(6);
-    decode_byte!(7);
-    decode_byte!(8);
-    decode_byte!(9);
-    decode_byte!(10);
-    decode_byte!(11);
-    decode_byte!(12);
-    decode_byte!(13);
-    decode_byte!(14);
-    decode_byte!(15);
-    decode_byte!(16);
-    decode_byte!(17);
-    decode_byte!(18);
-    decode_byte!(19);
-    decode_byte!(20);
-    decode_byte!(21);
-    decode_byte!(22);
-    decode_byte!(23);
-    decode_byte!(24);
-    decode_byte!(25);
-    decode_byte!(26);
-    decode_byte!(27);
-    decode_byte!(28);
-    decode_byte!(29);
-    decode_byte!(30);
-    decode_byte!(31);
-
-    Some(out)
 }
 
 /// Decode a 40-hex-character string into a 20-byte array.
@@ -147,37 +177,118 @@ pub fn decode_hex_32(hex: &[u8; 64]) -> Option<[u8; 32]> {
 /// ```
 #[inline(always)]
 pub fn decode_hex_20(hex: &[u8; 40]) -> Option<[u8; 20]> {
-    let mut out = [0u8; 20];
-
-    // Unrolled loop for better WASM performance - eliminates loop counter overhead
-    macro_rules! decode_byte {
-        ($i:expr) => {{
-            let high = hex_char_to_nibble(hex[$i * 2])?;
-            let low = hex_char_to_nibble(hex[$i * 2 + 1])?;
-            out[$i] = (high << 4) | low;
-        }};
+    #[cfg(feature = "compact-hex")]
+    {
+        decode_hex(hex)
     }
+    #[cfg(not(feature = "compact-hex"))]
+    {
+        decode_hex_unrolled!(hex, 20;
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19)
+    }
+}
 
-    decode_byte!(0);
-    decode_byte!(1);
-    decode_byte!(2);
-    decode_byte!(3);
-    decode_byte!(4);
-    decode_byte!(5);
-    decode_byte!(6);
-    decode_byte!(7);
-    decode_byte!(8);
-    decode_byte!(9);
-    decode_byte!(10);
-    decode_byte!(11);
-    decode_byte!(12);
-    decode_byte!(13);
-    decode_byte!(14);
-    decode_byte!(15);
-    decode_byte!(16);
-    decode_byte!(17);
-    decode_byte!(18);
-    decode_byte!(19);
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
 
-    Some(out)
+#[inline]
+fn encode_hex_with(bytes: &[u8], out: &mut [u8], digits: &[u8; 16]) -> usize {
+    let mut written = 0;
+    for (byte, pair) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+        pair[0] = digits[(byte >> 4) as usize];
+        pair[1] = digits[(byte & 0x0F) as usize];
+        written += 2;
+    }
+    written
+}
+
+/// Encode `bytes` as upper-case hex (the form XRPL tooling uses) into `out`.
+///
+/// Returns the number of characters written. If `out` holds fewer than `2 * bytes.len()`
+/// characters, only the leading bytes that fit are encoded; check the return value, or use
+/// [`encode_hex_array`] to have the size checked at compile time.
+///
+/// ```
+/// # use xrpl_common_stdlib::encode_hex;
+/// let mut out = [0u8; 8];
+/// let len = encode_hex(&[0xDE, 0xAD, 0xBE, 0xEF], &mut out);
+/// assert_eq!(&out[..len], b"DEADBEEF");
+/// ```
+pub fn encode_hex(bytes: &[u8], out: &mut [u8]) -> usize {
+    encode_hex_with(bytes, out, HEX_UPPER)
+}
+
+/// Like [`encode_hex`], but with lower-case digits.
+pub fn encode_hex_lower(bytes: &[u8], out: &mut [u8]) -> usize {
+    encode_hex_with(bytes, out, HEX_LOWER)
+}
+
+/// Encode a fixed-size value as upper-case hex, checking at compile time that the output holds
+/// exactly two characters per byte.
+///
+/// ```
+/// # use xrpl_common_stdlib::encode_hex_array;
+/// let hex: [u8; 4] = encode_hex_array(&[0x0A, 0xFF]);
+/// assert_eq!(&hex, b"0AFF");
+/// ```
+pub fn encode_hex_array<const N: usize, const H: usize>(bytes: &[u8; N]) -> [u8; H] {
+    const {
+        assert!(
+            H == N * 2,
+            "hex output must be exactly two characters per input byte"
+        )
+    };
+    let mut out = [0u8; H];
+    encode_hex_with(bytes, &mut out, HEX_UPPER);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_hex_round_trips_through_decode() {
+        let bytes: [u8; 32] = core::array::from_fn(|i| (i * 37) as u8);
+
+        let upper: [u8; 64] = encode_hex_array(&bytes);
+        assert_eq!(decode_hex_32(&upper), Some(bytes));
+
+        let mut lower = [0u8; 64];
+        assert_eq!(encode_hex_lower(&bytes, &mut lower), 64);
+        assert_eq!(decode_hex_32(&lower), Some(bytes));
+        assert_eq!(lower.to_ascii_uppercase(), upper);
+    }
+
+    #[test]
+    fn test_encode_hex_stops_at_output_capacity() {
+        let mut out = [b'.'; 5];
+        assert_eq!(encode_hex(&[0x12, 0x34, 0x56], &mut out), 4);
+        assert_eq!(&out, b"1234.");
+        assert_eq!(encode_hex(&[], &mut out), 0);
+    }
+
+    #[test]
+    fn test_generic_decode_matches_fixed_size_decoders() {
+        let hex = *b"00112233445566778899AABBCCDDEEFF00112233445566778899aabbccddeeff";
+        assert_eq!(decode_hex(&hex), decode_hex_32(&hex));
+
+        let hex_20: &[u8; 40] = hex[..40].try_into().unwrap();
+        assert_eq!(decode_hex(hex_20), decode_hex_20(hex_20));
+    }
+
+    #[test]
+    fn test_generic_decode_other_sizes() {
+        assert_eq!(decode_hex::<0, 0>(&[]), Some([]));
+        assert_eq!(decode_hex(b"dEaD"), Some([0xDE, 0xAD]));
+
+        let wide: [u8; 64] = decode_hex(&[b'f'; 128]).unwrap();
+        assert_eq!(wide, [0xFF; 64]);
+    }
+
+    #[test]
+    fn test_generic_decode_rejects_invalid_digit() {
+        assert_eq!(decode_hex::<2, 4>(b"0g12"), None);
+        assert_eq!(decode_hex_20(&[b'z'; 40]), None);
+    }
 }
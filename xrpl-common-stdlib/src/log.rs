@@ -0,0 +1,363 @@
+//! Leveled, rate-limited logging over the host trace functions.
+//!
+//! Every trace call costs gas and adds a line to the xrpld log, so tracing inside a loop can
+//! exhaust the computation allowance or drown out everything else. The [`error!`], [`warn!`],
+//! [`info!`] and [`debug!`] macros add two controls on top of [`host::trace`](crate::host::trace()):
+//!
+//! * A compile-time maximum level. Enable one of the `log-max-info`, `log-max-warn`,
+//!   `log-max-error` or `log-off` features and the disabled macros compile to nothing, including
+//!   their arguments. Without any of them, every level is enabled.
+//! * An optional cap on the number of messages per execution, set with [`set_limit`]. Messages
+//!   past the cap are dropped; [`dropped`] reports how many.
+//!
+//! Each macro takes a message and optionally a value, which is traced with the host function for
+//! its type (see [`TraceValue`]):
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::log;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! log::set_limit(16);
+//! log::info!("finishing escrow");
+//! for (i, owner) in [AccountID([1; 20]), AccountID([2; 20])].iter().enumerate() {
+//!     log::debug!("signer index:", i);
+//!     log::debug!("signer:", owner);
+//! }
+//! ```
+//!
+//! Trace errors are ignored: a contract should not fail because a log line could not be written.
+
+use crate::host::Result;
+use crate::host::trace::{DataRepr, trace, trace_account, trace_amount, trace_data, trace_num};
+use crate::types::account_id::AccountID;
+use crate::types::amount::Amount;
+use crate::types::transaction_type::TransactionType;
+
+/// Severity of a log message. Lower levels are more severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+}
+
+/// The most verbose level compiled in, or `None` if logging is compiled out (`log-off`).
+pub const MAX_LEVEL: Option<Level> = if cfg!(feature = "log-off") {
+    None
+} else if cfg!(feature = "log-max-error") {
+    Some(Level::Error)
+} else if cfg!(feature = "log-max-warn") {
+    Some(Level::Warn)
+} else if cfg!(feature = "log-max-info") {
+    Some(Level::Info)
+} else {
+    Some(Level::Debug)
+};
+
+/// Returns whether messages at `level` are compiled in.
+#[inline(always)]
+pub const fn level_enabled(level: Level) -> bool {
+    match MAX_LEVEL {
+        Some(max) => level as u8 <= max as u8,
+        None => false,
+    }
+}
+
+/// Limits the number of messages the macros write for the rest of this execution, counting
+/// from now. Pass `u32::MAX` (the initial value) for no limit.
+pub fn set_limit(max_messages: u32) {
+    budget::set(max_messages);
+    budget::reset_dropped();
+}
+
+/// Number of messages dropped since the last [`set_limit`] because the limit was reached.
+pub fn dropped() -> u32 {
+    budget::dropped()
+}
+
+/// A value that can be written to the trace log after a message.
+pub trait TraceValue {
+    /// Writes `msg` followed by `self` with the matching host trace function.
+    fn trace_value(&self, msg: &str) -> Result<i32>;
+}
+
+macro_rules! trace_value_as_num {
+    ($($ty:ty),*) => {
+        $(impl TraceValue for $ty {
+            fn trace_value(&self, msg: &str) -> Result<i32> {
+                trace_num(msg, *self as i64)
+            }
+        })*
+    };
+}
+
+trace_value_as_num!(
+    bool,
+    u8,
+    u16,
+    u32,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    TransactionType
+);
+
+/// Traced as a decimal number when it fits in an `i64`, otherwise as big-endian hex.
+impl TraceValue for u64 {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        match i64::try_from(*self) {
+            core::result::Result::Ok(value) => trace_num(msg, value),
+            core::result::Result::Err(_) => trace_data(msg, &self.to_be_bytes(), DataRepr::AsHex),
+        }
+    }
+}
+
+impl TraceValue for usize {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        (*self as u64).trace_value(msg)
+    }
+}
+
+impl TraceValue for AccountID {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        trace_account(msg, self)
+    }
+}
+
+impl TraceValue for Amount {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        trace_amount(msg, self)
+    }
+}
+
+impl TraceValue for [u8] {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        trace_data(msg, self, DataRepr::AsHex)
+    }
+}
+
+impl<const N: usize> TraceValue for [u8; N] {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        trace_data(msg, self, DataRepr::AsHex)
+    }
+}
+
+impl<T: TraceValue + ?Sized> TraceValue for &T {
+    fn trace_value(&self, msg: &str) -> Result<i32> {
+        (**self).trace_value(msg)
+    }
+}
+
+#[doc(hidden)]
+pub fn __log(msg: &str) {
+    if budget::take() {
+        let _ = trace(msg);
+    }
+}
+
+#[doc(hidden)]
+pub fn __log_value<T: TraceValue + ?Sized>(msg: &str, value: &T) {
+    if budget::take() {
+        let _ = value.trace_value(msg);
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_at {
+    ($level:expr, $msg:expr) => {
+        if $crate::log::level_enabled($level) {
+            $crate::log::__log($msg);
+        }
+    };
+    ($level:expr, $msg:expr, $value:expr) => {
+        if $crate::log::level_enabled($level) {
+            $crate::log::__log_value($msg, &$value);
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_error {
+    ($($args:expr),+ $(,)?) => { $crate::__log_at!($crate::log::Level::Error, $($args),+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_warn {
+    ($($args:expr),+ $(,)?) => { $crate::__log_at!($crate::log::Level::Warn, $($args),+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_info {
+    ($($args:expr),+ $(,)?) => { $crate::__log_at!($crate::log::Level::Info, $($args),+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_debug {
+    ($($args:expr),+ $(,)?) => { $crate::__log_at!($crate::log::Level::Debug, $($args),+) };
+}
+
+/// Logs a message, and optionally a value, at [`Level::Error`].
+#[doc(inline)]
+pub use crate::__log_error as error;
+
+/// Logs a message, and optionally a value, at [`Level::Warn`].
+#[doc(inline)]
+pub use crate::__log_warn as warn;
+
+/// Logs a message, and optionally a value, at [`Level::Info`].
+#[doc(inline)]
+pub use crate::__log_info as info;
+
+/// Logs a message, and optionally a value, at [`Level::Debug`].
+#[doc(inline)]
+pub use crate::__log_debug as debug;
+
+/// The per-execution message budget.
+///
+/// A contract runs single-threaded in a fresh instance, so a plain global resets with every
+/// execution. Native builds keep it per thread so that tests running in parallel do not share it.
+mod budget {
+    use core::cell::Cell;
+
+    struct Budget {
+        remaining: Cell<u32>,
+        dropped: Cell<u32>,
+    }
+
+    const fn new_budget() -> Budget {
+        Budget {
+            remaining: Cell::new(u32::MAX),
+            dropped: Cell::new(0),
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn with<R>(f: impl FnOnce(&Budget) -> R) -> R {
+        struct Global(Budget);
+        // SAFETY: wasm contracts are single-threaded.
+        unsafe impl Sync for Global {}
+        static BUDGET: Global = Global(new_budget());
+        f(&BUDGET.0)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn with<R>(f: impl FnOnce(&Budget) -> R) -> R {
+        std::thread_local! {
+            static BUDGET: Budget = const { new_budget() };
+        }
+        BUDGET.with(f)
+    }
+
+    pub(super) fn set(max: u32) {
+        with(|b| b.remaining.set(max));
+    }
+
+    pub(super) fn reset_dropped() {
+        with(|b| b.dropped.set(0));
+    }
+
+    pub(super) fn dropped() -> u32 {
+        with(|b| b.dropped.get())
+    }
+
+    /// Consumes one message from the budget, returning `false` if it is exhausted.
+    pub(super) fn take() -> bool {
+        with(|b| match b.remaining.get() {
+            0 => {
+                b.dropped.set(b.dropped.get().saturating_add(1));
+                false
+            }
+            u32::MAX => true,
+            n => {
+                b.remaining.set(n - 1);
+                true
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_enabled_follows_max_level() {
+        for level in [Level::Error, Level::Warn, Level::Info, Level::Debug] {
+            assert_eq!(
+                level_enabled(level),
+                MAX_LEVEL.is_some_and(|max| level <= max)
+            );
+        }
+    }
+
+    // These tests expect every level to be compiled in.
+    #[cfg(not(any(
+        feature = "log-off",
+        feature = "log-max-error",
+        feature = "log-max-warn",
+        feature = "log-max-info"
+    )))]
+    mod enabled {
+        use super::*;
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::log;
+        use mockall::predicate::{always, eq};
+
+        #[test]
+        fn test_macros_dispatch_on_value_type() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_trace()
+                .with(always(), eq(5), always(), eq(0), always())
+                .times(1)
+                .returning(|_, len, _, _, _| len as i32);
+            mock.expect_trace_num()
+                .with(always(), always(), eq(7))
+                .times(1)
+                .returning(|_, _, _| 0);
+            mock.expect_trace_account()
+                .times(1)
+                .returning(|_, _, _, _| 0);
+            mock.expect_trace()
+                .with(
+                    always(),
+                    always(),
+                    always(),
+                    eq(3),
+                    eq(DataRepr::AsHex as i32),
+                )
+                .times(1)
+                .returning(|_, _, _, _, _| 0);
+            let _guard = setup_mock(mock);
+
+            log::error!("hello");
+            log::warn!("count:", 7u32);
+            log::info!("owner:", AccountID([1; 20]));
+            log::debug!("bytes:", [1u8, 2, 3]);
+        }
+
+        #[test]
+        fn test_limit_drops_excess_messages() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_trace_num().times(2).returning(|_, _, _| 0);
+            let _guard = setup_mock(mock);
+
+            set_limit(2);
+            for i in 0..5 {
+                log::debug!("i:", i);
+            }
+            assert_eq!(dropped(), 3);
+
+            set_limit(u32::MAX);
+            assert_eq!(dropped(), 0);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Exact integer arithmetic on drop and unit counts.
+//!
+//! Proportional amounts such as `amount * numerator / denominator` overflow `u64` as soon as the
+//! product exceeds about 1.8e19, which about 18,000 XRP in drops times a rate in billionths
+//! already does.
+//! These helpers compute the product in `u128`, which cannot overflow for `u64` operands, and
+//! round the quotient explicitly. They are plain Rust and never call the float host functions,
+//! so they cost no host calls and round exactly as documented.
+
+use crate::host::{Error, Result};
+
+/// How [`mul_div_u64`] rounds a quotient that is not exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Toward zero. Never releases or charges more than the exact value.
+    Down,
+    /// Away from zero. Never releases or charges less than the exact value.
+    Up,
+    /// To the nearest integer, with ties going to the even one (banker's rounding), like the
+    /// float host functions' `FLOAT_ROUNDING_MODES_TO_NEAREST`.
+    NearestEven,
+}
+
+/// Computes `value * numerator / denominator` without intermediate overflow.
+///
+/// ```
+/// use xrpl_common_stdlib::math::{Rounding, mul_div_u64};
+///
+/// // 1.5% (15,000,000 billionths) of 100 billion XRP in drops: the product, 1.5e24, would
+/// // overflow u64.
+/// assert!(100_000_000_000_000_000u64.checked_mul(15_000_000).is_none());
+/// let fee = mul_div_u64(100_000_000_000_000_000, 15_000_000, 1_000_000_000, Rounding::Up).unwrap();
+/// assert_eq!(fee, 1_500_000_000_000_000);
+/// ```
+///
+/// # Errors
+///
+/// * `InvalidParams` - If `denominator` is zero or the result does not fit in a `u64`
+pub fn mul_div_u64(
+    value: u64,
+    numerator: u64,
+    denominator: u64,
+    rounding: Rounding,
+) -> Result<u64> {
+    if denominator == 0 {
+        return Result::Err(Error::InvalidParams);
+    }
+    let product = value as u128 * numerator as u128;
+    let denominator = denominator as u128;
+    let quotient = product / denominator;
+    let remainder = product % denominator;
+
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::Up => remainder > 0,
+        Rounding::NearestEven => {
+            remainder * 2 > denominator || (remainder * 2 == denominator && quotient % 2 == 1)
+        }
+    };
+
+    match u64::try_from(quotient + round_up as u128) {
+        core::result::Result::Ok(result) => Result::Ok(result),
+        core::result::Result::Err(_) => Result::Err(Error::InvalidParams),
+    }
+}
+
+/// Returns the share of `total` that `part` out of `whole` represents, rounded down.
+///
+/// `part` is clamped to `whole`, so the result never exceeds `total`, and a zero `whole` counts
+/// as complete. This is the usual shape of a linear vesting schedule, where `part` is the time
+/// elapsed and `whole` the vesting period:
+///
+/// ```
+/// use xrpl_common_stdlib::math::pro_rata;
+///
+/// let total_drops = 5_000_000_000;
+/// assert_eq!(pro_rata(total_drops, 30, 90), 1_666_666_666);
+/// assert_eq!(pro_rata(total_drops, 120, 90), total_drops);
+/// ```
+pub fn pro_rata(total: u64, part: u64, whole: u64) -> u64 {
+    if part >= whole {
+        return total;
+    }
+    // part < whole, so the quotient is below total and always fits.
+    (total as u128 * part as u128 / whole as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::INVALID_PARAMS;
+
+    #[test]
+    fn test_mul_div_exact_result_ignores_rounding() {
+        for rounding in [Rounding::Down, Rounding::Up, Rounding::NearestEven] {
+            assert_eq!(mul_div_u64(u64::MAX, 6, 6, rounding).unwrap(), u64::MAX);
+        }
+    }
+
+    #[test]
+    fn test_mul_div_rounding_modes() {
+        // 10 / 4 = 2.5, 14 / 4 = 3.5, 11 / 4 = 2.75
+        assert_eq!(mul_div_u64(10, 1, 4, Rounding::Down).unwrap(), 2);
+        assert_eq!(mul_div_u64(10, 1, 4, Rounding::Up).unwrap(), 3);
+        assert_eq!(mul_div_u64(10, 1, 4, Rounding::NearestEven).unwrap(), 2);
+        assert_eq!(mul_div_u64(14, 1, 4, Rounding::NearestEven).unwrap(), 4);
+        assert_eq!(mul_div_u64(11, 1, 4, Rounding::NearestEven).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_mul_div_rejects_zero_denominator_and_overflow() {
+        let result = mul_div_u64(1, 1, 0, Rounding::Down);
+        assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+
+        let result = mul_div_u64(u64::MAX, 3, 2, Rounding::Down);
+        assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+
+        // Rounding up can push an otherwise representable quotient past u64::MAX.
+        // 7 * 15811494920322472813 = 6 * u64::MAX + 1
+        let (value, numerator) = (7, 15_811_494_920_322_472_813);
+        assert_eq!(
+            mul_div_u64(value, numerator, 6, Rounding::Down).unwrap(),
+            u64::MAX
+        );
+        let result = mul_div_u64(value, numerator, 6, Rounding::Up);
+        assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_pro_rata_clamps_and_rounds_down() {
+        assert_eq!(pro_rata(100, 1, 3), 33);
+        assert_eq!(pro_rata(100, 0, 3), 0);
+        assert_eq!(pro_rata(100, 3, 3), 100);
+        assert_eq!(pro_rata(100, 4, 3), 100);
+        assert_eq!(pro_rata(100, 0, 0), 100);
+        assert_eq!(pro_rata(u64::MAX, u64::MAX - 1, u64::MAX), u64::MAX - 1);
+    }
+}
@@ -0,0 +1,258 @@
+//! A shared convention for passing commands to a contract in transaction memos.
+//!
+//! A transaction that instructs a contract (e.g. the `EscrowFinish` that releases a Smart
+//! Escrow) carries one memo per command:
+//!
+//! - `MemoType` is the command name: 1 to [`MAX_COMMAND_NAME_LEN`] ASCII letters, digits, `_`
+//!   or `-`. Names are matched case-insensitively, so `release` and `RELEASE` are the same
+//!   command. Memos whose type is anything else (e.g. a MIME type such as `text/plain`) are not
+//!   commands and are skipped.
+//! - `MemoData` holds the arguments, separated by ASCII whitespace. It may be absent.
+//!
+//! [`parse_command`] applies the convention to bytes already read, and [`find_command`] and
+//! [`first_command`] read them from the current transaction's `Memos`:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::memo_protocol::find_command;
+//!
+//! let release = match find_command(b"RELEASE") {
+//!     xrpl_common_stdlib::host::Result::Ok(Some(memo)) => {
+//!         // `RELEASE <recipient-tag>`
+//!         memo.command().arg(0) == Some(&b"42"[..])
+//!     }
+//!     _ => false,
+//! };
+//! # let _ = release;
+//! ```
+
+use crate::current_tx::{array_len, get_nested_field};
+use crate::fields::locator::{Locator, LocatorEntry};
+use crate::host::{Error, Result};
+use crate::sfield;
+use crate::types::blob::{Blob, StandardBlob};
+
+/// The longest command name, in bytes.
+pub const MAX_COMMAND_NAME_LEN: usize = 32;
+
+/// A command parsed from a memo's type and data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command<'a> {
+    /// The command name, as written in `MemoType`.
+    pub name: &'a [u8],
+    /// The arguments from `MemoData`, with surrounding whitespace trimmed.
+    pub args: &'a [u8],
+}
+
+impl<'a> Command<'a> {
+    /// Returns whether this is the command `name`, ignoring ASCII case.
+    pub fn is(&self, name: &[u8]) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    /// Returns an iterator over the whitespace-separated arguments.
+    pub fn args(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.args
+            .split(|b| b.is_ascii_whitespace())
+            .filter(|arg| !arg.is_empty())
+    }
+
+    /// Returns the argument at `index`, or `None` if there are fewer arguments.
+    pub fn arg(&self, index: usize) -> Option<&'a [u8]> {
+        self.args().nth(index)
+    }
+}
+
+/// Parses a memo's `MemoType` and `MemoData` as a [`Command`], or returns `None` if
+/// `memo_type` is not a command name.
+///
+/// ```
+/// use xrpl_common_stdlib::memo_protocol::parse_command;
+///
+/// let command = parse_command(b"Release", b" rPayee 100 ").unwrap();
+/// assert!(command.is(b"RELEASE"));
+/// assert_eq!(command.arg(1), Some(&b"100"[..]));
+/// assert!(parse_command(b"text/plain", b"hello").is_none());
+/// ```
+pub fn parse_command<'a>(memo_type: &'a [u8], memo_data: &'a [u8]) -> Option<Command<'a>> {
+    if !is_command_name(memo_type) {
+        return None;
+    }
+    Some(Command {
+        name: memo_type,
+        args: memo_data.trim_ascii(),
+    })
+}
+
+fn is_command_name(name: &[u8]) -> bool {
+    (1..=MAX_COMMAND_NAME_LEN).contains(&name.len())
+        && name
+            .iter()
+            .all(|&b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+/// A command read from one of the current transaction's memos.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoCommand {
+    /// Position of the memo in `Memos`.
+    pub index: usize,
+    name: Blob<MAX_COMMAND_NAME_LEN>,
+    data: StandardBlob,
+}
+
+impl MemoCommand {
+    /// The parsed command, borrowing from this memo.
+    pub fn command(&self) -> Command<'_> {
+        Command {
+            name: self.name.as_slice(),
+            args: self.data.as_slice().trim_ascii(),
+        }
+    }
+}
+
+/// Returns the first memo of the current transaction that carries the command `name` (ignoring
+/// ASCII case), or `Ok(None)` if there is none.
+///
+/// # Errors
+///
+/// Returns any host error other than a missing field while reading `Memos`.
+pub fn find_command(name: &[u8]) -> Result<Option<MemoCommand>> {
+    scan_commands(|command| command.is(name))
+}
+
+/// Returns the first memo of the current transaction that carries a command, or `Ok(None)` if
+/// there is none.
+///
+/// # Errors
+///
+/// Returns any host error other than a missing field while reading `Memos`.
+pub fn first_command() -> Result<Option<MemoCommand>> {
+    scan_commands(|_| true)
+}
+
+fn scan_commands(mut wanted: impl FnMut(&Command) -> bool) -> Result<Option<MemoCommand>> {
+    let count = match array_len(sfield::Memos) {
+        Result::Ok(count) => count,
+        Result::Err(Error::FieldNotFound) => return Result::Ok(None),
+        Result::Err(e) => return Result::Err(e),
+    };
+    for index in 0..count {
+        let mut locator = Locator::new();
+        let packed = locator
+            .pack(sfield::Memos)
+            .and_then(|_| locator.pack(LocatorEntry::Index(index as i32)))
+            .and_then(|_| locator.pack(sfield::Memo))
+            .and_then(|_| locator.pack(sfield::MemoType));
+        if let Result::Err(e) = packed {
+            return Result::Err(e);
+        }
+        // A type too long to be a command name does not fit the buffer; skip it like any other
+        // non-command memo.
+        let name = match get_nested_field::<Blob<MAX_COMMAND_NAME_LEN>>(&locator) {
+            Result::Ok(name) if is_command_name(name.as_slice()) => name,
+            Result::Ok(_) | Result::Err(Error::FieldNotFound | Error::BufferTooSmall) => continue,
+            Result::Err(e) => return Result::Err(e),
+        };
+        if let Result::Err(e) = locator.repack_last(sfield::MemoData) {
+            return Result::Err(e);
+        }
+        let data = match get_nested_field::<StandardBlob>(&locator) {
+            Result::Ok(data) => data,
+            Result::Err(Error::FieldNotFound) => StandardBlob::new(),
+            Result::Err(e) => return Result::Err(e),
+        };
+        let memo = MemoCommand { index, name, data };
+        if wanted(&memo.command()) {
+            return Result::Ok(Some(memo));
+        }
+    }
+    Result::Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{BUFFER_TOO_SMALL, FIELD_NOT_FOUND};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::eq;
+
+    #[test]
+    fn test_parse_command_names() {
+        assert!(parse_command(b"CANCEL", b"").is_some());
+        assert!(parse_command(b"set-price_2", b"").is_some());
+        assert!(parse_command(b"", b"").is_none());
+        assert!(parse_command(b"two words", b"").is_none());
+        assert!(parse_command(&[b'A'; MAX_COMMAND_NAME_LEN + 1], b"").is_none());
+    }
+
+    #[test]
+    fn test_args_split_on_whitespace() {
+        let command = parse_command(b"pay", b"\trA  1\n2 ").unwrap();
+        assert_eq!(command.args, b"rA  1\n2");
+        assert!(command.args().eq([&b"rA"[..], b"1", b"2"]));
+        assert_eq!(command.arg(3), None);
+        assert_eq!(parse_command(b"pay", b"   ").unwrap().args().count(), 0);
+    }
+
+    /// A memo as `(MemoType, MemoData)`; `None` leaves the field out.
+    type TestMemo = (Option<&'static [u8]>, Option<&'static [u8]>);
+
+    fn mock_memos(memos: &'static [TestMemo]) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_array_len()
+            .with(eq(i32::from(sfield::Memos)))
+            .returning(move |_| memos.len() as i32);
+        mock.expect_get_tx_nested_field()
+            .returning(move |locator, locator_len, out, out_len| {
+                let path = unsafe { core::slice::from_raw_parts(locator, locator_len) };
+                let word =
+                    |i: usize| i32::from_le_bytes(path[i * 4..i * 4 + 4].try_into().unwrap());
+                let (memo_type, memo_data) = memos[word(1) as usize];
+                let value = if word(3) == i32::from(sfield::MemoType) {
+                    memo_type
+                } else {
+                    memo_data
+                };
+                match value {
+                    None => FIELD_NOT_FOUND,
+                    Some(bytes) if bytes.len() > out_len => BUFFER_TOO_SMALL,
+                    Some(bytes) => {
+                        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len()) };
+                        bytes.len() as i32
+                    }
+                }
+            });
+        mock
+    }
+
+    #[test]
+    fn test_find_command_skips_other_memos() {
+        let _guard = setup_mock(mock_memos(&[
+            (Some(b"text/plain"), Some(b"hello")),
+            (None, Some(b"no type")),
+            (Some(&[b'X'; 40]), None),
+            (Some(b"Cancel"), None),
+            (Some(b"RELEASE"), Some(b"rDest 5")),
+        ]));
+
+        let release = find_command(b"release").unwrap().unwrap();
+        assert_eq!(release.index, 4);
+        assert_eq!(release.command().arg(1), Some(&b"5"[..]));
+
+        let first = first_command().unwrap().unwrap();
+        assert!(first.command().is(b"CANCEL"));
+        assert_eq!(first.command().args, b"");
+
+        assert_eq!(find_command(b"PAUSE").unwrap(), None);
+    }
+
+    #[test]
+    fn test_no_memos() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_array_len()
+            .returning(|_| FIELD_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(first_command().unwrap(), None);
+    }
+}
@@ -0,0 +1,79 @@
+//! Contract metadata embedded by [`contract_metadata!`](crate::contract_metadata).
+//!
+//! The macro writes a short description of the contract (name, version, and the host ABI version
+//! it targets) to a custom section named [`SECTION_NAME`]. Custom sections are ignored by the
+//! host, cost no gas, and survive deployment, so tools can identify the finish function behind a
+//! deployed escrow from its bytecode alone.
+//!
+//! The payload is UTF-8, one `key=value` line per field:
+//!
+//! ```text
+//! name=vesting_escrow
+//! version=1.2.0
+//! abi=1
+//! ```
+//!
+//! Unknown keys are ignored, so fields can be added later without breaking older readers.
+
+/// Name of the custom WASM section holding the metadata.
+pub const SECTION_NAME: &str = "xrpl_metadata";
+
+/// A contract's self-description, borrowed from the section payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractMetadata<'a> {
+    /// Contract name.
+    pub name: &'a str,
+    /// Contract version, in whatever scheme the author uses.
+    pub version: &'a str,
+    /// Version of the host ABI the contract was written against.
+    pub abi: u32,
+}
+
+impl<'a> ContractMetadata<'a> {
+    /// Parses a section payload, or returns `None` if it is not UTF-8 or lacks a field.
+    pub fn parse(payload: &'a [u8]) -> Option<Self> {
+        let text = core::str::from_utf8(payload).ok()?;
+        let mut name = None;
+        let mut version = None;
+        let mut abi = None;
+        for line in text.lines() {
+            match line.split_once('=') {
+                Some(("name", value)) => name = Some(value),
+                Some(("version", value)) => version = Some(value),
+                Some(("abi", value)) => abi = Some(value.parse().ok()?),
+                _ => {}
+            }
+        }
+        Some(ContractMetadata {
+            name: name?,
+            version: version?,
+            abi: abi?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reads_every_field() {
+        let metadata =
+            ContractMetadata::parse(b"name=escrow\nversion=1.2.0\nabi=1\nauthor=x\n").unwrap();
+        assert_eq!(
+            metadata,
+            ContractMetadata {
+                name: "escrow",
+                version: "1.2.0",
+                abi: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_incomplete_payloads() {
+        assert_eq!(ContractMetadata::parse(b"name=escrow\nversion=1\n"), None);
+        assert_eq!(ContractMetadata::parse(b"name=a\nversion=1\nabi=x\n"), None);
+        assert_eq!(ContractMetadata::parse(&[0xFF]), None);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::host::Result;
+use crate::keylets::amm_keylet;
+use crate::objects::slot::Slot;
+use crate::objects::traits::{AmmFields, LedgerObjectCommonFields};
+use crate::types::issue::Issue;
+use crate::types::uint::Hash256;
+
+/// A cached `AMM` ledger object: an automated market maker's pool of two assets.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Amm {
+    pub slot_num: i32,
+}
+
+impl Amm {
+    /// Caches the AMM for the pair `asset` and `asset2` (in either order) in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if no AMM exists for the pair.
+    pub fn load(asset: &Issue, asset2: &Issue) -> Result<Self> {
+        amm_keylet(asset, asset2)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Amm {
+                slot_num: slot.num(),
+            })
+    }
+
+    /// Caches the AMM whose ledger index is `amm_id`, as found in its pseudo-account's `AMMID`,
+    /// in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the AMM has been deleted.
+    pub fn load_by_id(amm_id: &Hash256) -> Result<Self> {
+        Slot::cache(amm_id.as_bytes()).map(|slot| Amm {
+            slot_num: slot.num(),
+        })
+    }
+}
+
+impl LedgerObjectCommonFields for Amm {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl AmmFields for Amm {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::account_root::AccountRoot;
+    use crate::objects::traits::AccountFields;
+    use crate::sfield;
+    use mockall::predicate::{always, eq};
+
+    fn mock_amm_id(amm_id: Option<[u8; 32]>) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(1), eq(i32::from(sfield::AMMID)), always(), eq(32))
+            .times(1)
+            .returning(move |_, _, ptr, _| match amm_id {
+                Some(id) => {
+                    unsafe { core::ptr::copy_nonoverlapping(id.as_ptr(), ptr, 32) };
+                    32
+                }
+                None => FIELD_NOT_FOUND,
+            });
+        mock
+    }
+
+    #[test]
+    fn test_account_amm_caches_the_ammid() {
+        let mut mock = mock_amm_id(Some([0xA3; 32]));
+        mock.expect_cache_ledger_obj()
+            .withf(|keylet, len, cache_num| {
+                *len == 32
+                    && *cache_num == 0
+                    && unsafe { core::slice::from_raw_parts(*keylet, 32) } == [0xA3; 32]
+            })
+            .times(1)
+            .returning(|_, _, _| 4);
+        mock.expect_get_ledger_obj_field()
+            .with(eq(4), eq(i32::from(sfield::TradingFee)), always(), eq(2))
+            .times(1)
+            .returning(|_, _, _, _| FIELD_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let amm = AccountRoot { slot_num: 1 }.amm().unwrap().unwrap();
+        assert_eq!(amm, Amm { slot_num: 4 });
+        assert_eq!(amm.get_trading_fee().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_account_amm_is_none_for_ordinary_accounts() {
+        let _guard = setup_mock(mock_amm_id(None));
+        assert_eq!(AccountRoot { slot_num: 1 }.amm().unwrap(), None);
+    }
+}
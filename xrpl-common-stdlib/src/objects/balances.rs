@@ -0,0 +1,292 @@
+//! How much of an asset an account holds.
+//!
+//! Each kind of asset keeps balances in a different ledger object: XRP in the account's
+//! `AccountRoot`, IOUs in the `RippleState` trust line between holder and issuer, and MPTs in the
+//! holder's `MPToken`. [`balance_of`] reads whichever one the asset calls for:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::objects::balances::balance_of;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//! use xrpl_common_stdlib::types::amount::Amount;
+//! use xrpl_common_stdlib::types::issue::{Issue, XrpIssue};
+//!
+//! let account = AccountID([0x2B; 20]);
+//! if let xrpl_common_stdlib::host::Result::Ok(Amount::XRP { num_drops }) =
+//!     balance_of(&account, &Issue::XRP(XrpIssue {}))
+//! {
+//!     # let _ = num_drops;
+//! }
+//! ```
+//!
+//! The object is read through [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT), so a query
+//! consumes no cache slots.
+
+use crate::host::{Error, FLOAT_ROUNDING_MODES_TO_NEAREST, Result};
+use crate::keylets::{account_keylet, line_keylet, mptoken_keylet};
+use crate::objects::ledger_object;
+use crate::objects::slot::cache_scratch;
+use crate::sfield;
+use crate::types::account_id::AccountID;
+use crate::types::amount::Amount;
+use crate::types::issue::{IouIssue, Issue};
+use crate::types::mpt_id::MptId;
+use crate::types::opaque_float::{FLOAT_NEGATIVE_ONE, FLOAT_ZERO, OpaqueFloat};
+
+/// Returns how much of `asset` `account` holds, as an amount of that asset.
+///
+/// * XRP: the account's whole `Balance`, including the part locked up as reserve.
+/// * IOU: the balance of the trust line with the issuer, from `account`'s side. It is negative if
+///   the issuer holds tokens issued by `account` on that line. The returned amount's issuer is the
+///   asset's issuer, so it is [`same_asset`](Amount::same_asset) as `asset`.
+/// * MPT: the `MPTAmount` of the account's `MPToken`.
+///
+/// An account, trust line or `MPToken` that does not exist holds zero. Freezes and locks are not
+/// taken into account; see [`mpt::holder_is_authorized`](crate::objects::mpt::holder_is_authorized)
+/// for MPTs.
+///
+/// # Errors
+///
+/// * `InvalidParams` - If `account` is the issuer of the IOU or MPT, which has no balance of it
+/// * Any error from computing keylets or reading the objects
+pub fn balance_of(account: &AccountID, asset: &Issue) -> Result<Amount> {
+    match asset {
+        Issue::XRP(_) => xrp_balance(account),
+        Issue::IOU(iou) => iou_balance(account, iou),
+        Issue::MPT(mpt) => mpt_balance(account, mpt.mpt_id()),
+    }
+}
+
+fn xrp_balance(account: &AccountID) -> Result<Amount> {
+    match account_keylet(account)
+        .and_then(|keylet| cache_scratch(&keylet))
+        .and_then(|slot| ledger_object::get_field(slot.num(), sfield::Balance))
+    {
+        Result::Err(Error::LedgerObjNotFound) => Result::Ok(Amount::XRP { num_drops: 0 }),
+        other => other,
+    }
+}
+
+fn iou_balance(account: &AccountID, iou: &IouIssue) -> Result<Amount> {
+    let issuer = iou.issuer();
+    let currency = iou.currency();
+    if account == &issuer {
+        return Result::Err(Error::InvalidParams);
+    }
+    let line_balance = match line_keylet(account, &issuer, &currency)
+        .and_then(|keylet| cache_scratch(&keylet))
+        .and_then(|slot| ledger_object::get_field(slot.num(), sfield::Balance))
+    {
+        // The stored balance is an IOU amount whose issuer is a placeholder.
+        Result::Ok(Amount::IOU { amount, .. }) => amount,
+        Result::Ok(_) => return Result::Err(Error::InvalidDecoding),
+        Result::Err(Error::LedgerObjNotFound) => OpaqueFloat(FLOAT_ZERO),
+        Result::Err(e) => return Result::Err(e),
+    };
+    // `Balance` is stored from the side of the low account, the one with the smaller ID.
+    let amount = if account < &issuer {
+        line_balance
+    } else {
+        match line_balance.multiply(
+            &OpaqueFloat(FLOAT_NEGATIVE_ONE),
+            FLOAT_ROUNDING_MODES_TO_NEAREST,
+        ) {
+            Result::Ok(negated) => negated,
+            Result::Err(e) => return Result::Err(e),
+        }
+    };
+    Result::Ok(Amount::IOU {
+        amount,
+        issuer,
+        currency,
+    })
+}
+
+fn mpt_balance(account: &AccountID, mpt_id: MptId) -> Result<Amount> {
+    if account == &mpt_id.get_issuer() {
+        return Result::Err(Error::InvalidParams);
+    }
+    // `MPTAmount` is omitted while zero.
+    let num_units = match mptoken_keylet(&mpt_id, account)
+        .and_then(|keylet| cache_scratch(&keylet))
+        .and_then(|slot| ledger_object::get_field_optional(slot.num(), sfield::MPTAmount))
+    {
+        Result::Ok(amount) => amount.unwrap_or(0),
+        Result::Err(Error::LedgerObjNotFound) => 0,
+        Result::Err(e) => return Result::Err(e),
+    };
+    Result::Ok(Amount::MPT {
+        num_units,
+        is_positive: true,
+        mpt_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{FIELD_NOT_FOUND, LEDGER_OBJ_NOT_FOUND};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::slot::SCRATCH_SLOT;
+    use crate::types::currency::Currency;
+    use crate::types::issue::{MptIssue, XrpIssue};
+    use crate::types::opaque_float::FLOAT_ONE;
+    use mockall::predicate::{always, eq};
+
+    const LOW: AccountID = AccountID([0x11; 20]);
+    const HIGH: AccountID = AccountID([0x99; 20]);
+
+    fn write(out: *mut u8, bytes: &[u8]) -> i32 {
+        unsafe { out.copy_from_nonoverlapping(bytes.as_ptr(), bytes.len()) };
+        bytes.len() as i32
+    }
+
+    /// A mock whose keylet calls succeed and whose object is either cached or missing.
+    fn mock_object(found: bool) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_account_keylet()
+            .returning(|_, _, out, _| write(out, &[0xAC; 32]));
+        mock.expect_line_keylet()
+            .returning(|_, _, _, _, _, _, out, _| write(out, &[0x11; 32]));
+        mock.expect_mptoken_keylet()
+            .returning(|_, _, _, _, out, _| write(out, &[0x70; 32]));
+        mock.expect_cache_ledger_obj()
+            .with(always(), eq(32), eq(SCRATCH_SLOT))
+            .times(1)
+            .returning(move |_, _, _| {
+                if found {
+                    SCRATCH_SLOT
+                } else {
+                    LEDGER_OBJ_NOT_FOUND
+                }
+            });
+        mock
+    }
+
+    fn usd_code() -> Currency {
+        let mut code = [0u8; 20];
+        code[12..15].copy_from_slice(b"USD");
+        Currency::from(code)
+    }
+
+    fn usd(issuer: AccountID) -> Issue {
+        Issue::IOU(IouIssue::new(issuer, usd_code()))
+    }
+
+    #[test]
+    fn test_xrp_balance_reads_account_root() {
+        let mut mock = mock_object(true);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::Balance)),
+                always(),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, out, _| write(out, &(0x4000_0000_0000_0000u64 | 250).to_be_bytes()));
+        let _guard = setup_mock(mock);
+
+        let balance = balance_of(&LOW, &Issue::XRP(XrpIssue {})).unwrap();
+        assert_eq!(balance, Amount::XRP { num_drops: 250 });
+    }
+
+    #[test]
+    fn test_missing_objects_hold_zero() {
+        {
+            let _guard = setup_mock(mock_object(false));
+            let balance = balance_of(&LOW, &Issue::XRP(XrpIssue {})).unwrap();
+            assert_eq!(balance, Amount::XRP { num_drops: 0 });
+        }
+        {
+            let _guard = setup_mock(mock_object(false));
+            let balance = balance_of(&LOW, &usd(HIGH)).unwrap();
+            assert!(balance.same_asset(&Amount::IOU {
+                amount: OpaqueFloat(FLOAT_ZERO),
+                issuer: HIGH,
+                currency: usd_code(),
+            }));
+        }
+        {
+            let mpt_id = MptId::new(4, HIGH);
+            let mut mock = mock_object(true);
+            mock.expect_get_ledger_obj_field()
+                .with(
+                    eq(SCRATCH_SLOT),
+                    eq(i32::from(sfield::MPTAmount)),
+                    always(),
+                    eq(8),
+                )
+                .times(1)
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            let _guard = setup_mock(mock);
+            let balance = balance_of(&LOW, &Issue::MPT(MptIssue::new(mpt_id))).unwrap();
+            assert_eq!(
+                balance,
+                Amount::MPT {
+                    num_units: 0,
+                    is_positive: true,
+                    mpt_id,
+                }
+            );
+        }
+    }
+
+    /// A trust line whose stored `Balance` is +1, i.e. the low account holds one token.
+    fn mock_line() -> MockHostBindings {
+        let mut mock = mock_object(true);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::Balance)),
+                always(),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, out, _| {
+                let mut bytes = [0u8; 48];
+                bytes[..8].copy_from_slice(&FLOAT_ONE);
+                write(out, &bytes)
+            });
+        mock
+    }
+
+    #[test]
+    fn test_iou_balance_is_taken_from_the_holders_side() {
+        {
+            let _guard = setup_mock(mock_line());
+            match balance_of(&LOW, &usd(HIGH)).unwrap() {
+                Amount::IOU { amount, issuer, .. } => {
+                    assert_eq!(amount, OpaqueFloat(FLOAT_ONE));
+                    assert_eq!(issuer, HIGH);
+                }
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+        {
+            let mut mock = mock_line();
+            mock.expect_float_multiply()
+                .withf(|_, _, other, _, _, _, _| unsafe {
+                    core::slice::from_raw_parts(*other, 8) == FLOAT_NEGATIVE_ONE
+                })
+                .times(1)
+                .returning(|_, _, _, _, out, _, _| write(out, &FLOAT_NEGATIVE_ONE));
+            let _guard = setup_mock(mock);
+            match balance_of(&HIGH, &usd(LOW)).unwrap() {
+                Amount::IOU { amount, issuer, .. } => {
+                    assert_eq!(amount, OpaqueFloat(FLOAT_NEGATIVE_ONE));
+                    assert_eq!(issuer, LOW);
+                }
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_issuer_has_no_balance_of_its_own_asset() {
+        let result = balance_of(&HIGH, &usd(HIGH));
+        assert_eq!(result.err().unwrap().code(), Error::InvalidParams.code());
+        let result = balance_of(&HIGH, &Issue::MPT(MptIssue::new(MptId::new(1, HIGH))));
+        assert_eq!(result.err().unwrap().code(), Error::InvalidParams.code());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::host::Result;
+use crate::keylets::check_keylet;
+use crate::objects::slot::Slot;
+use crate::objects::traits::{CheckFields, LedgerObjectCommonFields};
+use crate::types::account_id::AccountID;
+
+/// A cached `Check` ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Check {
+    pub slot_num: i32,
+}
+
+impl Check {
+    /// Caches the check `owner` created with sequence number `seq` in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the check has been cashed, cancelled or never existed.
+    pub fn load(owner: &AccountID, seq: u32) -> Result<Self> {
+        check_keylet(owner, seq)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Check {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for Check {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl CheckFields for Check {}
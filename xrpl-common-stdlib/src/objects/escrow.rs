@@ -0,0 +1,510 @@
+//! Reading escrows without caring where they come from.
+//!
+//! A Smart Escrow sees two kinds of escrow: the one it is running on, read through the
+//! current-object host functions, and others it has cached in a slot, such as the counterpart of
+//! an atomic swap. [`EscrowReadFields`] covers both.
+
+use crate::host::{Error, FLOAT_ROUNDING_MODES_UPWARD, Result, Result::Err, Result::Ok};
+use crate::keylets::account_keylet;
+use crate::objects::ledger_object;
+use crate::objects::slot::cache_scratch;
+use crate::objects::traits::EscrowFields;
+use crate::sfield;
+use crate::types::account_id::AccountID;
+use crate::types::amount::Amount;
+use crate::types::blob::{ConditionBlob, WasmBlob};
+use crate::types::contract_data::ContractData;
+use crate::types::transfer_rate::TransferRate;
+use crate::types::uint::Hash256;
+
+/// Read access to the fields of an Escrow, whether it is the escrow currently executing or one
+/// cached in a slot.
+///
+/// [`EscrowFields`] (cached escrows) and `CurrentEscrowFields` in `xrpl-escrow-stdlib` (the current
+/// escrow) read from different host functions, so they are separate traits. This trait has the
+/// read methods of both and is implemented for every `EscrowFields` type and for `CurrentEscrow`,
+/// so a check that applies to either side, such as comparing the two legs of an atomic swap, can
+/// be written once:
+///
+/// ```no_run
+/// use xrpl_common_stdlib::host::Result;
+/// use xrpl_common_stdlib::objects::escrow::EscrowReadFields;
+/// use xrpl_common_stdlib::types::account_id::AccountID;
+///
+/// fn parties<E: EscrowReadFields>(escrow: &E) -> Result<(AccountID, AccountID)> {
+///     let account = match escrow.get_account() {
+///         Result::Ok(account) => account,
+///         Result::Err(e) => return Result::Err(e),
+///     };
+///     escrow.get_destination().map(|destination| (account, destination))
+/// }
+/// ```
+///
+/// The methods have the same names as those of the per-source traits, so bring only one of them
+/// into scope where a concrete escrow type is used.
+pub trait EscrowReadFields {
+    /// The address of the owner (sender) of this escrow.
+    fn get_account(&self) -> Result<AccountID>;
+
+    /// The amount held in the escrow (XRP, IOU or MPT).
+    fn get_amount(&self) -> Result<Amount>;
+
+    /// The time, in seconds since the Ripple Epoch, after which the escrow can be canceled.
+    fn get_cancel_after(&self) -> Result<Option<u32>>;
+
+    /// The PREIMAGE-SHA-256 crypto-condition the finishing transaction must fulfill, if any.
+    fn get_condition(&self) -> Result<Option<ConditionBlob>>;
+
+    /// The destination address where the escrowed amount is paid if the escrow is finished.
+    fn get_destination(&self) -> Result<AccountID>;
+
+    /// The page of the destination's owner directory that links to this escrow.
+    fn get_destination_node(&self) -> Result<Option<u64>>;
+
+    /// The tag specifying a hosted recipient at the destination address.
+    fn get_destination_tag(&self) -> Result<Option<u32>>;
+
+    /// The time, in seconds since the Ripple Epoch, after which the escrow can be finished.
+    fn get_finish_after(&self) -> Result<Option<u32>>;
+
+    /// The page of the owner's directory that links to this escrow.
+    fn get_owner_node(&self) -> Result<u64>;
+
+    /// The identifying hash of the transaction that most recently modified this escrow.
+    fn get_previous_txn_id(&self) -> Result<Hash256>;
+
+    /// The index of the ledger that contains the transaction that most recently modified this
+    /// escrow.
+    fn get_previous_txn_lgr_seq(&self) -> Result<u32>;
+
+    /// The tag specifying a hosted sender at the owner's address.
+    fn get_source_tag(&self) -> Result<Option<u32>>;
+
+    /// The issuer's transfer rate locked in when the escrow was created, for IOU escrows.
+    fn get_transfer_rate(&self) -> Result<Option<u32>>;
+
+    /// The WebAssembly module that runs when the escrow is finished.
+    fn get_finish_function(&self) -> Result<Option<WasmBlob>>;
+
+    /// The contract data stored on the escrow.
+    fn get_data(&self) -> Result<ContractData>;
+
+    /// Checks that the escrow carries the destination tag `expected`.
+    ///
+    /// # Errors
+    ///
+    /// * `FieldNotFound` - The escrow has no destination tag
+    /// * `InvalidParams` - The escrow has a different destination tag
+    /// * Any error returned while reading `DestinationTag`
+    fn require_destination_tag(&self, expected: u32) -> Result<()> {
+        match self.get_destination_tag() {
+            Ok(Some(tag)) if tag == expected => Ok(()),
+            Ok(Some(_)) => Err(Error::InvalidParams),
+            Ok(None) => Err(Error::FieldNotFound),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Whether the escrow can be canceled as of `now`, the parent ledger's close time in seconds
+    /// since the Ripple Epoch.
+    ///
+    /// Matches the ledger's `EscrowCancel` check: the escrow must have a `CancelAfter`, and the
+    /// close time must be strictly later than it. An escrow without `CancelAfter` can never be
+    /// canceled.
+    fn can_cancel(&self, now: u32) -> Result<bool> {
+        self.get_cancel_after()
+            .map(|cancel_after| cancel_after.is_some_and(|cancel_after| now > cancel_after))
+    }
+
+    /// Checks that the escrow can be canceled as of `now`; see [`can_cancel`](Self::can_cancel).
+    ///
+    /// # Errors
+    ///
+    /// * `FieldNotFound` - The escrow has no `CancelAfter`
+    /// * `InvalidParams` - `CancelAfter` has not passed yet
+    /// * Any error returned while reading `CancelAfter`
+    fn require_cancelable(&self, now: u32) -> Result<()> {
+        match self.get_cancel_after() {
+            Ok(Some(cancel_after)) if now > cancel_after => Ok(()),
+            Ok(Some(_)) => Err(Error::InvalidParams),
+            Ok(None) => Err(Error::FieldNotFound),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The amount the destination receives if the escrow is finished, after the issuer's transfer
+    /// fee.
+    ///
+    /// For an IOU escrow this is `Amount / (TransferRate / 1_000_000_000)`, computed with the float
+    /// host functions and rounded up as the ledger does. Like `EscrowFinish`, it uses the lower of
+    /// the rate locked in the escrow at creation and the rate currently set on the issuer's
+    /// account, so an issuer can lower the fee on an existing escrow but not raise it. No fee is
+    /// charged when the issuer is the owner or the destination, or when the escrow locked no
+    /// `TransferRate`. XRP escrows are returned unchanged.
+    ///
+    /// The issuer's account is read through
+    /// [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT), so this consumes no cache slots.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - The escrow holds an MPT, whose fee comes from the issuance's
+    ///   `TransferFee` rather than a `TransferRate`, or a rate is out of range
+    /// * `LedgerObjNotFound` - The issuer's account does not exist
+    /// * Any error returned while reading the fields or by the float host functions
+    fn deliverable_amount(&self) -> Result<Amount> {
+        let (amount, issuer, currency) = match self.get_amount() {
+            Ok(xrp @ Amount::XRP { .. }) => return Ok(xrp),
+            Ok(Amount::MPT { .. }) => return Err(Error::InvalidParams),
+            Ok(Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            }) => (amount, issuer, currency),
+            Err(e) => return Err(e),
+        };
+        let unchanged = Amount::IOU {
+            amount,
+            issuer,
+            currency,
+        };
+        let owner = match self.get_account() {
+            Ok(owner) => owner,
+            Err(e) => return Err(e),
+        };
+        let destination = match self.get_destination() {
+            Ok(destination) => destination,
+            Err(e) => return Err(e),
+        };
+        if owner == issuer || destination == issuer {
+            return Ok(unchanged);
+        }
+
+        let locked = match self.get_transfer_rate().and_then(TransferRate::from_field) {
+            Ok(rate) if rate.is_parity() => return Ok(unchanged),
+            Ok(rate) => rate,
+            Err(e) => return Err(e),
+        };
+        let rate = match account_keylet(&issuer)
+            .and_then(|keylet| cache_scratch(&keylet))
+            .and_then(|slot| ledger_object::get_field_optional(slot.num(), sfield::TransferRate))
+            .and_then(TransferRate::from_field)
+        {
+            Ok(current) if current.is_parity() => return Ok(unchanged),
+            Ok(current) => current.min(locked),
+            Err(e) => return Err(e),
+        };
+        rate.as_multiplier()
+            .and_then(|rate| amount.divide(&rate, FLOAT_ROUNDING_MODES_UPWARD))
+            .map(|amount| Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            })
+    }
+}
+
+impl<T: EscrowFields> EscrowReadFields for T {
+    fn get_account(&self) -> Result<AccountID> {
+        EscrowFields::get_account(self)
+    }
+
+    fn get_amount(&self) -> Result<Amount> {
+        EscrowFields::get_amount(self)
+    }
+
+    fn get_cancel_after(&self) -> Result<Option<u32>> {
+        EscrowFields::get_cancel_after(self)
+    }
+
+    fn get_condition(&self) -> Result<Option<ConditionBlob>> {
+        EscrowFields::get_condition(self)
+    }
+
+    fn get_destination(&self) -> Result<AccountID> {
+        EscrowFields::get_destination(self)
+    }
+
+    fn get_destination_node(&self) -> Result<Option<u64>> {
+        EscrowFields::get_destination_node(self)
+    }
+
+    fn get_destination_tag(&self) -> Result<Option<u32>> {
+        EscrowFields::get_destination_tag(self)
+    }
+
+    fn get_finish_after(&self) -> Result<Option<u32>> {
+        EscrowFields::get_finish_after(self)
+    }
+
+    fn get_owner_node(&self) -> Result<u64> {
+        EscrowFields::get_owner_node(self)
+    }
+
+    fn get_previous_txn_id(&self) -> Result<Hash256> {
+        EscrowFields::get_previous_txn_id(self)
+    }
+
+    fn get_previous_txn_lgr_seq(&self) -> Result<u32> {
+        EscrowFields::get_previous_txn_lgr_seq(self)
+    }
+
+    fn get_source_tag(&self) -> Result<Option<u32>> {
+        EscrowFields::get_source_tag(self)
+    }
+
+    fn get_transfer_rate(&self) -> Result<Option<u32>> {
+        EscrowFields::get_transfer_rate(self)
+    }
+
+    fn get_finish_function(&self) -> Result<Option<WasmBlob>> {
+        EscrowFields::get_finish_function(self)
+    }
+
+    fn get_data(&self) -> Result<ContractData> {
+        EscrowFields::get_data(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::slot::SCRATCH_SLOT;
+    use crate::objects::traits::LedgerObjectCommonFields;
+    use crate::types::opaque_float::{FLOAT_ONE, OpaqueFloat};
+    use mockall::predicate::{always, eq};
+
+    struct TestEscrow;
+    impl LedgerObjectCommonFields for TestEscrow {
+        fn get_slot_num(&self) -> i32 {
+            5
+        }
+    }
+    impl EscrowFields for TestEscrow {}
+
+    fn read_through<E: EscrowReadFields>(escrow: &E) -> (Hash256, Result<()>) {
+        (
+            escrow.get_previous_txn_id().unwrap(),
+            escrow.require_destination_tag(42),
+        )
+    }
+
+    #[test]
+    fn test_escrow_fields_types_get_shared_helpers() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(sfield::PreviousTxnID), always(), eq(32))
+            .times(1)
+            .returning(|_, _, ptr, _| {
+                unsafe { core::ptr::write_bytes(ptr, 0xAB, 32) };
+                32
+            });
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(sfield::DestinationTag), always(), eq(4))
+            .times(1)
+            .returning(|_, _, _, _| FIELD_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let (previous, tag_check) = read_through(&TestEscrow);
+        assert_eq!(previous, Hash256::from([0xAB; 32]));
+        assert_eq!(tag_check.err().unwrap().code(), FIELD_NOT_FOUND);
+    }
+
+    fn mock_cancel_after(cancel_after: Option<u32>) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(sfield::CancelAfter), always(), eq(4))
+            .returning(move |_, _, ptr, _| match cancel_after {
+                Some(time) => {
+                    unsafe { core::ptr::copy_nonoverlapping(time.to_le_bytes().as_ptr(), ptr, 4) };
+                    4
+                }
+                None => FIELD_NOT_FOUND,
+            });
+        mock
+    }
+
+    #[test]
+    fn test_can_cancel_only_after_cancel_after() {
+        let _guard = setup_mock(mock_cancel_after(Some(1_000)));
+        assert!(!TestEscrow.can_cancel(999).unwrap());
+        assert!(!TestEscrow.can_cancel(1_000).unwrap());
+        assert!(TestEscrow.can_cancel(1_001).unwrap());
+        assert_eq!(
+            TestEscrow.require_cancelable(1_000).err().unwrap().code(),
+            Error::InvalidParams.code()
+        );
+        assert!(TestEscrow.require_cancelable(1_001).is_ok());
+    }
+
+    #[test]
+    fn test_escrow_without_cancel_after_is_never_cancelable() {
+        let _guard = setup_mock(mock_cancel_after(None));
+        assert!(!TestEscrow.can_cancel(u32::MAX).unwrap());
+        assert_eq!(
+            TestEscrow
+                .require_cancelable(u32::MAX)
+                .err()
+                .unwrap()
+                .code(),
+            FIELD_NOT_FOUND
+        );
+    }
+
+    const OWNER: AccountID = AccountID([0x11; 20]);
+    const ISSUER: AccountID = AccountID([0x22; 20]);
+    const DESTINATION: AccountID = AccountID([0x33; 20]);
+
+    /// Mocks an escrow of one IOU issued by [`ISSUER`], paid to `destination`.
+    fn mock_iou_escrow(destination: AccountID) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(i32::from(sfield::Amount)), always(), eq(48))
+            .times(1)
+            .returning(|_, _, ptr, _| {
+                let mut bytes = [0u8; 48];
+                bytes[..8].copy_from_slice(&FLOAT_ONE);
+                bytes[28..].copy_from_slice(&ISSUER.0);
+                unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, 48) };
+                48
+            });
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(i32::from(sfield::Account)), always(), eq(20))
+            .times(1)
+            .returning(|_, _, ptr, _| {
+                unsafe { core::ptr::copy_nonoverlapping(OWNER.0.as_ptr(), ptr, 20) };
+                20
+            });
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(i32::from(sfield::Destination)), always(), eq(20))
+            .times(1)
+            .returning(move |_, _, ptr, _| {
+                unsafe { core::ptr::copy_nonoverlapping(destination.0.as_ptr(), ptr, 20) };
+                20
+            });
+        mock
+    }
+
+    /// Mocks the `TransferRate` the escrow locked in at creation.
+    fn expect_locked_rate(mock: &mut MockHostBindings, locked: Option<u32>) {
+        mock.expect_get_ledger_obj_field()
+            .with(eq(5), eq(i32::from(sfield::TransferRate)), always(), eq(4))
+            .times(1)
+            .returning(move |_, _, ptr, _| match locked {
+                Some(rate) => {
+                    unsafe { core::ptr::copy_nonoverlapping(rate.to_le_bytes().as_ptr(), ptr, 4) };
+                    4
+                }
+                None => FIELD_NOT_FOUND,
+            });
+    }
+
+    /// Mocks the `TransferRate` currently set on [`ISSUER`]'s account, and the division of the
+    /// escrowed amount by `expected_rate`.
+    fn expect_issuer_rate(mock: &mut MockHostBindings, current: u32, expected_rate: u32) {
+        mock.expect_account_keylet()
+            .withf(|account, len, _, _| {
+                *len == 20 && unsafe { core::slice::from_raw_parts(*account, 20) == ISSUER.0 }
+            })
+            .times(1)
+            .returning(|_, _, out, _| {
+                unsafe { core::ptr::write_bytes(out, 0xAC, 32) };
+                32
+            });
+        mock.expect_cache_ledger_obj()
+            .with(always(), eq(32), eq(SCRATCH_SLOT))
+            .times(1)
+            .returning(|_, _, slot| slot);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::TransferRate)),
+                always(),
+                eq(4),
+            )
+            .times(1)
+            .returning(move |_, _, ptr, _| {
+                let rate = current.to_le_bytes();
+                unsafe { core::ptr::copy_nonoverlapping(rate.as_ptr(), ptr, 4) };
+                4
+            });
+        mock.expect_float_from_mant_exp()
+            .with(
+                eq(i64::from(expected_rate)),
+                eq(-9),
+                always(),
+                eq(8),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, out, _, _| {
+                unsafe { core::ptr::write_bytes(out, 0x0A, 8) };
+                8
+            });
+        mock.expect_float_divide()
+            .withf(|a, _, b, _, _, _, mode| {
+                *mode == FLOAT_ROUNDING_MODES_UPWARD
+                    && unsafe { core::slice::from_raw_parts(*a, 8) == FLOAT_ONE }
+                    && unsafe { core::slice::from_raw_parts(*b, 8) == [0x0A; 8] }
+            })
+            .times(1)
+            .returning(|_, _, _, _, out, _, _| {
+                unsafe { core::ptr::write_bytes(out, 0x0D, 8) };
+                8
+            });
+    }
+
+    fn assert_divided(amount: Amount) {
+        match amount {
+            Amount::IOU { amount, issuer, .. } => {
+                assert_eq!(amount, OpaqueFloat([0x0D; 8]));
+                assert_eq!(issuer, ISSUER);
+            }
+            other => panic!("expected an IOU, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_deliverable_amount_uses_a_lowered_issuer_rate() {
+        let mut mock = mock_iou_escrow(DESTINATION);
+        expect_locked_rate(&mut mock, Some(1_500_000_000));
+        expect_issuer_rate(&mut mock, 1_250_000_000, 1_250_000_000);
+        let _guard = setup_mock(mock);
+
+        assert_divided(TestEscrow.deliverable_amount().unwrap());
+    }
+
+    #[test]
+    fn test_deliverable_amount_keeps_the_locked_rate_when_issuer_raises_it() {
+        let mut mock = mock_iou_escrow(DESTINATION);
+        expect_locked_rate(&mut mock, Some(1_100_000_000));
+        expect_issuer_rate(&mut mock, 1_250_000_000, 1_100_000_000);
+        let _guard = setup_mock(mock);
+
+        assert_divided(TestEscrow.deliverable_amount().unwrap());
+    }
+
+    #[test]
+    fn test_deliverable_amount_charges_no_fee_without_locked_rate() {
+        let mut mock = mock_iou_escrow(DESTINATION);
+        expect_locked_rate(&mut mock, None);
+        let _guard = setup_mock(mock);
+
+        match TestEscrow.deliverable_amount().unwrap() {
+            Amount::IOU { amount, .. } => assert_eq!(amount, OpaqueFloat(FLOAT_ONE)),
+            other => panic!("expected an IOU, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_deliverable_amount_charges_no_fee_to_issuer() {
+        let _guard = setup_mock(mock_iou_escrow(ISSUER));
+
+        match TestEscrow.deliverable_amount().unwrap() {
+            Amount::IOU { amount, .. } => assert_eq!(amount, OpaqueFloat(FLOAT_ONE)),
+            other => panic!("expected an IOU, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Expiration shared by offers, checks and payment channels.
+//!
+//! `Offer`, `Check` and `PayChannel` objects all carry an optional `Expiration`, in seconds since
+//! the Ripple Epoch, and the ledger treats them the same way: the object is expired once the
+//! parent ledger's close time reaches that time. [`Expirable`] puts that comparison in one place:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::chain::parent_ledger_time;
+//! use xrpl_common_stdlib::objects::expiration::Expirable;
+//! use xrpl_common_stdlib::objects::offer::Offer;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! let offer = Offer::load(&AccountID([0x2B; 20]), 12).unwrap();
+//! let live = !offer.is_expired(parent_ledger_time().unwrap()).unwrap();
+//! # let _ = live;
+//! ```
+//!
+//! Credentials expire differently (only once the close time is strictly later than their
+//! `Expiration`), so they keep their own
+//! [`CredentialFields::is_expired`](crate::objects::traits::CredentialFields::is_expired).
+
+use crate::host::{Result, Result::Err, Result::Ok};
+use crate::objects::check::Check;
+use crate::objects::offer::Offer;
+use crate::objects::pay_channel::PayChannel;
+use crate::objects::traits::{CheckFields, OfferFields, PayChannelFields};
+
+/// A ledger object that stops being usable at a point in time.
+pub trait Expirable {
+    /// The time, in seconds since the Ripple Epoch, at which the object expires. `None` if it
+    /// never does.
+    fn expiration(&self) -> Result<Option<u32>>;
+
+    /// Whether the object has expired as of `now`, the parent ledger's close time in seconds
+    /// since the Ripple Epoch.
+    ///
+    /// An object expires at its expiration time itself, matching the ledger: an offer whose
+    /// `Expiration` equals the close time can no longer be crossed.
+    fn is_expired(&self, now: u32) -> Result<bool> {
+        self.expiration()
+            .map(|expiration| expiration.is_some_and(|expiration| now >= expiration))
+    }
+}
+
+impl Expirable for Offer {
+    fn expiration(&self) -> Result<Option<u32>> {
+        OfferFields::get_expiration(self)
+    }
+}
+
+impl Expirable for Check {
+    fn expiration(&self) -> Result<Option<u32>> {
+        CheckFields::get_expiration(self)
+    }
+}
+
+/// A channel expires at the earlier of its `Expiration` and its `CancelAfter`, after which any
+/// transaction on it closes it instead.
+impl Expirable for PayChannel {
+    fn expiration(&self) -> Result<Option<u32>> {
+        let expiration = match PayChannelFields::get_expiration(self) {
+            Ok(expiration) => expiration,
+            Err(e) => return Err(e),
+        };
+        PayChannelFields::get_cancel_after(self).map(|cancel_after| {
+            match (expiration, cancel_after) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::sfield;
+    use mockall::predicate::{always, eq};
+
+    /// Expects one read of the `u32` field `field_code` on `slot`, absent if `value` is `None`.
+    fn expect_time(mock: &mut MockHostBindings, slot: i32, field_code: i32, value: Option<u32>) {
+        mock.expect_get_ledger_obj_field()
+            .with(eq(slot), eq(field_code), always(), eq(4))
+            .times(1)
+            .returning(move |_, _, ptr, _| match value {
+                Some(value) => {
+                    unsafe { core::ptr::copy_nonoverlapping(value.to_le_bytes().as_ptr(), ptr, 4) };
+                    4
+                }
+                None => FIELD_NOT_FOUND,
+            });
+    }
+
+    #[test]
+    fn test_expires_at_the_expiration_time() {
+        let mut mock = MockHostBindings::new();
+        expect_time(&mut mock, 1, sfield::Expiration.into(), Some(1_000));
+        expect_time(&mut mock, 2, sfield::Expiration.into(), Some(1_000));
+        expect_time(&mut mock, 3, sfield::Expiration.into(), None);
+        let _guard = setup_mock(mock);
+
+        assert!(!Offer { slot_num: 1 }.is_expired(999).unwrap());
+        assert!(Check { slot_num: 2 }.is_expired(1_000).unwrap());
+        assert!(!Check { slot_num: 3 }.is_expired(u32::MAX).unwrap());
+    }
+
+    #[test]
+    fn test_pay_channel_expires_at_earlier_of_expiration_and_cancel_after() {
+        let mut mock = MockHostBindings::new();
+        expect_time(&mut mock, 4, sfield::Expiration.into(), Some(2_000));
+        expect_time(&mut mock, 4, sfield::CancelAfter.into(), Some(1_500));
+        expect_time(&mut mock, 5, sfield::Expiration.into(), None);
+        expect_time(&mut mock, 5, sfield::CancelAfter.into(), Some(1_500));
+        expect_time(&mut mock, 6, sfield::Expiration.into(), None);
+        expect_time(&mut mock, 6, sfield::CancelAfter.into(), None);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            PayChannel { slot_num: 4 }.expiration().unwrap(),
+            Some(1_500)
+        );
+        assert!(PayChannel { slot_num: 5 }.is_expired(1_500).unwrap());
+        assert_eq!(PayChannel { slot_num: 6 }.expiration().unwrap(), None);
+    }
+}
@@ -0,0 +1,383 @@
+//! JSON rendering of cached ledger objects, for debugging native builds.
+//!
+//! When a contract runs against the mock or simulator host, it is often easier to see the whole
+//! ledger object than to trace fields one at a time. [`ToJson::to_json_string`] reads every
+//! field the object's accessor trait knows about and renders them in rippled's field names:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::objects::account_root::AccountRoot;
+//! use xrpl_common_stdlib::objects::json::ToJson;
+//!
+//! let account = AccountRoot { slot_num: 1 };
+//! println!("{}", account.to_json_string());
+//! ```
+//!
+//! The output follows rippled's JSON where that needs no extra machinery: `UInt64` fields and
+//! XRP amounts are strings, hashes and blobs are uppercase hex. Two differences:
+//!
+//! * Accounts are rendered as hex account IDs, not r-addresses; the library has no base58
+//!   encoder.
+//! * Token amounts are rendered as `<mantissa>e<exponent>` rather than as a normalized decimal.
+//!
+//! Absent optional fields are left out. A field whose read fails with any other error is
+//! rendered as `{"error": <code>}`, so a broken mock shows up in the output instead of aborting
+//! it.
+//!
+//! This module only exists on non-`wasm32` targets.
+
+use core::fmt::Write;
+use std::string::String;
+
+use crate::host::{Error, Result};
+use crate::objects::account_root::AccountRoot;
+use crate::objects::offer::Offer;
+use crate::objects::traits::{AccountFields, EscrowFields, OfferFields};
+use crate::types::account_id::AccountID;
+use crate::types::amount::Amount;
+use crate::types::blob::Blob;
+use crate::types::contract_data::ContractData;
+use crate::types::currency::Currency;
+use crate::types::uint::UInt;
+
+/// A ledger object that can render all of its fields as a JSON object.
+pub trait ToJson {
+    /// Reads every known field through the host and renders them as a single-line JSON object.
+    fn to_json_string(&self) -> String;
+}
+
+impl ToJson for AccountRoot {
+    fn to_json_string(&self) -> String {
+        account_root_json(self)
+    }
+}
+
+impl ToJson for Offer {
+    fn to_json_string(&self) -> String {
+        offer_json(self)
+    }
+}
+
+/// Renders an `AccountRoot` through its [`AccountFields`].
+pub fn account_root_json<T: AccountFields>(obj: &T) -> String {
+    let mut json = JsonObject::new();
+    json.field("LedgerEntryType", obj.ledger_entry_type())
+        .field("Flags", obj.get_flags())
+        .field("Account", obj.get_account())
+        .optional("Balance", obj.balance())
+        .field("Sequence", obj.sequence())
+        .field("OwnerCount", obj.owner_count())
+        .field("PreviousTxnID", obj.previous_txn_id())
+        .field("PreviousTxnLgrSeq", obj.previous_txn_lgr_seq())
+        .optional("AccountTxnID", obj.account_txn_id())
+        .optional("RegularKey", obj.regular_key())
+        .optional("EmailHash", obj.email_hash())
+        .optional("WalletLocator", obj.wallet_locator())
+        .optional("WalletSize", obj.wallet_size())
+        .optional("MessageKey", obj.message_key())
+        .optional("TransferRate", obj.transfer_rate())
+        .optional("Domain", obj.domain())
+        .optional("TickSize", obj.tick_size())
+        .optional("TicketCount", obj.ticket_count())
+        .optional("NFTokenMinter", obj.nf_token_minter())
+        .optional("MintedNFTokens", obj.minted_nf_tokens())
+        .optional("BurnedNFTokens", obj.burned_nf_tokens())
+        .optional("FirstNFTokenSequence", obj.first_nf_token_sequence())
+        .optional("AMMID", obj.amm_id())
+        .optional("VaultID", obj.vault_id())
+        .optional("LoanBrokerID", obj.loan_broker_id());
+    json.finish()
+}
+
+/// Renders an `Escrow` through its [`EscrowFields`].
+pub fn escrow_json<T: EscrowFields>(obj: &T) -> String {
+    let mut json = JsonObject::new();
+    json.field("LedgerEntryType", obj.get_ledger_entry_type())
+        .field("Flags", obj.get_flags())
+        .field("Account", obj.get_account())
+        .field("Destination", obj.get_destination())
+        .field("Amount", obj.get_amount())
+        .optional("Condition", obj.get_condition())
+        .optional("CancelAfter", obj.get_cancel_after())
+        .optional("FinishAfter", obj.get_finish_after())
+        .optional("SourceTag", obj.get_source_tag())
+        .optional("DestinationTag", obj.get_destination_tag())
+        .field("OwnerNode", obj.get_owner_node())
+        .optional("DestinationNode", obj.get_destination_node())
+        .optional("FinishFunction", obj.get_finish_function())
+        .field("Data", obj.get_data())
+        .field("PreviousTxnID", obj.get_previous_txn_id())
+        .field("PreviousTxnLgrSeq", obj.get_previous_txn_lgr_seq());
+    json.finish()
+}
+
+/// Renders an `Offer` through its [`OfferFields`].
+pub fn offer_json<T: OfferFields>(obj: &T) -> String {
+    let mut json = JsonObject::new();
+    json.field("LedgerEntryType", obj.get_ledger_entry_type())
+        .field("Flags", obj.get_flags())
+        .field("Account", obj.get_account())
+        .field("Sequence", obj.get_sequence())
+        .field("TakerPays", obj.get_taker_pays())
+        .field("TakerGets", obj.get_taker_gets())
+        .optional("Expiration", obj.get_expiration())
+        .optional("DomainID", obj.get_domain_id());
+    json.finish()
+}
+
+/// Builds a JSON object one field at a time.
+///
+/// The `*_json` functions are built on this; use it to render ledger objects this module does
+/// not cover.
+#[derive(Debug)]
+pub struct JsonObject {
+    out: String,
+}
+
+impl JsonObject {
+    pub fn new() -> Self {
+        JsonObject {
+            out: String::from("{"),
+        }
+    }
+
+    /// Adds a required field, or its error code if the read failed.
+    pub fn field<T: JsonValue>(&mut self, name: &str, value: Result<T>) -> &mut Self {
+        self.key(name);
+        match value {
+            Result::Ok(value) => value.write_json(&mut self.out),
+            Result::Err(e) => {
+                let _ = write!(self.out, "{{\"error\":{}}}", e.code());
+            }
+        }
+        self
+    }
+
+    /// Adds an optional field, leaving it out if it is absent.
+    ///
+    /// Variable-length optional getters report an absent field as `FieldNotFound` rather than
+    /// `None`, so that error counts as absent too.
+    pub fn optional<T: JsonValue>(&mut self, name: &str, value: Result<Option<T>>) -> &mut Self {
+        match value {
+            Result::Ok(None) | Result::Err(Error::FieldNotFound) => self,
+            Result::Ok(Some(value)) => self.field(name, Result::Ok(value)),
+            Result::Err(e) => self.field::<T>(name, Result::Err(e)),
+        }
+    }
+
+    /// Closes the object and returns the rendered JSON.
+    pub fn finish(mut self) -> String {
+        self.out.push('}');
+        self.out
+    }
+
+    fn key(&mut self, name: &str) {
+        if self.out.len() > 1 {
+            self.out.push(',');
+        }
+        self.out.push('"');
+        self.out.push_str(name);
+        self.out.push_str("\":");
+    }
+}
+
+impl Default for JsonObject {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A field value that can be written as JSON.
+pub trait JsonValue {
+    fn write_json(&self, out: &mut String);
+}
+
+macro_rules! json_number {
+    ($($ty:ty),*) => {
+        $(impl JsonValue for $ty {
+            fn write_json(&self, out: &mut String) {
+                let _ = write!(out, "{}", self);
+            }
+        })*
+    };
+}
+
+json_number!(u8, u16, u32);
+
+/// Hex string without leading zeros, as rippled renders `UInt64` fields.
+impl JsonValue for u64 {
+    fn write_json(&self, out: &mut String) {
+        let _ = write!(out, "\"{:X}\"", self);
+    }
+}
+
+impl JsonValue for AccountID {
+    fn write_json(&self, out: &mut String) {
+        write_hex_string(out, &self.0);
+    }
+}
+
+impl<const N: usize> JsonValue for UInt<N> {
+    fn write_json(&self, out: &mut String) {
+        write_hex_string(out, self.as_bytes());
+    }
+}
+
+impl<const N: usize> JsonValue for Blob<N> {
+    fn write_json(&self, out: &mut String) {
+        write_hex_string(out, self.as_slice());
+    }
+}
+
+impl JsonValue for ContractData {
+    fn write_json(&self, out: &mut String) {
+        write_hex_string(out, self.as_slice());
+    }
+}
+
+/// Three-letter ISO-style codes as text, anything else as hex.
+impl JsonValue for Currency {
+    fn write_json(&self, out: &mut String) {
+        let bytes = self.as_bytes();
+        let code = &bytes[12..15];
+        let standard = bytes[..12].iter().chain(&bytes[15..]).all(|&b| b == 0)
+            && code.iter().all(|b| b.is_ascii_alphanumeric());
+        if standard {
+            out.push('"');
+            code.iter().for_each(|&b| out.push(b as char));
+            out.push('"');
+        } else {
+            write_hex_string(out, bytes);
+        }
+    }
+}
+
+impl JsonValue for Amount {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Amount::XRP { num_drops } => {
+                let _ = write!(out, "\"{}\"", num_drops);
+            }
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                out.push_str("{\"currency\":");
+                currency.write_json(out);
+                out.push_str(",\"issuer\":");
+                issuer.write_json(out);
+                out.push_str(",\"value\":\"");
+                write_token_value(out, u64::from_be_bytes(amount.0));
+                out.push_str("\"}");
+            }
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => {
+                out.push_str("{\"mpt_issuance_id\":");
+                write_hex_string(out, mpt_id.as_bytes());
+                let sign = if *is_positive { "" } else { "-" };
+                let _ = write!(out, ",\"value\":\"{}{}\"}}", sign, num_units);
+            }
+        }
+    }
+}
+
+/// Writes a serialized token amount as `<mantissa>e<exponent>`: bit 62 is the sign (set for
+/// positive), bits 54-61 the exponent biased by 97, and the low 54 bits the mantissa.
+fn write_token_value(out: &mut String, bits: u64) {
+    let mantissa = bits & ((1 << 54) - 1);
+    if mantissa == 0 {
+        out.push('0');
+        return;
+    }
+    let exponent = ((bits >> 54) & 0xFF) as i32 - 97;
+    let sign = if bits & (1 << 62) == 0 { "-" } else { "" };
+    let _ = write!(out, "{}{}e{}", sign, mantissa, exponent);
+}
+
+fn write_hex_string(out: &mut String, bytes: &[u8]) {
+    out.push('"');
+    for byte in bytes {
+        let _ = write!(out, "{:02X}", byte);
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::sfield;
+    use crate::types::opaque_float::OpaqueFloat;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_json_object_skips_absent_and_reports_errors() {
+        let mut json = JsonObject::new();
+        json.field("Sequence", Result::Ok(7u32))
+            .optional::<u32>("TicketCount", Result::Ok(None))
+            .optional::<Amount>("Balance", Result::Err(Error::FieldNotFound))
+            .optional::<u64>("OwnerNode", Result::Ok(Some(0x1F)))
+            .field::<AccountID>("Account", Result::Err(Error::InvalidField));
+        assert_eq!(
+            json.finish(),
+            "{\"Sequence\":7,\"OwnerNode\":\"1F\",\"Account\":{\"error\":-17}}"
+        );
+    }
+
+    #[test]
+    fn test_amount_rendering() {
+        let mut out = String::new();
+        Amount::XRP { num_drops: 25 }.write_json(&mut out);
+        assert_eq!(out, "\"25\"");
+
+        let mut currency = [0u8; 20];
+        currency[12..15].copy_from_slice(b"USD");
+        let mut out = String::new();
+        Amount::IOU {
+            // 1234e-2, positive
+            amount: OpaqueFloat((0xC000_0000_0000_0000u64 | (95 << 54) | 1234).to_be_bytes()),
+            issuer: AccountID([0xAB; 20]),
+            currency: Currency::from(currency),
+        }
+        .write_json(&mut out);
+        assert_eq!(
+            out,
+            "{\"currency\":\"USD\",\"issuer\":\"ABABABABABABABABABABABABABABABABABABABAB\",\"value\":\"1234e-2\"}"
+        );
+    }
+
+    #[test]
+    fn test_offer_to_json_string() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(2), always(), always(), always())
+            .returning(|_, field, out, len| {
+                if field == i32::from(sfield::TakerPays) || field == i32::from(sfield::TakerGets) {
+                    // XRP amount of 10 drops.
+                    let bytes = (0x4000_0000_0000_0000u64 | 10).to_be_bytes();
+                    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 8) };
+                    8
+                } else if field == i32::from(sfield::Expiration)
+                    || field == i32::from(sfield::DomainID)
+                {
+                    FIELD_NOT_FOUND
+                } else {
+                    unsafe { core::ptr::write_bytes(out, 0, len) };
+                    len as i32
+                }
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            Offer { slot_num: 2 }.to_json_string(),
+            "{\"LedgerEntryType\":0,\"Flags\":0,\
+             \"Account\":\"0000000000000000000000000000000000000000\",\"Sequence\":0,\
+             \"TakerPays\":\"10\",\"TakerGets\":\"10\"}"
+        );
+    }
+}
@@ -1,6 +1,25 @@
 pub mod account_root;
+pub mod amm;
 pub mod array_object;
+pub mod balances;
+pub mod check;
+pub mod escrow;
+pub mod expiration;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod json;
+pub mod mpt;
+pub mod offer;
+pub mod oracle;
+pub mod pay_channel;
+pub mod permissioned_domain;
+pub mod reserves;
+pub mod singletons;
+pub mod slot;
 pub mod traits;
+pub mod view;
+pub mod xchain;
+
+pub use slot::exists;
 
 use crate::host::error_codes::{
     match_result_code_with_expected_bytes, match_result_code_with_expected_bytes_optional,
@@ -379,9 +398,20 @@ impl LedgerObjectFieldGetter for Hash192 {
     }
 }
 
+/// Field access for the ledger object the contract is attached to, e.g. the escrow being finished.
+///
+/// The functions mirror the free functions of [`current_tx`](crate::current_tx), and the module
+/// is re-exported at the crate root as `current_ledger_object` for the same reason.
 pub mod current_ledger_object {
     use super::LedgerObjectFieldGetter;
-    use crate::host::Result;
+    use crate::fields::decoder::{FromLedger, decode_field};
+    use crate::fields::locator::Locator;
+    use crate::host::error_codes::match_result_code;
+    use crate::host::{
+        Result, get_current_ledger_obj_array_len, get_current_ledger_obj_nested_array_len,
+        get_current_ledger_obj_nested_field,
+    };
+    use crate::objects::array_object::Array;
     use crate::sfield::SField;
 
     /// Retrieves a field from the current ledger object.
@@ -432,6 +462,92 @@ pub mod current_ledger_object {
         T::get_from_current_ledger_obj_optional(field)
     }
 
+    /// Returns the number of elements in an array field of the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - An array SField constant (e.g., `sfield::SignerEntries`)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of elements in the array
+    /// * `Err(Error)` - If the field is missing or is not an array
+    #[inline]
+    pub fn array_len<const CODE: i32>(field: SField<Array, CODE>) -> Result<usize> {
+        let result_code = unsafe { get_current_ledger_obj_array_len(field.into()) };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Returns the number of elements in a nested array of the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - A [`Locator`] whose path ends at an array field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of elements in the array
+    /// * `Err(Error)` - If the locator is malformed or does not point at an array
+    #[inline]
+    pub fn nested_array_len(locator: &Locator) -> Result<usize> {
+        let result_code =
+            unsafe { get_current_ledger_obj_nested_array_len(locator.as_ptr(), locator.len()) };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Reads the raw bytes of a nested field of the current ledger object into `out`.
+    ///
+    /// Use this for fields the typed getters do not cover, e.g. one entry of an array:
+    ///
+    /// ```rust,no_run
+    /// use xrpl_common_stdlib::current_ledger_object;
+    /// use xrpl_common_stdlib::fields::locator::{Locator, LocatorEntry};
+    /// use xrpl_common_stdlib::sfield;
+    ///
+    /// let mut locator = Locator::new();
+    /// locator.pack(sfield::SignerEntries).unwrap();
+    /// locator.pack(LocatorEntry::Index(0)).unwrap();
+    /// locator.pack(sfield::Account).unwrap();
+    /// let mut account = [0u8; 20];
+    /// let len = current_ledger_object::nested_field(&locator, &mut account).unwrap();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of bytes written to `out`
+    /// * `Err(Error)` - If the locator is malformed, the field is missing, or `out` is too small
+    #[inline]
+    pub fn nested_field(locator: &Locator, out: &mut [u8]) -> Result<usize> {
+        let result_code = unsafe {
+            get_current_ledger_obj_nested_field(
+                locator.as_ptr(),
+                locator.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Retrieves a nested field of the current ledger object as a `T`.
+    ///
+    /// See [`fields::decoder`](crate::fields::decoder#nested-fields) for an example.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<T>` where:
+    /// * `Ok(T)` - The field value at `locator`
+    /// * `Err(Error::InvalidDecoding)` - If the field does not decode as a `T`
+    /// * `Err(Error)` - If the locator is malformed or the field cannot be retrieved
+    #[inline]
+    pub fn get_nested_field<T: FromLedger>(locator: &Locator) -> Result<T> {
+        let mut buf = T::Buffer::default();
+        nested_field(locator, buf.as_mut()).and_then(|len| decode_field(&buf.as_mut()[..len]))
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -813,7 +929,14 @@ pub mod current_ledger_object {
 
 pub mod ledger_object {
     use super::LedgerObjectFieldGetter;
-    use crate::host::Result;
+    use crate::fields::decoder::{FromLedger, decode_field};
+    use crate::fields::locator::Locator;
+    use crate::host::error_codes::match_result_code;
+    use crate::host::{
+        Result, get_ledger_obj_array_len, get_ledger_obj_nested_array_len,
+        get_ledger_obj_nested_field,
+    };
+    use crate::objects::array_object::Array;
     use crate::sfield::SField;
 
     /// Retrieves a field from a specified ledger object.
@@ -868,6 +991,98 @@ pub mod ledger_object {
         T::get_from_ledger_obj_optional(register_num, field)
     }
 
+    /// Returns the number of elements in an array field of a cached ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object
+    /// * `field` - An array SField constant (e.g., `sfield::SignerEntries`)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of elements in the array
+    /// * `Err(Error)` - If the slot is empty, or the field is missing or is not an array
+    #[inline]
+    pub fn array_len<const CODE: i32>(
+        register_num: i32,
+        field: SField<Array, CODE>,
+    ) -> Result<usize> {
+        let result_code = unsafe { get_ledger_obj_array_len(register_num, field.into()) };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Returns the number of elements in a nested array of a cached ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object
+    /// * `locator` - A [`Locator`] whose path ends at an array field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of elements in the array
+    /// * `Err(Error)` - If the slot is empty, or the locator does not point at an array
+    #[inline]
+    pub fn nested_array_len(register_num: i32, locator: &Locator) -> Result<usize> {
+        let result_code = unsafe {
+            get_ledger_obj_nested_array_len(register_num, locator.as_ptr(), locator.len())
+        };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Retrieves a nested field of a cached ledger object into `out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object
+    /// * `locator` - The path to the field
+    /// * `out` - The buffer to write the field into
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of bytes written to `out`
+    /// * `Err(Error)` - If the slot is empty, the locator is malformed, the field is missing, or
+    ///   `out` is too small
+    #[inline]
+    pub fn nested_field(register_num: i32, locator: &Locator, out: &mut [u8]) -> Result<usize> {
+        let result_code = unsafe {
+            get_ledger_obj_nested_field(
+                register_num,
+                locator.as_ptr(),
+                locator.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Retrieves a nested field of a cached ledger object as a `T`.
+    ///
+    /// See [`fields::decoder`](crate::fields::decoder#nested-fields) for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object
+    /// * `locator` - The path to the field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<T>` where:
+    /// * `Ok(T)` - The field value at `locator`
+    /// * `Err(Error::InvalidDecoding)` - If the field does not decode as a `T`
+    /// * `Err(Error)` - If the slot is empty, the locator is malformed or the field cannot be
+    ///   retrieved
+    #[inline]
+    pub fn get_nested_field<T: FromLedger>(register_num: i32, locator: &Locator) -> Result<T> {
+        let mut buf = T::Buffer::default();
+        nested_field(register_num, locator, buf.as_mut())
+            .and_then(|len| decode_field(&buf.as_mut()[..len]))
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -1139,4 +1354,120 @@ mod tests {
     fn test_object_get_field_optional_with_slot_panics() {
         let _ = ledger_object::get_field_optional(0, sfield::Memo);
     }
+
+    mod array_len {
+        use super::{current_ledger_object, ledger_object};
+        use crate::fields::locator::{Locator, LocatorEntry};
+        use crate::host::error_codes::{BUFFER_TOO_SMALL, EMPTY_SLOT, NO_ARRAY};
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use mockall::predicate::{always, eq};
+
+        #[test]
+        fn test_current_ledger_object_array_len() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_array_len()
+                .with(eq::<i32>(sfield::SignerEntries.into()))
+                .times(1)
+                .returning(|_| 4);
+            mock.expect_get_current_ledger_obj_array_len()
+                .with(eq::<i32>(sfield::Memos.into()))
+                .times(1)
+                .returning(|_| NO_ARRAY);
+            let _guard = setup_mock(mock);
+
+            assert_eq!(
+                current_ledger_object::array_len(sfield::SignerEntries).unwrap(),
+                4
+            );
+            let result = current_ledger_object::array_len(sfield::Memos);
+            assert_eq!(result.err().unwrap().code(), NO_ARRAY);
+        }
+
+        #[test]
+        fn test_current_ledger_object_nested_array_len() {
+            let mut locator = Locator::new();
+            locator.pack(sfield::SignerEntries).unwrap();
+
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_nested_array_len()
+                .with(always(), eq(4))
+                .times(1)
+                .returning(|_, _| 8);
+            let _guard = setup_mock(mock);
+
+            assert_eq!(
+                current_ledger_object::nested_array_len(&locator).unwrap(),
+                8
+            );
+        }
+
+        #[test]
+        fn test_current_ledger_object_nested_field() {
+            let mut locator = Locator::new();
+            locator.pack(sfield::SignerEntries).unwrap();
+            locator.pack(LocatorEntry::Index(0)).unwrap();
+            locator.pack(sfield::Account).unwrap();
+
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_nested_field()
+                .with(always(), eq(locator.len()), always(), eq(20))
+                .times(1)
+                .returning(|_, _, out, _| {
+                    unsafe { core::ptr::write_bytes(out, 0xAA, 20) };
+                    20
+                });
+            mock.expect_get_current_ledger_obj_nested_field()
+                .with(always(), always(), always(), eq(4))
+                .times(1)
+                .returning(|_, _, _, _| BUFFER_TOO_SMALL);
+            let _guard = setup_mock(mock);
+
+            let mut account = [0u8; 20];
+            assert_eq!(
+                current_ledger_object::nested_field(&locator, &mut account).unwrap(),
+                20
+            );
+            assert_eq!(account, [0xAA; 20]);
+            let result = current_ledger_object::nested_field(&locator, &mut [0u8; 4]);
+            assert_eq!(result.err().unwrap().code(), BUFFER_TOO_SMALL);
+        }
+
+        #[test]
+        fn test_ledger_object_array_len() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_ledger_obj_array_len()
+                .with(eq(1), eq::<i32>(sfield::SignerEntries.into()))
+                .times(1)
+                .returning(|_, _| 2);
+            mock.expect_get_ledger_obj_array_len()
+                .with(eq(2), always())
+                .times(1)
+                .returning(|_, _| EMPTY_SLOT);
+            let _guard = setup_mock(mock);
+
+            assert_eq!(
+                ledger_object::array_len(1, sfield::SignerEntries).unwrap(),
+                2
+            );
+            let result = ledger_object::array_len(2, sfield::SignerEntries);
+            assert_eq!(result.err().unwrap().code(), EMPTY_SLOT);
+        }
+
+        #[test]
+        fn test_ledger_object_nested_array_len() {
+            let mut locator = Locator::new();
+            locator.pack(sfield::SignerEntries).unwrap();
+
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_ledger_obj_nested_array_len()
+                .with(eq(3), always(), eq(4))
+                .times(1)
+                .returning(|_, _, _| 5);
+            let _guard = setup_mock(mock);
+
+            assert_eq!(ledger_object::nested_array_len(3, &locator).unwrap(), 5);
+        }
+    }
 }
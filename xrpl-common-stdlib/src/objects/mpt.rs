@@ -0,0 +1,213 @@
+//! Multi-Purpose Token (MPT) authorization.
+//!
+//! An MPT issuance can require the issuer to authorize each holder (`lsfMPTRequireAuth`), and the
+//! issuer can lock the whole issuance or a single holder's balance (`lsfMPTLocked`).
+//! [`holder_is_authorized`] combines these checks, so an escrow paying out in an MPT can refuse a
+//! recipient the ledger would not let hold or move the token:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::Result;
+//! use xrpl_common_stdlib::objects::mpt::holder_is_authorized;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//! use xrpl_common_stdlib::types::mpt_id::MptId;
+//!
+//! let mpt_id = MptId::new(1, AccountID([0x1A; 20]));
+//! let recipient = AccountID([0x2B; 20]);
+//! if let Result::Ok(true) = holder_is_authorized(&mpt_id, &recipient) {
+//!     // release
+//! }
+//! ```
+//!
+//! Both objects are read through [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT), so the
+//! check consumes no cache slots.
+
+use crate::host::{Error, Result};
+use crate::keylets::{mpt_issuance_keylet, mptoken_keylet};
+use crate::objects::slot::cache_scratch;
+use crate::objects::traits::LedgerObjectCommonFields;
+use crate::types::account_id::AccountID;
+use crate::types::mpt_id::MptId;
+
+/// `lsfMPTLocked`, on both `MPTokenIssuance` and `MPToken`.
+const LSF_MPT_LOCKED: u32 = 0x0000_0001;
+/// `lsfMPTAuthorized` on an `MPToken`.
+const LSF_MPT_AUTHORIZED: u32 = 0x0000_0002;
+/// `lsfMPTRequireAuth` on an `MPTokenIssuance`.
+const LSF_MPT_REQUIRE_AUTH: u32 = 0x0000_0004;
+
+/// Returns whether `holder` may currently hold and transfer the MPT `mpt_id`.
+///
+/// * The issuer is always authorized.
+/// * Nobody else is while the issuance is locked, or while the holder's own `MPToken` is locked.
+/// * If the issuance requires authorization, the holder's `MPToken` must exist and carry
+///   `lsfMPTAuthorized`. Otherwise a missing `MPToken` is not disqualifying: the holder can
+///   create one without the issuer's involvement.
+///
+/// Authorization through a permissioned domain (`DomainID` on the issuance) is not considered.
+///
+/// # Errors
+///
+/// * `LedgerObjNotFound` - If the issuance does not exist
+/// * Any other error from computing keylets or reading the objects
+pub fn holder_is_authorized(mpt_id: &MptId, holder: &AccountID) -> Result<bool> {
+    let issuer = mpt_id.get_issuer();
+    let issuance_flags = match mpt_issuance_keylet(&issuer, mpt_id.get_sequence_num())
+        .and_then(|keylet| cache_scratch(&keylet))
+        .and_then(|slot| slot.get_flags())
+    {
+        Result::Ok(flags) => flags,
+        Result::Err(e) => return Result::Err(e),
+    };
+    if holder == &issuer {
+        return Result::Ok(true);
+    }
+    if issuance_flags & LSF_MPT_LOCKED != 0 {
+        return Result::Ok(false);
+    }
+    let require_auth = issuance_flags & LSF_MPT_REQUIRE_AUTH != 0;
+
+    let token_flags = match mptoken_keylet(mpt_id, holder)
+        .and_then(|keylet| cache_scratch(&keylet))
+        .and_then(|slot| slot.get_flags())
+    {
+        Result::Ok(flags) => flags,
+        Result::Err(Error::LedgerObjNotFound) => return Result::Ok(!require_auth),
+        Result::Err(e) => return Result::Err(e),
+    };
+    Result::Ok(
+        token_flags & LSF_MPT_LOCKED == 0
+            && (!require_auth || token_flags & LSF_MPT_AUTHORIZED != 0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::LEDGER_OBJ_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::slot::SCRATCH_SLOT;
+    use crate::sfield;
+    use mockall::Sequence;
+    use mockall::predicate::{always, eq};
+
+    const ISSUER: AccountID = AccountID([0x1A; 20]);
+    const HOLDER: AccountID = AccountID([0x2B; 20]);
+    const ISSUANCE_KEYLET: [u8; 32] = [0x15; 32];
+    const TOKEN_KEYLET: [u8; 32] = [0x70; 32];
+
+    fn write_keylet(out: *mut u8, keylet: [u8; 32]) -> i32 {
+        unsafe { out.copy_from_nonoverlapping(keylet.as_ptr(), 32) };
+        32
+    }
+
+    /// Expects the issuance with `issuance_flags`, then, if `token_flags` is given, the holder's
+    /// `MPToken` (`None` inside meaning it does not exist).
+    fn mock_objects(issuance_flags: u32, token_flags: Option<Option<u32>>) -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        let mut seq = Sequence::new();
+        mock.expect_mpt_issuance_keylet()
+            .returning(|_, _, _, _, out, _| write_keylet(out, ISSUANCE_KEYLET));
+        mock.expect_mptoken_keylet()
+            .returning(|_, _, _, _, out, _| write_keylet(out, TOKEN_KEYLET));
+        mock.expect_cache_ledger_obj()
+            .withf(|ptr, _, slot| {
+                *slot == SCRATCH_SLOT
+                    && unsafe { core::slice::from_raw_parts(*ptr, 32) } == ISSUANCE_KEYLET
+            })
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| SCRATCH_SLOT);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::Flags)),
+                always(),
+                eq(4),
+            )
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_, _, out, _| {
+                unsafe { out.copy_from_nonoverlapping(issuance_flags.to_le_bytes().as_ptr(), 4) };
+                4
+            });
+        if let Some(token_flags) = token_flags {
+            mock.expect_cache_ledger_obj()
+                .withf(|ptr, _, _| unsafe { core::slice::from_raw_parts(*ptr, 32) == TOKEN_KEYLET })
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(move |_, _, _| match token_flags {
+                    Some(_) => SCRATCH_SLOT,
+                    None => LEDGER_OBJ_NOT_FOUND,
+                });
+            if let Some(flags) = token_flags {
+                mock.expect_get_ledger_obj_field()
+                    .with(
+                        eq(SCRATCH_SLOT),
+                        eq(i32::from(sfield::Flags)),
+                        always(),
+                        eq(4),
+                    )
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .returning(move |_, _, out, _| {
+                        unsafe { out.copy_from_nonoverlapping(flags.to_le_bytes().as_ptr(), 4) };
+                        4
+                    });
+            }
+        }
+        mock
+    }
+
+    fn authorized(holder: &AccountID, mock: MockHostBindings) -> bool {
+        let _guard = setup_mock(mock);
+        holder_is_authorized(&MptId::new(1, ISSUER), holder).unwrap()
+    }
+
+    #[test]
+    fn test_open_issuance() {
+        assert!(authorized(&HOLDER, mock_objects(0, Some(Some(0)))));
+        assert!(authorized(&HOLDER, mock_objects(0, Some(None))));
+        assert!(!authorized(
+            &HOLDER,
+            mock_objects(0, Some(Some(LSF_MPT_LOCKED)))
+        ));
+    }
+
+    #[test]
+    fn test_require_auth_needs_authorized_token() {
+        let require_auth = LSF_MPT_REQUIRE_AUTH;
+        assert!(authorized(
+            &HOLDER,
+            mock_objects(require_auth, Some(Some(LSF_MPT_AUTHORIZED)))
+        ));
+        assert!(!authorized(
+            &HOLDER,
+            mock_objects(require_auth, Some(Some(0)))
+        ));
+        assert!(!authorized(&HOLDER, mock_objects(require_auth, Some(None))));
+    }
+
+    #[test]
+    fn test_issuance_lock_and_issuer() {
+        assert!(!authorized(&HOLDER, mock_objects(LSF_MPT_LOCKED, None)));
+        assert!(authorized(
+            &ISSUER,
+            mock_objects(LSF_MPT_LOCKED | LSF_MPT_REQUIRE_AUTH, None)
+        ));
+    }
+
+    #[test]
+    fn test_missing_issuance_is_an_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_mpt_issuance_keylet()
+            .returning(|_, _, _, _, out, _| write_keylet(out, ISSUANCE_KEYLET));
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let result = holder_is_authorized(&MptId::new(1, ISSUER), &HOLDER);
+        assert_eq!(result.err().unwrap().code(), LEDGER_OBJ_NOT_FOUND);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::host::Result;
+use crate::keylets::offer_keylet;
+use crate::objects::slot::Slot;
+use crate::objects::traits::{LedgerObjectCommonFields, OfferFields};
+use crate::types::account_id::AccountID;
+
+/// A cached `Offer` ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Offer {
+    pub slot_num: i32,
+}
+
+impl Offer {
+    /// Caches the offer `owner` created with sequence number `seq` in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the offer has been filled or cancelled.
+    pub fn load(owner: &AccountID, seq: u32) -> Result<Self> {
+        offer_keylet(owner, seq)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Offer {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for Offer {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl OfferFields for Offer {}
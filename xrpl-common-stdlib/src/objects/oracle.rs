@@ -0,0 +1,305 @@
+use crate::fields::locator::{Locator, LocatorEntry};
+use crate::host::{Error, Result};
+use crate::keylets::oracle_keylet;
+use crate::objects::ledger_object;
+use crate::objects::slot::Slot;
+use crate::objects::traits::{LedgerObjectCommonFields, OracleFields};
+use crate::sfield;
+use crate::types::account_id::AccountID;
+use crate::types::currency::Currency;
+use crate::types::document_id::DocumentId;
+use crate::types::pair::{Orientation, Pair};
+
+/// A cached `Oracle` (price oracle) ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Oracle {
+    pub slot_num: i32,
+}
+
+impl Oracle {
+    /// Caches the oracle `owner` publishes under `document_id` in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the owner has no oracle with that document ID.
+    pub fn load(owner: &AccountID, document_id: DocumentId) -> Result<Self> {
+        oracle_keylet(owner, document_id)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Oracle {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for Oracle {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl OracleFields for Oracle {}
+
+/// One entry of an oracle's `PriceDataSeries`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PriceData {
+    /// The asset being priced.
+    pub base_asset: Currency,
+    /// The asset the price is quoted in.
+    pub quote_asset: Currency,
+    /// The price, scaled by `10^scale`. `None` if the pair was left out of the oracle's most
+    /// recent update, which keeps the pair but drops its price.
+    pub asset_price: Option<u64>,
+    /// The number of decimal places in `asset_price`; 0 if the oracle did not set one.
+    pub scale: u8,
+}
+
+impl PriceData {
+    /// Returns whether this entry prices `base` in units of `quote`.
+    pub fn is_pair(&self, base: &Currency, quote: &Currency) -> bool {
+        &self.base_asset == base && &self.quote_asset == quote
+    }
+
+    /// Returns how this entry relates to `pair`, or `None` if it prices other currencies (or
+    /// `pair` includes an MPT, which oracles cannot price). An `Inverted` entry's price is for
+    /// `pair.inverted()`.
+    pub fn orientation(&self, pair: &Pair) -> Option<Orientation> {
+        match pair.oracle_currencies() {
+            Some((base, quote)) if self.is_pair(&base, &quote) => Some(Orientation::Same),
+            Some((base, quote)) if self.is_pair(&quote, &base) => Some(Orientation::Inverted),
+            _ => None,
+        }
+    }
+}
+
+/// Iterator over the entries of an oracle's `PriceDataSeries`, returned by
+/// [`OracleFields::price_series`].
+///
+/// Each entry costs three or four nested-field reads. Iteration stops after the first error.
+#[derive(Debug, Clone)]
+pub struct PriceSeries {
+    slot_num: i32,
+    index: usize,
+    len: usize,
+}
+
+impl PriceSeries {
+    pub(crate) fn new(slot_num: i32, len: usize) -> Self {
+        PriceSeries {
+            slot_num,
+            index: 0,
+            len,
+        }
+    }
+
+    fn read(&self, index: usize) -> Result<PriceData> {
+        let mut locator = Locator::new();
+        let base_asset = match locator
+            .pack(sfield::PriceDataSeries)
+            .and_then(|_| locator.pack(LocatorEntry::Index(index as i32)))
+            .and_then(|_| locator.pack(sfield::BaseAsset))
+            .and_then(|_| ledger_object::get_nested_field(self.slot_num, &locator))
+        {
+            Result::Ok(currency) => currency,
+            Result::Err(e) => return Result::Err(e),
+        };
+        let quote_asset = match locator
+            .repack_last(sfield::QuoteAsset)
+            .and_then(|_| ledger_object::get_nested_field(self.slot_num, &locator))
+        {
+            Result::Ok(currency) => currency,
+            Result::Err(e) => return Result::Err(e),
+        };
+        let asset_price = match locator
+            .repack_last(sfield::AssetPrice)
+            .and_then(|_| ledger_object::get_nested_field::<u64>(self.slot_num, &locator))
+        {
+            Result::Ok(price) => Some(price),
+            Result::Err(Error::FieldNotFound) => None,
+            Result::Err(e) => return Result::Err(e),
+        };
+        let scale = if asset_price.is_some() {
+            match locator
+                .repack_last(sfield::Scale)
+                .and_then(|_| ledger_object::get_nested_field::<u8>(self.slot_num, &locator))
+            {
+                Result::Ok(scale) => scale,
+                Result::Err(Error::FieldNotFound) => 0,
+                Result::Err(e) => return Result::Err(e),
+            }
+        } else {
+            0
+        };
+        Result::Ok(PriceData {
+            base_asset,
+            quote_asset,
+            asset_price,
+            scale,
+        })
+    }
+}
+
+impl Iterator for PriceSeries {
+    type Item = Result<PriceData>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let entry = self.read(self.index);
+        self.index = match entry {
+            Result::Ok(_) => self.index + 1,
+            Result::Err(_) => self.len,
+        };
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::chain::RIPPLE_EPOCH_UNIX_OFFSET;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_load_caches_keylet_for_document_id() {
+        let owner = AccountID([0x0C; 20]);
+        let mut mock = MockHostBindings::new();
+        mock.expect_oracle_keylet()
+            .withf(|_, _, id_ptr, id_len, _, _| {
+                *id_len == 4 && unsafe { core::slice::from_raw_parts(*id_ptr, 4) } == [7, 0, 0, 0]
+            })
+            .times(1)
+            .returning(|_, _, _, _, out, len| {
+                unsafe { core::ptr::write_bytes(out, 0x0E, len) };
+                len as i32
+            });
+        mock.expect_cache_ledger_obj()
+            .withf(|keylet, len, cache_num| {
+                *len == 32
+                    && *cache_num == 0
+                    && unsafe { core::slice::from_raw_parts(*keylet, 32) } == [0x0E; 32]
+            })
+            .times(1)
+            .returning(|_, _, _| 3);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(3),
+                eq(i32::from(sfield::LastUpdateTime)),
+                always(),
+                eq(4),
+            )
+            .times(1)
+            .returning(|_, _, ptr, _| {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(1_700_000_000u32.to_le_bytes().as_ptr(), ptr, 4)
+                };
+                4
+            });
+        let _guard = setup_mock(mock);
+
+        let oracle = Oracle::load(&owner, DocumentId::new(7)).unwrap();
+        assert_eq!(oracle.slot_num, 3);
+        assert_eq!(oracle.get_last_update_time().unwrap(), 1_700_000_000);
+    }
+
+    fn currency(code: &[u8; 3]) -> Currency {
+        Currency::from(*code)
+    }
+
+    /// A two-entry series: XRP/USD priced at 74 with scale 2, and XRP/EUR without a price.
+    fn mock_series(mock: &mut MockHostBindings) {
+        mock.expect_get_ledger_obj_array_len()
+            .with(eq(3), eq(i32::from(sfield::PriceDataSeries)))
+            .returning(|_, _| 2);
+        mock.expect_get_ledger_obj_nested_field()
+            .withf(|slot, _, len, _, _| *slot == 3 && *len == 12)
+            .returning(|_, locator, _, out, out_len| {
+                let locator = unsafe { core::slice::from_raw_parts(locator, 12) };
+                let index = i32::from_le_bytes(locator[4..8].try_into().unwrap());
+                let field = i32::from_le_bytes(locator[8..12].try_into().unwrap());
+                let bytes: &[u8] = match (index, field) {
+                    (_, f) if f == i32::from(sfield::BaseAsset) => &currency(b"XRP").0,
+                    (0, f) if f == i32::from(sfield::QuoteAsset) => &currency(b"USD").0,
+                    (1, f) if f == i32::from(sfield::QuoteAsset) => &currency(b"EUR").0,
+                    (0, f) if f == i32::from(sfield::AssetPrice) => &74u64.to_ne_bytes(),
+                    (0, f) if f == i32::from(sfield::Scale) => &[2],
+                    _ => return FIELD_NOT_FOUND,
+                };
+                assert!(bytes.len() <= out_len);
+                unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len()) };
+                bytes.len() as i32
+            });
+    }
+
+    fn mock_times(mock: &mut MockHostBindings, ledger_unix_time: u32, last_update: u32) {
+        mock.expect_get_parent_ledger_time()
+            .returning(move |out, _| {
+                let time = ledger_unix_time - RIPPLE_EPOCH_UNIX_OFFSET;
+                unsafe { core::ptr::copy_nonoverlapping(time.to_le_bytes().as_ptr(), out, 4) };
+                4
+            });
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(3),
+                eq(i32::from(sfield::LastUpdateTime)),
+                always(),
+                eq(4),
+            )
+            .returning(move |_, _, out, _| {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(last_update.to_le_bytes().as_ptr(), out, 4)
+                };
+                4
+            });
+    }
+
+    #[test]
+    fn test_price_series_reads_each_entry() {
+        let mut mock = MockHostBindings::new();
+        mock_series(&mut mock);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        let oracle = Oracle { slot_num: 3 };
+        let entries: Vec<PriceData> = oracle.price_series().unwrap().map(|e| e.unwrap()).collect();
+        assert_eq!(
+            entries,
+            [
+                PriceData {
+                    base_asset: currency(b"XRP"),
+                    quote_asset: currency(b"USD"),
+                    asset_price: Some(74),
+                    scale: 2,
+                },
+                PriceData {
+                    base_asset: currency(b"XRP"),
+                    quote_asset: currency(b"EUR"),
+                    asset_price: None,
+                    scale: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_latest_fresh_price() {
+        let mut mock = MockHostBindings::new();
+        mock_series(&mut mock);
+        mock_times(&mut mock, 1_700_000_100, 1_700_000_000);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        let oracle = Oracle { slot_num: 3 };
+        let (xrp, usd, eur) = (currency(b"XRP"), currency(b"USD"), currency(b"EUR"));
+        let price = oracle.latest_fresh_price(&xrp, &usd, 100).unwrap().unwrap();
+        assert_eq!((price.asset_price, price.scale), (Some(74), 2));
+        assert_eq!(oracle.latest_fresh_price(&xrp, &usd, 99).unwrap(), None);
+        assert_eq!(oracle.latest_fresh_price(&xrp, &eur, 100).unwrap(), None);
+        assert_eq!(oracle.latest_fresh_price(&usd, &xrp, 100).unwrap(), None);
+    }
+}
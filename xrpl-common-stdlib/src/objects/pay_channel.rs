@@ -0,0 +1,33 @@
+use crate::host::Result;
+use crate::keylets::paychan_keylet;
+use crate::objects::slot::Slot;
+use crate::objects::traits::{LedgerObjectCommonFields, PayChannelFields};
+use crate::types::account_id::AccountID;
+
+/// A cached `PayChannel` (payment channel) ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PayChannel {
+    pub slot_num: i32,
+}
+
+impl PayChannel {
+    /// Caches the channel from `account` to `destination` created with sequence number `seq` in a
+    /// new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the channel has been closed or never existed.
+    pub fn load(account: &AccountID, destination: &AccountID, seq: u32) -> Result<Self> {
+        paychan_keylet(account, destination, seq)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| PayChannel {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for PayChannel {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl PayChannelFields for PayChannel {}
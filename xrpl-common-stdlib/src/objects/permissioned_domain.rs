@@ -0,0 +1,346 @@
+//! Permissioned domains and the credentials that grant membership in them.
+//!
+//! An account is a member of a domain if it owns the domain, or if it holds an accepted,
+//! unexpired `Credential` matching one of the domain's `AcceptedCredentials`. Offers placed in a
+//! domain carry its ID in their `DomainID` field. [`account_in_domain`] and [`offer_in_domain`]
+//! apply the same rules rippled uses for the permissioned DEX, so a domain-restricted escrow can
+//! check them without joining the objects by hand.
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::chain::parent_ledger_time;
+//! use xrpl_common_stdlib::objects::permissioned_domain::account_in_domain;
+//! use xrpl_common_stdlib::objects::slot::SlotPool;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//! use xrpl_common_stdlib::types::uint::Hash256;
+//!
+//! let domain_id = Hash256::from([0x5D; 32]);
+//! let destination = AccountID([0x11; 20]);
+//! let mut pool: SlotPool<2> = SlotPool::new();
+//! let now = parent_ledger_time().unwrap();
+//! let allowed = account_in_domain(&destination, &domain_id, now, &mut pool).unwrap();
+//! # let _ = allowed;
+//! ```
+
+use crate::host::{Error, Result};
+use crate::keylets::{credential_keylet, permissioned_domain_keylet};
+use crate::objects::offer::Offer;
+use crate::objects::slot::{Slot, SlotPool};
+use crate::objects::traits::{
+    CredentialFields, LedgerObjectCommonFields, OfferFields, PermissionedDomainFields,
+};
+use crate::types::account_id::AccountID;
+use crate::types::uint::Hash256;
+
+/// A cached `PermissionedDomain` ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PermissionedDomain {
+    pub slot_num: i32,
+}
+
+impl PermissionedDomain {
+    /// Caches the domain `owner` created with sequence number `seq` in a new host slot.
+    pub fn load(owner: &AccountID, seq: u32) -> Result<Self> {
+        permissioned_domain_keylet(owner, seq).and_then(|keylet| Self::cache(&keylet))
+    }
+
+    /// Caches the domain with ID `domain_id`, e.g. from an offer's `DomainID`, in a new host slot.
+    pub fn load_by_id(domain_id: &Hash256) -> Result<Self> {
+        Self::cache(domain_id.as_bytes())
+    }
+
+    fn cache(keylet: &[u8; 32]) -> Result<Self> {
+        Slot::cache(keylet).map(|slot| PermissionedDomain {
+            slot_num: slot.num(),
+        })
+    }
+
+    /// Returns whether `account` is a member of this domain as of `close_time`, the parent
+    /// ledger's close time.
+    ///
+    /// Credentials are looked up one at a time through `pool`, so this needs a single free slot
+    /// however many credentials the domain accepts.
+    pub fn has_member<const N: usize>(
+        &self,
+        account: &AccountID,
+        close_time: u32,
+        pool: &mut SlotPool<N>,
+    ) -> Result<bool> {
+        match self.get_owner() {
+            Result::Ok(owner) if &owner == account => return Result::Ok(true),
+            Result::Ok(_) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+
+        let count = match self.get_accepted_credentials_count() {
+            Result::Ok(count) => count,
+            Result::Err(e) => return Result::Err(e),
+        };
+        for index in 0..count {
+            let (issuer, credential_type) = match self.get_accepted_credential(index) {
+                Result::Ok(accepted) => accepted,
+                Result::Err(e) => return Result::Err(e),
+            };
+            let keylet = match credential_keylet(account, &issuer, credential_type.as_slice()) {
+                Result::Ok(keylet) => keylet,
+                Result::Err(e) => return Result::Err(e),
+            };
+            let valid = pool.with_object(&keylet, |slot| {
+                let credential = Credential {
+                    slot_num: slot.num(),
+                };
+                credential.is_accepted().and_then(|accepted| {
+                    credential
+                        .is_expired(close_time)
+                        .map(|expired| accepted && !expired)
+                })
+            });
+            match valid {
+                Result::Ok(Result::Ok(true)) => return Result::Ok(true),
+                Result::Ok(Result::Ok(false)) | Result::Err(Error::LedgerObjNotFound) => {}
+                Result::Ok(Result::Err(e)) | Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Ok(false)
+    }
+}
+
+impl LedgerObjectCommonFields for PermissionedDomain {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl PermissionedDomainFields for PermissionedDomain {}
+
+/// A cached `Credential` ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Credential {
+    pub slot_num: i32,
+}
+
+impl Credential {
+    /// Caches the credential of `credential_type` that `issuer` issued to `subject` in a new host
+    /// slot.
+    pub fn load(subject: &AccountID, issuer: &AccountID, credential_type: &[u8]) -> Result<Self> {
+        credential_keylet(subject, issuer, credential_type)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Credential {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for Credential {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl CredentialFields for Credential {}
+
+/// Returns whether `account` is a member of the domain `domain_id` as of `close_time`.
+///
+/// Returns `Ok(false)` if the domain does not exist. The domain itself takes one slot from
+/// `pool` for the duration of the call, and credentials another, so `pool` needs two free slots.
+pub fn account_in_domain<const N: usize>(
+    account: &AccountID,
+    domain_id: &Hash256,
+    close_time: u32,
+    pool: &mut SlotPool<N>,
+) -> Result<bool> {
+    let slot = match pool.acquire(domain_id.as_bytes()) {
+        Result::Ok(slot) => slot,
+        Result::Err(Error::LedgerObjNotFound) => return Result::Ok(false),
+        Result::Err(e) => return Result::Err(e),
+    };
+    let domain = PermissionedDomain {
+        slot_num: slot.num(),
+    };
+    let result = domain.has_member(account, close_time, pool);
+    pool.release(slot);
+    result
+}
+
+/// Returns whether `offer` trades in the domain `domain_id` and its owner is still a member.
+///
+/// Needs two free slots in `pool`, like [`account_in_domain`].
+pub fn offer_in_domain<const N: usize>(
+    offer: &Offer,
+    domain_id: &Hash256,
+    close_time: u32,
+    pool: &mut SlotPool<N>,
+) -> Result<bool> {
+    match offer.get_domain_id() {
+        Result::Ok(Some(offer_domain)) if &offer_domain == domain_id => {}
+        Result::Ok(_) => return Result::Ok(false),
+        Result::Err(e) => return Result::Err(e),
+    }
+    offer
+        .get_account()
+        .and_then(|owner| account_in_domain(&owner, domain_id, close_time, pool))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::LEDGER_OBJ_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::traits::LSF_CREDENTIAL_ACCEPTED;
+    use crate::sfield;
+    use mockall::predicate::{always, eq};
+
+    const DOMAIN_SLOT: i32 = 1;
+    const CREDENTIAL_SLOT: i32 = 2;
+    const OWNER: [u8; 20] = [0x0A; 20];
+    const MEMBER: [u8; 20] = [0x0B; 20];
+    const ISSUER: [u8; 20] = [0x0C; 20];
+
+    fn write(out: *mut u8, bytes: &[u8]) -> i32 {
+        unsafe { out.copy_from_nonoverlapping(bytes.as_ptr(), bytes.len()) };
+        bytes.len() as i32
+    }
+
+    /// A domain owned by `OWNER` that accepts one `KYC` credential from `ISSUER`, cached in
+    /// `DOMAIN_SLOT`.
+    fn expect_domain(mock: &mut MockHostBindings) {
+        mock.expect_cache_ledger_obj()
+            .withf(|ptr, len, _| unsafe { core::slice::from_raw_parts(*ptr, *len) } == [0x5D; 32])
+            .times(1)
+            .returning(|_, _, _| DOMAIN_SLOT);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(DOMAIN_SLOT),
+                eq(i32::from(sfield::Owner)),
+                always(),
+                always(),
+            )
+            .returning(|_, _, out, _| write(out, &OWNER));
+        mock.expect_get_ledger_obj_array_len()
+            .with(eq(DOMAIN_SLOT), eq(i32::from(sfield::AcceptedCredentials)))
+            .returning(|_, _| 1);
+        mock.expect_get_ledger_obj_nested_field()
+            .with(eq(DOMAIN_SLOT), always(), always(), always(), always())
+            .returning(|_, locator, len, out, _| {
+                let last = unsafe { core::slice::from_raw_parts(locator.add(len - 4), 4) };
+                if i32::from_le_bytes(last.try_into().unwrap()) == i32::from(sfield::Issuer) {
+                    write(out, &ISSUER)
+                } else {
+                    write(out, b"KYC")
+                }
+            });
+        mock.expect_credential_keylet()
+            .returning(|_, _, _, _, _, _, out, _| write(out, &[0xCE; 32]));
+    }
+
+    /// A credential in `CREDENTIAL_SLOT` with the given flags and expiration.
+    fn expect_credential(mock: &mut MockHostBindings, flags: u32, expiration: Option<u32>) {
+        mock.expect_cache_ledger_obj()
+            .withf(|ptr, len, _| unsafe { core::slice::from_raw_parts(*ptr, *len) } == [0xCE; 32])
+            .times(1)
+            .returning(|_, _, _| CREDENTIAL_SLOT);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(CREDENTIAL_SLOT),
+                eq(i32::from(sfield::Flags)),
+                always(),
+                always(),
+            )
+            .returning(move |_, _, out, _| write(out, &flags.to_le_bytes()));
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(CREDENTIAL_SLOT),
+                eq(i32::from(sfield::Expiration)),
+                always(),
+                always(),
+            )
+            .returning(move |_, _, out, _| match expiration {
+                Some(expiration) => write(out, &expiration.to_le_bytes()),
+                None => crate::host::error_codes::FIELD_NOT_FOUND,
+            });
+    }
+
+    fn in_domain(account: [u8; 20], close_time: u32) -> bool {
+        let mut pool: SlotPool<2> = SlotPool::new();
+        account_in_domain(
+            &AccountID(account),
+            &Hash256::from([0x5D; 32]),
+            close_time,
+            &mut pool,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_owner_is_member() {
+        let mut mock = MockHostBindings::new();
+        expect_domain(&mut mock);
+        let _guard = setup_mock(mock);
+
+        assert!(in_domain(OWNER, 100));
+    }
+
+    #[test]
+    fn test_accepted_unexpired_credential_grants_membership() {
+        let mut mock = MockHostBindings::new();
+        expect_domain(&mut mock);
+        expect_credential(&mut mock, LSF_CREDENTIAL_ACCEPTED, Some(100));
+        let _guard = setup_mock(mock);
+
+        assert!(in_domain(MEMBER, 100));
+    }
+
+    #[test]
+    fn test_expired_or_unaccepted_credential_does_not() {
+        {
+            let mut mock = MockHostBindings::new();
+            expect_domain(&mut mock);
+            expect_credential(&mut mock, LSF_CREDENTIAL_ACCEPTED, Some(99));
+            let _guard = setup_mock(mock);
+            assert!(!in_domain(MEMBER, 100));
+        }
+        {
+            let mut mock = MockHostBindings::new();
+            expect_domain(&mut mock);
+            expect_credential(&mut mock, 0, None);
+            let _guard = setup_mock(mock);
+            assert!(!in_domain(MEMBER, 100));
+        }
+    }
+
+    #[test]
+    fn test_missing_credential_or_domain_is_not_membership() {
+        {
+            let mut mock = MockHostBindings::new();
+            expect_domain(&mut mock);
+            mock.expect_cache_ledger_obj()
+                .times(1)
+                .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+            let _guard = setup_mock(mock);
+            assert!(!in_domain(MEMBER, 100));
+        }
+        {
+            let mut mock = MockHostBindings::new();
+            mock.expect_cache_ledger_obj()
+                .times(1)
+                .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+            let _guard = setup_mock(mock);
+            assert!(!in_domain(OWNER, 100));
+        }
+    }
+
+    #[test]
+    fn test_offer_in_other_domain_is_rejected_without_lookup() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(7), eq(i32::from(sfield::DomainID)), always(), always())
+            .times(1)
+            .returning(|_, _, out, _| write(out, &[0x99; 32]));
+        let _guard = setup_mock(mock);
+
+        let mut pool: SlotPool<2> = SlotPool::new();
+        let offer = Offer { slot_num: 7 };
+        let result = offer_in_domain(&offer, &Hash256::from([0x5D; 32]), 100, &mut pool);
+        assert!(!result.unwrap());
+    }
+}
@@ -0,0 +1,270 @@
+//! Account reserves.
+//!
+//! An account must keep a base reserve of XRP, plus an owner reserve for each object it owns
+//! (its `OwnerCount`). Both amounts are set by validator voting and stored in the `FeeSettings`
+//! ledger object, which [`reserves`] reads. A contract can use them to check that an account can
+//! take on another object without dropping below its reserve:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::objects::reserves::{ReserveParams, can_own_more, reserves};
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! let account = AccountID([0x2B; 20]);
+//! let params = reserves().unwrap_or(ReserveParams::MAINNET);
+//! let ok = can_own_more(&account, 1, &params).unwrap_or(false);
+//! # let _ = ok;
+//! ```
+//!
+//! The objects are read through [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT), so no cache
+//! slots are consumed.
+
+use crate::host::{Error, Result};
+use crate::keylets::{KeyletBytes, account_keylet};
+use crate::objects::slot::cache_scratch;
+use crate::objects::{LedgerObjectFieldGetter, ledger_object};
+use crate::sfield;
+use crate::types::account_id::AccountID;
+use crate::types::amount::Amount;
+
+/// Ledger entry ID of the `FeeSettings` singleton, which is the same on every network.
+pub const FEE_SETTINGS_KEYLET: KeyletBytes = [
+    0x4B, 0xC5, 0x0C, 0x9B, 0x0D, 0x85, 0x15, 0xD3, 0xEA, 0xAE, 0x1E, 0x74, 0xB2, 0x9A, 0x95, 0x80,
+    0x43, 0x46, 0xC4, 0x91, 0xEE, 0x1A, 0x95, 0xBF, 0x25, 0xE4, 0xAA, 0xB8, 0x54, 0xA6, 0xA6, 0x51,
+];
+
+/// Maximum number of entries in one page of an owner directory.
+pub const DIR_NODE_MAX_ENTRIES: u32 = 32;
+
+/// The reserve requirements in force on a ledger, in drops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReserveParams {
+    /// XRP every account must hold, regardless of what it owns.
+    pub base: u64,
+    /// Additional XRP required for each object the account owns.
+    pub increment: u64,
+}
+
+impl ReserveParams {
+    /// Mainnet's reserves since the December 2024 fee vote: 1 XRP base, 0.2 XRP per object.
+    ///
+    /// Use this only as a fallback when [`reserves`] fails; the ledger's own values are
+    /// authoritative and change whenever validators vote new ones.
+    pub const MAINNET: ReserveParams = ReserveParams {
+        base: 1_000_000,
+        increment: 200_000,
+    };
+
+    /// Same as [`owner_count_reserve`] with these parameters.
+    pub const fn for_owner_count(&self, owner_count: u32) -> u64 {
+        owner_count_reserve(owner_count, self)
+    }
+}
+
+/// Returns the XRP, in drops, an account owning `owner_count` objects must keep: the base
+/// reserve plus one increment per object. Saturates at `u64::MAX` rather than overflowing.
+pub const fn owner_count_reserve(owner_count: u32, params: &ReserveParams) -> u64 {
+    params
+        .base
+        .saturating_add(params.increment.saturating_mul(owner_count as u64))
+}
+
+/// Returns the fewest pages an owner directory holding `owner_count` entries can span.
+///
+/// This is a lower bound: pages are not compacted when entries are removed, so a directory that
+/// has shrunk may span more.
+pub const fn owner_directory_pages(owner_count: u32) -> u32 {
+    if owner_count == 0 {
+        1
+    } else {
+        owner_count.div_ceil(DIR_NODE_MAX_ENTRIES)
+    }
+}
+
+/// Reads the current reserves from the `FeeSettings` ledger object.
+///
+/// Ledgers with the XRPFees amendment store them as `ReserveBaseDrops` and
+/// `ReserveIncrementDrops`; older ledgers use the 32-bit `ReserveBase` and `ReserveIncrement`.
+/// Both layouts are accepted.
+///
+/// # Errors
+///
+/// * `LedgerObjNotFound` - If the ledger has no `FeeSettings` object
+/// * `InvalidParams` - If a `...Drops` field holds something other than XRP
+/// * Any error from caching the object or reading its fields
+pub fn reserves() -> Result<ReserveParams> {
+    cache_scratch(&FEE_SETTINGS_KEYLET).and_then(|slot| read_reserves(slot.num()))
+}
+
+/// Reads the reserves from the `FeeSettings` object cached in `slot`.
+pub(crate) fn read_reserves(slot: i32) -> Result<ReserveParams> {
+    let base = match read_drops(slot, sfield::ReserveBaseDrops, sfield::ReserveBase) {
+        Result::Ok(drops) => drops,
+        Result::Err(e) => return Result::Err(e),
+    };
+    read_drops(
+        slot,
+        sfield::ReserveIncrementDrops,
+        sfield::ReserveIncrement,
+    )
+    .map(|increment| ReserveParams { base, increment })
+}
+
+/// Reads an XRP amount from `drops_field`, falling back to the pre-XRPFees `legacy_field`.
+pub(crate) fn read_drops<
+    T: LedgerObjectFieldGetter + Into<u64>,
+    const DROPS: i32,
+    const LEGACY: i32,
+>(
+    slot: i32,
+    drops_field: sfield::SField<Amount, DROPS>,
+    legacy_field: sfield::SField<T, LEGACY>,
+) -> Result<u64> {
+    match ledger_object::get_field_optional(slot, drops_field) {
+        Result::Ok(Some(Amount::XRP { num_drops })) if num_drops >= 0 => {
+            Result::Ok(num_drops as u64)
+        }
+        Result::Ok(Some(_)) => Result::Err(Error::InvalidParams),
+        // Variable-size optional reads report an absent field as `FieldNotFound`, not `None`.
+        Result::Ok(None) | Result::Err(Error::FieldNotFound) => {
+            ledger_object::get_field(slot, legacy_field).map(Into::into)
+        }
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Returns whether `account` holds enough XRP to own `additional` more objects without
+/// dropping below its reserve under `params`.
+///
+/// Only the reserve is checked; XRP the account is about to spend (such as a transaction fee or
+/// an amount it is sending) is not subtracted.
+///
+/// # Errors
+///
+/// * `LedgerObjNotFound` - If `account` does not exist
+/// * Any error from computing the keylet or reading `Balance` and `OwnerCount`
+pub fn can_own_more(account: &AccountID, additional: u32, params: &ReserveParams) -> Result<bool> {
+    let slot = match account_keylet(account).and_then(|keylet| cache_scratch(&keylet)) {
+        Result::Ok(slot) => slot.num(),
+        Result::Err(e) => return Result::Err(e),
+    };
+    let owner_count: u32 = match ledger_object::get_field(slot, sfield::OwnerCount) {
+        Result::Ok(count) => count,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let balance = match ledger_object::get_field(slot, sfield::Balance) {
+        Result::Ok(Amount::XRP { num_drops }) => num_drops,
+        Result::Ok(_) => return Result::Err(Error::InvalidParams),
+        Result::Err(e) => return Result::Err(e),
+    };
+    let required = owner_count_reserve(owner_count.saturating_add(additional), params);
+    Result::Ok(balance >= 0 && balance as u64 >= required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::slot::SCRATCH_SLOT;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_owner_count_reserve() {
+        let params = ReserveParams::MAINNET;
+        assert_eq!(owner_count_reserve(0, &params), 1_000_000);
+        assert_eq!(params.for_owner_count(3), 1_600_000);
+        let huge = ReserveParams {
+            base: 1,
+            increment: u64::MAX,
+        };
+        assert_eq!(owner_count_reserve(2, &huge), u64::MAX);
+        assert_eq!(owner_directory_pages(0), 1);
+        assert_eq!(owner_directory_pages(32), 1);
+        assert_eq!(owner_directory_pages(33), 2);
+    }
+
+    fn write_xrp(out: *mut u8, drops: u64) -> i32 {
+        let bytes = (drops | 0x4000_0000_0000_0000).to_be_bytes();
+        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 8) };
+        8
+    }
+
+    #[test]
+    fn test_reserves_reads_either_layout() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .withf(|keylet, len, _| unsafe {
+                core::slice::from_raw_parts(*keylet, *len) == FEE_SETTINGS_KEYLET
+            })
+            .returning(|_, _, _| SCRATCH_SLOT);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::ReserveBaseDrops)),
+                always(),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, out, _| write_xrp(out, 1_000_000));
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::ReserveIncrementDrops)),
+                always(),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, _, _| FIELD_NOT_FOUND);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::ReserveIncrement)),
+                always(),
+                eq(4),
+            )
+            .times(1)
+            .returning(|_, _, out, _| {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(200_000u32.to_ne_bytes().as_ptr(), out, 4)
+                };
+                4
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(reserves().unwrap(), ReserveParams::MAINNET);
+    }
+
+    #[test]
+    fn test_can_own_more() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_account_keylet().returning(|_, _, _, _| 32);
+        mock.expect_cache_ledger_obj()
+            .returning(|_, _, _| SCRATCH_SLOT);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::OwnerCount)),
+                always(),
+                eq(4),
+            )
+            .returning(|_, _, out, _| {
+                unsafe { core::ptr::copy_nonoverlapping(2u32.to_ne_bytes().as_ptr(), out, 4) };
+                4
+            });
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::Balance)),
+                always(),
+                always(),
+            )
+            .returning(|_, _, out, _| write_xrp(out, 1_600_000));
+        let _guard = setup_mock(mock);
+
+        let account = AccountID([0x2B; 20]);
+        let params = ReserveParams::MAINNET;
+        assert!(can_own_more(&account, 1, &params).unwrap());
+        assert!(!can_own_more(&account, 2, &params).unwrap());
+    }
+}
@@ -0,0 +1,354 @@
+//! The ledger's singleton objects: `FeeSettings`, `Amendments` and `NegativeUNL`.
+//!
+//! Each network has at most one of each, at a fixed ledger index, holding parameters the
+//! validators vote on. Reading them lets a contract follow the network's actual fees, enabled
+//! amendments and disabled validators instead of hard-coding mainnet's:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::objects::singletons::fee_settings;
+//!
+//! let settings = fee_settings().unwrap();
+//! let cost = settings.base_fee + settings.reserves.increment;
+//! # let _ = cost;
+//! ```
+//!
+//! [`fee_settings`] reads through [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT);
+//! [`Amendments`] and [`NegativeUnl`] keep their own slots so their entries can be read one at a
+//! time, without copying the whole list onto the stack.
+
+use crate::fields::locator::{Locator, LocatorEntry};
+use crate::host::{Error, Result};
+use crate::keylets::KeyletBytes;
+use crate::objects::array_object::Array;
+use crate::objects::ledger_object;
+pub use crate::objects::reserves::FEE_SETTINGS_KEYLET;
+use crate::objects::reserves::{ReserveParams, read_drops, read_reserves};
+use crate::objects::slot::{Slot, cache_scratch};
+use crate::sfield::{self, SField};
+use crate::types::blob::PublicKeyBlob;
+use crate::types::uint::Hash256;
+
+/// Ledger entry ID of the `Amendments` singleton, which is the same on every network.
+pub const AMENDMENTS_KEYLET: KeyletBytes = [
+    0x7D, 0xB0, 0x78, 0x8C, 0x02, 0x0F, 0x02, 0x78, 0x0A, 0x67, 0x3D, 0xC7, 0x47, 0x57, 0xF2, 0x38,
+    0x23, 0xFA, 0x30, 0x14, 0xC1, 0x86, 0x6E, 0x72, 0xCC, 0x4C, 0xD8, 0xB2, 0x26, 0xCD, 0x6E, 0xF4,
+];
+
+/// Ledger entry ID of the `NegativeUNL` singleton, which is the same on every network.
+pub const NEGATIVE_UNL_KEYLET: KeyletBytes = [
+    0x2E, 0x8A, 0x59, 0xAA, 0x9D, 0x3B, 0x5B, 0x18, 0x6B, 0x0B, 0x9E, 0x0F, 0x62, 0xE6, 0xC0, 0x25,
+    0x87, 0xCA, 0x74, 0xA4, 0xD7, 0x78, 0x93, 0x8E, 0x95, 0x7B, 0x63, 0x57, 0xD3, 0x64, 0xB2, 0x44,
+];
+
+/// The transaction cost and reserves in force on a ledger, in drops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSettings {
+    /// The cost of a reference transaction.
+    pub base_fee: u64,
+    /// The account reserves. See [`reserves`](crate::objects::reserves).
+    pub reserves: ReserveParams,
+}
+
+/// Reads the `FeeSettings` object.
+///
+/// Like [`reserves`](crate::objects::reserves::reserves), both the XRPFees layout
+/// (`BaseFeeDrops`, ...) and the older one (`BaseFee`, ...) are accepted.
+///
+/// # Errors
+///
+/// * `LedgerObjNotFound` - If the ledger has no `FeeSettings` object
+/// * `InvalidParams` - If a `...Drops` field holds something other than XRP
+/// * Any error from caching the object or reading its fields
+pub fn fee_settings() -> Result<FeeSettings> {
+    let slot = match cache_scratch(&FEE_SETTINGS_KEYLET) {
+        Result::Ok(slot) => slot.num(),
+        Result::Err(e) => return Result::Err(e),
+    };
+    let base_fee = match read_drops(slot, sfield::BaseFeeDrops, sfield::BaseFee) {
+        Result::Ok(drops) => drops,
+        Result::Err(e) => return Result::Err(e),
+    };
+    read_reserves(slot).map(|reserves| FeeSettings { base_fee, reserves })
+}
+
+/// A cached `Amendments` object: the IDs of the amendments enabled on this ledger.
+///
+/// The IDs are read one at a time; to check a single amendment,
+/// [`amendment_enabled`](crate::host::chain::amendment_enabled) is cheaper still.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Amendments {
+    pub slot_num: i32,
+}
+
+impl Amendments {
+    /// Caches the `Amendments` object in a new host slot, or returns `Ok(None)` if the ledger
+    /// has none, which means no amendment is enabled.
+    pub fn load() -> Result<Option<Self>> {
+        match Slot::cache(&AMENDMENTS_KEYLET) {
+            Result::Ok(slot) => Result::Ok(Some(Amendments {
+                slot_num: slot.num(),
+            })),
+            Result::Err(Error::LedgerObjNotFound) => Result::Ok(None),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Returns how many amendments are enabled.
+    pub fn enabled_count(&self) -> Result<usize> {
+        match ledger_object::array_len(self.slot_num, AMENDMENTS) {
+            Result::Err(Error::FieldNotFound) => Result::Ok(0),
+            other => other,
+        }
+    }
+
+    /// Returns the ID of the enabled amendment at `index`.
+    pub fn enabled_amendment(&self, index: usize) -> Result<Hash256> {
+        let mut locator = Locator::new();
+        locator
+            .pack(sfield::Amendments)
+            .and_then(|_| locator.pack(LocatorEntry::Index(index as i32)))
+            .and_then(|_| ledger_object::get_nested_field(self.slot_num, &locator))
+    }
+
+    /// Returns whether the amendment with ID `id` is enabled.
+    pub fn is_enabled(&self, id: &Hash256) -> Result<bool> {
+        let count = match self.enabled_count() {
+            Result::Ok(count) => count,
+            Result::Err(e) => return Result::Err(e),
+        };
+        for index in 0..count {
+            match self.enabled_amendment(index) {
+                Result::Ok(enabled) if enabled == *id => return Result::Ok(true),
+                Result::Ok(_) => {}
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Ok(false)
+    }
+}
+
+/// A cached `NegativeUNL` object: the trusted validators the network currently treats as
+/// offline when counting quorum.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NegativeUnl {
+    pub slot_num: i32,
+}
+
+impl NegativeUnl {
+    /// Caches the `NegativeUNL` object in a new host slot, or returns `Ok(None)` if the ledger
+    /// has none, which means no validator is disabled.
+    pub fn load() -> Result<Option<Self>> {
+        match Slot::cache(&NEGATIVE_UNL_KEYLET) {
+            Result::Ok(slot) => Result::Ok(Some(NegativeUnl {
+                slot_num: slot.num(),
+            })),
+            Result::Err(Error::LedgerObjNotFound) => Result::Ok(None),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Returns how many validators are disabled.
+    pub fn disabled_count(&self) -> Result<usize> {
+        match ledger_object::array_len(self.slot_num, sfield::DisabledValidators) {
+            Result::Err(Error::FieldNotFound) => Result::Ok(0),
+            other => other,
+        }
+    }
+
+    /// Returns the master public key of the disabled validator at `index`, and the ledger
+    /// sequence from which it has been disabled.
+    pub fn disabled_validator(&self, index: usize) -> Result<(PublicKeyBlob, u32)> {
+        let mut locator = Locator::new();
+        let public_key = match locator
+            .pack(sfield::DisabledValidators)
+            .and_then(|_| locator.pack(LocatorEntry::Index(index as i32)))
+            .and_then(|_| locator.pack(sfield::DisabledValidator))
+            .and_then(|_| locator.pack(sfield::PublicKey))
+            .and_then(|_| ledger_object::get_nested_field(self.slot_num, &locator))
+        {
+            Result::Ok(key) => key,
+            Result::Err(e) => return Result::Err(e),
+        };
+        locator
+            .repack_last(sfield::FirstLedgerSequence)
+            .and_then(|_| ledger_object::get_nested_field(self.slot_num, &locator))
+            .map(|seq| (public_key, seq))
+    }
+
+    /// Returns whether the validator with master public key `public_key` is disabled.
+    pub fn is_disabled(&self, public_key: &[u8]) -> Result<bool> {
+        let count = match self.disabled_count() {
+            Result::Ok(count) => count,
+            Result::Err(e) => return Result::Err(e),
+        };
+        for index in 0..count {
+            match self.disabled_validator(index) {
+                Result::Ok((key, _)) if key.as_slice() == public_key => return Result::Ok(true),
+                Result::Ok(_) => {}
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Ok(false)
+    }
+
+    /// The validator the network will disable at the next flag ledger, if any.
+    pub fn validator_to_disable(&self) -> Result<Option<PublicKeyBlob>> {
+        ledger_object::get_field_optional(self.slot_num, VALIDATOR_TO_DISABLE)
+    }
+
+    /// The validator the network will re-enable at the next flag ledger, if any.
+    pub fn validator_to_re_enable(&self) -> Result<Option<PublicKeyBlob>> {
+        ledger_object::get_field_optional(self.slot_num, VALIDATOR_TO_RE_ENABLE)
+    }
+}
+
+// The generated constants read `Amendments` as a whole into a 32-entry buffer, where its length
+// is wanted here, and the validator fields as `StandardBlob` although they only ever hold a
+// public key.
+const AMENDMENTS: SField<Array, 1245187> = SField::new();
+const VALIDATOR_TO_DISABLE: SField<PublicKeyBlob, 458772> = SField::new();
+const VALIDATOR_TO_RE_ENABLE: SField<PublicKeyBlob, 458773> = SField::new();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{FIELD_NOT_FOUND, LEDGER_OBJ_NOT_FOUND};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::slot::SCRATCH_SLOT;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_retyped_fields_keep_their_codes() {
+        assert_eq!(i32::from(AMENDMENTS), i32::from(sfield::Amendments));
+        assert_eq!(
+            i32::from(VALIDATOR_TO_DISABLE),
+            i32::from(sfield::ValidatorToDisable)
+        );
+        assert_eq!(
+            i32::from(VALIDATOR_TO_RE_ENABLE),
+            i32::from(sfield::ValidatorToReEnable)
+        );
+    }
+
+    fn write_xrp(out: *mut u8, drops: u64) -> i32 {
+        let bytes = (drops | 0x4000_0000_0000_0000).to_be_bytes();
+        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 8) };
+        8
+    }
+
+    #[test]
+    fn test_fee_settings_reads_fee_and_reserves_from_one_slot() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .withf(|keylet, len, _| unsafe {
+                core::slice::from_raw_parts(*keylet, *len) == FEE_SETTINGS_KEYLET
+            })
+            .times(1)
+            .returning(|_, _, _| SCRATCH_SLOT);
+        mock.expect_get_ledger_obj_field()
+            .with(eq(SCRATCH_SLOT), always(), always(), always())
+            .returning(|_, field, out, _| {
+                if field == i32::from(sfield::BaseFeeDrops) {
+                    write_xrp(out, 10)
+                } else if field == i32::from(sfield::ReserveBaseDrops) {
+                    write_xrp(out, 1_000_000)
+                } else if field == i32::from(sfield::ReserveIncrementDrops) {
+                    write_xrp(out, 200_000)
+                } else {
+                    FIELD_NOT_FOUND
+                }
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            fee_settings().unwrap(),
+            FeeSettings {
+                base_fee: 10,
+                reserves: ReserveParams::MAINNET,
+            }
+        );
+    }
+
+    #[test]
+    fn test_amendments_are_read_one_at_a_time() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .withf(|keylet, len, cache_num| {
+                *cache_num == 0
+                    && unsafe { core::slice::from_raw_parts(*keylet, *len) } == AMENDMENTS_KEYLET
+            })
+            .returning(|_, _, _| 5);
+        mock.expect_get_ledger_obj_array_len()
+            .with(eq(5), eq(i32::from(sfield::Amendments)))
+            .returning(|_, _| 2);
+        mock.expect_get_ledger_obj_nested_field()
+            .with(eq(5), always(), always(), always(), eq(32))
+            .returning(|_, locator, locator_len, out, _| {
+                let path = unsafe { core::slice::from_raw_parts(locator, locator_len) };
+                let index = i32::from_le_bytes(path[4..8].try_into().unwrap()) as u8;
+                unsafe { core::ptr::write_bytes(out, 0x01 + index, 32) };
+                32
+            });
+        let _guard = setup_mock(mock);
+
+        let amendments = Amendments::load().unwrap().unwrap();
+        assert_eq!(amendments.enabled_count().unwrap(), 2);
+        assert_eq!(
+            amendments.enabled_amendment(1).unwrap(),
+            Hash256::from([0x02; 32])
+        );
+        assert!(amendments.is_enabled(&[0x02; 32].into()).unwrap());
+        assert!(!amendments.is_enabled(&[0x03; 32].into()).unwrap());
+    }
+
+    #[test]
+    fn test_no_amendments_object_means_none_enabled() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(Amendments::load().unwrap(), None);
+        assert_eq!(NegativeUnl::load().unwrap(), None);
+    }
+
+    #[test]
+    fn test_negative_unl_lists_disabled_validators() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .withf(|keylet, len, cache_num| {
+                *cache_num == 0
+                    && unsafe { core::slice::from_raw_parts(*keylet, *len) } == NEGATIVE_UNL_KEYLET
+            })
+            .returning(|_, _, _| 6);
+        mock.expect_get_ledger_obj_array_len()
+            .with(eq(6), eq(i32::from(sfield::DisabledValidators)))
+            .returning(|_, _| 2);
+        mock.expect_get_ledger_obj_nested_field()
+            .with(eq(6), always(), always(), always(), always())
+            .returning(|_, locator, locator_len, out, _| {
+                let path = unsafe { core::slice::from_raw_parts(locator, locator_len) };
+                let word =
+                    |i: usize| i32::from_le_bytes(path[i * 4..i * 4 + 4].try_into().unwrap());
+                let index = word(1) as u8;
+                if word(3) == i32::from(sfield::PublicKey) {
+                    unsafe { core::ptr::write_bytes(out, 0xED + index, 33) };
+                    33
+                } else {
+                    let seq = (1000 + index as u32).to_le_bytes();
+                    unsafe { core::ptr::copy_nonoverlapping(seq.as_ptr(), out, 4) };
+                    4
+                }
+            });
+        let _guard = setup_mock(mock);
+
+        let unl = NegativeUnl::load().unwrap().unwrap();
+        assert_eq!(unl.disabled_count().unwrap(), 2);
+        let (key, since) = unl.disabled_validator(1).unwrap();
+        assert_eq!(key.as_slice(), &[0xEE; 33]);
+        assert_eq!(since, 1001);
+        assert!(unl.is_disabled(&[0xED; 33]).unwrap());
+        assert!(!unl.is_disabled(&[0xEF; 33]).unwrap());
+    }
+}
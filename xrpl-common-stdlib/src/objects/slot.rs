@@ -0,0 +1,442 @@
+//! Handles to the host's ledger-object cache.
+//!
+//! The host keeps up to [`MAX_CACHE_SLOTS`] ledger objects cached per execution. Each call to
+//! `cache_ledger_obj` with a `cache_num` of 0 consumes a new slot, so a loop that caches one
+//! object per iteration eventually fails with `SlotsFull`. Passing a non-zero `cache_num`
+//! instead overwrites that slot in place; [`Slot::replace_with`] exposes this for a single handle.
+//!
+//! [`SlotPool`] builds on this: it remembers every slot it has obtained from the host and hands
+//! released slots back out by overwriting them, so the number of host slots in use is bounded
+//! by the pool's capacity rather than by the number of objects visited.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::keylets::account_keylet;
+//! use xrpl_common_stdlib::objects::slot::SlotPool;
+//! use xrpl_common_stdlib::objects::traits::LedgerObjectCommonFields;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//!
+//! let accounts = [AccountID([1; 20]), AccountID([2; 20])];
+//! let mut pool: SlotPool<1> = SlotPool::new();
+//! for account in &accounts {
+//!     let keylet = account_keylet(account).unwrap();
+//!     // Both iterations reuse the same host slot.
+//!     let flags = pool.with_object(&keylet, |obj| obj.get_flags()).unwrap();
+//!     # let _ = flags;
+//! }
+//! ```
+
+use crate::host::{Error, Result, cache_ledger_obj};
+use crate::keylets::KeyletBytes;
+use crate::objects::traits::LedgerObjectCommonFields;
+
+/// Maximum number of ledger objects the host can cache at once.
+pub const MAX_CACHE_SLOTS: usize = 255;
+
+/// The slot [`exists`] overwrites on every call.
+///
+/// It is the highest slot the host offers, which `cache_ledger_obj` with a `cache_num` of 0 only
+/// hands out once every other slot is taken. Don't keep a [`Slot`] with this number across a call
+/// to [`exists`].
+pub const SCRATCH_SLOT: i32 = MAX_CACHE_SLOTS as i32;
+
+/// Returns whether a ledger object exists for `keylet`, without consuming a cache slot.
+///
+/// The host has no dedicated existence check, so the object is cached into [`SCRATCH_SLOT`],
+/// replacing whatever was there; the gas cost is that of `cache_ledger_obj`.
+///
+/// # Returns
+///
+/// * `Ok(true)` - An object exists for `keylet`
+/// * `Ok(false)` - The host reported `LedgerObjNotFound`
+/// * `Err(Error)` - Any other host error
+pub fn exists(keylet: &KeyletBytes) -> Result<bool> {
+    match cache_scratch(keylet) {
+        Result::Ok(_) => Result::Ok(true),
+        Result::Err(Error::LedgerObjNotFound) => Result::Ok(false),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// A ledger object cached by the host, identified by its slot (cache) number.
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this 4-byte struct, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slot {
+    slot_num: i32,
+}
+
+impl Slot {
+    /// Caches the ledger object identified by `keylet` in a new host slot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Slot)` - A handle to the newly cached object
+    /// * `Err(Error)` - `SlotsFull` if the host cache is exhausted, `LedgerObjNotFound` if no
+    ///   object exists for `keylet`, or another host error
+    pub fn cache(keylet: &KeyletBytes) -> Result<Slot> {
+        cache_into(keylet, 0)
+    }
+
+    /// Overwrites this slot with the ledger object identified by `keylet`.
+    ///
+    /// The host evicts the previously cached object and caches the new one under the same slot
+    /// number, so no additional slot is consumed. After a successful call, every field read
+    /// through this handle (or any copy of it) returns data from the new object; values already
+    /// read from the old object are unaffected.
+    ///
+    /// If the host rejects the replacement (for example because no object exists for `keylet`),
+    /// the slot's contents are unspecified and it should be replaced again before further reads.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The slot now holds the object identified by `keylet`
+    /// * `Err(Error)` - `LedgerObjNotFound` if no object exists for `keylet`, or another host
+    ///   error
+    pub fn replace_with(&mut self, keylet: &KeyletBytes) -> Result<()> {
+        cache_into(keylet, self.slot_num).and_then(|slot| {
+            if slot.slot_num == self.slot_num {
+                Result::Ok(())
+            } else {
+                // The host is expected to reuse the requested slot; anything else means the
+                // handle would silently point at a different cache entry.
+                Result::Err(Error::InternalError)
+            }
+        })
+    }
+
+    /// Returns the host slot number of this object.
+    #[inline]
+    pub fn num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl LedgerObjectCommonFields for Slot {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+/// Caches the object for `keylet` in [`SCRATCH_SLOT`], for reads that finish before the next
+/// scratch use. Like [`exists`], this consumes no slot.
+pub(crate) fn cache_scratch(keylet: &KeyletBytes) -> Result<Slot> {
+    cache_into(keylet, SCRATCH_SLOT)
+}
+
+/// Calls `cache_ledger_obj` and wraps a successful (positive) result in a [`Slot`].
+fn cache_into(keylet: &KeyletBytes, cache_num: i32) -> Result<Slot> {
+    let result_code = unsafe { cache_ledger_obj(keylet.as_ptr(), keylet.len(), cache_num) };
+    match result_code {
+        code if code > 0 => Result::Ok(Slot { slot_num: code }),
+        0 => Result::Err(Error::SlotOutRange),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// A fixed-capacity pool of reusable host cache slots.
+///
+/// The pool allocates at most `N` slots from the host. Once a slot is [released](Self::release),
+/// the next [`acquire`](Self::acquire) overwrites it instead of allocating a new one.
+///
+/// ## Derived Traits
+///
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone)]
+pub struct SlotPool<const N: usize> {
+    /// Slot numbers obtained from the host, in allocation order. Only the first `allocated`
+    /// entries are meaningful.
+    slots: [i32; N],
+    in_use: [bool; N],
+    allocated: usize,
+}
+
+impl<const N: usize> Default for SlotPool<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> SlotPool<N> {
+    /// Creates an empty pool. No host slots are allocated until the first
+    /// [`acquire`](Self::acquire).
+    pub const fn new() -> Self {
+        Self {
+            slots: [0; N],
+            in_use: [false; N],
+            allocated: 0,
+        }
+    }
+
+    /// Caches the object identified by `keylet`, reusing a released slot if one is available.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Slot)` - A handle to the cached object; it stays valid until released
+    /// * `Err(Error)` - `SlotsFull` if all `N` slots are in use, or any host error
+    pub fn acquire(&mut self, keylet: &KeyletBytes) -> Result<Slot> {
+        if let Some(idx) = (0..self.allocated).find(|&idx| !self.in_use[idx]) {
+            return cache_into(keylet, self.slots[idx]).map(|slot| {
+                self.in_use[idx] = true;
+                slot
+            });
+        }
+
+        if self.allocated == N {
+            return Result::Err(Error::SlotsFull);
+        }
+
+        cache_into(keylet, 0).map(|slot| {
+            self.slots[self.allocated] = slot.num();
+            self.in_use[self.allocated] = true;
+            self.allocated += 1;
+            slot
+        })
+    }
+
+    /// Returns `slot` to the pool so a later [`acquire`](Self::acquire) can overwrite it.
+    ///
+    /// Releasing a slot that was not acquired from this pool has no effect.
+    pub fn release(&mut self, slot: Slot) {
+        if let Some(idx) = (0..self.allocated).find(|&idx| self.slots[idx] == slot.num()) {
+            self.in_use[idx] = false;
+        }
+    }
+
+    /// Caches the object identified by `keylet`, passes it to `f`, and releases the slot again.
+    ///
+    /// This keeps slot usage flat in loops that visit many objects one at a time.
+    pub fn with_object<R>(
+        &mut self,
+        keylet: &KeyletBytes,
+        f: impl FnOnce(&Slot) -> R,
+    ) -> Result<R> {
+        self.acquire(keylet).map(|slot| {
+            let result = f(&slot);
+            self.release(slot);
+            result
+        })
+    }
+
+    /// Returns the number of host slots this pool has allocated so far.
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Returns the number of slots currently acquired and not yet released.
+    pub fn in_use(&self) -> usize {
+        self.in_use[..self.allocated]
+            .iter()
+            .filter(|&&used| used)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{INTERNAL_ERROR, LEDGER_OBJ_NOT_FOUND, SLOTS_FULL};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::Sequence;
+    use mockall::predicate::{always, eq};
+
+    const KEYLET_A: KeyletBytes = [0xAA; 32];
+    const KEYLET_B: KeyletBytes = [0xBB; 32];
+
+    // ---- Slot ----
+
+    #[test]
+    fn test_slot_cache_returns_host_slot() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .with(always(), eq(32), eq(0))
+            .times(1)
+            .returning(|_, _, _| 7);
+        let _guard = setup_mock(mock);
+
+        let slot = Slot::cache(&KEYLET_A).unwrap();
+        assert_eq!(slot.num(), 7);
+        assert_eq!(slot.get_slot_num(), 7);
+    }
+
+    #[test]
+    fn test_slot_cache_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let result = Slot::cache(&KEYLET_A);
+        assert_eq!(result.err().unwrap().code(), LEDGER_OBJ_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_slot_replace_with_reuses_slot() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .with(always(), eq(32), eq(0))
+            .times(1)
+            .returning(|_, _, _| 5);
+        mock.expect_cache_ledger_obj()
+            .with(always(), eq(32), eq(5))
+            .times(2)
+            .returning(|_, _, cache_num| cache_num);
+        let _guard = setup_mock(mock);
+
+        let mut slot = Slot::cache(&KEYLET_A).unwrap();
+        assert!(slot.replace_with(&KEYLET_B).is_ok());
+        assert!(slot.replace_with(&KEYLET_A).is_ok());
+        assert_eq!(slot.num(), 5);
+    }
+
+    #[test]
+    fn test_slot_replace_with_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .with(always(), always(), eq(5))
+            .times(1)
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let mut slot = Slot { slot_num: 5 };
+        let result = slot.replace_with(&KEYLET_B);
+        assert_eq!(result.err().unwrap().code(), LEDGER_OBJ_NOT_FOUND);
+        assert_eq!(slot.num(), 5);
+    }
+
+    #[test]
+    fn test_slot_replace_with_unexpected_slot() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj().returning(|_, _, _| 6);
+        let _guard = setup_mock(mock);
+
+        let mut slot = Slot { slot_num: 5 };
+        let result = slot.replace_with(&KEYLET_B);
+        assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+    }
+
+    // ---- exists ----
+
+    #[test]
+    fn test_exists_uses_scratch_slot() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .with(always(), eq(32), eq(SCRATCH_SLOT))
+            .times(2)
+            .returning(|_, _, cache_num| cache_num);
+        let _guard = setup_mock(mock);
+
+        assert!(exists(&KEYLET_A).unwrap());
+        assert!(exists(&KEYLET_B).unwrap());
+    }
+
+    #[test]
+    fn test_exists_not_found_is_false() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        assert!(!exists(&KEYLET_A).unwrap());
+    }
+
+    #[test]
+    fn test_exists_propagates_other_errors() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| INTERNAL_ERROR);
+        let _guard = setup_mock(mock);
+
+        let result = exists(&KEYLET_A);
+        assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+    }
+
+    // ---- SlotPool ----
+
+    #[test]
+    fn test_pool_reuses_released_slot() {
+        let mut mock = MockHostBindings::new();
+        let mut seq = Sequence::new();
+        mock.expect_cache_ledger_obj()
+            .with(always(), always(), eq(0))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| 3);
+        mock.expect_cache_ledger_obj()
+            .with(always(), always(), eq(3))
+            .times(2)
+            .in_sequence(&mut seq)
+            .returning(|_, _, cache_num| cache_num);
+        let _guard = setup_mock(mock);
+
+        let mut pool: SlotPool<1> = SlotPool::new();
+        for keylet in [KEYLET_A, KEYLET_B, KEYLET_A] {
+            let num = pool.with_object(&keylet, |obj| obj.num()).unwrap();
+            assert_eq!(num, 3);
+        }
+        assert_eq!(pool.allocated(), 1);
+        assert_eq!(pool.in_use(), 0);
+    }
+
+    #[test]
+    fn test_pool_allocates_until_capacity_then_reports_slots_full() {
+        let mut mock = MockHostBindings::new();
+        let mut next = 0;
+        mock.expect_cache_ledger_obj()
+            .with(always(), always(), eq(0))
+            .times(2)
+            .returning(move |_, _, _| {
+                next += 1;
+                next
+            });
+        let _guard = setup_mock(mock);
+
+        let mut pool: SlotPool<2> = SlotPool::new();
+        let a = pool.acquire(&KEYLET_A).unwrap();
+        let b = pool.acquire(&KEYLET_B).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(pool.in_use(), 2);
+
+        let result = pool.acquire(&KEYLET_A);
+        assert_eq!(result.err().unwrap().code(), SLOTS_FULL);
+    }
+
+    #[test]
+    fn test_pool_failed_acquire_does_not_consume_slot() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .with(always(), always(), eq(0))
+            .times(1)
+            .returning(|_, _, _| 9);
+        mock.expect_cache_ledger_obj()
+            .with(always(), always(), eq(9))
+            .times(1)
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let mut pool: SlotPool<1> = SlotPool::new();
+        let slot = pool.acquire(&KEYLET_A).unwrap();
+        pool.release(slot);
+
+        assert!(pool.with_object(&KEYLET_B, |_| ()).is_err());
+        assert_eq!(pool.allocated(), 1);
+        assert_eq!(pool.in_use(), 0);
+    }
+
+    #[test]
+    fn test_pool_release_of_foreign_slot_is_ignored() {
+        let mut pool: SlotPool<4> = SlotPool::new();
+        pool.release(Slot { slot_num: 42 });
+        assert_eq!(pool.allocated(), 0);
+        assert_eq!(pool.in_use(), 0);
+    }
+}
@@ -2,15 +2,32 @@
 //!
 //! Escrow-specific traits live in the `xrpl-escrow-stdlib` crate.
 
+use crate::fields::decoder::{FromLedger, decode_field};
+use crate::fields::locator::{Locator, LocatorEntry};
+use crate::host::chain::{RIPPLE_EPOCH_UNIX_OFFSET, parent_ledger_time};
 use crate::host::error_codes::{match_result_code, match_result_code_optional};
-use crate::host::{Error, Result, Result::Err, Result::Ok, get_ledger_obj_field};
-use crate::objects::{current_ledger_object, ledger_object};
+use crate::host::{
+    Error, Result, Result::Err, Result::Ok, get_ledger_obj_field, get_ledger_obj_nested_field,
+};
+use crate::keylets::{KeyletBytes, account_keylet, signers_keylet};
+use crate::objects::amm::Amm;
+use crate::objects::oracle::{PriceData, PriceSeries};
+use crate::objects::view::CurrentObject;
+#[cfg(doc)]
+use crate::objects::view::LedgerObjectView;
+use crate::objects::{current_ledger_object, ledger_object, slot};
 use crate::sfield;
 use crate::types::account_id::AccountID;
 use crate::types::amount::Amount;
-use crate::types::blob::{CONDITION_BLOB_SIZE, ConditionBlob, PublicKeyBlob, UriBlob, WasmBlob};
+use crate::types::blob::{
+    CONDITION_BLOB_SIZE, ConditionBlob, CredentialTypeBlob, PublicKeyBlob, StandardBlob, UriBlob,
+    WasmBlob,
+};
 use crate::types::contract_data::{ContractData, XRPL_CONTRACT_DATA_SIZE};
+use crate::types::currency::Currency;
+use crate::types::issue::Issue;
 use crate::types::uint::{Hash128, Hash256};
+use crate::types::xchain_bridge::XChainBridge;
 
 /// Trait providing access to common fields present in all ledger objects.
 ///
@@ -54,6 +71,40 @@ pub trait LedgerObjectCommonFields {
     fn get_ledger_entry_type(&self) -> Result<u16> {
         ledger_object::get_field(self.get_slot_num(), sfield::LedgerEntryType)
     }
+
+    /// Reads any field of the ledger object into `buf` without interpreting it.
+    ///
+    /// This is the escape hatch for fields that have no typed getter yet (for example, fields added by
+    /// amendments newer than this crate). `field_code` is usually an `sfield` constant, but a raw field code
+    /// works too.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of bytes written to `buf`
+    /// * `Err(Error)` - `FieldNotFound` if the object does not have the field, or another host error
+    fn get_field_raw(&self, field_code: impl Into<i32>, buf: &mut [u8]) -> Result<usize> {
+        let result_code = unsafe {
+            get_ledger_obj_field(
+                self.get_slot_num(),
+                field_code.into(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Reads any field of the ledger object and decodes it as `T`.
+    ///
+    /// Unlike the `sfield`-based getters, the field code is not tied to `T` at compile time, so a mismatch
+    /// between the field and `T` surfaces as `InvalidDecoding` rather than a type error.
+    fn get_field_typed<T: FromLedger>(&self, field_code: impl Into<i32>) -> Result<T> {
+        let mut buf = T::Buffer::default();
+        match self.get_field_raw(field_code, buf.as_mut()) {
+            Ok(len) => decode_field(&buf.as_mut()[..len]),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 /// Trait providing access to common fields in the current ledger object.
@@ -66,6 +117,12 @@ pub trait CurrentLedgerObjectCommonFields {
     // object (it's a synthetic field that maps to the `index` field, which is the unique ID of an object in the
     // ledger's state tree). See https://github.com/XRPLF/rippled/issues/3649 for more context.
 
+    /// Returns a [`LedgerObjectView`] of the current ledger object, for helpers written against
+    /// any ledger object rather than a particular type.
+    fn view(&self) -> CurrentObject {
+        CurrentObject
+    }
+
     /// Retrieves the flags field of the current ledger object.
     ///
     /// # Returns
@@ -85,8 +142,39 @@ pub trait CurrentLedgerObjectCommonFields {
     fn get_ledger_entry_type(&self) -> Result<u16> {
         current_ledger_object::get_field(sfield::LedgerEntryType)
     }
+
+    /// Returns the keylet (the ledger index) of the current ledger object.
+    ///
+    /// The host does not expose an object's index (see the note above), so it is recomputed
+    /// from the fields the object's keylet is derived from. This default handles AccountRoot
+    /// objects, whose keylet depends only on `Account`. Types whose keylet needs data from
+    /// elsewhere override it; the current escrow, for one, is keyed by the `Owner` and
+    /// `OfferSequence` of the transaction finishing or canceling it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(KeyletBytes)` - The 32-byte keylet
+    /// * `Err(Error)` - `InvalidParams` if the keylet cannot be recomputed for this object's
+    ///   type, or any error from reading its fields or computing the keylet
+    fn get_keylet(&self) -> Result<KeyletBytes> {
+        match self.get_ledger_entry_type() {
+            Ok(ACCOUNT_ROOT_ENTRY_TYPE) => current_ledger_object::get_field(sfield::Account)
+                .and_then(|account: AccountID| account_keylet(&account)),
+            Ok(_) => Err(Error::InvalidParams),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the ledger index of the current ledger object: its keylet as a [`Hash256`].
+    /// See [`get_keylet`](Self::get_keylet).
+    fn get_index(&self) -> Result<Hash256> {
+        self.get_keylet().map(Hash256::from)
+    }
 }
 
+/// `LedgerEntryType` of AccountRoot objects.
+const ACCOUNT_ROOT_ENTRY_TYPE: u16 = 0x0061;
+
 /// Trait providing access to fields specific to Escrow objects in any ledger.
 ///
 /// This trait extends `LedgerObjectCommonFields` and provides methods to access
@@ -114,7 +202,9 @@ pub trait EscrowFields: LedgerObjectCommonFields {
             )
         };
 
-        match_result_code(result_code, || Amount::from(buffer))
+        match_result_code(result_code, || {
+            Amount::from_host_bytes(buffer, result_code as usize)
+        })
     }
 
     /// The escrow can be canceled if and only if this field is present and the time it specifies
@@ -198,6 +288,12 @@ pub trait EscrowFields: LedgerObjectCommonFields {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::SourceTag)
     }
 
+    /// The issuer's transfer rate when the escrow was created, for IOU escrows. Absent when the
+    /// rate was parity or the escrow holds XRP or an MPT.
+    fn get_transfer_rate(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::TransferRate)
+    }
+
     /// The WASM code that is executing.
     fn get_finish_function(&self) -> Result<Option<WasmBlob>> {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::FinishFunction)
@@ -263,13 +359,24 @@ pub trait AccountFields: LedgerObjectCommonFields {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::AMMID)
     }
 
+    /// Caches the AMM this account belongs to in a new host slot, or returns `Ok(None)` if this
+    /// is not an AMM pseudo-account. The `AMMID` is the AMM object's ledger index, so no keylet
+    /// needs computing.
+    fn amm(&self) -> Result<Option<Amm>> {
+        match self.amm_id() {
+            Ok(Some(amm_id)) => Amm::load_by_id(&amm_id).map(Some),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// The account's current XRP balance in drops.
     fn balance(&self) -> Result<Option<Amount>> {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::Balance)
     }
 
     /// How many total of this account's issued non-fungible tokens have been burned.
-    /// This number is always equal or less than MintedNFTokens.
+    /// This number is always equal or less than MintedNFTokens. Omitted while zero.
     fn burned_nf_tokens(&self) -> Result<Option<u32>> {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::BurnedNFTokens)
     }
@@ -280,11 +387,45 @@ pub trait AccountFields: LedgerObjectCommonFields {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::Domain)
     }
 
+    /// Whether the account's `Domain` is `expected`, ignoring ASCII case since domain names are
+    /// case-insensitive. `expected` is the plain domain, e.g. `b"example.com"`, not the hex form
+    /// shown in JSON. An account without a `Domain` matches nothing.
+    fn domain_matches(&self, expected: &[u8]) -> Result<bool> {
+        match self.domain() {
+            Ok(Some(domain)) => Ok(domain.as_slice().eq_ignore_ascii_case(expected)),
+            // Variable-size optional reads report an absent field as `FieldNotFound`.
+            Ok(None) | Err(Error::FieldNotFound) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The account's `Domain` hashed with [`crypto::domain_hash`](crate::crypto::domain_hash),
+    /// so an allowlist can hold 32-byte hashes instead of domain names. `None` if the account has
+    /// no `Domain`.
+    fn domain_hash(&self) -> Result<Option<Hash256>> {
+        match self.domain() {
+            Ok(Some(domain)) => {
+                crate::crypto::domain_hash(domain.as_slice()).map(|hash| Some(Hash256::from(hash)))
+            }
+            Ok(None) | Err(Error::FieldNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// The MD5 hash of an email address. Clients can use this to look up an avatar through services such as Gravatar.
     fn email_hash(&self) -> Result<Option<Hash128>> {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::EmailHash)
     }
 
+    /// Whether the account's `EmailHash` is the hash of `email`, normalized as
+    /// [`email_hash`](crate::crypto::md5::email_hash) does (trimmed, lowercased). An account
+    /// without an `EmailHash` matches nothing.
+    fn email_hash_matches(&self, email: &[u8]) -> Result<bool> {
+        self.email_hash().map(|stored| {
+            stored.is_some_and(|stored| stored.0 == crate::crypto::md5::email_hash(email))
+        })
+    }
+
     /// The account's Sequence Number at the time it minted its first non-fungible-token.
     /// (Added by the fixNFTokenRemint amendment)
     fn first_nf_token_sequence(&self) -> Result<Option<u32>> {
@@ -305,7 +446,7 @@ pub trait AccountFields: LedgerObjectCommonFields {
     }
 
     /// How many total non-fungible tokens have been minted by and on behalf of this account.
-    /// (Added by the NonFungibleTokensV1_1 amendment)
+    /// Omitted while zero. (Added by the NonFungibleTokensV1_1 amendment)
     fn minted_nf_tokens(&self) -> Result<Option<u32>> {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::MintedNFTokens)
     }
@@ -331,6 +472,12 @@ pub trait AccountFields: LedgerObjectCommonFields {
         ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnLgrSeq)
     }
 
+    /// The ledger entry ID of the LoanBroker that owns this pseudo-account. Always omitted on ordinary accounts.
+    /// (Added by the LendingProtocol amendment)
+    fn loan_broker_id(&self) -> Result<Option<Hash256>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::LoanBrokerID)
+    }
+
     /// The address of a key pair that can be used to sign transactions for this account instead of the master key.
     /// Use a SetRegularKey transaction to change this value.
     fn regular_key(&self) -> Result<Option<AccountID>> {
@@ -360,16 +507,645 @@ pub trait AccountFields: LedgerObjectCommonFields {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::TransferRate)
     }
 
+    /// The ledger entry ID of the Vault that owns this pseudo-account. Always omitted on ordinary accounts.
+    /// (Added by the SingleAssetVault amendment)
+    fn vault_id(&self) -> Result<Option<Hash256>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::VaultID)
+    }
+
     /// An arbitrary 256-bit value that users can set.
     fn wallet_locator(&self) -> Result<Option<Hash256>> {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::WalletLocator)
     }
+
+    /// Unused. The field is still part of the AccountRoot format, so it may be present on old accounts.
+    fn wallet_size(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::WalletSize)
+    }
+
+    /// Whether payments to this account must carry a destination tag (`lsfRequireDestTag`).
+    fn requires_dest_tag(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_REQUIRE_DEST_TAG != 0)
+    }
+
+    /// Whether holders need this issuer's approval before holding its tokens (`lsfRequireAuth`).
+    fn requires_auth(&self) -> Result<bool> {
+        self.get_flags().map(|flags| flags & LSF_REQUIRE_AUTH != 0)
+    }
+
+    /// Whether the account asks not to be sent XRP (`lsfDisallowXRP`). Advisory only: the ledger
+    /// does not enforce it.
+    fn disallows_xrp(&self) -> Result<bool> {
+        self.get_flags().map(|flags| flags & LSF_DISALLOW_XRP != 0)
+    }
+
+    /// Whether this is a pseudo-account owned by a protocol object (an AMM, Vault or LoanBroker)
+    /// rather than by a key holder. Pseudo-accounts have no usable keys, so funds sent to one
+    /// only move through the owning object's transactions.
+    fn is_pseudo_account(&self) -> Result<bool> {
+        match self.amm_id() {
+            Ok(None) => {}
+            other => return other.map(|_| true),
+        }
+        match self.vault_id() {
+            Ok(None) => {}
+            other => return other.map(|_| true),
+        }
+        self.loan_broker_id().map(|id| id.is_some())
+    }
+
+    /// Whether the account's master key pair is disabled (`lsfDisableMaster`).
+    fn is_master_disabled(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_DISABLE_MASTER != 0)
+    }
+
+    /// Whether the issuer has permanently given up freezing its tokens (`lsfNoFreeze`).
+    fn has_no_freeze(&self) -> Result<bool> {
+        self.get_flags().map(|flags| flags & LSF_NO_FREEZE != 0)
+    }
+
+    /// Whether all tokens issued by this account are frozen (`lsfGlobalFreeze`).
+    fn is_globally_frozen(&self) -> Result<bool> {
+        self.get_flags().map(|flags| flags & LSF_GLOBAL_FREEZE != 0)
+    }
+
+    /// Whether rippling is enabled by default on this account's trust lines (`lsfDefaultRipple`).
+    fn default_ripple(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_DEFAULT_RIPPLE != 0)
+    }
+
+    /// Whether the account only accepts funds from senders it has preauthorized
+    /// (`lsfDepositAuth`).
+    fn has_deposit_auth(&self) -> Result<bool> {
+        self.get_flags().map(|flags| flags & LSF_DEPOSIT_AUTH != 0)
+    }
+
+    /// Whether the account blocks incoming NFT offers (`lsfDisallowIncomingNFTokenOffer`).
+    fn disallows_incoming_nftoken_offers(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_DISALLOW_INCOMING_NFTOKEN_OFFER != 0)
+    }
+
+    /// Whether the account blocks incoming checks (`lsfDisallowIncomingCheck`).
+    fn disallows_incoming_checks(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_DISALLOW_INCOMING_CHECK != 0)
+    }
+
+    /// Whether the account blocks incoming payment channels (`lsfDisallowIncomingPayChan`).
+    fn disallows_incoming_pay_chans(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_DISALLOW_INCOMING_PAY_CHAN != 0)
+    }
+
+    /// Whether the account blocks incoming trust lines (`lsfDisallowIncomingTrustline`).
+    fn disallows_incoming_trust_lines(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_DISALLOW_INCOMING_TRUSTLINE != 0)
+    }
+
+    /// Whether the issuer may claw back its trust-line tokens (`lsfAllowTrustLineClawback`).
+    fn allows_trust_line_clawback(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_ALLOW_TRUSTLINE_CLAWBACK != 0)
+    }
+
+    /// Whether the account has a `SignerList`, i.e. can sign with a multi-signature quorum.
+    ///
+    /// The check caches the signer list in [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT).
+    fn has_signer_list(&self) -> Result<bool> {
+        match self.get_account() {
+            Ok(account) => match signers_keylet(&account) {
+                Ok(keylet) => slot::exists(&keylet),
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Whether no one can sign for this account any more: the master key is disabled, there is
+    /// no signer list, and the regular key is either absent or one no key pair is known for
+    /// ([`ACCOUNT_ZERO`](crate::types::account_id::ACCOUNT_ZERO) or
+    /// [`ACCOUNT_ONE`](crate::types::account_id::ACCOUNT_ONE)).
+    ///
+    /// Issuers are commonly blackholed to prove their token supply is fixed. The regular key and
+    /// signer list are only read if the master key is disabled.
+    fn is_blackholed(&self) -> Result<bool> {
+        match self.is_master_disabled() {
+            Ok(true) => {}
+            other => return other,
+        }
+        match self.regular_key() {
+            Ok(Some(key)) if !key.is_unspendable_placeholder() => return Ok(false),
+            Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+        self.has_signer_list().map(|has_signers| !has_signers)
+    }
+}
+
+/// Trait providing access to fields of `Bridge` ledger objects, which record one side of a
+/// cross-chain bridge and the counters its transactions allocate from.
+pub trait BridgeFields: LedgerObjectCommonFields {
+    /// The door account that owns the bridge.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The door accounts and assets of both chains.
+    fn get_xchain_bridge(&self) -> Result<XChainBridge> {
+        ledger_object::get_field(self.get_slot_num(), sfield::XChainBridge)
+    }
+
+    /// The total amount, in XRP, paid to the witness servers for their signatures on a claim.
+    fn get_signature_reward(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::SignatureReward)
+    }
+
+    /// The minimum XRP an `XChainAccountCreateCommit` must send to create an account on the
+    /// other chain. Omitted if the bridge does not allow account creation.
+    fn get_min_account_create_amount(&self) -> Result<Option<Amount>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::MinAccountCreateAmount)
+    }
+
+    /// The value of the next claim ID to be created with `XChainCreateClaimID`.
+    fn get_xchain_claim_id(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::XChainClaimID)
+    }
+
+    /// How many accounts have been created on the other chain through this bridge, i.e. the
+    /// number of `XChainAccountCreateCommit` transactions on this chain.
+    fn get_xchain_account_create_count(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::XChainAccountCreateCount)
+    }
+
+    /// How many accounts have been created on this chain through this bridge.
+    fn get_xchain_account_claim_count(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::XChainAccountClaimCount)
+    }
+
+    /// A hint indicating which page of the door account's owner directory links to this object.
+    fn get_owner_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
+    }
+}
+
+/// Trait providing access to fields of `XChainOwnedClaimID` ledger objects, which reserve a
+/// claim ID for a cross-chain transfer and collect the witnesses' attestations for it.
+pub trait XChainOwnedClaimIdFields: LedgerObjectCommonFields {
+    /// The account that created the claim ID and owns this object.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The door accounts and assets of the bridge the claim ID belongs to.
+    fn get_xchain_bridge(&self) -> Result<XChainBridge> {
+        ledger_object::get_field(self.get_slot_num(), sfield::XChainBridge)
+    }
+
+    /// The claim ID reserved by this object.
+    fn get_xchain_claim_id(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::XChainClaimID)
+    }
+
+    /// The account that must send the `XChainCommit` on the other chain.
+    fn get_other_chain_source(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OtherChainSource)
+    }
+
+    /// The signature reward the bridge charged when the claim ID was created.
+    fn get_signature_reward(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::SignatureReward)
+    }
+
+    /// How many witness attestations have been collected for the claim so far.
+    fn get_attestation_count(&self) -> Result<usize> {
+        ledger_object::array_len(self.get_slot_num(), sfield::XChainClaimAttestations)
+    }
+
+    /// A hint indicating which page of the owner's directory links to this object.
+    fn get_owner_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
+    }
+}
+
+/// Trait providing access to fields of `PermissionedDomain` ledger objects, which define a set of
+/// accounts by the credentials they hold.
+pub trait PermissionedDomainFields: LedgerObjectCommonFields {
+    /// The account that created and owns the domain.
+    fn get_owner(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Owner)
+    }
+
+    /// The sequence number of the transaction that created the domain.
+    fn get_sequence(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Sequence)
+    }
+
+    /// How many credentials the domain accepts (at most 10).
+    fn get_accepted_credentials_count(&self) -> Result<usize> {
+        ledger_object::array_len(self.get_slot_num(), sfield::AcceptedCredentials)
+    }
+
+    /// The issuer and credential type of the accepted credential at `index`.
+    fn get_accepted_credential(&self, index: usize) -> Result<(AccountID, CredentialTypeBlob)> {
+        let mut locator = Locator::new();
+        let mut issuer = AccountID([0; 20]);
+        let issuer_len = locator
+            .pack(sfield::AcceptedCredentials)
+            .and_then(|_| locator.pack(LocatorEntry::Index(index as i32)))
+            .and_then(|_| locator.pack(sfield::Issuer))
+            .and_then(|_| nested_field_raw(self.get_slot_num(), &locator, &mut issuer.0));
+        match issuer_len {
+            Ok(20) => {}
+            Ok(_) => return Err(Error::InvalidDecoding),
+            Err(e) => return Err(e),
+        }
+
+        let mut credential_type = CredentialTypeBlob::new();
+        match locator.repack_last(sfield::CredentialType).and_then(|_| {
+            nested_field_raw(self.get_slot_num(), &locator, &mut credential_type.data)
+        }) {
+            Ok(len) => {
+                credential_type.len = len;
+                Ok((issuer, credential_type))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Trait providing access to fields of `Credential` ledger objects.
+pub trait CredentialFields: LedgerObjectCommonFields {
+    /// The account the credential is about.
+    fn get_subject(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Subject)
+    }
+
+    /// The account that issued the credential.
+    fn get_issuer(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Issuer)
+    }
+
+    /// What kind of credential this is, as defined by the issuer.
+    fn get_credential_type(&self) -> Result<CredentialTypeBlob> {
+        ledger_object::get_field(self.get_slot_num(), sfield::CredentialType)
+    }
+
+    /// The time, in seconds since the Ripple Epoch, after which the credential is no longer
+    /// valid. Omitted if the credential does not expire.
+    fn get_expiration(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::Expiration)
+    }
+
+    /// Whether the subject has accepted the credential. Credentials that are not accepted do not
+    /// count towards membership of a permissioned domain.
+    fn is_accepted(&self) -> Result<bool> {
+        self.get_flags()
+            .map(|flags| flags & LSF_CREDENTIAL_ACCEPTED != 0)
+    }
+
+    /// Whether the credential has expired as of `close_time`, the parent ledger's close time.
+    fn is_expired(&self, close_time: u32) -> Result<bool> {
+        self.get_expiration()
+            .map(|expiration| expiration.is_some_and(|expiration| close_time > expiration))
+    }
+}
+
+/// Trait providing access to fields of `Offer` ledger objects.
+pub trait OfferFields: LedgerObjectCommonFields {
+    /// The account that placed the offer.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The sequence number of the transaction that created the offer.
+    fn get_sequence(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Sequence)
+    }
+
+    /// The remaining amount the offer's owner wants to receive.
+    fn get_taker_pays(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::TakerPays)
+    }
+
+    /// The remaining amount the offer's owner is selling.
+    fn get_taker_gets(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::TakerGets)
+    }
+
+    /// The time, in seconds since the Ripple Epoch, after which the offer is no longer active.
+    fn get_expiration(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::Expiration)
+    }
+
+    /// The permissioned domain the offer trades in. Omitted for offers on the open DEX.
+    fn get_domain_id(&self) -> Result<Option<Hash256>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::DomainID)
+    }
+}
+
+/// Trait providing access to fields of `AMM` ledger objects.
+pub trait AmmFields: LedgerObjectCommonFields {
+    /// The AMM's pseudo-account, which holds the pool's assets and issues its LP tokens.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// One of the two assets in the pool.
+    fn get_asset(&self) -> Result<Issue> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Asset)
+    }
+
+    /// The other asset in the pool.
+    fn get_asset2(&self) -> Result<Issue> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Asset2)
+    }
+
+    /// The total outstanding LP tokens, issued by the pseudo-account.
+    fn get_lp_token_balance(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::LPTokenBalance)
+    }
+
+    /// The fee charged on trades against the pool, in units of 1/100,000 (so 1000 is 1%). The
+    /// ledger omits the field when the fee is 0.
+    fn get_trading_fee(&self) -> Result<u16> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::TradingFee)
+            .map(|fee| fee.unwrap_or(0))
+    }
+
+    /// A hint indicating which page of the pseudo-account's owner directory links to this object.
+    fn get_owner_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
+    }
+}
+
+/// Trait providing access to fields of `Check` ledger objects.
+pub trait CheckFields: LedgerObjectCommonFields {
+    /// The account that created the check, and pays when it is cashed.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The account that can cash the check.
+    fn get_destination(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Destination)
+    }
+
+    /// The most the check can debit from its creator when cashed, including transfer fees.
+    fn get_send_max(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::SendMax)
+    }
+
+    /// The sequence number of the transaction that created the check.
+    fn get_sequence(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Sequence)
+    }
+
+    /// The time, in seconds since the Ripple Epoch, after which the check can no longer be
+    /// cashed.
+    fn get_expiration(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::Expiration)
+    }
+
+    /// An arbitrary hash the creator attached, such as the invoice the check pays.
+    fn get_invoice_id(&self) -> Result<Option<Hash256>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::InvoiceID)
+    }
+
+    /// The tag specifying a hosted recipient at the destination address.
+    fn get_destination_tag(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::DestinationTag)
+    }
+
+    /// The tag specifying a hosted sender at the creator's address.
+    fn get_source_tag(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::SourceTag)
+    }
+
+    /// A hint indicating which page of the creator's directory links to this object.
+    fn get_owner_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
+    }
+
+    /// A hint indicating which page of the destination's directory links to this object.
+    fn get_destination_node(&self) -> Result<Option<u64>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::DestinationNode)
+    }
+}
+
+/// Trait providing access to fields of `PayChannel` (payment channel) ledger objects.
+pub trait PayChannelFields: LedgerObjectCommonFields {
+    /// The account that funds the channel and signs claims against it.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The account that receives XRP from the channel.
+    fn get_destination(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Destination)
+    }
+
+    /// The total XRP, in drops, that has been allocated to the channel, including what has been
+    /// paid out.
+    fn get_amount(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Amount)
+    }
+
+    /// The total XRP, in drops, already paid out by the channel.
+    fn get_balance(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Balance)
+    }
+
+    /// The public key that claims against the channel must be signed with.
+    fn get_public_key(&self) -> Result<PublicKeyBlob> {
+        ledger_object::get_field(self.get_slot_num(), sfield::PublicKey)
+    }
+
+    /// How many seconds the source must wait to close the channel if it still holds XRP.
+    fn get_settle_delay(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::SettleDelay)
+    }
+
+    /// The time, in seconds since the Ripple Epoch, after which the channel can be closed. Set
+    /// when the source requests a close, or updated by a `PaymentChannelFund`.
+    fn get_expiration(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::Expiration)
+    }
+
+    /// The immutable time, in seconds since the Ripple Epoch, after which the channel expires.
+    fn get_cancel_after(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::CancelAfter)
+    }
+
+    /// The tag specifying a hosted recipient at the destination address.
+    fn get_destination_tag(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::DestinationTag)
+    }
+
+    /// The tag specifying a hosted sender at the source address.
+    fn get_source_tag(&self) -> Result<Option<u32>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::SourceTag)
+    }
+
+    /// A hint indicating which page of the source's directory links to this object.
+    fn get_owner_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
+    }
+
+    /// A hint indicating which page of the destination's directory links to this object.
+    fn get_destination_node(&self) -> Result<Option<u64>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::DestinationNode)
+    }
+}
+
+/// Trait providing access to fields of an `Oracle` (price oracle) ledger object.
+///
+/// The oracle's `OracleDocumentID` is not stored on the object: it is part of the keylet, see
+/// [`Oracle::load`](crate::objects::oracle::Oracle::load).
+pub trait OracleFields: LedgerObjectCommonFields {
+    /// The account that publishes the oracle and owns the object.
+    fn get_owner(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Owner)
+    }
+
+    /// The oracle provider's identifier, such as `"chainlink"`, as set by the owner.
+    fn get_provider(&self) -> Result<StandardBlob> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Provider)
+    }
+
+    /// The asset class of the prices, such as `"currency"`, as set by the owner.
+    fn get_asset_class(&self) -> Result<StandardBlob> {
+        ledger_object::get_field(self.get_slot_num(), sfield::AssetClass)
+    }
+
+    /// An optional URI pointing to more information about the oracle's data.
+    fn get_uri(&self) -> Result<Option<UriBlob>> {
+        ledger_object::get_field_optional(self.get_slot_num(), sfield::URI)
+    }
+
+    /// The time the prices were last updated, in seconds since the Unix epoch. Unlike most ledger
+    /// times, this is not relative to the Ripple Epoch.
+    fn get_last_update_time(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::LastUpdateTime)
+    }
+
+    /// How many price pairs the oracle publishes in its `PriceDataSeries` (at most 10).
+    fn get_price_data_count(&self) -> Result<usize> {
+        ledger_object::array_len(self.get_slot_num(), sfield::PriceDataSeries)
+    }
+
+    /// Iterates over the oracle's `PriceDataSeries` as typed [`PriceData`] entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading the length of the series; errors reading individual entries
+    /// are yielded by the iterator.
+    fn price_series(&self) -> Result<PriceSeries> {
+        self.get_price_data_count()
+            .map(|len| PriceSeries::new(self.get_slot_num(), len))
+    }
+
+    /// Returns the entry pricing `base` in `quote`, provided the oracle was updated at most
+    /// `max_age` seconds before the parent ledger closed.
+    ///
+    /// Returns `Ok(None)` if the oracle is older than that, has no entry for the pair, or left
+    /// the pair's price out of its last update.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading the ledger time, `LastUpdateTime` or the series.
+    fn latest_fresh_price(
+        &self,
+        base: &Currency,
+        quote: &Currency,
+        max_age: u32,
+    ) -> Result<Option<PriceData>> {
+        let now = match parent_ledger_time() {
+            Result::Ok(time) => time.saturating_add(RIPPLE_EPOCH_UNIX_OFFSET),
+            Result::Err(e) => return Result::Err(e),
+        };
+        let updated = match self.get_last_update_time() {
+            Result::Ok(time) => time,
+            Result::Err(e) => return Result::Err(e),
+        };
+        if now.saturating_sub(updated) > max_age {
+            return Result::Ok(None);
+        }
+        let series = match self.price_series() {
+            Result::Ok(series) => series,
+            Result::Err(e) => return Result::Err(e),
+        };
+        for entry in series {
+            match entry {
+                Result::Ok(entry) if entry.is_pair(base, quote) => {
+                    return Result::Ok(entry.asset_price.map(|_| entry));
+                }
+                Result::Ok(_) => {}
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Ok(None)
+    }
+
+    /// A hint indicating which page of the owner's directory links to this object.
+    fn get_owner_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
+    }
+
+    /// The identifying hash of the transaction that most recently modified this object.
+    fn get_previous_txn_id(&self) -> Result<Hash256> {
+        ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnID)
+    }
+
+    /// The index of the ledger that contains the transaction that most recently modified this
+    /// object.
+    fn get_previous_txn_lgr_seq(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnLgrSeq)
+    }
+}
+
+/// `lsfAccepted` on a `Credential` ledger object.
+pub(crate) const LSF_CREDENTIAL_ACCEPTED: u32 = 0x0001_0000;
+
+// `AccountRoot` flags.
+pub(crate) const LSF_REQUIRE_DEST_TAG: u32 = 0x0002_0000;
+pub(crate) const LSF_REQUIRE_AUTH: u32 = 0x0004_0000;
+pub(crate) const LSF_DISALLOW_XRP: u32 = 0x0008_0000;
+pub(crate) const LSF_DISABLE_MASTER: u32 = 0x0010_0000;
+pub(crate) const LSF_NO_FREEZE: u32 = 0x0020_0000;
+pub(crate) const LSF_GLOBAL_FREEZE: u32 = 0x0040_0000;
+pub(crate) const LSF_DEFAULT_RIPPLE: u32 = 0x0080_0000;
+pub(crate) const LSF_DEPOSIT_AUTH: u32 = 0x0100_0000;
+pub(crate) const LSF_DISALLOW_INCOMING_NFTOKEN_OFFER: u32 = 0x0400_0000;
+pub(crate) const LSF_DISALLOW_INCOMING_CHECK: u32 = 0x0800_0000;
+pub(crate) const LSF_DISALLOW_INCOMING_PAY_CHAN: u32 = 0x1000_0000;
+pub(crate) const LSF_DISALLOW_INCOMING_TRUSTLINE: u32 = 0x2000_0000;
+pub(crate) const LSF_ALLOW_TRUSTLINE_CLAWBACK: u32 = 0x8000_0000;
+
+/// Reads the field of a cached object that `locator` points at into `buf`.
+fn nested_field_raw(slot: i32, locator: &Locator, buf: &mut [u8]) -> Result<usize> {
+    let result_code = unsafe {
+        get_ledger_obj_nested_field(
+            slot,
+            locator.as_ptr(),
+            locator.len(),
+            buf.as_mut_ptr(),
+            buf.len(),
+        )
+    };
+    match_result_code(result_code, || result_code as usize)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR, INVALID_FIELD};
+    use crate::host::error_codes::{
+        FIELD_NOT_FOUND, INTERNAL_ERROR, INVALID_DECODING, INVALID_FIELD, INVALID_PARAMS,
+    };
     use crate::host::host_bindings_trait::MockHostBindings;
     use crate::objects::LedgerObjectFieldGetter;
     use crate::objects::account_root::AccountRoot;
@@ -505,6 +1281,86 @@ mod tests {
             assert!(result.is_err());
             assert_eq!(result.err().unwrap().code(), INVALID_FIELD);
         }
+
+        #[test]
+        fn test_get_field_raw_returns_bytes_written() {
+            let mut mock = MockHostBindings::new();
+
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(sfield::MutableFlags), always(), eq(8))
+                .times(1)
+                .returning(|_, _, ptr, _| {
+                    unsafe { core::ptr::copy_nonoverlapping([1u8, 2, 3, 4].as_ptr(), ptr, 4) };
+                    4
+                });
+
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            let mut buf = [0u8; 8];
+            let len = account
+                .get_field_raw(sfield::MutableFlags, &mut buf)
+                .unwrap();
+
+            assert_eq!(len, 4);
+            assert_eq!(&buf[..len], &[1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn test_get_field_raw_returns_error_when_field_not_found() {
+            let mut mock = MockHostBindings::new();
+
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(0x0002_0099), always(), eq(4))
+                .times(1)
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
+
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            let mut buf = [0u8; 4];
+            let result = account.get_field_raw(0x0002_0099, &mut buf);
+
+            assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+        }
+
+        #[test]
+        fn test_get_field_typed_decodes_value() {
+            let mut mock = MockHostBindings::new();
+
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(sfield::OwnerCount), always(), eq(4))
+                .times(1)
+                .returning(|_, _, ptr, _| {
+                    let bytes = 42u32.to_ne_bytes();
+                    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, 4) };
+                    4
+                });
+
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            let owner_count = account.get_field_typed::<u32>(sfield::OwnerCount).unwrap();
+
+            assert_eq!(owner_count, 42);
+        }
+
+        #[test]
+        fn test_get_field_typed_rejects_short_value() {
+            let mut mock = MockHostBindings::new();
+
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(sfield::Account), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _, _| 20);
+
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            let result = account.get_field_typed::<Hash256>(sfield::Account);
+
+            assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+        }
     }
 
     mod escrow_fields {
@@ -715,8 +1571,9 @@ mod tests {
 
     mod account_fields {
         use super::*;
+        use crate::host::error_codes::{FIELD_NOT_FOUND, LEDGER_OBJ_NOT_FOUND};
         use crate::host::setup_mock;
-        use crate::types::account_id::ACCOUNT_ID_SIZE;
+        use crate::types::account_id::{ACCOUNT_ID_SIZE, ACCOUNT_ONE};
         use crate::types::blob::{DOMAIN_BLOB_SIZE, PUBLIC_KEY_BLOB_SIZE};
 
         #[test]
@@ -783,6 +1640,12 @@ mod tests {
             expect_ledger_field(&mut mock, 1, sfield::TransferRate, 4, 1);
             // wallet_locator
             expect_ledger_field(&mut mock, 1, sfield::WalletLocator, 32, 1);
+            // loan_broker_id
+            expect_ledger_field(&mut mock, 1, sfield::LoanBrokerID, 32, 1);
+            // vault_id
+            expect_ledger_field(&mut mock, 1, sfield::VaultID, 32, 1);
+            // wallet_size
+            expect_ledger_field(&mut mock, 1, sfield::WalletSize, 4, 1);
 
             let _guard = setup_mock(mock);
 
@@ -804,6 +1667,9 @@ mod tests {
             assert!(account.tick_size().unwrap().is_some());
             assert!(account.transfer_rate().unwrap().is_some());
             assert!(account.wallet_locator().unwrap().is_some());
+            assert!(account.loan_broker_id().unwrap().is_some());
+            assert!(account.vault_id().unwrap().is_some());
+            assert!(account.wallet_size().unwrap().is_some());
         }
 
         #[test]
@@ -890,6 +1756,21 @@ mod tests {
                 .with(eq(1), eq(sfield::WalletLocator), always(), eq(32))
                 .times(1)
                 .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            // loan_broker_id
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(sfield::LoanBrokerID), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            // vault_id
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(sfield::VaultID), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            // wallet_size
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(sfield::WalletSize), always(), eq(4))
+                .times(1)
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
 
             let _guard = setup_mock(mock);
 
@@ -908,6 +1789,9 @@ mod tests {
             assert!(account.tick_size().unwrap().is_none());
             assert!(account.transfer_rate().unwrap().is_none());
             assert!(account.wallet_locator().unwrap().is_none());
+            assert!(account.loan_broker_id().unwrap().is_none());
+            assert!(account.vault_id().unwrap().is_none());
+            assert!(account.wallet_size().unwrap().is_none());
 
             // Variable-size optional fields return Some with len=0 when not found
             // (they cannot distinguish between "not present" and "present with 0 bytes")
@@ -958,6 +1842,194 @@ mod tests {
             assert!(result.is_err());
             assert_eq!(result.err().unwrap().code(), INVALID_FIELD);
         }
+
+        /// Answers every `Flags` read on slot 1 with `flags`.
+        fn expect_account_flags(mock: &mut MockHostBindings, flags: u32) {
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(i32::from(sfield::Flags)), always(), eq(4))
+                .returning(move |_, _, out, _| {
+                    unsafe { core::ptr::copy_nonoverlapping(flags.to_le_bytes().as_ptr(), out, 4) };
+                    4
+                });
+        }
+
+        /// Answers `RegularKey` reads on slot 1 with `key`, or `FieldNotFound` for `None`.
+        fn expect_regular_key(mock: &mut MockHostBindings, key: Option<AccountID>) {
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(i32::from(sfield::RegularKey)), always(), always())
+                .times(1)
+                .returning(move |_, _, out, _| match key {
+                    Some(key) => {
+                        unsafe { core::ptr::copy_nonoverlapping(key.0.as_ptr(), out, 20) };
+                        20
+                    }
+                    None => FIELD_NOT_FOUND,
+                });
+        }
+
+        /// Expects the signer-list lookup for the account on slot 1.
+        fn expect_signer_list(mock: &mut MockHostBindings, exists: bool) {
+            expect_ledger_field(mock, 1, sfield::Account, ACCOUNT_ID_SIZE, 1);
+            mock.expect_signers_keylet()
+                .times(1)
+                .returning(|_, _, _, len| len as i32);
+            mock.expect_cache_ledger_obj()
+                .times(1)
+                .returning(move |_, _, slot| if exists { slot } else { LEDGER_OBJ_NOT_FOUND });
+        }
+
+        #[test]
+        fn test_flag_queries_decode_account_root_flags() {
+            let mut mock = MockHostBindings::new();
+            expect_account_flags(
+                &mut mock,
+                LSF_REQUIRE_DEST_TAG
+                    | LSF_DEFAULT_RIPPLE
+                    | LSF_DEPOSIT_AUTH
+                    | LSF_ALLOW_TRUSTLINE_CLAWBACK,
+            );
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            assert!(account.requires_dest_tag().unwrap());
+            assert!(account.default_ripple().unwrap());
+            assert!(account.has_deposit_auth().unwrap());
+            assert!(account.allows_trust_line_clawback().unwrap());
+            assert!(!account.requires_auth().unwrap());
+            assert!(!account.is_master_disabled().unwrap());
+            assert!(!account.is_globally_frozen().unwrap());
+            assert!(!account.disallows_incoming_checks().unwrap());
+        }
+
+        #[test]
+        fn test_domain_matches_ignores_case() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_ledger_obj_field()
+                .with(
+                    eq(1),
+                    eq(i32::from(sfield::Domain)),
+                    always(),
+                    eq(DOMAIN_BLOB_SIZE),
+                )
+                .returning(|_, _, out, _| {
+                    unsafe { core::ptr::copy_nonoverlapping(b"Example.com".as_ptr(), out, 11) };
+                    11
+                });
+            mock.expect_get_ledger_obj_field()
+                .with(eq(2), eq(i32::from(sfield::Domain)), always(), always())
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            mock.expect_compute_sha512_half()
+                .withf(|data, len, _, _| unsafe {
+                    core::slice::from_raw_parts(*data, *len) == b"example.com"
+                })
+                .times(1)
+                .returning(|_, _, out, _| {
+                    unsafe { core::ptr::write_bytes(out, 0xEE, 32) };
+                    32
+                });
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            assert!(account.domain_matches(b"example.COM").unwrap());
+            assert!(!account.domain_matches(b"example.org").unwrap());
+            assert_eq!(
+                account.domain_hash().unwrap(),
+                Some(Hash256::from([0xEE; 32]))
+            );
+            let no_domain = AccountRoot { slot_num: 2 };
+            assert!(!no_domain.domain_matches(b"example.com").unwrap());
+            assert_eq!(no_domain.domain_hash().unwrap(), None);
+        }
+
+        #[test]
+        fn test_email_hash_matches_normalized_address() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(i32::from(sfield::EmailHash)), always(), eq(16))
+                .returning(|_, _, out, _| {
+                    let hash = crate::crypto::md5::md5(b"user@example.com");
+                    unsafe { core::ptr::copy_nonoverlapping(hash.as_ptr(), out, 16) };
+                    16
+                });
+            mock.expect_get_ledger_obj_field()
+                .with(eq(2), eq(i32::from(sfield::EmailHash)), always(), eq(16))
+                .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            let _guard = setup_mock(mock);
+
+            let account = AccountRoot { slot_num: 1 };
+            assert!(account.email_hash_matches(b"User@Example.com\n").unwrap());
+            assert!(!account.email_hash_matches(b"other@example.com").unwrap());
+            assert!(
+                !AccountRoot { slot_num: 2 }
+                    .email_hash_matches(b"user@example.com")
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_is_pseudo_account_checks_each_designator() {
+            let mut mock = MockHostBindings::new();
+            for field in [i32::from(sfield::AMMID), i32::from(sfield::VaultID)] {
+                mock.expect_get_ledger_obj_field()
+                    .with(eq(1), eq(field), always(), eq(32))
+                    .times(1)
+                    .returning(|_, _, _, _| FIELD_NOT_FOUND);
+            }
+            mock.expect_get_ledger_obj_field()
+                .with(eq(1), eq(i32::from(sfield::LoanBrokerID)), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _, len| len as i32);
+            mock.expect_get_ledger_obj_field()
+                .with(eq(2), eq(i32::from(sfield::AMMID)), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _, len| len as i32);
+            let _guard = setup_mock(mock);
+
+            assert!(AccountRoot { slot_num: 1 }.is_pseudo_account().unwrap());
+            // The first designator found settles it.
+            assert!(AccountRoot { slot_num: 2 }.is_pseudo_account().unwrap());
+        }
+
+        #[test]
+        fn test_is_blackholed_stops_at_enabled_master_key() {
+            let mut mock = MockHostBindings::new();
+            expect_account_flags(&mut mock, LSF_DEFAULT_RIPPLE);
+            let _guard = setup_mock(mock);
+
+            assert!(!AccountRoot { slot_num: 1 }.is_blackholed().unwrap());
+        }
+
+        #[test]
+        fn test_is_blackholed_with_placeholder_regular_key_and_no_signers() {
+            let mut mock = MockHostBindings::new();
+            expect_account_flags(&mut mock, LSF_DISABLE_MASTER);
+            expect_regular_key(&mut mock, Some(ACCOUNT_ONE));
+            expect_signer_list(&mut mock, false);
+            let _guard = setup_mock(mock);
+
+            assert!(AccountRoot { slot_num: 1 }.is_blackholed().unwrap());
+        }
+
+        #[test]
+        fn test_is_not_blackholed_with_usable_regular_key() {
+            let mut mock = MockHostBindings::new();
+            expect_account_flags(&mut mock, LSF_DISABLE_MASTER);
+            expect_regular_key(&mut mock, Some(AccountID([0x42; 20])));
+            let _guard = setup_mock(mock);
+
+            assert!(!AccountRoot { slot_num: 1 }.is_blackholed().unwrap());
+        }
+
+        #[test]
+        fn test_is_not_blackholed_with_signer_list() {
+            let mut mock = MockHostBindings::new();
+            expect_account_flags(&mut mock, LSF_DISABLE_MASTER);
+            expect_regular_key(&mut mock, None);
+            expect_signer_list(&mut mock, true);
+            let _guard = setup_mock(mock);
+
+            assert!(!AccountRoot { slot_num: 1 }.is_blackholed().unwrap());
+        }
     }
 
     mod current_ledger_object_common_fields {
@@ -1040,5 +2112,54 @@ mod tests {
             assert!(result.is_err());
             assert_eq!(result.err().unwrap().code(), INVALID_FIELD);
         }
+
+        fn expect_entry_type(mock: &mut MockHostBindings, entry_type: u16) {
+            mock.expect_get_current_ledger_obj_field()
+                .with(eq(i32::from(sfield::LedgerEntryType)), always(), eq(2))
+                .times(1)
+                .returning(move |_, ptr, _| {
+                    let bytes = entry_type.to_le_bytes();
+                    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, 2) };
+                    2
+                });
+        }
+
+        #[test]
+        fn test_get_keylet_of_account_root() {
+            let mut mock = MockHostBindings::new();
+            expect_entry_type(&mut mock, 0x0061);
+            mock.expect_get_current_ledger_obj_field()
+                .with(eq(i32::from(sfield::Account)), always(), eq(20))
+                .times(1)
+                .returning(|_, ptr, len| {
+                    unsafe { core::ptr::write_bytes(ptr, 0xAA, len) };
+                    20
+                });
+            mock.expect_account_keylet()
+                .withf(|account, len, _, _| {
+                    *len == 20 && unsafe { core::slice::from_raw_parts(*account, 20) } == [0xAA; 20]
+                })
+                .times(1)
+                .returning(|_, _, out, _| {
+                    unsafe { core::ptr::write_bytes(out, 0x61, 32) };
+                    32
+                });
+            let _guard = setup_mock(mock);
+
+            assert_eq!(
+                TestCurrentLedgerObject.get_index().unwrap(),
+                Hash256::from([0x61; 32])
+            );
+        }
+
+        #[test]
+        fn test_get_keylet_of_other_types_is_invalid_params() {
+            let mut mock = MockHostBindings::new();
+            expect_entry_type(&mut mock, 0x0075);
+            let _guard = setup_mock(mock);
+
+            let result = TestCurrentLedgerObject.get_keylet();
+            assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+        }
     }
 }
@@ -0,0 +1,251 @@
+//! Reading fields of a ledger object without caring where it comes from.
+//!
+//! The current ledger object and objects cached in a slot are read through different host
+//! functions, and so through different module functions ([`current_ledger_object`] and
+//! [`ledger_object`]). [`LedgerObjectView`] puts both behind one trait, implemented by the
+//! [`CurrentObject`] and [`Slot`] handles, so a helper that only needs to read fields can be
+//! written once:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::Result;
+//! use xrpl_common_stdlib::objects::slot::Slot;
+//! use xrpl_common_stdlib::objects::view::{CurrentObject, LedgerObjectView};
+//! use xrpl_common_stdlib::sfield;
+//!
+//! fn owner_count<V: LedgerObjectView>(object: &V) -> Result<u32> {
+//!     object.get_field(sfield::OwnerCount)
+//! }
+//!
+//! # fn example(keylet: &[u8; 32]) {
+//! let mine = owner_count(&CurrentObject);
+//! let theirs = Slot::cache(keylet).and_then(|slot| owner_count(&slot));
+//! # }
+//! ```
+//!
+//! Typed wrappers of the current object hand out [`CurrentObject`] with
+//! [`CurrentLedgerObjectCommonFields::view`](crate::objects::traits::CurrentLedgerObjectCommonFields::view).
+//! A cached object is viewed through the [`Slot`] that [`Slot::cache`] or a
+//! [`SlotPool`](crate::objects::slot::SlotPool) returned for it.
+
+use crate::fields::decoder::FromLedger;
+use crate::fields::locator::Locator;
+use crate::host::{Result, Result::Err, Result::Ok};
+use crate::objects::array_object::Array;
+use crate::objects::slot::Slot;
+use crate::objects::{LedgerObjectFieldGetter, current_ledger_object, ledger_object};
+use crate::sfield::{self, SField};
+use crate::types::uint::Hash256;
+
+/// The transaction that most recently modified a ledger entry, as recorded in the entry's
+/// `PreviousTxnID` and `PreviousTxnLgrSeq` fields.
+///
+/// The host has no function for loading a transaction by hash, so the fields of that transaction
+/// (its type, account, flags) cannot be inspected from a contract. What can be checked is whether
+/// the entry has changed since a known point: for example, an escrow whose `ledger_seq` equals the
+/// sequence stored in its `Data` at creation time has not been modified since.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviousTxn {
+    /// Hash of the transaction (`PreviousTxnID`).
+    pub id: Hash256,
+    /// Index of the ledger that contains the transaction (`PreviousTxnLgrSeq`).
+    pub ledger_seq: u32,
+}
+
+/// A ledger object whose fields can be read, wherever the host keeps it.
+///
+/// See the [module documentation](self).
+pub trait LedgerObjectView {
+    /// Retrieves a field of the object.
+    ///
+    /// # Errors
+    ///
+    /// `FieldNotFound` if the object does not have the field, or another host error.
+    fn get_field<T: LedgerObjectFieldGetter, const CODE: i32>(
+        &self,
+        field: SField<T, CODE>,
+    ) -> Result<T>;
+
+    /// Retrieves an optionally present field of the object, returning `None` if it is absent.
+    fn get_field_optional<T: LedgerObjectFieldGetter, const CODE: i32>(
+        &self,
+        field: SField<T, CODE>,
+    ) -> Result<Option<T>>;
+
+    /// Returns the number of elements in an array field of the object.
+    fn array_len<const CODE: i32>(&self, field: SField<Array, CODE>) -> Result<usize>;
+
+    /// Retrieves the field at `locator`, typed as `T`.
+    fn get_nested_field<T: FromLedger>(&self, locator: &Locator) -> Result<T>;
+
+    /// The object's `Flags` field.
+    fn flags(&self) -> Result<u32> {
+        self.get_field(sfield::Flags)
+    }
+
+    /// Returns whether every bit of `mask` is set in the object's `Flags`.
+    fn has_flags(&self, mask: u32) -> Result<bool> {
+        self.flags().map(|flags| flags & mask == mask)
+    }
+
+    /// The object's `LedgerEntryType` field.
+    fn ledger_entry_type(&self) -> Result<u16> {
+        self.get_field(sfield::LedgerEntryType)
+    }
+
+    /// The transaction that most recently modified the object, from its `PreviousTxnID` and
+    /// `PreviousTxnLgrSeq` fields.
+    ///
+    /// # Errors
+    ///
+    /// `FieldNotFound` for objects that do not record it, such as directory pages and singletons
+    /// last modified before the `fixPreviousTxnID` amendment, or another host error.
+    fn previous_txn(&self) -> Result<PreviousTxn> {
+        let id: Hash256 = match self.get_field(sfield::PreviousTxnID) {
+            Ok(id) => id,
+            Err(e) => return Err(e),
+        };
+        self.get_field(sfield::PreviousTxnLgrSeq)
+            .map(|ledger_seq| PreviousTxn { id, ledger_seq })
+    }
+}
+
+/// Handle to the ledger object the contract is running on (for a Smart Escrow, the escrow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CurrentObject;
+
+impl LedgerObjectView for CurrentObject {
+    #[inline]
+    fn get_field<T: LedgerObjectFieldGetter, const CODE: i32>(
+        &self,
+        field: SField<T, CODE>,
+    ) -> Result<T> {
+        current_ledger_object::get_field(field)
+    }
+
+    #[inline]
+    fn get_field_optional<T: LedgerObjectFieldGetter, const CODE: i32>(
+        &self,
+        field: SField<T, CODE>,
+    ) -> Result<Option<T>> {
+        current_ledger_object::get_field_optional(field)
+    }
+
+    #[inline]
+    fn array_len<const CODE: i32>(&self, field: SField<Array, CODE>) -> Result<usize> {
+        current_ledger_object::array_len(field)
+    }
+
+    #[inline]
+    fn get_nested_field<T: FromLedger>(&self, locator: &Locator) -> Result<T> {
+        current_ledger_object::get_nested_field(locator)
+    }
+}
+
+impl LedgerObjectView for Slot {
+    #[inline]
+    fn get_field<T: LedgerObjectFieldGetter, const CODE: i32>(
+        &self,
+        field: SField<T, CODE>,
+    ) -> Result<T> {
+        ledger_object::get_field(self.num(), field)
+    }
+
+    #[inline]
+    fn get_field_optional<T: LedgerObjectFieldGetter, const CODE: i32>(
+        &self,
+        field: SField<T, CODE>,
+    ) -> Result<Option<T>> {
+        ledger_object::get_field_optional(self.num(), field)
+    }
+
+    #[inline]
+    fn array_len<const CODE: i32>(&self, field: SField<Array, CODE>) -> Result<usize> {
+        ledger_object::array_len(self.num(), field)
+    }
+
+    #[inline]
+    fn get_nested_field<T: FromLedger>(&self, locator: &Locator) -> Result<T> {
+        ledger_object::get_nested_field(self.num(), locator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::{always, eq};
+
+    // Written once, run against both handles below.
+    fn audit<V: LedgerObjectView>(object: &V) -> (bool, Result<PreviousTxn>) {
+        (
+            object.has_flags(0x0001_0000).unwrap(),
+            object.previous_txn(),
+        )
+    }
+
+    fn write_u32(ptr: *mut u8, value: u32) -> i32 {
+        unsafe { core::ptr::copy_nonoverlapping(value.to_le_bytes().as_ptr(), ptr, 4) };
+        4
+    }
+
+    #[test]
+    fn test_current_object_reads_current_ledger_object() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::Flags)), always(), eq(4))
+            .times(1)
+            .returning(|_, ptr, _| write_u32(ptr, 0x0003_0000));
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::PreviousTxnID)), always(), eq(32))
+            .times(1)
+            .returning(|_, ptr, _| {
+                unsafe { core::ptr::write_bytes(ptr, 0xAB, 32) };
+                32
+            });
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::PreviousTxnLgrSeq)), always(), eq(4))
+            .times(1)
+            .returning(|_, ptr, _| write_u32(ptr, 77));
+        let _guard = setup_mock(mock);
+
+        let (flagged, previous) = audit(&CurrentObject);
+        assert!(flagged);
+        assert_eq!(
+            previous.unwrap(),
+            PreviousTxn {
+                id: Hash256::from([0xAB; 32]),
+                ledger_seq: 77,
+            }
+        );
+    }
+
+    #[test]
+    fn test_slot_reads_cached_ledger_object() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| 4);
+        mock.expect_get_ledger_obj_field()
+            .with(eq(4), eq(i32::from(sfield::Flags)), always(), eq(4))
+            .times(1)
+            .returning(|_, _, ptr, _| write_u32(ptr, 0x0002_0000));
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(4),
+                eq(i32::from(sfield::PreviousTxnID)),
+                always(),
+                eq(32),
+            )
+            .times(1)
+            .returning(|_, _, _, _| FIELD_NOT_FOUND);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        let slot = Slot::cache(&[0; 32]).unwrap();
+        let (flagged, previous) = audit(&slot);
+        assert!(!flagged);
+        assert_eq!(previous.err().unwrap().code(), FIELD_NOT_FOUND);
+    }
+}
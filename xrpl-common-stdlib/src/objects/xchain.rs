@@ -0,0 +1,145 @@
+//! `Bridge` and `XChainOwnedClaimID` ledger objects, for contracts that release funds based on
+//! the state of a cross-chain transfer.
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::current_tx;
+//! use xrpl_common_stdlib::objects::traits::XChainOwnedClaimIdFields;
+//! use xrpl_common_stdlib::objects::xchain::XChainOwnedClaimID;
+//! use xrpl_common_stdlib::sfield;
+//!
+//! // Release once the witnesses have attested to the claim named in the transaction.
+//! let bridge = current_tx::get_field(sfield::XChainBridge).unwrap();
+//! let claim_id = current_tx::get_field(sfield::XChainClaimID).unwrap();
+//! let claim = XChainOwnedClaimID::load(&bridge, claim_id).unwrap();
+//! let attested = claim.get_attestation_count().unwrap() > 0;
+//! # let _ = attested;
+//! ```
+
+use crate::host::Result;
+use crate::keylets::{bridge_keylet, xchain_owned_claim_id_keylet};
+use crate::objects::slot::Slot;
+use crate::objects::traits::{BridgeFields, LedgerObjectCommonFields, XChainOwnedClaimIdFields};
+use crate::types::xchain_bridge::{ChainType, XChainBridge};
+
+/// A cached `Bridge` ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Bridge {
+    pub slot_num: i32,
+}
+
+impl Bridge {
+    /// Caches the `Bridge` object for the given side of `bridge` in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the bridge does not exist on this chain.
+    pub fn load(bridge: &XChainBridge, chain: ChainType) -> Result<Self> {
+        bridge_keylet(bridge, chain)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Bridge {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for Bridge {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl BridgeFields for Bridge {}
+
+/// A cached `XChainOwnedClaimID` ledger object.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct XChainOwnedClaimID {
+    pub slot_num: i32,
+}
+
+impl XChainOwnedClaimID {
+    /// Caches the `XChainOwnedClaimID` object for `claim_id` on `bridge` in a new host slot.
+    ///
+    /// Returns `LedgerObjNotFound` if the claim ID was never created or has already been claimed.
+    pub fn load(bridge: &XChainBridge, claim_id: u64) -> Result<Self> {
+        xchain_owned_claim_id_keylet(bridge, claim_id)
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| XChainOwnedClaimID {
+                slot_num: slot.num(),
+            })
+    }
+}
+
+impl LedgerObjectCommonFields for XChainOwnedClaimID {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl XChainOwnedClaimIdFields for XChainOwnedClaimID {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::LEDGER_OBJ_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::sfield;
+    use crate::types::account_id::AccountID;
+    use crate::types::issue::{Issue, XrpIssue};
+    use mockall::predicate::{always, eq};
+
+    fn xrp_bridge() -> XChainBridge {
+        XChainBridge {
+            locking_chain_door: AccountID([0x11; 20]),
+            locking_chain_issue: Issue::XRP(XrpIssue {}),
+            issuing_chain_door: AccountID([0x22; 20]),
+            issuing_chain_issue: Issue::XRP(XrpIssue {}),
+        }
+    }
+
+    fn expect_keylet_hash(mock: &mut MockHostBindings) {
+        mock.expect_compute_sha512_half()
+            .times(1)
+            .returning(|_, _, out, len| {
+                unsafe { core::ptr::write_bytes(out, 0xAB, len) };
+                len as i32
+            });
+    }
+
+    #[test]
+    fn test_load_claim_id_and_read_counters() {
+        let mut mock = MockHostBindings::new();
+        expect_keylet_hash(&mut mock);
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| 3);
+        mock.expect_get_ledger_obj_field()
+            .with(eq(3), eq(i32::from(sfield::XChainClaimID)), always(), eq(8))
+            .times(1)
+            .returning(|_, _, ptr, _| {
+                unsafe { ptr.copy_from_nonoverlapping(9u64.to_le_bytes().as_ptr(), 8) };
+                8
+            });
+        mock.expect_get_ledger_obj_array_len()
+            .with(eq(3), eq(i32::from(sfield::XChainClaimAttestations)))
+            .times(1)
+            .returning(|_, _| 2);
+        let _guard = setup_mock(mock);
+
+        let claim = XChainOwnedClaimID::load(&xrp_bridge(), 9).unwrap();
+        assert_eq!(claim.slot_num, 3);
+        assert_eq!(claim.get_xchain_claim_id().unwrap(), 9);
+        assert_eq!(claim.get_attestation_count().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_load_missing_bridge() {
+        let mut mock = MockHostBindings::new();
+        expect_keylet_hash(&mut mock);
+        mock.expect_cache_ledger_obj()
+            .times(1)
+            .returning(|_, _, _| LEDGER_OBJ_NOT_FOUND);
+        let _guard = setup_mock(mock);
+
+        let result = Bridge::load(&xrp_bridge(), ChainType::Locking);
+        assert_eq!(result.err().unwrap().code(), LEDGER_OBJ_NOT_FOUND);
+    }
+}
@@ -0,0 +1,354 @@
+//! Dutch (descending-price) auction schedules.
+//!
+//! A Dutch auction starts at a high ask and lowers it over time until a bidder accepts. This
+//! module computes the current ask from the parent ledger close time so that an auction-style
+//! escrow can compare the amount offered in the triggering transaction against it.
+//!
+//! All arithmetic is delegated to the host `float_*` functions, so prices are [`OpaqueFloat`]
+//! values and results match rippled's `Number` rounding exactly.
+//!
+//! Two schedules are supported:
+//!
+//! - [`Decay::Linear`]: the ask falls in a straight line from the start price to the floor
+//!   price over a fixed duration.
+//! - [`Decay::Exponential`]: every `step_secs` seconds the ask is multiplied by `factor`
+//!   (e.g. `0.95` for a 5% drop per step), and never falls below the floor price.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::patterns::auction::{Decay, DutchAuction};
+//! use xrpl_common_stdlib::types::amount::Amount;
+//! use xrpl_common_stdlib::types::opaque_float::{FLOAT_ONE, OpaqueFloat};
+//!
+//! let auction = DutchAuction {
+//!     start_price: OpaqueFloat(FLOAT_ONE),
+//!     floor_price: OpaqueFloat([0x80, 0, 0, 0, 0, 0, 0, 0]),
+//!     start_time: 800_000_000,
+//!     decay: Decay::Linear { duration_secs: 3_600 },
+//! };
+//!
+//! let bid = Amount::XRP { num_drops: 1 };
+//! let accepted = auction.accepts(&bid).unwrap_or(false);
+//! # let _ = accepted;
+//! ```
+
+use crate::host::chain::parent_ledger_time;
+use crate::host::{Error, FLOAT_ROUNDING_MODES_TO_NEAREST, Result};
+use crate::types::amount::Amount;
+use crate::types::opaque_float::OpaqueFloat;
+
+/// Rounding used for every step of the price computation.
+const ROUNDING: i32 = FLOAT_ROUNDING_MODES_TO_NEAREST;
+
+/// How the ask of a [`DutchAuction`] falls over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decay {
+    /// The ask falls linearly from the start price to the floor price over `duration_secs`.
+    Linear { duration_secs: u32 },
+
+    /// The ask is multiplied by `factor` once every `step_secs` seconds. `factor` is expected
+    /// to be between 0 and 1.
+    Exponential { factor: OpaqueFloat, step_secs: u32 },
+}
+
+/// Parameters of a descending-price auction.
+///
+/// Times are expressed in seconds since the Ripple epoch, i.e. the same unit as
+/// [`parent_ledger_time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DutchAuction {
+    /// The ask at (and before) `start_time`.
+    pub start_price: OpaqueFloat,
+    /// The lowest ask the auction will ever reach.
+    pub floor_price: OpaqueFloat,
+    /// The time at which the ask starts falling.
+    pub start_time: u32,
+    /// The decay schedule applied after `start_time`.
+    pub decay: Decay,
+}
+
+impl DutchAuction {
+    /// Computes the ask at time `now`.
+    ///
+    /// Returns the start price if `now` is at or before `start_time`, and never returns less
+    /// than the floor price.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If an exponential schedule has a `step_secs` of zero
+    /// * Any error returned by the underlying float host functions
+    pub fn price_at(&self, now: u32) -> Result<OpaqueFloat> {
+        if now <= self.start_time {
+            return Result::Ok(self.start_price);
+        }
+        let elapsed = now - self.start_time;
+
+        match self.decay {
+            Decay::Linear { duration_secs } => {
+                if elapsed >= duration_secs {
+                    return Result::Ok(self.floor_price);
+                }
+                // start - (start - floor) * elapsed / duration
+                self.start_price
+                    .subtract(&self.floor_price, ROUNDING)
+                    .and_then(|span| {
+                        OpaqueFloat::from_int(elapsed as i64, ROUNDING)
+                            .and_then(|elapsed| span.multiply(&elapsed, ROUNDING))
+                    })
+                    .and_then(|scaled| {
+                        OpaqueFloat::from_int(duration_secs as i64, ROUNDING)
+                            .and_then(|duration| scaled.divide(&duration, ROUNDING))
+                    })
+                    .and_then(|drop| self.start_price.subtract(&drop, ROUNDING))
+            }
+            Decay::Exponential { factor, step_secs } => {
+                if step_secs == 0 {
+                    return Result::Err(Error::InvalidParams);
+                }
+                let steps = (elapsed / step_secs).min(i32::MAX as u32) as i32;
+                if steps == 0 {
+                    return Result::Ok(self.start_price);
+                }
+                factor
+                    .pow(steps, ROUNDING)
+                    .and_then(|multiplier| self.start_price.multiply(&multiplier, ROUNDING))
+                    .and_then(|price| price.max(self.floor_price))
+            }
+        }
+    }
+
+    /// Computes the ask as of the parent ledger's close time.
+    pub fn current_price(&self) -> Result<OpaqueFloat> {
+        parent_ledger_time().and_then(|now| self.price_at(now))
+    }
+
+    /// Returns `true` if `bid` is greater than or equal to the current ask.
+    ///
+    /// XRP bids are compared in drops and MPT bids in raw units, so the auction prices must
+    /// be expressed in the same unit as the bids it is meant to accept.
+    pub fn accepts(&self, bid: &Amount) -> Result<bool> {
+        let ask = match self.current_price() {
+            Result::Ok(ask) => ask,
+            Result::Err(e) => return Result::Err(e),
+        };
+        amount_to_float(bid)
+            .and_then(|bid| bid.less_than(&ask))
+            .map(|below| !below)
+    }
+}
+
+/// Converts the numeric part of an [`Amount`] into an [`OpaqueFloat`].
+fn amount_to_float(amount: &Amount) -> Result<OpaqueFloat> {
+    match amount {
+        Amount::XRP { num_drops } => OpaqueFloat::from_int(*num_drops, ROUNDING),
+        Amount::IOU { amount, .. } => Result::Ok(*amount),
+        Amount::MPT {
+            num_units,
+            is_positive,
+            ..
+        } => {
+            let Ok(units) = i64::try_from(*num_units) else {
+                return Result::Err(Error::InvalidParams);
+            };
+            OpaqueFloat::from_int(if *is_positive { units } else { -units }, ROUNDING)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{INTERNAL_ERROR, INVALID_FLOAT_COMPUTATION, INVALID_PARAMS};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::types::opaque_float::FLOAT_ONE;
+    use mockall::predicate::{always, eq};
+
+    const START: OpaqueFloat = OpaqueFloat(FLOAT_ONE);
+    const FLOOR: OpaqueFloat = OpaqueFloat([0x80, 0, 0, 0, 0, 0, 0, 0]);
+    const MARKER: [u8; 8] = [0xD5, 0, 0, 0, 0, 0, 0, 0x42];
+
+    fn write_float(ptr: *mut u8, bytes: [u8; 8]) {
+        unsafe {
+            for (i, b) in bytes.iter().enumerate() {
+                *ptr.add(i) = *b;
+            }
+        }
+    }
+
+    fn linear(duration_secs: u32) -> DutchAuction {
+        DutchAuction {
+            start_price: START,
+            floor_price: FLOOR,
+            start_time: 1_000,
+            decay: Decay::Linear { duration_secs },
+        }
+    }
+
+    fn exponential(step_secs: u32) -> DutchAuction {
+        DutchAuction {
+            start_price: START,
+            floor_price: FLOOR,
+            start_time: 1_000,
+            decay: Decay::Exponential {
+                factor: OpaqueFloat(FLOAT_ONE),
+                step_secs,
+            },
+        }
+    }
+
+    // ---- linear ----
+
+    #[test]
+    fn test_linear_before_start_returns_start_price() {
+        let _guard = setup_mock(MockHostBindings::new());
+        assert_eq!(linear(100).price_at(1_000).unwrap(), START);
+        assert_eq!(linear(100).price_at(0).unwrap(), START);
+    }
+
+    #[test]
+    fn test_linear_after_end_returns_floor_price() {
+        let _guard = setup_mock(MockHostBindings::new());
+        assert_eq!(linear(100).price_at(1_100).unwrap(), FLOOR);
+        assert_eq!(linear(0).price_at(1_001).unwrap(), FLOOR);
+    }
+
+    #[test]
+    fn test_linear_midway_uses_host_float_math() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_from_int()
+            .with(eq(25), always(), always(), always())
+            .times(1)
+            .returning(|_, _, len, _| len as i32);
+        mock.expect_float_from_int()
+            .with(eq(100), always(), always(), always())
+            .times(1)
+            .returning(|_, _, len, _| len as i32);
+        mock.expect_float_subtract()
+            .times(2)
+            .returning(|_, _, _, _, out, len, _| {
+                write_float(out, MARKER);
+                len as i32
+            });
+        mock.expect_float_multiply()
+            .times(1)
+            .returning(|_, _, _, _, _, len, _| len as i32);
+        mock.expect_float_divide()
+            .times(1)
+            .returning(|_, _, _, _, _, len, _| len as i32);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(linear(100).price_at(1_025).unwrap(), OpaqueFloat(MARKER));
+    }
+
+    #[test]
+    fn test_linear_propagates_float_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_subtract()
+            .times(1)
+            .returning(|_, _, _, _, _, _, _| INVALID_FLOAT_COMPUTATION);
+        let _guard = setup_mock(mock);
+
+        let result = linear(100).price_at(1_050);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().code(), INVALID_FLOAT_COMPUTATION);
+    }
+
+    // ---- exponential ----
+
+    #[test]
+    fn test_exponential_zero_step_is_invalid() {
+        let _guard = setup_mock(MockHostBindings::new());
+        let result = exponential(0).price_at(2_000);
+        assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_exponential_before_first_step_returns_start_price() {
+        let _guard = setup_mock(MockHostBindings::new());
+        assert_eq!(exponential(60).price_at(1_059).unwrap(), START);
+    }
+
+    #[test]
+    fn test_exponential_raises_factor_to_elapsed_steps() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_pow()
+            .with(always(), always(), eq(3), always(), always(), always())
+            .times(1)
+            .returning(|_, _, _, _, len, _| len as i32);
+        mock.expect_float_multiply()
+            .times(1)
+            .returning(|_, _, _, _, out, len, _| {
+                write_float(out, MARKER);
+                len as i32
+            });
+        mock.expect_float_compare()
+            .times(1)
+            .returning(|_, _, _, _| 1);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            exponential(60).price_at(1_190).unwrap(),
+            OpaqueFloat(MARKER)
+        );
+    }
+
+    #[test]
+    fn test_exponential_clamps_to_floor_price() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_pow()
+            .times(1)
+            .returning(|_, _, _, _, len, _| len as i32);
+        mock.expect_float_multiply()
+            .times(1)
+            .returning(|_, _, _, _, _, len, _| len as i32);
+        mock.expect_float_compare()
+            .times(1)
+            .returning(|_, _, _, _| 2);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(exponential(1).price_at(5_000).unwrap(), FLOOR);
+    }
+
+    // ---- accepts ----
+
+    #[test]
+    fn test_accepts_bid_at_or_above_ask() {
+        for (compare, expected) in [(0, true), (1, true), (2, false)] {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_parent_ledger_time()
+                .times(1)
+                .returning(|_, len| len as i32);
+            mock.expect_float_from_int()
+                .with(eq(500), always(), always(), always())
+                .times(1)
+                .returning(|_, _, len, _| len as i32);
+            mock.expect_float_compare()
+                .times(1)
+                .returning(move |_, _, _, _| compare);
+            let _guard = setup_mock(mock);
+
+            let bid = Amount::XRP { num_drops: 500 };
+            assert_eq!(linear(100).accepts(&bid).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_accepts_reports_unexpected_compare_result() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_parent_ledger_time()
+            .times(1)
+            .returning(|_, len| len as i32);
+        mock.expect_float_from_int()
+            .times(1)
+            .returning(|_, _, len, _| len as i32);
+        mock.expect_float_compare()
+            .times(1)
+            .returning(|_, _, _, _| 3);
+        let _guard = setup_mock(mock);
+
+        let result = linear(100).accepts(&Amount::XRP { num_drops: 500 });
+        assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+    }
+}
@@ -0,0 +1,213 @@
+//! Computation-allowance budgeting.
+//!
+//! Every programmability trigger carries a `ComputationAllowance`: the maximum amount of gas the
+//! host will let the contract burn before aborting it. The host does not report how much has been
+//! used so far, so a contract that wants to stop gracefully — for example, by returning a
+//! "not yet" result instead of being killed mid-way — has to keep its own estimate.
+//!
+//! [`GasBudget`] holds that estimate. Authors register an estimated cost before each expensive
+//! section and check [`GasBudget::remaining`] (or use [`GasBudget::try_charge`]) to decide whether
+//! to continue. [`GasBudget::checkpoint`] writes the remaining estimate to the trace log, which is
+//! the easiest way to calibrate the estimates against the real usage reported by rippled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::patterns::budget::GasBudget;
+//! use xrpl_common_stdlib::types::gas::Gas;
+//!
+//! const PER_OBJECT: Gas = Gas(2_000);
+//!
+//! let mut budget = GasBudget::from_current_tx().unwrap_or(GasBudget::new(Gas::ZERO));
+//! for _ in 0..10 {
+//!     if !budget.try_charge(PER_OBJECT) {
+//!         break; // stop before the host runs out of allowance
+//!     }
+//!     // ...load and inspect one ledger object...
+//! }
+//! let _ = budget.checkpoint("after scan");
+//! ```
+
+use crate::current_tx::traits::TransactionCommonFields;
+use crate::host::Result;
+use crate::host::costs::{HostCall, estimated_cost};
+use crate::host::trace::trace_num;
+use crate::types::gas::Gas;
+
+/// Running estimate of how much of the computation allowance has been used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasBudget {
+    allowance: Gas,
+    spent: Gas,
+}
+
+impl GasBudget {
+    /// Creates a budget for an allowance of `allowance` gas, with nothing spent yet.
+    pub const fn new(allowance: Gas) -> Self {
+        GasBudget {
+            allowance,
+            spent: Gas::ZERO,
+        }
+    }
+
+    /// Creates a budget from `tx`'s `ComputationAllowance` field.
+    pub fn from_tx<T: TransactionCommonFields>(tx: &T) -> Result<Self> {
+        tx.get_computation_allowance().map(Self::new)
+    }
+
+    /// Creates a budget from the current transaction's `ComputationAllowance` field.
+    ///
+    /// Every transaction type carries the field, so this does not need a typed transaction; use
+    /// [`from_tx`](Self::from_tx) when one is at hand.
+    pub fn from_current_tx() -> Result<Self> {
+        struct CurrentTx;
+        impl TransactionCommonFields for CurrentTx {}
+
+        Self::from_tx(&CurrentTx)
+    }
+
+    /// The allowance this budget was created with.
+    pub const fn allowance(&self) -> Gas {
+        self.allowance
+    }
+
+    /// The total estimated cost charged so far.
+    pub const fn spent(&self) -> Gas {
+        self.spent
+    }
+
+    /// The estimated allowance left, or `0` once the charges exceed it.
+    pub const fn remaining(&self) -> Gas {
+        self.allowance.saturating_sub(self.spent)
+    }
+
+    /// Returns whether `cost` more gas fits in the remaining estimate.
+    pub const fn can_afford(&self, cost: Gas) -> bool {
+        cost.0 <= self.remaining().0
+    }
+
+    /// Records `cost` as spent, whether or not it fits in the remaining estimate.
+    ///
+    /// Use this for work that has already happened or cannot be skipped.
+    pub fn charge(&mut self, cost: Gas) {
+        self.spent = self.spent.saturating_add(cost);
+    }
+
+    /// Records `cost` as spent and returns `true` if it fits in the remaining estimate; otherwise
+    /// leaves the budget unchanged and returns `false`.
+    pub fn try_charge(&mut self, cost: Gas) -> bool {
+        if !self.can_afford(cost) {
+            return false;
+        }
+        self.spent += cost;
+        true
+    }
+
+    /// Charges `count` calls of the host function family `call`, using the estimates in
+    /// [`costs`](crate::host::costs), if they fit in the remaining estimate.
+    ///
+    /// See [`try_charge`](Self::try_charge).
+    pub fn try_charge_calls(&mut self, call: HostCall, count: u32) -> bool {
+        self.try_charge(Gas(estimated_cost(call).0.saturating_mul(count)))
+    }
+
+    /// Writes `label` and the remaining estimate to the trace log.
+    pub fn checkpoint(&self, label: &str) -> Result<i32> {
+        trace_num(label, self.remaining().0 as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::FIELD_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::sfield;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_charges_reduce_remaining() {
+        let mut budget = GasBudget::new(Gas(1_000));
+        budget.charge(Gas(300));
+
+        assert_eq!(budget.spent(), Gas(300));
+        assert_eq!(budget.remaining(), Gas(700));
+        assert!(budget.can_afford(Gas(700)));
+        assert!(!budget.can_afford(Gas(701)));
+    }
+
+    #[test]
+    fn test_charge_saturates_past_the_allowance() {
+        let mut budget = GasBudget::new(Gas(100));
+        budget.charge(Gas(u32::MAX));
+        budget.charge(Gas(1));
+
+        assert_eq!(budget.spent(), Gas(u32::MAX));
+        assert_eq!(budget.remaining(), Gas::ZERO);
+    }
+
+    #[test]
+    fn test_try_charge_refuses_without_spending() {
+        let mut budget = GasBudget::new(Gas(100));
+
+        assert!(budget.try_charge(Gas(60)));
+        assert!(!budget.try_charge(Gas(41)));
+        assert_eq!(budget.spent(), Gas(60));
+        assert!(budget.try_charge(Gas(40)));
+        assert_eq!(budget.remaining(), Gas::ZERO);
+    }
+
+    #[test]
+    fn test_try_charge_calls_uses_cost_table() {
+        let mut budget = GasBudget::new(estimated_cost(HostCall::GetField) * 3);
+
+        assert!(budget.try_charge_calls(HostCall::GetField, 2));
+        assert!(!budget.try_charge_calls(HostCall::GetField, 2));
+        assert!(budget.try_charge_calls(HostCall::GetField, 1));
+        assert!(!budget.try_charge_calls(HostCall::CacheLedgerObj, u32::MAX));
+    }
+
+    #[test]
+    fn test_from_current_tx_reads_computation_allowance() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::ComputationAllowance)), always(), eq(4))
+            .times(1)
+            .returning(|_, ptr, _| {
+                unsafe { core::ptr::copy_nonoverlapping(5_000u32.to_le_bytes().as_ptr(), ptr, 4) };
+                4
+            });
+        let _guard = setup_mock(mock);
+
+        let budget = GasBudget::from_current_tx().unwrap();
+        assert_eq!(budget.allowance(), Gas(5_000));
+        assert_eq!(budget.remaining(), Gas(5_000));
+    }
+
+    #[test]
+    fn test_from_current_tx_propagates_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .returning(|_, _, _| FIELD_NOT_FOUND);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        let result = GasBudget::from_current_tx();
+        assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_checkpoint_traces_remaining() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_trace_num()
+            .with(always(), eq(5), eq(750))
+            .times(1)
+            .returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        let mut budget = GasBudget::new(Gas(1_000));
+        budget.charge(Gas(250));
+        assert!(budget.checkpoint("phase").is_ok());
+    }
+}
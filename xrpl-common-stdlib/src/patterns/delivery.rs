@@ -0,0 +1,365 @@
+//! Checking ahead of time whether an account can receive an asset.
+//!
+//! A finish function that lets an escrow finish only for the ledger to reject the delivery wastes
+//! the finisher's fee and leaves the escrow in place with no explanation. [`can_deliver`] runs the
+//! ledger's main delivery checks first, so the contract can refuse with a specific reason:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::Result;
+//! use xrpl_common_stdlib::patterns::delivery::{DeliveryCheck, can_deliver};
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//! use xrpl_common_stdlib::types::issue::{Issue, XrpIssue};
+//!
+//! let destination = AccountID([0x2B; 20]);
+//! match can_deliver(&destination, &Issue::XRP(XrpIssue {})) {
+//!     Result::Ok(DeliveryCheck::Deliverable) => { /* finish */ }
+//!     Result::Ok(blocked) => { let _ = blocked.reason(); /* reject */ }
+//!     Result::Err(_) => { /* reject */ }
+//! }
+//! ```
+//!
+//! The checks are:
+//!
+//! * the destination account exists;
+//! * if it has `lsfDepositAuth`, the account submitting the transaction is the destination or
+//!   has a `DepositPreauth` entry from it;
+//! * for an IOU, the destination has a trust line to the issuer with room below its limit;
+//! * for an MPT, [`holder_is_authorized`] accepts the destination.
+//!
+//! Freezes, credential-based preauthorization, and trust lines or `MPToken`s the ledger would
+//! create during the finish are not taken into account, so `Deliverable` is a strong hint rather
+//! than a guarantee. All objects are read through
+//! [`SCRATCH_SLOT`](crate::objects::slot::SCRATCH_SLOT).
+
+use crate::current_tx;
+use crate::host::{Error, FLOAT_ROUNDING_MODES_TO_NEAREST, Result};
+use crate::keylets::{account_keylet, deposit_preauth_keylet, line_keylet};
+use crate::objects::exists;
+use crate::objects::ledger_object;
+use crate::objects::mpt::holder_is_authorized;
+use crate::objects::slot::cache_scratch;
+use crate::objects::traits::{LSF_DEPOSIT_AUTH, LedgerObjectCommonFields};
+use crate::sfield;
+use crate::types::account_id::AccountID;
+use crate::types::amount::Amount;
+use crate::types::issue::{IouIssue, Issue};
+use crate::types::opaque_float::{FLOAT_NEGATIVE_ONE, OpaqueFloat};
+use core::cmp::Ordering;
+
+/// Outcome of [`can_deliver`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryCheck {
+    /// None of the checks found a reason the delivery would fail.
+    Deliverable,
+    /// The destination account does not exist.
+    DestinationMissing,
+    /// The destination requires deposit authorization and has not preauthorized the submitter.
+    DepositAuthRequired,
+    /// The destination has no trust line for the IOU.
+    NoTrustLine,
+    /// The destination already holds as much of the IOU as its trust line allows.
+    TrustLineFull,
+    /// The destination may not hold the MPT.
+    MptNotAuthorized,
+}
+
+impl DeliveryCheck {
+    /// Returns whether the delivery passed every check.
+    pub const fn is_deliverable(self) -> bool {
+        matches!(self, DeliveryCheck::Deliverable)
+    }
+
+    /// A short description of the outcome, suitable for tracing.
+    pub const fn reason(self) -> &'static str {
+        match self {
+            DeliveryCheck::Deliverable => "deliverable",
+            DeliveryCheck::DestinationMissing => "destination account does not exist",
+            DeliveryCheck::DepositAuthRequired => "destination requires deposit preauthorization",
+            DeliveryCheck::NoTrustLine => "destination has no trust line for the asset",
+            DeliveryCheck::TrustLineFull => "destination trust line is at its limit",
+            DeliveryCheck::MptNotAuthorized => "destination is not authorized to hold the MPT",
+        }
+    }
+}
+
+/// Checks whether `asset` can be delivered to `destination` by the current transaction, whose
+/// `Account` is taken as the submitter for deposit authorization.
+///
+/// For an `EscrowFinish` this is the account finishing the escrow, which is the one the ledger
+/// checks against the destination's preauthorizations.
+///
+/// # Errors
+///
+/// Returns any error from reading the transaction's `Account`, or from [`can_deliver_from`].
+pub fn can_deliver(destination: &AccountID, asset: &Issue) -> Result<DeliveryCheck> {
+    match current_tx::get_field(sfield::Account) {
+        Result::Ok(submitter) => can_deliver_from(&submitter, destination, asset),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Checks whether `asset` can be delivered to `destination` by a transaction submitted by
+/// `submitter`. See the [module documentation](self) for the checks made.
+///
+/// # Errors
+///
+/// * `LedgerObjNotFound` - If `asset` is an MPT whose issuance does not exist
+/// * Any other error from computing keylets or reading the objects
+pub fn can_deliver_from(
+    submitter: &AccountID,
+    destination: &AccountID,
+    asset: &Issue,
+) -> Result<DeliveryCheck> {
+    let flags = match account_keylet(destination)
+        .and_then(|keylet| cache_scratch(&keylet))
+        .and_then(|slot| slot.get_flags())
+    {
+        Result::Ok(flags) => flags,
+        Result::Err(Error::LedgerObjNotFound) => {
+            return Result::Ok(DeliveryCheck::DestinationMissing);
+        }
+        Result::Err(e) => return Result::Err(e),
+    };
+    if flags & LSF_DEPOSIT_AUTH != 0 && submitter != destination {
+        match deposit_preauth_keylet(destination, submitter).and_then(|keylet| exists(&keylet)) {
+            Result::Ok(true) => {}
+            Result::Ok(false) => return Result::Ok(DeliveryCheck::DepositAuthRequired),
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    match asset {
+        Issue::XRP(_) => Result::Ok(DeliveryCheck::Deliverable),
+        Issue::IOU(iou) => check_trust_line(destination, iou),
+        Issue::MPT(mpt) => match holder_is_authorized(&mpt.mpt_id(), destination) {
+            Result::Ok(true) => Result::Ok(DeliveryCheck::Deliverable),
+            Result::Ok(false) => Result::Ok(DeliveryCheck::MptNotAuthorized),
+            Result::Err(e) => Result::Err(e),
+        },
+    }
+}
+
+fn check_trust_line(destination: &AccountID, iou: &IouIssue) -> Result<DeliveryCheck> {
+    let issuer = iou.issuer();
+    if destination == &issuer {
+        return Result::Ok(DeliveryCheck::Deliverable);
+    }
+    let slot = match line_keylet(destination, &issuer, &iou.currency())
+        .and_then(|keylet| cache_scratch(&keylet))
+    {
+        Result::Ok(slot) => slot.num(),
+        Result::Err(Error::LedgerObjNotFound) => return Result::Ok(DeliveryCheck::NoTrustLine),
+        Result::Err(e) => return Result::Err(e),
+    };
+    // `Balance` is from the low account's side; each side keeps its own limit.
+    let destination_is_low = destination < &issuer;
+    let limit = if destination_is_low {
+        ledger_object::get_field(slot, sfield::LowLimit)
+    } else {
+        ledger_object::get_field(slot, sfield::HighLimit)
+    };
+    let limit = match limit {
+        Result::Ok(Amount::IOU { amount, .. }) => amount,
+        Result::Ok(_) => return Result::Err(Error::InvalidDecoding),
+        Result::Err(e) => return Result::Err(e),
+    };
+    let balance = match ledger_object::get_field(slot, sfield::Balance) {
+        Result::Ok(Amount::IOU { amount, .. }) if destination_is_low => amount,
+        Result::Ok(Amount::IOU { amount, .. }) => {
+            match amount.multiply(
+                &OpaqueFloat(FLOAT_NEGATIVE_ONE),
+                FLOAT_ROUNDING_MODES_TO_NEAREST,
+            ) {
+                Result::Ok(negated) => negated,
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Ok(_) => return Result::Err(Error::InvalidDecoding),
+        Result::Err(e) => return Result::Err(e),
+    };
+    match balance.compare(&limit) {
+        Result::Ok(Ordering::Less) => Result::Ok(DeliveryCheck::Deliverable),
+        Result::Ok(_) => Result::Ok(DeliveryCheck::TrustLineFull),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::LEDGER_OBJ_NOT_FOUND;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::slot::SCRATCH_SLOT;
+    use crate::types::currency::Currency;
+    use crate::types::issue::XrpIssue;
+    use crate::types::opaque_float::{FLOAT_ONE, FLOAT_ZERO};
+    use mockall::predicate::{always, eq};
+
+    const SUBMITTER: AccountID = AccountID([0x22; 20]);
+    const DESTINATION: AccountID = AccountID([0x11; 20]);
+    const ISSUER: AccountID = AccountID([0x99; 20]);
+
+    fn write(out: *mut u8, bytes: &[u8]) -> i32 {
+        unsafe { out.copy_from_nonoverlapping(bytes.as_ptr(), bytes.len()) };
+        bytes.len() as i32
+    }
+
+    /// Keylets whose bytes identify the object: account `A`, preauth `P`, trust line `L`.
+    fn mock_keylets(mock: &mut MockHostBindings) {
+        mock.expect_account_keylet()
+            .returning(|_, _, out, _| write(out, &[b'A'; 32]));
+        mock.expect_deposit_preauth_keylet()
+            .returning(|_, _, _, _, out, _| write(out, &[b'P'; 32]));
+        mock.expect_line_keylet()
+            .returning(|_, _, _, _, _, _, out, _| write(out, &[b'L'; 32]));
+    }
+
+    /// Caches objects whose keylet starts with one of `present`; reports the rest missing.
+    fn mock_cache(mock: &mut MockHostBindings, present: &'static [u8]) {
+        mock.expect_cache_ledger_obj()
+            .returning(move |keylet, _, _| {
+                if present.contains(&unsafe { *keylet }) {
+                    SCRATCH_SLOT
+                } else {
+                    LEDGER_OBJ_NOT_FOUND
+                }
+            });
+    }
+
+    fn mock_flags(mock: &mut MockHostBindings, flags: u32) {
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::Flags)),
+                always(),
+                eq(4),
+            )
+            .returning(move |_, _, out, _| write(out, &flags.to_ne_bytes()));
+    }
+
+    fn xrp() -> Issue {
+        Issue::XRP(XrpIssue {})
+    }
+
+    #[test]
+    fn test_deposit_auth_and_missing_destination() {
+        let mut mock = MockHostBindings::new();
+        mock_keylets(&mut mock);
+        mock_cache(&mut mock, b"A");
+        mock_flags(&mut mock, LSF_DEPOSIT_AUTH);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            can_deliver_from(&SUBMITTER, &DESTINATION, &xrp()).unwrap(),
+            DeliveryCheck::DepositAuthRequired
+        );
+        assert_eq!(
+            can_deliver_from(&DESTINATION, &DESTINATION, &xrp()).unwrap(),
+            DeliveryCheck::Deliverable
+        );
+    }
+
+    #[test]
+    fn test_preauthorized_submitter_is_deliverable() {
+        let mut mock = MockHostBindings::new();
+        mock_keylets(&mut mock);
+        mock_cache(&mut mock, b"AP");
+        mock_flags(&mut mock, LSF_DEPOSIT_AUTH);
+        let _guard = setup_mock(mock);
+
+        let result = can_deliver_from(&SUBMITTER, &DESTINATION, &xrp()).unwrap();
+        assert!(result.is_deliverable());
+    }
+
+    #[test]
+    fn test_destination_missing() {
+        let mut mock = MockHostBindings::new();
+        mock_keylets(&mut mock);
+        mock_cache(&mut mock, b"");
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            can_deliver_from(&SUBMITTER, &DESTINATION, &xrp()).unwrap(),
+            DeliveryCheck::DestinationMissing
+        );
+    }
+
+    fn iou() -> Issue {
+        let mut code = [0u8; 20];
+        code[12..15].copy_from_slice(b"USD");
+        Issue::IOU(IouIssue::new(ISSUER, Currency::from(code)))
+    }
+
+    /// Serialized IOU amount whose value is the float `value`.
+    fn write_iou(out: *mut u8, value: [u8; 8]) -> i32 {
+        let mut bytes = [0u8; 48];
+        bytes[..8].copy_from_slice(&value);
+        write(out, &bytes)
+    }
+
+    fn mock_line(mock: &mut MockHostBindings, balance: [u8; 8], low_limit: [u8; 8]) {
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::Balance)),
+                always(),
+                always(),
+            )
+            .returning(move |_, _, out, _| write_iou(out, balance));
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(SCRATCH_SLOT),
+                eq(i32::from(sfield::LowLimit)),
+                always(),
+                always(),
+            )
+            .returning(move |_, _, out, _| write_iou(out, low_limit));
+    }
+
+    #[test]
+    fn test_trust_line_room() {
+        let mut mock = MockHostBindings::new();
+        mock_keylets(&mut mock);
+        mock_cache(&mut mock, b"AL");
+        mock_flags(&mut mock, 0);
+        mock_line(&mut mock, FLOAT_ZERO, FLOAT_ONE);
+        mock.expect_float_compare().returning(|a, _, b, _| unsafe {
+            let a = core::slice::from_raw_parts(a, 8);
+            let b = core::slice::from_raw_parts(b, 8);
+            if a == b {
+                0
+            } else if a == FLOAT_ZERO {
+                2
+            } else {
+                1
+            }
+        });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            can_deliver_from(&SUBMITTER, &DESTINATION, &iou()).unwrap(),
+            DeliveryCheck::Deliverable
+        );
+    }
+
+    #[test]
+    fn test_trust_line_missing() {
+        let mut mock = MockHostBindings::new();
+        mock_keylets(&mut mock);
+        mock_cache(&mut mock, b"A");
+        mock_flags(&mut mock, 0);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            can_deliver_from(&SUBMITTER, &DESTINATION, &iou()).unwrap(),
+            DeliveryCheck::NoTrustLine
+        );
+        assert_eq!(
+            can_deliver_from(&SUBMITTER, &ISSUER, &iou()).unwrap(),
+            DeliveryCheck::Deliverable
+        );
+    }
+}
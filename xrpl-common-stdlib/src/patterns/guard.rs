@@ -0,0 +1,234 @@
+//! Replay / one-shot guards kept in the `Data` field.
+//!
+//! Some contract logic must run at most once per ledger (or once per trigger), even though the
+//! contract itself may be invoked many times. A [`GuardTable`] records, for each caller-chosen
+//! key, the last ledger sequence in which the guarded logic ran, and
+//! [`once_per`](GuardTable::once_per) refuses to run it again until a later ledger.
+//!
+//! The table is a [`DataSection`], so it carries over between executions by being loaded from
+//! and saved to `Data` together with the rest of the contract's state (in an escrow, with
+//! `EscrowFinishContext::load_state` and `save_state`).
+//!
+//! # Data layout
+//!
+//! ```text
+//! ┌─────────────────────┬──────────────────┬──────────────────────────┐
+//! │ record count (u32)  │ key (u32, LE)    │ last ledger seq (u32, LE)│  × count
+//! └─────────────────────┴──────────────────┴──────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use xrpl_common_stdlib::patterns::guard::GuardTable;
+//!
+//! const PAYOUT: u32 = 1;
+//!
+//! // Loaded from `Data` in a contract; the table is empty on the first run.
+//! let mut guards = GuardTable::<4>::new();
+//! assert!(guards.once_per(PAYOUT, 100).unwrap());
+//! assert!(!guards.once_per(PAYOUT, 100).unwrap());
+//! assert_eq!(guards.last_run(PAYOUT), Some(100));
+//! // ...save `guards` back to `Data` after running the guarded logic...
+//! ```
+
+use crate::collections::ArrayMap;
+use crate::host::{Error, Result};
+use crate::types::contract_data::XRPL_CONTRACT_DATA_SIZE;
+use crate::types::data_cursor::{DataCursor, DataSection};
+
+/// Size in bytes of one `(key, ledger_seq)` record in the `Data` field.
+pub const GUARD_RECORD_SIZE: usize = 8;
+
+/// Maximum number of distinct keys that fit in the `Data` field.
+pub const MAX_GUARD_KEYS: usize = (XRPL_CONTRACT_DATA_SIZE - 4) / GUARD_RECORD_SIZE;
+
+/// The last ledger in which each of up to `N` guarded pieces of logic ran.
+///
+/// `N` must be at most [`MAX_GUARD_KEYS`] for a full table to be saved.
+#[derive(Debug, Clone, Default)]
+pub struct GuardTable<const N: usize> {
+    last_runs: ArrayMap<u32, u32, N>,
+}
+
+impl<const N: usize> GuardTable<N> {
+    /// Creates a table in which nothing has run yet.
+    pub const fn new() -> Self {
+        GuardTable {
+            last_runs: ArrayMap::new(),
+        }
+    }
+
+    /// Returns whether guarded logic identified by `key` should run in ledger `ledger_seq`.
+    ///
+    /// If `key` has not run in `ledger_seq` or any later ledger, its record is updated to
+    /// `ledger_seq` and `Ok(true)` is returned. Otherwise the table is left untouched and
+    /// `Ok(false)` is returned. Only the in-memory table changes; save it to keep the record.
+    ///
+    /// # Errors
+    ///
+    /// * `BufferTooSmall` - If `key` is new and the table already holds `N` keys
+    pub fn once_per(&mut self, key: u32, ledger_seq: u32) -> Result<bool> {
+        match self.last_runs.get_mut(&key) {
+            Some(last) if *last >= ledger_seq => Result::Ok(false),
+            Some(last) => {
+                *last = ledger_seq;
+                Result::Ok(true)
+            }
+            None => self.last_runs.insert(key, ledger_seq).map(|_| true),
+        }
+    }
+
+    /// Returns the last ledger sequence recorded for `key`, or `None` if it has never run.
+    pub fn last_run(&self, key: u32) -> Option<u32> {
+        self.last_runs.get(&key).copied()
+    }
+}
+
+impl<const N: usize> DataSection for GuardTable<N> {
+    /// Reads a table stored in the format described in the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If the records are truncated, a key appears twice, or there are
+    ///   more than `N` of them
+    fn read_section(cursor: &mut DataCursor) -> Result<Self> {
+        let count = match cursor.read_u32_le() {
+            Result::Ok(count) => count,
+            Result::Err(e) => return Result::Err(e),
+        };
+        let mut table = Self::new();
+        for _ in 0..count {
+            let record = cursor
+                .read_u32_le()
+                .and_then(|key| cursor.read_u32_le().map(|seq| (key, seq)));
+            let (key, seq) = match record {
+                Result::Ok(record) => record,
+                Result::Err(e) => return Result::Err(e),
+            };
+            match table.last_runs.insert(key, seq) {
+                Result::Ok(None) => {}
+                _ => return Result::Err(Error::InvalidDecoding),
+            }
+        }
+        Result::Ok(table)
+    }
+
+    fn write_section(&self, cursor: &mut DataCursor) -> Result<()> {
+        if let Result::Err(e) = cursor.write_u32_le(self.last_runs.len() as u32) {
+            return Result::Err(e);
+        }
+        for (&key, &seq) in self.last_runs.iter() {
+            let written = cursor
+                .write_u32_le(key)
+                .and_then(|()| cursor.write_u32_le(seq));
+            if let Result::Err(e) = written {
+                return Result::Err(e);
+            }
+        }
+        Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{BUFFER_TOO_SMALL, DATA_FIELD_TOO_LARGE, INVALID_DECODING};
+    use crate::types::contract_data::ContractData;
+
+    fn encoded<const N: usize>(table: &GuardTable<N>) -> ContractData {
+        let mut data = ContractData::new();
+        DataCursor::new(&mut data).write_section(table).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_first_run_records_the_ledger() {
+        let mut guards = GuardTable::<4>::new();
+        assert_eq!(guards.last_run(7), None);
+        assert!(guards.once_per(7, 100).unwrap());
+        assert_eq!(guards.last_run(7), Some(100));
+    }
+
+    #[test]
+    fn test_same_or_earlier_ledger_is_rejected() {
+        let mut guards = GuardTable::<4>::new();
+        guards.once_per(7, 100).unwrap();
+
+        assert!(!guards.once_per(7, 100).unwrap());
+        assert!(!guards.once_per(7, 99).unwrap());
+        assert_eq!(guards.last_run(7), Some(100));
+    }
+
+    #[test]
+    fn test_later_ledger_updates_the_record() {
+        let mut guards = GuardTable::<4>::new();
+        guards.once_per(1, 50).unwrap();
+        guards.once_per(7, 100).unwrap();
+
+        assert!(guards.once_per(7, 101).unwrap());
+        assert_eq!(guards.last_run(1), Some(50));
+        assert_eq!(guards.last_run(7), Some(101));
+    }
+
+    #[test]
+    fn test_full_table_rejects_new_key() {
+        let mut guards = GuardTable::<2>::new();
+        guards.once_per(1, 1).unwrap();
+        guards.once_per(2, 1).unwrap();
+
+        let result = guards.once_per(3, 2);
+        assert_eq!(result.err().unwrap().code(), BUFFER_TOO_SMALL);
+        assert!(guards.once_per(2, 2).unwrap());
+    }
+
+    #[test]
+    fn test_section_round_trip() {
+        let mut guards = GuardTable::<4>::new();
+        guards.once_per(9, 1234).unwrap();
+        guards.once_per(10, 5).unwrap();
+
+        let mut data = encoded(&guards);
+        assert_eq!(data.len, 4 + 2 * GUARD_RECORD_SIZE);
+        assert_eq!(&data.data[..4], &2u32.to_le_bytes());
+
+        let decoded: GuardTable<4> = DataCursor::new(&mut data).read_section().unwrap();
+        assert_eq!(decoded.last_run(9), Some(1234));
+        assert_eq!(decoded.last_run(10), Some(5));
+        assert_eq!(decoded.last_run(11), None);
+    }
+
+    #[test]
+    fn test_read_rejects_malformed_tables() {
+        let mut guards = GuardTable::<4>::new();
+        guards.once_per(9, 1234).unwrap();
+        guards.once_per(10, 5).unwrap();
+
+        let mut truncated = encoded(&guards);
+        truncated.len -= 1;
+        let result = DataCursor::new(&mut truncated).read_section::<GuardTable<4>>();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+
+        let result = DataCursor::new(&mut encoded(&guards)).read_section::<GuardTable<1>>();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+
+        let mut duplicate = ContractData::new();
+        let mut cursor = DataCursor::new(&mut duplicate);
+        for value in [2, 9, 1, 9, 2] {
+            cursor.write_u32_le(value).unwrap();
+        }
+        let result = DataCursor::new(&mut duplicate).read_section::<GuardTable<4>>();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+    }
+
+    #[test]
+    fn test_write_rejects_oversized_table() {
+        let mut guards = GuardTable::<{ MAX_GUARD_KEYS + 1 }>::new();
+        for key in 0..=MAX_GUARD_KEYS as u32 {
+            guards.once_per(key, 1).unwrap();
+        }
+        let mut data = ContractData::new();
+        let result = DataCursor::new(&mut data).write_section(&guards);
+        assert_eq!(result.err().unwrap().code(), DATA_FIELD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,245 @@
+//! Code identity for Smart Escrows.
+//!
+//! An escrow's `FinishFunction` field holds the WebAssembly module that runs when it is
+//! finished. Hashing it gives a compact identity for "this contract", which a contract can record
+//! in `Data`, or compare against another escrow's to check that a counterpart runs the code it
+//! expects (for example, the two legs of an atomic swap).
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::Result;
+//! use xrpl_common_stdlib::objects::escrow::EscrowReadFields;
+//! use xrpl_common_stdlib::patterns::identity::{code_hash_of, self_hash};
+//!
+//! fn runs_same_contract<T: EscrowReadFields>(counterpart: &T) -> bool {
+//!     match (code_hash_of(counterpart), self_hash()) {
+//!         (Result::Ok(theirs), Result::Ok(ours)) => theirs == ours,
+//!         _ => false,
+//!     }
+//! }
+//! ```
+//!
+//! # Hash construction
+//!
+//! The host's SHA-512Half accepts at most 1024 bytes, less than a finish function can be, so the
+//! code is hashed in chunks of [`CODE_HASH_CHUNK`] bytes:
+//!
+//! ```text
+//! state = [0; 32]
+//! for chunk in code.chunks(992):
+//!     state = SHA-512Half(state || chunk)
+//! ```
+//!
+//! An empty module hashes once with no chunk bytes. Off-chain tooling computing the expected
+//! identity of a compiled contract must use the same construction; it is not the plain
+//! SHA-512Half of the module.
+//!
+//! # Size limit
+//!
+//! [`self_hash`] and [`code_hash_of`] read the `FinishFunction` into a [`WasmBlob`], so they can
+//! only hash modules of up to [`MAX_HASHED_CODE_SIZE`] bytes. Larger modules are reported as
+//! `DataFieldTooLarge` rather than hashed; contracts that need to exceed the limit must be
+//! identified some other way.
+
+use crate::crypto::sha512_half;
+use crate::host::{Error, Result};
+use crate::objects::current_ledger_object;
+use crate::objects::escrow::EscrowReadFields;
+use crate::sfield;
+use crate::types::blob::{WASM_BLOB_SIZE, WasmBlob};
+
+/// Number of code bytes absorbed per SHA-512Half call: the host's 1024-byte input limit minus
+/// the 32-byte chaining state.
+pub const CODE_HASH_CHUNK: usize = 1024 - 32;
+
+/// Largest `FinishFunction`, in bytes, that [`self_hash`] and [`code_hash_of`] can hash: the
+/// capacity of a [`WasmBlob`].
+pub const MAX_HASHED_CODE_SIZE: usize = WASM_BLOB_SIZE;
+
+/// Computes the identity hash of a WebAssembly module, as described in the
+/// [module documentation](self).
+///
+/// # Errors
+///
+/// Returns any error from the host's SHA-512Half.
+pub fn code_hash(code: &[u8]) -> Result<[u8; 32]> {
+    let mut preimage = [0u8; 32 + CODE_HASH_CHUNK];
+    let mut state = [0u8; 32];
+    let mut chunks = code.chunks(CODE_HASH_CHUNK);
+    let mut chunk = chunks.next().unwrap_or(&[]);
+    loop {
+        preimage[..32].copy_from_slice(&state);
+        preimage[32..32 + chunk.len()].copy_from_slice(chunk);
+        state = match sha512_half(&preimage[..32 + chunk.len()]) {
+            Result::Ok(hash) => hash,
+            Result::Err(e) => return Result::Err(e),
+        };
+        chunk = match chunks.next() {
+            Some(next) => next,
+            None => return Result::Ok(state),
+        };
+    }
+}
+
+/// Returns the identity hash of the finish function that is currently executing, read from the
+/// current escrow's `FinishFunction` field.
+///
+/// # Errors
+///
+/// * `FieldNotFound` - If the current ledger object has no `FinishFunction`, i.e. the contract
+///   is not running as a Smart Escrow
+/// * `DataFieldTooLarge` - If the `FinishFunction` is larger than [`MAX_HASHED_CODE_SIZE`] bytes
+/// * Any error from reading the field or hashing it
+pub fn self_hash() -> Result<[u8; 32]> {
+    match current_ledger_object::get_field::<WasmBlob, _>(sfield::FinishFunction) {
+        Result::Ok(code) => code_hash(code.as_slice()),
+        Result::Err(e) => Result::Err(too_large(e)),
+    }
+}
+
+/// Returns the identity hash of another escrow's finish function.
+///
+/// # Errors
+///
+/// * `FieldNotFound` - If the escrow has no `FinishFunction`
+/// * `DataFieldTooLarge` - If the `FinishFunction` is larger than [`MAX_HASHED_CODE_SIZE`] bytes
+/// * Any error from reading the field or hashing it
+pub fn code_hash_of<T: EscrowReadFields>(escrow: &T) -> Result<[u8; 32]> {
+    match escrow.get_finish_function() {
+        Result::Ok(Some(code)) => code_hash(code.as_slice()),
+        Result::Ok(None) => Result::Err(Error::FieldNotFound),
+        Result::Err(e) => Result::Err(too_large(e)),
+    }
+}
+
+/// The host answers a `FinishFunction` that does not fit in a [`WasmBlob`] with
+/// `BufferTooSmall`, which says nothing about the cause to the caller.
+fn too_large(error: Error) -> Error {
+    match error {
+        Error::BufferTooSmall => Error::DataFieldTooLarge,
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{BUFFER_TOO_SMALL, DATA_FIELD_TOO_LARGE, FIELD_NOT_FOUND};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::traits::{EscrowFields, LedgerObjectCommonFields};
+    use crate::types::blob::WASM_BLOB_SIZE;
+    use mockall::Sequence;
+    use mockall::predicate::{always, eq};
+
+    /// Expects one SHA-512Half call over `state || chunk`, answering with `[out; 32]`.
+    fn expect_round(
+        mock: &mut MockHostBindings,
+        seq: &mut Sequence,
+        state: u8,
+        chunk_len: usize,
+        out: u8,
+    ) {
+        mock.expect_compute_sha512_half()
+            .withf(move |data, len, _, _| {
+                let data = unsafe { core::slice::from_raw_parts(*data, *len) };
+                *len == 32 + chunk_len
+                    && data[..32] == [state; 32]
+                    && data[32..].iter().all(|&b| b == 0xC0)
+            })
+            .times(1)
+            .in_sequence(seq)
+            .returning(move |_, _, ptr, len| {
+                unsafe { core::ptr::write_bytes(ptr, out, len) };
+                32
+            });
+    }
+
+    #[test]
+    fn test_code_hash_chains_chunks() {
+        let mut mock = MockHostBindings::new();
+        let mut seq = Sequence::new();
+        expect_round(&mut mock, &mut seq, 0x00, CODE_HASH_CHUNK, 0x11);
+        expect_round(&mut mock, &mut seq, 0x11, CODE_HASH_CHUNK, 0x22);
+        expect_round(&mut mock, &mut seq, 0x22, 16, 0x33);
+        let _guard = setup_mock(mock);
+
+        let code = [0xC0; 2 * CODE_HASH_CHUNK + 16];
+        assert_eq!(code_hash(&code).unwrap(), [0x33; 32]);
+    }
+
+    #[test]
+    fn test_code_hash_of_empty_module_hashes_once() {
+        let mut mock = MockHostBindings::new();
+        let mut seq = Sequence::new();
+        expect_round(&mut mock, &mut seq, 0x00, 0, 0x44);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(code_hash(&[]).unwrap(), [0x44; 32]);
+    }
+
+    #[test]
+    fn test_self_hash_reads_finish_function() {
+        let mut mock = MockHostBindings::new();
+        let mut seq = Sequence::new();
+        mock.expect_get_current_ledger_obj_field()
+            .with(
+                eq(i32::from(sfield::FinishFunction)),
+                always(),
+                eq(WASM_BLOB_SIZE),
+            )
+            .times(1)
+            .returning(|_, ptr, _| {
+                unsafe { core::ptr::write_bytes(ptr, 0xC0, 100) };
+                100
+            });
+        expect_round(&mut mock, &mut seq, 0x00, 100, 0x55);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(self_hash().unwrap(), [0x55; 32]);
+    }
+
+    #[test]
+    fn test_self_hash_outside_escrow() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_current_ledger_obj_field()
+            .times(1)
+            .returning(|_, _, _| FIELD_NOT_FOUND);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(self_hash().err().unwrap().code(), FIELD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_oversized_finish_function_is_too_large() {
+        struct TestEscrow;
+        impl LedgerObjectCommonFields for TestEscrow {
+            fn get_slot_num(&self) -> i32 {
+                5
+            }
+        }
+        impl EscrowFields for TestEscrow {}
+
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_current_ledger_obj_field()
+            .times(1)
+            .returning(|_, _, _| BUFFER_TOO_SMALL);
+        mock.expect_get_ledger_obj_field()
+            .with(
+                eq(5),
+                eq(i32::from(sfield::FinishFunction)),
+                always(),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, _, _| BUFFER_TOO_SMALL);
+        mock.expect_trace_num().returning(|_, _, _| 0);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(self_hash().err().unwrap().code(), DATA_FIELD_TOO_LARGE);
+        assert_eq!(
+            code_hash_of(&TestEscrow).err().unwrap().code(),
+            DATA_FIELD_TOO_LARGE
+        );
+    }
+}
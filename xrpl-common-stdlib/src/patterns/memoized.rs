@@ -0,0 +1,107 @@
+//! Caching of repeated host reads.
+//!
+//! Each field read is a host call with its own gas cost, and contracts often read the same field
+//! from several code paths (e.g. `CancelAfter` in both a validity check and a payout calculation).
+//! A [`Memoized`] stores the first successful read so later paths get it for free:
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::current_tx::get_field;
+//! use xrpl_common_stdlib::patterns::memoized::Memoized;
+//! use xrpl_common_stdlib::sfield;
+//!
+//! let sequence: Memoized<u32> = Memoized::new();
+//!
+//! let first = sequence.get_or_read(|| get_field(sfield::Sequence));
+//! let second = sequence.get_or_read(|| get_field(sfield::Sequence)); // no host call
+//! # let _ = (first, second);
+//! ```
+//!
+//! Errors are not cached: if a read fails, the next call tries again.
+
+use core::cell::OnceCell;
+
+use crate::host::Result;
+
+/// A lazily filled, write-once cache for a value read from the host.
+///
+/// `Memoized` is meant to live for a single contract invocation, typically as a local in the entry
+/// point or as a field of a context struct. It is not `Sync`, so it cannot be used in a `static`.
+#[derive(Debug, Default)]
+pub struct Memoized<T> {
+    value: OnceCell<T>,
+}
+
+impl<T> Memoized<T> {
+    /// Creates an empty cache.
+    pub const fn new() -> Self {
+        Memoized {
+            value: OnceCell::new(),
+        }
+    }
+
+    /// Creates a cache that already holds `value`, so it never reads from the host. Useful for
+    /// fabricating state in tests.
+    pub fn with_value(value: T) -> Self {
+        Memoized {
+            value: OnceCell::from(value),
+        }
+    }
+
+    /// Returns the cached value, or calls `read` and caches its result if it succeeds.
+    pub fn get_or_read(&self, read: impl FnOnce() -> Result<T>) -> Result<&T> {
+        if let Some(value) = self.value.get() {
+            return Result::Ok(value);
+        }
+        match read() {
+            Result::Ok(value) => Result::Ok(self.value.get_or_init(|| value)),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Returns the cached value without reading it, if it has been read.
+    pub fn get(&self) -> Option<&T> {
+        self.value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::Error;
+    use crate::host::error_codes::INTERNAL_ERROR;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_reads_once_and_caches() {
+        let memo = Memoized::new();
+        let reads = Cell::new(0);
+        let read = || {
+            reads.set(reads.get() + 1);
+            Result::Ok(42u32)
+        };
+
+        assert_eq!(memo.get(), None);
+        assert_eq!(*memo.get_or_read(read).unwrap(), 42);
+        assert_eq!(*memo.get_or_read(read).unwrap(), 42);
+        assert_eq!(reads.get(), 1);
+        assert_eq!(memo.get(), Some(&42));
+    }
+
+    #[test]
+    fn test_errors_are_not_cached() {
+        let memo = Memoized::new();
+
+        let failed = memo.get_or_read(|| Result::Err(Error::InternalError));
+        assert_eq!(failed.err().unwrap().code(), INTERNAL_ERROR);
+        assert_eq!(memo.get(), None);
+
+        assert_eq!(*memo.get_or_read(|| Result::Ok(7u32)).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_with_value_skips_the_read() {
+        let memo = Memoized::with_value(3u32);
+        let value = memo.get_or_read(|| panic!("prefilled memo read from the host"));
+        assert_eq!(*value.unwrap(), 3);
+    }
+}
@@ -0,0 +1,26 @@
+//! Reusable building blocks for common smart-contract designs.
+//!
+//! Each submodule packages a small piece of logic that contracts would otherwise reimplement
+//! on top of the raw host API (for example, float arithmetic via the `float_*` host functions).
+//!
+//! - [`auction`]: Descending-price (Dutch auction) schedules driven by ledger time.
+//! - [`budget`]: Contract-side estimates of how much computation allowance is left.
+//! - [`delivery`]: Pre-checks that an account can receive an asset ([`can_deliver`]).
+//! - [`guard`]: Once-per-ledger execution guards kept in the `Data` field.
+//! - [`identity`]: Hashes of Smart Escrow finish functions, including the running one
+//!   ([`self_hash`]).
+//! - [`memo`]: Per-invocation caching of host reads that several code paths need.
+//! - [`tranches`]: Time-locked payout schedules and release progress kept in the `Data` field.
+//! - [`twap`]: Time-weighted average prices over a sample history kept in the `Data` field.
+
+pub mod auction;
+pub mod budget;
+pub mod delivery;
+pub mod guard;
+pub mod identity;
+pub mod memoized;
+pub mod tranches;
+pub mod twap;
+
+pub use delivery::can_deliver;
+pub use identity::self_hash;
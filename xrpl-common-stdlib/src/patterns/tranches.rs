@@ -0,0 +1,325 @@
+//! Release schedules that pay out a total amount in time-locked tranches.
+//!
+//! A payout ladder splits a total into tranches, each unlocking at its own time. Whether the
+//! funds move through several escrows or a contract-driven payment, the arithmetic is the same:
+//! which tranche is due now, and how much of the total it carries. [`TrancheProgress`] records
+//! how many tranches have already been released, and [`TrancheProgress::next`] answers both. The
+//! progress is a [`DataSection`], so it carries over between executions by being loaded from and
+//! saved to `Data` together with the rest of the contract's state (in an escrow, with
+//! `EscrowFinishContext::load_state` and `save_state`).
+//!
+//! # Data layout
+//!
+//! ```text
+//! ┌──────────────────────────────┐
+//! │ tranches released (u32, LE)  │
+//! └──────────────────────────────┘
+//! ```
+//!
+//! A missing section means nothing has been released yet.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::host::chain::parent_ledger_time;
+//! use xrpl_common_stdlib::patterns::tranches::{Tranche, TrancheProgress};
+//! use xrpl_common_stdlib::types::amount::Amount;
+//!
+//! # fn load_progress() -> TrancheProgress { TrancheProgress::default() }
+//!
+//! const SCHEDULE: [Tranche; 3] = [
+//!     Tranche { unlock_time: 800_000_000, share_bps: 5_000 },
+//!     Tranche { unlock_time: 810_000_000, share_bps: 2_500 },
+//!     Tranche { unlock_time: 820_000_000, share_bps: 2_500 },
+//! ];
+//!
+//! let total = Amount::XRP { num_drops: 1_000_000_000 };
+//! let now = parent_ledger_time().unwrap_or(0);
+//! // Loaded from `Data`, e.g. with `EscrowFinishContext::load_state`.
+//! let progress = load_progress();
+//! if let Some(Some(release)) = progress.next(&total, &SCHEDULE, now).ok() {
+//!     // ...pay out `release.amount`, then record it...
+//!     let progress = TrancheProgress { released: release.index as u32 + 1 };
+//!     // ...by saving `progress` back to `Data`.
+//! #   let _ = progress;
+//! }
+//! ```
+
+use crate::host::{Error, FLOAT_ROUNDING_MODES_TOWARDS_ZERO, Result};
+use crate::math::{Rounding, mul_div_u64};
+use crate::types::amount::Amount;
+use crate::types::data_cursor::{DataCursor, DataSection};
+
+/// Basis points in a whole: the shares of a schedule must add up to this.
+pub const TOTAL_BPS: u32 = 10_000;
+
+/// Size in bytes of the [`TrancheProgress`] record in the `Data` field.
+pub const TRANCHE_PROGRESS_SIZE: usize = 4;
+
+/// One step of a release schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tranche {
+    /// When the tranche unlocks, in seconds since the Ripple Epoch. It is due once the parent
+    /// ledger close time reaches this value.
+    pub unlock_time: u32,
+    /// The tranche's share of the total, in basis points.
+    pub share_bps: u32,
+}
+
+/// A tranche that is due, as returned by [`TrancheProgress::next`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrancheRelease {
+    /// The tranche's position in the schedule.
+    pub index: usize,
+    /// The part of the total the tranche carries, in the total's asset.
+    pub amount: Amount,
+}
+
+/// How many tranches of a schedule have been released, in schedule order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrancheProgress {
+    pub released: u32,
+}
+
+impl TrancheProgress {
+    /// Returns whether every tranche of `schedule` has been released.
+    pub fn is_complete(&self, schedule: &[Tranche]) -> bool {
+        self.released as usize >= schedule.len()
+    }
+
+    /// Returns the first unreleased tranche of `schedule` if it is due at `now`, or `Ok(None)`
+    /// if it is not due yet or the schedule is complete.
+    ///
+    /// Only one tranche is returned at a time. If several have become due since the last
+    /// release, record the returned one and call again for the next.
+    ///
+    /// XRP and MPT amounts are split with exact integer math, so the tranches of a schedule add
+    /// up to exactly `total`, with rounding remainders going to later tranches. IOU amounts are
+    /// scaled with the float host functions, rounding toward zero.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If the schedule is empty, its shares do not add up to
+    ///   [`TOTAL_BPS`], its unlock times decrease, more tranches are recorded as released than
+    ///   it has, or `total` is negative
+    /// * Any error returned by the float host functions (IOU only)
+    pub fn next(
+        &self,
+        total: &Amount,
+        schedule: &[Tranche],
+        now: u32,
+    ) -> Result<Option<TrancheRelease>> {
+        if let Result::Err(e) = validate(schedule) {
+            return Result::Err(e);
+        }
+        let index = self.released as usize;
+        let tranche = match schedule.get(index) {
+            Some(tranche) => tranche,
+            None if index == schedule.len() => return Result::Ok(None),
+            None => return Result::Err(Error::InvalidParams),
+        };
+        if now < tranche.unlock_time {
+            return Result::Ok(None);
+        }
+        let before: u32 = schedule[..index].iter().map(|t| t.share_bps).sum();
+        tranche_amount(total, before, before + tranche.share_bps)
+            .map(|amount| Some(TrancheRelease { index, amount }))
+    }
+}
+
+impl DataSection for TrancheProgress {
+    /// Reads progress stored in the format described in the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If fewer than [`TRANCHE_PROGRESS_SIZE`] bytes remain
+    fn read_section(cursor: &mut DataCursor) -> Result<Self> {
+        cursor
+            .read_u32_le()
+            .map(|released| TrancheProgress { released })
+    }
+
+    fn write_section(&self, cursor: &mut DataCursor) -> Result<()> {
+        cursor.write_u32_le(self.released)
+    }
+}
+
+fn validate(schedule: &[Tranche]) -> Result<()> {
+    let mut shares: u64 = 0;
+    let mut previous_time = 0;
+    for tranche in schedule {
+        if tranche.unlock_time < previous_time {
+            return Result::Err(Error::InvalidParams);
+        }
+        previous_time = tranche.unlock_time;
+        shares += tranche.share_bps as u64;
+    }
+    if schedule.is_empty() || shares != TOTAL_BPS as u64 {
+        return Result::Err(Error::InvalidParams);
+    }
+    Result::Ok(())
+}
+
+/// Returns the part of `total` between the cumulative shares `from_bps` and `to_bps`.
+fn tranche_amount(total: &Amount, from_bps: u32, to_bps: u32) -> Result<Amount> {
+    // Each tranche is the difference of two rounded cumulative amounts, so the rounding never
+    // accumulates and the last tranche ends exactly at `total`.
+    let split = |whole: u64| {
+        mul_div_u64(whole, from_bps as u64, TOTAL_BPS as u64, Rounding::Down).and_then(|from| {
+            mul_div_u64(whole, to_bps as u64, TOTAL_BPS as u64, Rounding::Down).map(|to| to - from)
+        })
+    };
+    match total {
+        Amount::XRP { num_drops } if *num_drops >= 0 => split(*num_drops as u64).map(|drops| {
+            // At most `num_drops`, so it fits in an i64.
+            Amount::XRP {
+                num_drops: drops as i64,
+            }
+        }),
+        Amount::MPT {
+            num_units,
+            is_positive: true,
+            mpt_id,
+        } => split(*num_units).map(|num_units| Amount::MPT {
+            num_units,
+            is_positive: true,
+            mpt_id: *mpt_id,
+        }),
+        Amount::IOU {
+            amount,
+            issuer,
+            currency,
+        } => amount
+            .mul_bps(to_bps - from_bps, FLOAT_ROUNDING_MODES_TOWARDS_ZERO)
+            .map(|amount| Amount::IOU {
+                amount,
+                issuer: *issuer,
+                currency: *currency,
+            }),
+        _ => Result::Err(Error::InvalidParams),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::contract_data::ContractData;
+    use crate::types::mpt_id::MptId;
+
+    const SCHEDULE: [Tranche; 3] = [
+        Tranche {
+            unlock_time: 100,
+            share_bps: 3_333,
+        },
+        Tranche {
+            unlock_time: 200,
+            share_bps: 3_333,
+        },
+        Tranche {
+            unlock_time: 300,
+            share_bps: 3_334,
+        },
+    ];
+
+    fn xrp(num_drops: i64) -> Amount {
+        Amount::XRP { num_drops }
+    }
+
+    fn release_at(released: u32, total: &Amount, now: u32) -> Option<TrancheRelease> {
+        TrancheProgress { released }
+            .next(total, &SCHEDULE, now)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_nothing_is_due_before_the_unlock_time() {
+        assert_eq!(release_at(0, &xrp(1_000), 99), None);
+        assert_eq!(release_at(1, &xrp(1_000), 199), None);
+    }
+
+    #[test]
+    fn test_tranches_add_up_to_the_total() {
+        let total = xrp(1_000);
+        let amounts: [Amount; 3] = core::array::from_fn(|i| {
+            let release = release_at(i as u32, &total, 300).unwrap();
+            assert_eq!(release.index, i);
+            release.amount
+        });
+        assert_eq!(amounts, [xrp(333), xrp(333), xrp(334)]);
+        assert_eq!(release_at(3, &total, u32::MAX), None);
+        assert!(TrancheProgress { released: 3 }.is_complete(&SCHEDULE));
+    }
+
+    #[test]
+    fn test_mpt_amounts_keep_the_issuance() {
+        let mpt_id = MptId::new(1, crate::types::account_id::AccountID([7; 20]));
+        let total = Amount::MPT {
+            num_units: u64::MAX,
+            is_positive: true,
+            mpt_id,
+        };
+        let release = release_at(2, &total, 300).unwrap();
+        let expected = u64::MAX - (u64::MAX as u128 * 6_666 / 10_000) as u64;
+        assert_eq!(
+            release.amount,
+            Amount::MPT {
+                num_units: expected,
+                is_positive: true,
+                mpt_id,
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_schedules_and_totals_are_rejected() {
+        let progress = TrancheProgress::default();
+        let short = [Tranche {
+            unlock_time: 0,
+            share_bps: 9_999,
+        }];
+        let unordered = [
+            Tranche {
+                unlock_time: 10,
+                share_bps: 5_000,
+            },
+            Tranche {
+                unlock_time: 5,
+                share_bps: 5_000,
+            },
+        ];
+        for schedule in [&[][..], &short[..], &unordered[..]] {
+            assert!(matches!(
+                progress.next(&xrp(1), schedule, 100).err(),
+                Some(Error::InvalidParams)
+            ));
+        }
+        assert!(matches!(
+            progress.next(&xrp(-1), &SCHEDULE, 100).err(),
+            Some(Error::InvalidParams)
+        ));
+        assert!(matches!(
+            TrancheProgress { released: 4 }
+                .next(&xrp(1), &SCHEDULE, 100)
+                .err(),
+            Some(Error::InvalidParams)
+        ));
+    }
+
+    #[test]
+    fn test_progress_round_trip() {
+        let progress = TrancheProgress { released: 2 };
+        let mut data = ContractData::new();
+        DataCursor::new(&mut data).write_section(&progress).unwrap();
+        assert_eq!(data.as_slice(), &2u32.to_le_bytes());
+        let decoded: TrancheProgress = DataCursor::new(&mut data).read_section().unwrap();
+        assert_eq!(decoded, progress);
+
+        data.len = 3;
+        assert!(matches!(
+            DataCursor::new(&mut data)
+                .read_section::<TrancheProgress>()
+                .err(),
+            Some(Error::InvalidDecoding)
+        ));
+    }
+}
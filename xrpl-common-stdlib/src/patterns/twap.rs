@@ -0,0 +1,437 @@
+//! Time-weighted average prices from a price history kept in the `Data` field.
+//!
+//! A single oracle reading can be moved by whoever controls that oracle for one update. A
+//! contract that records a sample on every execution and conditions on the time-weighted
+//! average price (TWAP) over a window is much harder to push: a manipulated price only counts
+//! for as long as it stays on the ledger. Taking the [`median`] of several oracles per sample
+//! additionally limits the influence of any one of them.
+//!
+//! [`PriceHistory`] keeps the last `N` [`PriceSample`]s. It is a [`DataSection`],
+//! so it carries over between executions by being loaded from and saved to `Data` together with
+//! the rest of the contract's state (in an escrow, with `EscrowFinishContext::load_state` and
+//! `save_state`).
+//!
+//! # Data layout
+//!
+//! The samples are stored oldest first, after their count:
+//!
+//! ```text
+//! ┌─────────────────────┬──────────────────┬──────────────────┐
+//! │ sample count (u32)  │ time (u32, LE)   │ price (u64, LE)  │  × count
+//! └─────────────────────┴──────────────────┴──────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xrpl_common_stdlib::collections::ArrayVec;
+//! use xrpl_common_stdlib::host::chain::parent_ledger_time;
+//! use xrpl_common_stdlib::patterns::twap::{PriceHistory, PriceSample, median};
+//!
+//! # fn load_history() -> PriceHistory<32> { PriceHistory::new() }
+//!
+//! const WINDOW_SECS: u32 = 3_600;
+//! const STRIKE: u64 = 1_250_000;
+//!
+//! let now = parent_ledger_time().unwrap_or(0);
+//! // Prices read from several oracles (e.g. with `OracleFields::latest_fresh_price`), all at
+//! // the same scale. Oracles without a fresh price are left out.
+//! let mut prices: ArrayVec<u64, 8> = ArrayVec::new();
+//! for price in [Some(1_249_000), None, Some(1_251_500), Some(1_250_200)].into_iter().flatten() {
+//!     let _ = prices.push(price);
+//! }
+//!
+//! // Loaded from `Data`, e.g. with `EscrowFinishContext::load_state`.
+//! let mut history = load_history();
+//! if let Some(price) = median(prices.as_mut_slice()) {
+//!     let _ = history.push(PriceSample { time: now, price });
+//!     // ...save `history` back to `Data`...
+//! }
+//! let release = history.covers(now, WINDOW_SECS)
+//!     && history.twap(now, WINDOW_SECS).ok().flatten().is_some_and(|p| p >= STRIKE);
+//! # let _ = release;
+//! ```
+
+use crate::collections::ArrayVec;
+use crate::host::{Error, Result};
+use crate::types::contract_data::XRPL_CONTRACT_DATA_SIZE;
+use crate::types::data_cursor::{DataCursor, DataSection};
+
+/// Size in bytes of one `(time, price)` record in the `Data` field.
+pub const TWAP_SAMPLE_SIZE: usize = 12;
+
+/// Maximum number of samples that fit in the `Data` field.
+pub const MAX_TWAP_SAMPLES: usize = (XRPL_CONTRACT_DATA_SIZE - 4) / TWAP_SAMPLE_SIZE;
+
+/// A price observed at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PriceSample {
+    /// When the price was observed, in seconds since the Ripple Epoch (e.g. the parent ledger
+    /// close time).
+    pub time: u32,
+    /// The price, in whatever fixed-point scale the contract uses consistently.
+    pub price: u64,
+}
+
+/// The last `N` price samples, oldest first.
+///
+/// `N` must be at most [`MAX_TWAP_SAMPLES`] for the history to be saved.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PriceHistory<const N: usize> {
+    samples: ArrayVec<PriceSample, N>,
+}
+
+impl<const N: usize> PriceHistory<N> {
+    /// Creates an empty history.
+    pub const fn new() -> Self {
+        PriceHistory {
+            samples: ArrayVec::new(),
+        }
+    }
+
+    /// Returns the number of samples.
+    pub const fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns whether there are no samples.
+    pub const fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the maximum number of samples kept.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the oldest sample still kept.
+    pub fn oldest(&self) -> Option<PriceSample> {
+        self.samples.first().copied()
+    }
+
+    /// Returns the most recent sample.
+    pub fn latest(&self) -> Option<PriceSample> {
+        self.samples.last().copied()
+    }
+
+    /// Returns an iterator over the samples, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = PriceSample> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Records `sample`, dropping the oldest sample if the history is full.
+    ///
+    /// Only the first sample for a given time is kept: if the latest sample has the same time,
+    /// `sample` is ignored and `Ok(false)` is returned. Repeated executions within one ledger,
+    /// which all see the same parent close time, therefore cannot add weight to a price.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `sample` is older than the latest sample, or `N` is zero
+    pub fn push(&mut self, sample: PriceSample) -> Result<bool> {
+        if N == 0 {
+            return Result::Err(Error::InvalidParams);
+        }
+        match self.latest() {
+            Some(latest) if sample.time < latest.time => Result::Err(Error::InvalidParams),
+            Some(latest) if sample.time == latest.time => Result::Ok(false),
+            _ => {
+                self.insert(sample);
+                Result::Ok(true)
+            }
+        }
+    }
+
+    /// Returns whether the samples reach back to the start of the `window_secs` seconds ending
+    /// at `now`, i.e. whether [`twap`](Self::twap) averages over the whole window.
+    pub fn covers(&self, now: u32, window_secs: u32) -> bool {
+        self.oldest()
+            .is_some_and(|oldest| oldest.time <= now.saturating_sub(window_secs))
+    }
+
+    /// Returns the time-weighted average price over the `window_secs` seconds ending at `now`,
+    /// rounded down.
+    ///
+    /// Each sample's price counts from its time until the next sample's (or until `now` for the
+    /// latest one), clipped to the window. If the samples do not reach back to the start of the
+    /// window, the average is over the part they cover; check [`covers`](Self::covers) to
+    /// require the full window. If no time at all is covered (a zero window, or every sample
+    /// taken at `now`), the latest price is returned.
+    ///
+    /// Returns `Ok(None)` if there are no samples.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `now` is before the latest sample
+    pub fn twap(&self, now: u32, window_secs: u32) -> Result<Option<u64>> {
+        let latest = match self.latest() {
+            Some(latest) => latest,
+            None => return Result::Ok(None),
+        };
+        if now < latest.time {
+            return Result::Err(Error::InvalidParams);
+        }
+        let window_start = now.saturating_sub(window_secs);
+
+        let mut weighted_sum: u128 = 0;
+        let mut covered: u64 = 0;
+        for (i, sample) in self.samples.iter().enumerate() {
+            let end = match self.samples.get(i + 1) {
+                Some(next) => next.time,
+                None => now,
+            };
+            let begin = sample.time.max(window_start);
+            if end > begin {
+                let secs = (end - begin) as u64;
+                weighted_sum += sample.price as u128 * secs as u128;
+                covered += secs;
+            }
+        }
+
+        if covered == 0 {
+            return Result::Ok(Some(latest.price));
+        }
+        // The average never exceeds the largest price, so it fits in a u64.
+        Result::Ok(Some((weighted_sum / covered as u128) as u64))
+    }
+
+    /// Appends `sample`, first dropping the oldest sample if the history is full. Does nothing
+    /// if `N` is zero.
+    fn insert(&mut self, sample: PriceSample) {
+        if self.samples.is_full() {
+            self.samples.remove(0);
+        }
+        let _ = self.samples.push(sample);
+    }
+}
+
+impl<const N: usize> DataSection for PriceHistory<N> {
+    /// Reads a history stored in the format described in the [module docs](self).
+    ///
+    /// If more than `N` samples are stored, only the newest `N` are kept, so a contract can
+    /// lower its capacity between versions.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If the samples are truncated or their times are not in order
+    fn read_section(cursor: &mut DataCursor) -> Result<Self> {
+        let count = match cursor.read_u32_le() {
+            Result::Ok(count) => count,
+            Result::Err(e) => return Result::Err(e),
+        };
+        let mut history = Self::new();
+        for _ in 0..count {
+            let sample = cursor.read_u32_le().and_then(|time| {
+                cursor
+                    .read_u64_le()
+                    .map(|price| PriceSample { time, price })
+            });
+            let sample = match sample {
+                Result::Ok(sample) => sample,
+                Result::Err(e) => return Result::Err(e),
+            };
+            if history
+                .latest()
+                .is_some_and(|latest| sample.time <= latest.time)
+            {
+                return Result::Err(Error::InvalidDecoding);
+            }
+            history.insert(sample);
+        }
+        Result::Ok(history)
+    }
+
+    fn write_section(&self, cursor: &mut DataCursor) -> Result<()> {
+        if let Result::Err(e) = cursor.write_u32_le(self.len() as u32) {
+            return Result::Err(e);
+        }
+        for sample in self.iter() {
+            let written = cursor
+                .write_u32_le(sample.time)
+                .and_then(|()| cursor.write_u64_le(sample.price));
+            if let Result::Err(e) = written {
+                return Result::Err(e);
+            }
+        }
+        Result::Ok(())
+    }
+}
+
+/// Returns the median of `prices`, sorting them in place, or `None` if there are none.
+///
+/// With an even number of prices, the result is the midpoint of the two middle ones, rounded
+/// down. A single oracle can then move the result only as far as the honest prices around it.
+///
+/// ```
+/// use xrpl_common_stdlib::patterns::twap::median;
+///
+/// assert_eq!(median(&mut [105, 98, 1_000_000]), Some(105));
+/// assert_eq!(median(&mut [100, 103]), Some(101));
+/// assert_eq!(median(&mut []), None);
+/// ```
+pub fn median(prices: &mut [u64]) -> Option<u64> {
+    if prices.is_empty() {
+        return None;
+    }
+    prices.sort_unstable();
+    let mid = prices.len() / 2;
+    if prices.len() % 2 == 1 {
+        Some(prices[mid])
+    } else {
+        let (low, high) = (prices[mid - 1], prices[mid]);
+        Some(low + (high - low) / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::DATA_FIELD_TOO_LARGE;
+    use crate::types::contract_data::ContractData;
+    use std::vec::Vec;
+
+    fn sample(time: u32, price: u64) -> PriceSample {
+        PriceSample { time, price }
+    }
+
+    fn history_of<const N: usize>(samples: &[PriceSample]) -> PriceHistory<N> {
+        let mut history = PriceHistory::new();
+        for &s in samples {
+            assert!(history.push(s).unwrap());
+        }
+        history
+    }
+
+    #[test]
+    fn test_push_drops_oldest_when_full() {
+        let history =
+            history_of::<3>(&[sample(10, 1), sample(20, 2), sample(30, 3), sample(40, 4)]);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.oldest(), Some(sample(20, 2)));
+        assert_eq!(history.latest(), Some(sample(40, 4)));
+        assert_eq!(
+            history.iter().map(|s| s.price).collect::<Vec<_>>(),
+            [2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_push_rejects_out_of_order_and_ignores_same_time() {
+        let mut history = history_of::<4>(&[sample(10, 1)]);
+        assert!(!history.push(sample(10, 99)).unwrap());
+        assert_eq!(history.latest(), Some(sample(10, 1)));
+        assert!(matches!(
+            history.push(sample(9, 1)).err(),
+            Some(Error::InvalidParams)
+        ));
+    }
+
+    #[test]
+    fn test_twap_weights_prices_by_duration() {
+        // 100 for 10s, 200 for 30s.
+        let history = history_of::<4>(&[sample(0, 100), sample(10, 200)]);
+        assert_eq!(history.twap(40, 40).unwrap(), Some(175));
+        assert!(history.covers(40, 40));
+    }
+
+    #[test]
+    fn test_twap_clips_to_window() {
+        // Only 5s of the first price falls inside [25, 40].
+        let history = history_of::<4>(&[sample(0, 100), sample(20, 200), sample(30, 400)]);
+        assert_eq!(
+            history.twap(40, 15).unwrap(),
+            Some((200 * 5 + 400 * 10) / 15)
+        );
+        assert!(history.covers(40, 15));
+    }
+
+    #[test]
+    fn test_twap_over_partial_coverage() {
+        let history = history_of::<4>(&[sample(100, 50), sample(110, 150)]);
+        assert!(!history.covers(120, 60));
+        assert_eq!(history.twap(120, 60).unwrap(), Some(100));
+    }
+
+    #[test]
+    fn test_twap_edge_cases() {
+        assert_eq!(PriceHistory::<4>::new().twap(10, 10).unwrap(), None);
+
+        let history = history_of::<4>(&[sample(5, 1), sample(10, 7)]);
+        assert_eq!(history.twap(10, 0).unwrap(), Some(7));
+        assert!(matches!(
+            history.twap(9, 10).err(),
+            Some(Error::InvalidParams)
+        ));
+
+        let extreme = history_of::<4>(&[sample(0, u64::MAX), sample(u32::MAX - 1, u64::MAX)]);
+        assert_eq!(extreme.twap(u32::MAX, u32::MAX).unwrap(), Some(u64::MAX));
+    }
+
+    fn encoded<const N: usize>(history: &PriceHistory<N>) -> ContractData {
+        let mut data = ContractData::new();
+        DataCursor::new(&mut data).write_section(history).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_section_round_trip() {
+        let history =
+            history_of::<3>(&[sample(10, 1), sample(20, 2), sample(30, 3), sample(40, 4)]);
+        let mut data = encoded(&history);
+        assert_eq!(data.len, 4 + 3 * TWAP_SAMPLE_SIZE);
+        assert_eq!(&data.data[..8], &[3, 0, 0, 0, 20, 0, 0, 0]);
+        let decoded: PriceHistory<3> = DataCursor::new(&mut data).read_section().unwrap();
+        assert_eq!(decoded, history);
+
+        // A smaller capacity keeps the newest samples.
+        let smaller: PriceHistory<2> = DataCursor::new(&mut data).read_section().unwrap();
+        assert_eq!(
+            smaller.iter().collect::<Vec<_>>(),
+            [sample(30, 3), sample(40, 4)]
+        );
+    }
+
+    #[test]
+    fn test_read_rejects_malformed_data() {
+        let mut truncated = encoded(&history_of::<2>(&[sample(10, 1), sample(20, 2)]));
+        truncated.len -= 1;
+        assert!(matches!(
+            DataCursor::new(&mut truncated)
+                .read_section::<PriceHistory<2>>()
+                .err(),
+            Some(Error::InvalidDecoding)
+        ));
+
+        let mut unordered = ContractData::new();
+        let mut cursor = DataCursor::new(&mut unordered);
+        cursor.write_u32_le(2).unwrap();
+        for time in [20u32, 10] {
+            cursor.write_u32_le(time).unwrap();
+            cursor.write_u64_le(1).unwrap();
+        }
+        assert!(matches!(
+            DataCursor::new(&mut unordered)
+                .read_section::<PriceHistory<2>>()
+                .err(),
+            Some(Error::InvalidDecoding)
+        ));
+    }
+
+    #[test]
+    fn test_write_rejects_oversized_history() {
+        let mut history = PriceHistory::<{ MAX_TWAP_SAMPLES + 1 }>::new();
+        for time in 0..=MAX_TWAP_SAMPLES as u32 {
+            history.push(sample(time, 1)).unwrap();
+        }
+        let mut data = ContractData::new();
+        let result = DataCursor::new(&mut data).write_section(&history);
+        assert_eq!(result.err().unwrap().code(), DATA_FIELD_TOO_LARGE);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&mut [3, 1, 2]), Some(2));
+        assert_eq!(median(&mut [u64::MAX, u64::MAX - 1]), Some(u64::MAX - 1));
+        assert_eq!(median(&mut [7]), Some(7));
+    }
+}
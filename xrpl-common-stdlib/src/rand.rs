@@ -0,0 +1,134 @@
+//! Deterministic pseudo-randomness derived from ledger data.
+//!
+//! Every validator must compute the same result for a contract, so there is no real source of
+//! randomness. [`ledger_entropy_for`] instead hashes values that are fixed for one invocation but
+//! hard to choose in advance: the parent ledger hash, the keylet of the object the contract acts
+//! on, and the submitting account with its `Sequence` (or `TicketSequence`). The transaction's
+//! own hash is not available to contracts.
+//!
+//! # Manipulation resistance
+//!
+//! The result is unpredictable to a casual observer, not to an adversary:
+//!
+//! * The submitter chooses the account and can pick among sequence numbers (e.g. by creating
+//!   tickets), keeping one whose outcome suits them as soon as they know which parent ledger the
+//!   transaction will be applied on top of.
+//! * The parent ledger hash is public before the transaction is applied, and anyone who can delay
+//!   a transaction to a later ledger gets a fresh draw.
+//! * Validators decide which transactions make it into a ledger and so influence its hash.
+//!
+//! Use it for tie-breaking and similar choices where no party gains from a particular outcome.
+//! Never use it to pick a winner of something valuable or to derive secrets.
+
+use crate::crypto::sha512_half;
+use crate::current_tx::{get_field, get_field_optional};
+use crate::host::Result;
+use crate::host::chain::parent_ledger_hash;
+use crate::sfield;
+
+/// Derives 32 bytes of entropy from the parent ledger hash, `object_keylet`, and the current
+/// transaction's `Account` and `Sequence`/`TicketSequence`.
+///
+/// The account and sequence identify the transaction within its ledger, so different
+/// transactions acting on the same object get different values.
+///
+/// `object_keylet` ties the value to one ledger object, so two contracts handling the same
+/// transaction (e.g. inner transactions of a `Batch` finishing different escrows) get unrelated
+/// values. See the [module documentation](self) for what this does and does not protect against.
+///
+/// # Errors
+///
+/// Returns any error from reading the parent ledger hash or the transaction's `Account`,
+/// `Sequence` or `TicketSequence`, or from the host's SHA-512Half.
+pub fn ledger_entropy_for(object_keylet: &[u8; 32]) -> Result<[u8; 32]> {
+    let parent_hash = match parent_ledger_hash() {
+        Result::Ok(hash) => hash,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let account = match get_field(sfield::Account) {
+        Result::Ok(account) => account,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let sequence: u32 = match get_field(sfield::Sequence) {
+        Result::Ok(sequence) => sequence,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let ticket_sequence: u32 = match get_field_optional(sfield::TicketSequence) {
+        Result::Ok(ticket_sequence) => ticket_sequence.unwrap_or(0),
+        Result::Err(e) => return Result::Err(e),
+    };
+
+    let mut preimage = [0u8; 92];
+    preimage[..32].copy_from_slice(&parent_hash);
+    preimage[32..64].copy_from_slice(object_keylet);
+    preimage[64..84].copy_from_slice(&account.0);
+    preimage[84..88].copy_from_slice(&sequence.to_be_bytes());
+    preimage[88..].copy_from_slice(&ticket_sequence.to_be_bytes());
+    sha512_half(&preimage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR};
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_hashes_parent_hash_keylet_and_tx_identity() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_parent_ledger_hash()
+            .times(1)
+            .returning(|ptr, len| {
+                unsafe { core::ptr::write_bytes(ptr, 0x01, len) };
+                32
+            });
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Account)), always(), eq(20))
+            .times(1)
+            .returning(|_, ptr, len| {
+                unsafe { core::ptr::write_bytes(ptr, 0x02, len) };
+                20
+            });
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Sequence)), always(), eq(4))
+            .times(1)
+            .returning(|_, ptr, _| {
+                unsafe { core::ptr::copy_nonoverlapping(7u32.to_le_bytes().as_ptr(), ptr, 4) };
+                4
+            });
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::TicketSequence)), always(), eq(4))
+            .times(1)
+            .returning(|_, _, _| FIELD_NOT_FOUND);
+        mock.expect_compute_sha512_half()
+            .withf(|data, len, _, _| {
+                let data = unsafe { core::slice::from_raw_parts(*data, *len) };
+                data[..32] == [0x01; 32]
+                    && data[32..64] == [0x03; 32]
+                    && data[64..84] == [0x02; 20]
+                    && data[84..] == [0, 0, 0, 7, 0, 0, 0, 0]
+            })
+            .times(1)
+            .returning(|_, _, out, len| {
+                unsafe { core::ptr::write_bytes(out, 0xEE, len) };
+                32
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(ledger_entropy_for(&[0x03; 32]).unwrap(), [0xEE; 32]);
+    }
+
+    #[test]
+    fn test_propagates_missing_parent_hash() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_parent_ledger_hash()
+            .times(1)
+            .returning(|_, _| INTERNAL_ERROR);
+        let _guard = setup_mock(mock);
+
+        let result = ledger_entropy_for(&[0; 32]);
+        assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+    }
+}
@@ -4,12 +4,15 @@ use crate::objects::array_object::{Array, Object};
 use crate::types::account_id::AccountID;
 use crate::types::amount::Amount;
 use crate::types::blob::{
-    ConditionBlob, FulfillmentBlob, PublicKeyBlob, SignatureBlob, StandardBlob, UriBlob, WasmBlob,
+    ConditionBlob, CredentialTypeBlob, FulfillmentBlob, PublicKeyBlob, SignatureBlob, StandardBlob,
+    UriBlob, WasmBlob,
 };
 use crate::types::currency::Currency;
 use crate::types::issue::Issue;
 use crate::types::transaction_type::TransactionType;
 use crate::types::uint::{Hash128, Hash160, Hash192, Hash256};
+use crate::types::vector256::StandardVector256;
+use crate::types::xchain_bridge::XChainBridge;
 use core::borrow::Borrow;
 use core::marker::PhantomData;
 
@@ -292,7 +295,7 @@ pub const Data: SField<StandardBlob, 458779> = SField::new();
 pub const AssetClass: SField<StandardBlob, 458780> = SField::new();
 pub const Provider: SField<StandardBlob, 458781> = SField::new();
 pub const MPTokenMetadata: SField<StandardBlob, 458782> = SField::new();
-pub const CredentialType: SField<StandardBlob, 458783> = SField::new();
+pub const CredentialType: SField<CredentialTypeBlob, 458783> = SField::new();
 pub const FinishFunction: SField<WasmBlob, 458784> = SField::new();
 pub const ContractCode: SField<StandardBlob, 458785> = SField::new();
 pub const FunctionName: SField<StandardBlob, 458786> = SField::new();
@@ -422,11 +425,11 @@ pub const TakerPaysIssuer: SField<Hash160, 1114114> = SField::new();
 pub const TakerGetsCurrency: SField<Hash160, 1114115> = SField::new();
 pub const TakerGetsIssuer: SField<Hash160, 1114116> = SField::new();
 pub const Paths: SField<u8, 1179649> = SField::new();
-pub const Indexes: SField<u8, 1245185> = SField::new();
-pub const Hashes: SField<u8, 1245186> = SField::new();
-pub const Amendments: SField<u8, 1245187> = SField::new();
-pub const NFTokenOffers: SField<u8, 1245188> = SField::new();
-pub const CredentialIDs: SField<u8, 1245189> = SField::new();
+pub const Indexes: SField<StandardVector256, 1245185> = SField::new();
+pub const Hashes: SField<StandardVector256, 1245186> = SField::new();
+pub const Amendments: SField<StandardVector256, 1245187> = SField::new();
+pub const NFTokenOffers: SField<StandardVector256, 1245188> = SField::new();
+pub const CredentialIDs: SField<StandardVector256, 1245189> = SField::new();
 pub const MPTokenIssuanceID: SField<Hash192, 1376257> = SField::new();
 pub const ShareMPTID: SField<Hash192, 1376258> = SField::new();
 pub const TakerPaysMPT: SField<Hash192, 1376259> = SField::new();
@@ -435,7 +438,7 @@ pub const LockingChainIssue: SField<Issue, 1572865> = SField::new();
 pub const IssuingChainIssue: SField<Issue, 1572866> = SField::new();
 pub const Asset: SField<Issue, 1572867> = SField::new();
 pub const Asset2: SField<Issue, 1572868> = SField::new();
-pub const XChainBridge: SField<u8, 1638401> = SField::new();
+pub const XChainBridge: SField<XChainBridge, 1638401> = SField::new();
 pub const BaseAsset: SField<Currency, 1703937> = SField::new();
 pub const QuoteAsset: SField<Currency, 1703938> = SField::new();
 pub const ParameterValue: SField<u8, 1769473> = SField::new();
@@ -445,3 +448,375 @@ pub const Transaction: SField<u8, 655425793> = SField::new();
 pub const LedgerEntry: SField<u8, 655491329> = SField::new();
 pub const Validation: SField<u8, 655556865> = SField::new();
 pub const Metadata: SField<u8, 655622401> = SField::new();
+
+/// Returns the name of the field with serialized code `code`, or `None` if there is no such field.
+///
+/// Only available with the `sfield-names` feature, which adds every field name to the binary.
+#[cfg(feature = "sfield-names")]
+pub const fn name(code: i32) -> Option<&'static str> {
+    match code {
+        65537 => Some("LedgerEntryType"),
+        65538 => Some("TransactionType"),
+        65539 => Some("SignerWeight"),
+        65540 => Some("TransferFee"),
+        65541 => Some("TradingFee"),
+        65542 => Some("DiscountedFee"),
+        65552 => Some("Version"),
+        65553 => Some("HookStateChangeCount"),
+        65554 => Some("HookEmitCount"),
+        65555 => Some("HookExecutionIndex"),
+        65556 => Some("HookApiVersion"),
+        65557 => Some("LedgerFixType"),
+        65558 => Some("ManagementFeeRate"),
+        131073 => Some("NetworkID"),
+        131074 => Some("Flags"),
+        131075 => Some("SourceTag"),
+        131076 => Some("Sequence"),
+        131077 => Some("PreviousTxnLgrSeq"),
+        131078 => Some("LedgerSequence"),
+        131079 => Some("CloseTime"),
+        131080 => Some("ParentCloseTime"),
+        131081 => Some("SigningTime"),
+        131082 => Some("Expiration"),
+        131083 => Some("TransferRate"),
+        131084 => Some("WalletSize"),
+        131085 => Some("OwnerCount"),
+        131086 => Some("DestinationTag"),
+        131087 => Some("LastUpdateTime"),
+        131088 => Some("HighQualityIn"),
+        131089 => Some("HighQualityOut"),
+        131090 => Some("LowQualityIn"),
+        131091 => Some("LowQualityOut"),
+        131092 => Some("QualityIn"),
+        131093 => Some("QualityOut"),
+        131094 => Some("StampEscrow"),
+        131095 => Some("BondAmount"),
+        131096 => Some("LoadFee"),
+        131097 => Some("OfferSequence"),
+        131098 => Some("FirstLedgerSequence"),
+        131099 => Some("LastLedgerSequence"),
+        131100 => Some("TransactionIndex"),
+        131101 => Some("OperationLimit"),
+        131102 => Some("ReferenceFeeUnits"),
+        131103 => Some("ReserveBase"),
+        131104 => Some("ReserveIncrement"),
+        131105 => Some("SetFlag"),
+        131106 => Some("ClearFlag"),
+        131107 => Some("SignerQuorum"),
+        131108 => Some("CancelAfter"),
+        131109 => Some("FinishAfter"),
+        131110 => Some("SignerListID"),
+        131111 => Some("SettleDelay"),
+        131112 => Some("TicketCount"),
+        131113 => Some("TicketSequence"),
+        131114 => Some("NFTokenTaxon"),
+        131115 => Some("MintedNFTokens"),
+        131116 => Some("BurnedNFTokens"),
+        131117 => Some("HookStateCount"),
+        131118 => Some("EmitGeneration"),
+        131120 => Some("VoteWeight"),
+        131122 => Some("FirstNFTokenSequence"),
+        131123 => Some("OracleDocumentID"),
+        131124 => Some("PermissionValue"),
+        131125 => Some("MutableFlags"),
+        131126 => Some("StartDate"),
+        131127 => Some("PaymentInterval"),
+        131128 => Some("GracePeriod"),
+        131129 => Some("PreviousPaymentDueDate"),
+        131130 => Some("NextPaymentDueDate"),
+        131131 => Some("PaymentRemaining"),
+        131132 => Some("PaymentTotal"),
+        131133 => Some("LoanSequence"),
+        131134 => Some("CoverRateMinimum"),
+        131135 => Some("CoverRateLiquidation"),
+        131136 => Some("OverpaymentFee"),
+        131137 => Some("InterestRate"),
+        131138 => Some("LateInterestRate"),
+        131139 => Some("CloseInterestRate"),
+        131140 => Some("OverpaymentInterestRate"),
+        131141 => Some("ExtensionComputeLimit"),
+        131142 => Some("ExtensionSizeLimit"),
+        131143 => Some("GasPrice"),
+        131144 => Some("ComputationAllowance"),
+        131145 => Some("GasUsed"),
+        131146 => Some("ParameterFlag"),
+        196609 => Some("IndexNext"),
+        196610 => Some("IndexPrevious"),
+        196611 => Some("BookNode"),
+        196612 => Some("OwnerNode"),
+        196613 => Some("BaseFee"),
+        196614 => Some("ExchangeRate"),
+        196615 => Some("LowNode"),
+        196616 => Some("HighNode"),
+        196617 => Some("DestinationNode"),
+        196618 => Some("Cookie"),
+        196619 => Some("ServerVersion"),
+        196620 => Some("NFTokenOfferNode"),
+        196621 => Some("EmitBurden"),
+        196624 => Some("HookOn"),
+        196625 => Some("HookInstructionCount"),
+        196626 => Some("HookReturnCode"),
+        196627 => Some("ReferenceCount"),
+        196628 => Some("XChainClaimID"),
+        196629 => Some("XChainAccountCreateCount"),
+        196630 => Some("XChainAccountClaimCount"),
+        196631 => Some("AssetPrice"),
+        196632 => Some("MaximumAmount"),
+        196633 => Some("OutstandingAmount"),
+        196634 => Some("MPTAmount"),
+        196635 => Some("IssuerNode"),
+        196636 => Some("SubjectNode"),
+        196637 => Some("LockedAmount"),
+        196638 => Some("VaultNode"),
+        196639 => Some("LoanBrokerNode"),
+        262145 => Some("EmailHash"),
+        327681 => Some("LedgerHash"),
+        327682 => Some("ParentHash"),
+        327683 => Some("TransactionHash"),
+        327684 => Some("AccountHash"),
+        327685 => Some("PreviousTxnID"),
+        327686 => Some("LedgerIndex"),
+        327687 => Some("WalletLocator"),
+        327688 => Some("RootIndex"),
+        327689 => Some("AccountTxnID"),
+        327690 => Some("NFTokenID"),
+        327691 => Some("EmitParentTxnID"),
+        327692 => Some("EmitNonce"),
+        327693 => Some("EmitHookHash"),
+        327694 => Some("AMMID"),
+        327696 => Some("BookDirectory"),
+        327697 => Some("InvoiceID"),
+        327698 => Some("Nickname"),
+        327699 => Some("Amendment"),
+        327701 => Some("Digest"),
+        327702 => Some("Channel"),
+        327703 => Some("ConsensusHash"),
+        327704 => Some("CheckID"),
+        327705 => Some("ValidatedHash"),
+        327706 => Some("PreviousPageMin"),
+        327707 => Some("NextPageMin"),
+        327708 => Some("NFTokenBuyOffer"),
+        327709 => Some("NFTokenSellOffer"),
+        327710 => Some("HookStateKey"),
+        327711 => Some("HookHash"),
+        327712 => Some("HookNamespace"),
+        327713 => Some("HookSetTxnID"),
+        327714 => Some("DomainID"),
+        327715 => Some("VaultID"),
+        327716 => Some("ParentBatchID"),
+        327717 => Some("LoanBrokerID"),
+        327718 => Some("LoanID"),
+        327719 => Some("ContractHash"),
+        327720 => Some("ContractID"),
+        393217 => Some("Amount"),
+        393218 => Some("Balance"),
+        393219 => Some("LimitAmount"),
+        393220 => Some("TakerPays"),
+        393221 => Some("TakerGets"),
+        393222 => Some("LowLimit"),
+        393223 => Some("HighLimit"),
+        393224 => Some("Fee"),
+        393225 => Some("SendMax"),
+        393226 => Some("DeliverMin"),
+        393227 => Some("Amount2"),
+        393228 => Some("BidMin"),
+        393229 => Some("BidMax"),
+        393232 => Some("MinimumOffer"),
+        393233 => Some("RippleEscrow"),
+        393234 => Some("DeliveredAmount"),
+        393235 => Some("NFTokenBrokerFee"),
+        393238 => Some("BaseFeeDrops"),
+        393239 => Some("ReserveBaseDrops"),
+        393240 => Some("ReserveIncrementDrops"),
+        393241 => Some("LPTokenOut"),
+        393242 => Some("LPTokenIn"),
+        393243 => Some("EPrice"),
+        393244 => Some("Price"),
+        393245 => Some("SignatureReward"),
+        393246 => Some("MinAccountCreateAmount"),
+        393247 => Some("LPTokenBalance"),
+        458753 => Some("PublicKey"),
+        458754 => Some("MessageKey"),
+        458755 => Some("SigningPubKey"),
+        458756 => Some("TxnSignature"),
+        458757 => Some("URI"),
+        458758 => Some("Signature"),
+        458759 => Some("Domain"),
+        458760 => Some("FundCode"),
+        458761 => Some("RemoveCode"),
+        458762 => Some("ExpireCode"),
+        458763 => Some("CreateCode"),
+        458764 => Some("MemoType"),
+        458765 => Some("MemoData"),
+        458766 => Some("MemoFormat"),
+        458768 => Some("Fulfillment"),
+        458769 => Some("Condition"),
+        458770 => Some("MasterSignature"),
+        458771 => Some("UNLModifyValidator"),
+        458772 => Some("ValidatorToDisable"),
+        458773 => Some("ValidatorToReEnable"),
+        458774 => Some("HookStateData"),
+        458775 => Some("HookReturnString"),
+        458776 => Some("HookParameterName"),
+        458777 => Some("HookParameterValue"),
+        458778 => Some("DIDDocument"),
+        458779 => Some("Data"),
+        458780 => Some("AssetClass"),
+        458781 => Some("Provider"),
+        458782 => Some("MPTokenMetadata"),
+        458783 => Some("CredentialType"),
+        458784 => Some("FinishFunction"),
+        458785 => Some("ContractCode"),
+        458786 => Some("FunctionName"),
+        524289 => Some("Account"),
+        524290 => Some("Owner"),
+        524291 => Some("Destination"),
+        524292 => Some("Issuer"),
+        524293 => Some("Authorize"),
+        524294 => Some("Unauthorize"),
+        524296 => Some("RegularKey"),
+        524297 => Some("NFTokenMinter"),
+        524298 => Some("EmitCallback"),
+        524299 => Some("Holder"),
+        524300 => Some("Delegate"),
+        524304 => Some("HookAccount"),
+        524306 => Some("OtherChainSource"),
+        524307 => Some("OtherChainDestination"),
+        524308 => Some("AttestationSignerAccount"),
+        524309 => Some("AttestationRewardAccount"),
+        524310 => Some("LockingChainDoor"),
+        524311 => Some("IssuingChainDoor"),
+        524312 => Some("Subject"),
+        524313 => Some("Borrower"),
+        524314 => Some("Counterparty"),
+        524315 => Some("ContractAccount"),
+        589825 => Some("Number"),
+        589826 => Some("AssetsAvailable"),
+        589827 => Some("AssetsMaximum"),
+        589828 => Some("AssetsTotal"),
+        589829 => Some("LossUnrealized"),
+        589830 => Some("DebtTotal"),
+        589831 => Some("DebtMaximum"),
+        589832 => Some("CoverAvailable"),
+        589833 => Some("LoanOriginationFee"),
+        589834 => Some("LoanServiceFee"),
+        589835 => Some("LatePaymentFee"),
+        589836 => Some("ClosePaymentFee"),
+        589837 => Some("PrincipalOutstanding"),
+        589838 => Some("PrincipalRequested"),
+        589839 => Some("TotalValueOutstanding"),
+        589840 => Some("PeriodicPayment"),
+        589841 => Some("ManagementFeeOutstanding"),
+        655361 => Some("LoanScale"),
+        655362 => Some("WasmReturnCode"),
+        917506 => Some("TransactionMetaData"),
+        917507 => Some("CreatedNode"),
+        917508 => Some("DeletedNode"),
+        917509 => Some("ModifiedNode"),
+        917510 => Some("PreviousFields"),
+        917511 => Some("FinalFields"),
+        917512 => Some("NewFields"),
+        917513 => Some("TemplateEntry"),
+        917514 => Some("Memo"),
+        917515 => Some("SignerEntry"),
+        917516 => Some("NFToken"),
+        917517 => Some("EmitDetails"),
+        917518 => Some("Hook"),
+        917519 => Some("Permission"),
+        917520 => Some("Signer"),
+        917522 => Some("Majority"),
+        917523 => Some("DisabledValidator"),
+        917524 => Some("EmittedTxn"),
+        917525 => Some("HookExecution"),
+        917526 => Some("HookDefinition"),
+        917527 => Some("HookParameter"),
+        917528 => Some("HookGrant"),
+        917529 => Some("VoteEntry"),
+        917530 => Some("AuctionSlot"),
+        917531 => Some("AuthAccount"),
+        917532 => Some("XChainClaimProofSig"),
+        917533 => Some("XChainCreateAccountProofSig"),
+        917534 => Some("XChainClaimAttestationCollectionElement"),
+        917535 => Some("XChainCreateAccountAttestationCollectionElement"),
+        917536 => Some("PriceData"),
+        917537 => Some("Credential"),
+        917538 => Some("RawTransaction"),
+        917539 => Some("BatchSigner"),
+        917540 => Some("Book"),
+        917541 => Some("CounterpartySignature"),
+        917542 => Some("Function"),
+        917543 => Some("InstanceParameter"),
+        917544 => Some("InstanceParameterValue"),
+        917545 => Some("Parameter"),
+        983043 => Some("Signers"),
+        983044 => Some("SignerEntries"),
+        983045 => Some("Template"),
+        983046 => Some("Necessary"),
+        983047 => Some("Sufficient"),
+        983048 => Some("AffectedNodes"),
+        983049 => Some("Memos"),
+        983050 => Some("NFTokens"),
+        983051 => Some("Hooks"),
+        983052 => Some("VoteSlots"),
+        983053 => Some("AdditionalBooks"),
+        983056 => Some("Majorities"),
+        983057 => Some("DisabledValidators"),
+        983058 => Some("HookExecutions"),
+        983059 => Some("HookParameters"),
+        983060 => Some("HookGrants"),
+        983061 => Some("XChainClaimAttestations"),
+        983062 => Some("XChainCreateAccountAttestations"),
+        983064 => Some("PriceDataSeries"),
+        983065 => Some("AuthAccounts"),
+        983066 => Some("AuthorizeCredentials"),
+        983067 => Some("UnauthorizeCredentials"),
+        983068 => Some("AcceptedCredentials"),
+        983069 => Some("Permissions"),
+        983070 => Some("RawTransactions"),
+        983071 => Some("BatchSigners"),
+        983072 => Some("Functions"),
+        983073 => Some("InstanceParameters"),
+        983074 => Some("InstanceParameterValues"),
+        983075 => Some("Parameters"),
+        1048577 => Some("CloseResolution"),
+        1048578 => Some("Method"),
+        1048579 => Some("TransactionResult"),
+        1048580 => Some("Scale"),
+        1048581 => Some("AssetScale"),
+        1048592 => Some("TickSize"),
+        1048593 => Some("UNLModifyDisabling"),
+        1048594 => Some("HookResult"),
+        1048595 => Some("WasLockingChainSend"),
+        1048596 => Some("WithdrawalPolicy"),
+        1048597 => Some("ContractResult"),
+        1114113 => Some("TakerPaysCurrency"),
+        1114114 => Some("TakerPaysIssuer"),
+        1114115 => Some("TakerGetsCurrency"),
+        1114116 => Some("TakerGetsIssuer"),
+        1179649 => Some("Paths"),
+        1245185 => Some("Indexes"),
+        1245186 => Some("Hashes"),
+        1245187 => Some("Amendments"),
+        1245188 => Some("NFTokenOffers"),
+        1245189 => Some("CredentialIDs"),
+        1376257 => Some("MPTokenIssuanceID"),
+        1376258 => Some("ShareMPTID"),
+        1376259 => Some("TakerPaysMPT"),
+        1376260 => Some("TakerGetsMPT"),
+        1572865 => Some("LockingChainIssue"),
+        1572866 => Some("IssuingChainIssue"),
+        1572867 => Some("Asset"),
+        1572868 => Some("Asset2"),
+        1638401 => Some("XChainBridge"),
+        1703937 => Some("BaseAsset"),
+        1703938 => Some("QuoteAsset"),
+        1769473 => Some("ParameterValue"),
+        1835009 => Some("ParameterType"),
+        1900545 => Some("ContractJson"),
+        655425793 => Some("Transaction"),
+        655491329 => Some("LedgerEntry"),
+        655556865 => Some("Validation"),
+        655622401 => Some("Metadata"),
+        _ => None,
+    }
+}
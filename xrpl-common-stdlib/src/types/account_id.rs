@@ -22,12 +22,71 @@ pub const ACCOUNT_ID_SIZE: usize = 20;
 /// ## Derived Traits
 ///
 /// - `Copy`: Efficient for this 20-byte struct, enabling implicit copying
-/// - `PartialEq, Eq`: Enable comparisons and use in hash-based collections
+/// - `PartialEq, Eq, Hash`: Enable comparisons and use in hash-based collections
+/// - `PartialOrd, Ord`: Byte-wise ordering, for sorted collections and binary search
 /// - `Debug, Clone`: Standard traits for development and consistency
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C)]
 pub struct AccountID(pub [u8; ACCOUNT_ID_SIZE]);
 
+/// `rrrrrrrrrrrrrrrrrrrrrhoLvTp`, the all-zero account.
+///
+/// It is what an account field decodes to when it was encoded from an empty value, and it is the
+/// `Issuer` of XRP in issue and currency-pair structures. No key pair is known for it, so funds
+/// sent there are lost.
+pub const ACCOUNT_ZERO: AccountID = AccountID([0; ACCOUNT_ID_SIZE]);
+
+/// `rrrrrrrrrrrrrrrrrrrrBZbvji`, the account whose ID is 1.
+///
+/// Used as a placeholder issuer in some legacy structures. Like [`ACCOUNT_ZERO`], no key pair is
+/// known for it.
+pub const ACCOUNT_ONE: AccountID = {
+    let mut bytes = [0; ACCOUNT_ID_SIZE];
+    bytes[ACCOUNT_ID_SIZE - 1] = 1;
+    AccountID(bytes)
+};
+
+/// `rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh`, the genesis account that holds all XRP in a new ledger.
+///
+/// Its secret is public (`masterpassphrase`), so on Mainnet it has been blackholed; on
+/// standalone and test networks it is the usual funding source.
+pub const GENESIS_ACCOUNT: AccountID = AccountID([
+    0xB5, 0xF7, 0x62, 0x79, 0x8A, 0x53, 0xD5, 0x43, 0xA0, 0x14, 0xCA, 0xF8, 0xB2, 0x97, 0xCF, 0xF8,
+    0xF2, 0xF9, 0x37, 0xE8,
+]);
+
+impl AccountID {
+    /// Returns `true` for [`ACCOUNT_ZERO`].
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < ACCOUNT_ID_SIZE {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` for the accounts no key pair is known for ([`ACCOUNT_ZERO`] and
+    /// [`ACCOUNT_ONE`]). Payments to them are unrecoverable, so contracts releasing funds to a
+    /// caller-supplied account can reject these outright.
+    ///
+    /// Other unspendable accounts exist (any account whose master key is disabled and has no
+    /// regular key or signer list, as well as AMM and other pseudo-accounts), but telling those
+    /// apart requires reading the `AccountRoot`.
+    pub const fn is_unspendable_placeholder(&self) -> bool {
+        let mut i = 0;
+        while i < ACCOUNT_ID_SIZE - 1 {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        self.0[ACCOUNT_ID_SIZE - 1] <= 1
+    }
+}
+
 impl From<[u8; ACCOUNT_ID_SIZE]> for AccountID {
     fn from(value: [u8; ACCOUNT_ID_SIZE]) -> Self {
         AccountID(value)
@@ -123,3 +182,24 @@ impl CurrentTxFieldGetter for AccountID {
         .map(|buffer| buffer.map(|b| b.into()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_accounts() {
+        assert!(ACCOUNT_ZERO.is_zero());
+        assert!(!ACCOUNT_ONE.is_zero());
+        assert!(!GENESIS_ACCOUNT.is_zero());
+
+        assert!(ACCOUNT_ZERO.is_unspendable_placeholder());
+        assert!(ACCOUNT_ONE.is_unspendable_placeholder());
+        assert!(!GENESIS_ACCOUNT.is_unspendable_placeholder());
+        assert!(!AccountID([0x01; ACCOUNT_ID_SIZE]).is_unspendable_placeholder());
+
+        let mut two = [0; ACCOUNT_ID_SIZE];
+        two[ACCOUNT_ID_SIZE - 1] = 2;
+        assert!(!AccountID(two).is_unspendable_placeholder());
+    }
+}
@@ -4,10 +4,12 @@ use crate::host::Error::InvalidParams;
 use crate::host::Result::{Err, Ok};
 use crate::host::field_helpers::{get_variable_size_field, get_variable_size_field_optional};
 use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field};
+use crate::math::{Rounding, mul_div_u64};
 use crate::objects::LedgerObjectFieldGetter;
 use crate::sfield::SField;
 use crate::types::account_id::AccountID;
-use crate::types::currency::Currency;
+use crate::types::constants::{DROPS_PER_XRP, MAX_DROPS, MAX_XRP, xrp_to_drops};
+use crate::types::currency::{CURRENCY_SIZE, Currency};
 use crate::types::mpt_id::MptId;
 use crate::types::opaque_float::OpaqueFloat;
 
@@ -276,6 +278,279 @@ impl Amount {
     }
 }
 
+impl Amount {
+    /// Creates an XRP amount from a whole number of XRP.
+    ///
+    /// For fractional amounts, use the [`xrp!`](crate::xrp) macro instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xrp` exceeds [`MAX_XRP`]. In a `const` binding this is a compile error.
+    pub const fn xrp_from_whole(xrp: u64) -> Amount {
+        assert!(xrp <= MAX_XRP, "XRP amount exceeds the total supply");
+        Amount::XRP {
+            num_drops: xrp_to_drops(xrp) as i64,
+        }
+    }
+
+    /// Returns the number of whole XRP in an XRP amount, truncating any fractional drops toward
+    /// zero, or `None` for IOU and MPT amounts.
+    pub fn num_xrp(&self) -> Option<i64> {
+        match self {
+            Amount::XRP { num_drops } => Some(num_drops / DROPS_PER_XRP as i64),
+            _ => None,
+        }
+    }
+
+    /// Returns the signed drop count of an XRP amount after checking it against the XRP supply.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidParams` if this is not an XRP amount, or if its magnitude exceeds
+    /// [`MAX_DROPS`] (which can only happen for values built by hand, not read from the ledger).
+    pub fn as_drops_checked(&self) -> Result<i64> {
+        match self {
+            Amount::XRP { num_drops } if num_drops.unsigned_abs() <= MAX_DROPS => Ok(*num_drops),
+            _ => Err(InvalidParams),
+        }
+    }
+
+    /// Converts a non-negative XRP or MPT amount to its raw integer count (drops or units).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidParams` for IOU amounts (convert those with the float host functions), for
+    /// negative amounts, and for XRP amounts above [`MAX_DROPS`] (see
+    /// [`as_drops_checked`](Self::as_drops_checked)).
+    pub fn try_into_u64(&self) -> Result<u64> {
+        match self {
+            Amount::XRP { .. } => match self.as_drops_checked() {
+                Ok(drops) if drops >= 0 => Ok(drops as u64),
+                _ => Err(InvalidParams),
+            },
+            Amount::MPT {
+                num_units,
+                is_positive,
+                ..
+            } if *is_positive || *num_units == 0 => Ok(*num_units),
+            _ => Err(InvalidParams),
+        }
+    }
+
+    /// Converts an XRP or MPT amount to a signed integer count (drops or units), e.g. for
+    /// `trace_num`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidParams` for IOU amounts, for XRP amounts whose magnitude exceeds
+    /// [`MAX_DROPS`] (see [`as_drops_checked`](Self::as_drops_checked)), and for MPT amounts whose
+    /// magnitude does not fit in an `i64`.
+    pub fn try_into_i64(&self) -> Result<i64> {
+        match self {
+            Amount::XRP { .. } => self.as_drops_checked(),
+            Amount::MPT {
+                num_units,
+                is_positive,
+                ..
+            } => match i64::try_from(*num_units) {
+                core::result::Result::Ok(units) if *is_positive => Ok(units),
+                core::result::Result::Ok(units) => Ok(-units),
+                core::result::Result::Err(_) => Err(InvalidParams),
+            },
+            Amount::IOU { .. } => Err(InvalidParams),
+        }
+    }
+
+    /// Returns whether `self` and `other` are amounts of the same asset, ignoring their values:
+    /// both XRP, IOUs with the same currency and issuer, or MPTs with the same issuance ID.
+    ///
+    /// An IOU whose currency code is all zeros is XRP's currency code, so it counts as XRP
+    /// whatever its issuer; a well-formed ledger never holds one, but hand-built amounts might.
+    /// The three-letter code `"XRP"` is an ordinary (if invalid) IOU currency and does not match
+    /// XRP.
+    pub fn same_asset(&self, other: &Amount) -> bool {
+        match (self, other) {
+            (a, b) if a.is_xrp_asset() || b.is_xrp_asset() => a.is_xrp_asset() && b.is_xrp_asset(),
+            (
+                Amount::IOU {
+                    issuer, currency, ..
+                },
+                Amount::IOU {
+                    issuer: other_issuer,
+                    currency: other_currency,
+                    ..
+                },
+            ) => issuer == other_issuer && currency == other_currency,
+            (
+                Amount::MPT { mpt_id, .. },
+                Amount::MPT {
+                    mpt_id: other_id, ..
+                },
+            ) => mpt_id == other_id,
+            _ => false,
+        }
+    }
+
+    fn is_xrp_asset(&self) -> bool {
+        match self {
+            Amount::XRP { .. } => true,
+            Amount::IOU { currency, .. } => currency.as_bytes() == &[0; CURRENCY_SIZE],
+            Amount::MPT { .. } => false,
+        }
+    }
+}
+
+/// The `TransferRate` value meaning "no fee" (1.0, expressed in billionths).
+pub const TRANSFER_RATE_PARITY: u32 = 1_000_000_000;
+
+/// The largest valid `TransferRate` (a 100% fee).
+pub const MAX_TRANSFER_RATE: u32 = 2_000_000_000;
+
+impl Amount {
+    /// Applies an issuer's `TransferRate` to this amount, returning `self * rate / 1_000_000_000`.
+    ///
+    /// For example, a rate of `1_002_000_000` (a 0.2% fee) turns 1,000 units into 1,002. A rate of
+    /// `0` is treated as [`TRANSFER_RATE_PARITY`], matching how the ledger interprets an unset field.
+    ///
+    /// `rounding_mode` is one of the `host::FLOAT_ROUNDING_MODES_*` constants. IOU amounts are scaled
+    /// with the float host functions; XRP and MPT amounts are scaled with integer math using the same
+    /// rounding rules (`TO_NEAREST` rounds half to even).
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `rate` is outside `[TRANSFER_RATE_PARITY, MAX_TRANSFER_RATE]`, if
+    ///   `rounding_mode` is unknown, or if the scaled integer amount overflows
+    /// * Any error returned by the float host functions (IOU only)
+    pub fn apply_transfer_rate(&self, rate: u32, rounding_mode: i32) -> Result<Amount> {
+        let rate = match rate {
+            0 => TRANSFER_RATE_PARITY,
+            TRANSFER_RATE_PARITY..=MAX_TRANSFER_RATE => rate,
+            _ => return Err(InvalidParams),
+        };
+
+        match self {
+            Amount::XRP { num_drops } => {
+                let negative = *num_drops < 0;
+                scale_billionths(num_drops.unsigned_abs(), negative, rate, rounding_mode).and_then(
+                    |magnitude| match i64::try_from(magnitude) {
+                        core::result::Result::Ok(drops) => Ok(Amount::XRP {
+                            num_drops: if negative { -drops } else { drops },
+                        }),
+                        core::result::Result::Err(_) => Err(InvalidParams),
+                    },
+                )
+            }
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => {
+                scale_billionths(*num_units, !*is_positive, rate, rounding_mode).map(|num_units| {
+                    Amount::MPT {
+                        num_units,
+                        is_positive: *is_positive,
+                        mpt_id: *mpt_id,
+                    }
+                })
+            }
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => amount
+                .scale_pow10(rate, -9, rounding_mode)
+                .map(|amount| Amount::IOU {
+                    amount,
+                    issuer: *issuer,
+                    currency: *currency,
+                }),
+        }
+    }
+}
+
+/// Computes `magnitude * factor / 10^9`, rounding the way the float host functions would for a
+/// value with the given sign.
+fn scale_billionths(
+    magnitude: u64,
+    negative: bool,
+    factor: u32,
+    rounding_mode: i32,
+) -> Result<u64> {
+    let rounding = match rounding_mode {
+        host::FLOAT_ROUNDING_MODES_TO_NEAREST => Rounding::NearestEven,
+        host::FLOAT_ROUNDING_MODES_TOWARDS_ZERO => Rounding::Down,
+        host::FLOAT_ROUNDING_MODES_DOWNWARD if negative => Rounding::Up,
+        host::FLOAT_ROUNDING_MODES_DOWNWARD => Rounding::Down,
+        host::FLOAT_ROUNDING_MODES_UPWARD if negative => Rounding::Down,
+        host::FLOAT_ROUNDING_MODES_UPWARD => Rounding::Up,
+        _ => return Err(InvalidParams),
+    };
+    mul_div_u64(magnitude, factor as u64, 1_000_000_000, rounding)
+}
+
+/// How a host laid out an amount it wrote into a 48-byte buffer.
+///
+/// Current hosts write the serialized form of the amount: 8 bytes for XRP, 33 for an MPT and 48
+/// for an IOU, each starting with the type and sign flags that [`Amount::from_bytes`] reads. Hosts
+/// from before that change (the "Amount Format Update" noted in the `host_functions_test` e2e
+/// contract) wrote XRP amounts as a plain big-endian drop count at the start of the buffer,
+/// reporting the whole 48 bytes as written and setting no flag bits. `from_bytes` would read such
+/// a count as a negative amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountEncoding {
+    /// The serialized format.
+    Current,
+    /// A 48-byte XRP drop count without flags, from a host that predates the serialized format.
+    Legacy,
+}
+
+impl AmountEncoding {
+    /// Works out the encoding of `bytes`, of which the host reported writing `len`.
+    ///
+    /// A legacy XRP amount is the only 48-byte value with none of the IOU, positive or MPT flags
+    /// set. A current host never writes one: its XRP amounts are 8 bytes long, and its 48-byte
+    /// values are IOUs. Legacy hosts only ever wrote non-negative XRP amounts, so a negative drop
+    /// count is never mistaken for one.
+    pub fn detect(bytes: &[u8; AMOUNT_SIZE], len: usize) -> AmountEncoding {
+        if len == AMOUNT_SIZE && bytes[0] & 0xE0 == 0 {
+            AmountEncoding::Legacy
+        } else {
+            AmountEncoding::Current
+        }
+    }
+}
+
+impl Amount {
+    /// Decodes an amount the host wrote into `bytes`, reporting a length of `len`.
+    ///
+    /// With the `legacy-amount-encoding` feature, amounts in the [`AmountEncoding::Legacy`] layout
+    /// are recognised and decoded too, so one contract binary can read amounts from hosts on
+    /// either side of the format change. Without it this is the same as [`Amount::from`], and
+    /// `len` is ignored.
+    pub fn from_host_bytes(bytes: [u8; AMOUNT_SIZE], len: usize) -> Amount {
+        if cfg!(feature = "legacy-amount-encoding") {
+            Amount::decode_as(bytes, AmountEncoding::detect(&bytes, len))
+        } else {
+            Amount::from(bytes)
+        }
+    }
+
+    /// Decodes `bytes` as an amount in the given encoding, whatever the enabled features.
+    pub fn decode_as(bytes: [u8; AMOUNT_SIZE], encoding: AmountEncoding) -> Amount {
+        match encoding {
+            AmountEncoding::Current => Amount::from(bytes),
+            AmountEncoding::Legacy => {
+                let mut drops = [0u8; 8];
+                drops.copy_from_slice(&bytes[..8]);
+                // Clearing the three flag bits leaves a count below 2^61, which fits in an i64.
+                Amount::XRP {
+                    num_drops: (u64::from_be_bytes(drops) & 0x1FFF_FFFF_FFFF_FFFF) as i64,
+                }
+            }
+        }
+    }
+}
+
 impl From<[u8; AMOUNT_SIZE]> for Amount {
     fn from(bytes: [u8; AMOUNT_SIZE]) -> Self {
         match Self::from_bytes(&bytes) {
@@ -295,14 +570,15 @@ impl From<[u8; AMOUNT_SIZE]> for Amount {
 ///
 /// Uses a 48-byte buffer to accommodate the largest possible amount representation.
 /// The Amount type handles the parsing of different amount formats internally.
-/// No strict byte count validation is performed since amounts can vary in size.
+/// No strict byte count validation is performed since amounts can vary in size; the length is
+/// only used to recognise legacy encodings (see [`Amount::from_host_bytes`]).
 impl LedgerObjectFieldGetter for Amount {
     #[inline]
     fn get_from_current_ledger_obj<const CODE: i32>(field: SField<Self, CODE>) -> Result<Self> {
         get_variable_size_field::<AMOUNT_SIZE, _>(field, |fc, buf, size| unsafe {
             get_current_ledger_obj_field(fc, buf, size)
         })
-        .map(|(buffer, _len)| Amount::from(buffer))
+        .map(|(buffer, len)| Amount::from_host_bytes(buffer, len))
     }
 
     #[inline]
@@ -312,7 +588,7 @@ impl LedgerObjectFieldGetter for Amount {
         get_variable_size_field_optional::<AMOUNT_SIZE, _>(field, |fc, buf, size| unsafe {
             get_current_ledger_obj_field(fc, buf, size)
         })
-        .map(|opt| opt.map(|(buffer, _len)| Amount::from(buffer)))
+        .map(|opt| opt.map(|(buffer, len)| Amount::from_host_bytes(buffer, len)))
     }
 
     #[inline]
@@ -323,7 +599,7 @@ impl LedgerObjectFieldGetter for Amount {
         get_variable_size_field::<AMOUNT_SIZE, _>(field, |fc, buf, size| unsafe {
             get_ledger_obj_field(register_num, fc, buf, size)
         })
-        .map(|(buffer, _len)| Amount::from(buffer))
+        .map(|(buffer, len)| Amount::from_host_bytes(buffer, len))
     }
 
     #[inline]
@@ -334,7 +610,7 @@ impl LedgerObjectFieldGetter for Amount {
         get_variable_size_field_optional::<AMOUNT_SIZE, _>(field, |fc, buf, size| unsafe {
             get_ledger_obj_field(register_num, fc, buf, size)
         })
-        .map(|opt| opt.map(|(buffer, _len)| Amount::from(buffer)))
+        .map(|opt| opt.map(|(buffer, len)| Amount::from_host_bytes(buffer, len)))
     }
 }
 
@@ -349,14 +625,15 @@ impl LedgerObjectFieldGetter for Amount {
 ///
 /// Uses a 48-byte buffer (AMOUNT_SIZE) to accommodate the largest possible amount
 /// representation. The Amount type handles the parsing of different amount formats
-/// internally. No strict byte count validation is performed since amounts can vary in size.
+/// internally. No strict byte count validation is performed since amounts can vary in size; the
+/// length is only used to recognise legacy encodings (see [`Amount::from_host_bytes`]).
 impl CurrentTxFieldGetter for Amount {
     #[inline]
     fn get_from_current_tx<const CODE: i32>(field: SField<Self, CODE>) -> Result<Self> {
         get_variable_size_field::<AMOUNT_SIZE, _>(i32::from(field), |fc, buf, size| unsafe {
             get_tx_field(fc, buf, size)
         })
-        .map(|(buffer, _len)| Amount::from(buffer))
+        .map(|(buffer, len)| Amount::from_host_bytes(buffer, len))
     }
 
     #[inline]
@@ -367,7 +644,7 @@ impl CurrentTxFieldGetter for Amount {
             i32::from(field),
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
         )
-        .map(|opt| opt.map(|(buffer, _len)| Amount::from(buffer)))
+        .map(|opt| opt.map(|(buffer, len)| Amount::from_host_bytes(buffer, len)))
     }
 }
 
@@ -376,6 +653,86 @@ mod tests {
     use super::*;
     use crate::types::opaque_float::OpaqueFloat;
 
+    #[test]
+    fn test_from_host_bytes_recognises_legacy_xrp() {
+        let mut legacy = [0u8; 48];
+        legacy[..8].copy_from_slice(&1_000_000u64.to_be_bytes());
+        let mut current = [0u8; 48];
+        current[..8].copy_from_slice(&(0x4000_0000_0000_0000u64 | 1_000_000).to_be_bytes());
+
+        assert_eq!(AmountEncoding::detect(&legacy, 48), AmountEncoding::Legacy);
+        assert_eq!(AmountEncoding::detect(&legacy, 8), AmountEncoding::Current);
+        assert_eq!(
+            AmountEncoding::detect(&current, 48),
+            AmountEncoding::Current
+        );
+
+        let expected = Amount::XRP {
+            num_drops: 1_000_000,
+        };
+        assert_eq!(
+            Amount::decode_as(current, AmountEncoding::Current),
+            expected
+        );
+        assert_eq!(Amount::decode_as(legacy, AmountEncoding::Legacy), expected);
+        assert_eq!(Amount::from_host_bytes(current, 8), expected);
+        if cfg!(feature = "legacy-amount-encoding") {
+            assert_eq!(Amount::from_host_bytes(legacy, 48), expected);
+        } else {
+            assert_eq!(Amount::from_host_bytes(legacy, 48), Amount::from(legacy));
+        }
+    }
+
+    #[test]
+    fn test_getters_decode_both_encodings() {
+        use crate::current_tx::get_field;
+        use crate::host::host_bindings_trait::MockHostBindings;
+        use crate::host::setup_mock;
+        use crate::sfield;
+        use mockall::predicate::{always, eq};
+
+        // The current host writes Fee as 8 serialized bytes, a legacy one Amount as a 48-byte
+        // drop count without flags.
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Fee)), always(), eq(AMOUNT_SIZE))
+            .returning(|_, out, _| {
+                let bytes = (0x4000_0000_0000_0000u64 | 12).to_be_bytes();
+                unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 8) };
+                8
+            });
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Amount)), always(), eq(AMOUNT_SIZE))
+            .returning(|_, out, len| {
+                unsafe { core::ptr::write_bytes(out, 0, len) };
+                let bytes = 5_000_000u64.to_be_bytes();
+                unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 8) };
+                AMOUNT_SIZE as i32
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            get_field(sfield::Fee).unwrap(),
+            Amount::XRP { num_drops: 12 }
+        );
+        let amount = get_field(sfield::Amount).unwrap();
+        if cfg!(feature = "legacy-amount-encoding") {
+            assert_eq!(
+                amount,
+                Amount::XRP {
+                    num_drops: 5_000_000
+                }
+            );
+        } else {
+            assert_eq!(
+                amount,
+                Amount::XRP {
+                    num_drops: -5_000_000
+                }
+            );
+        }
+    }
+
     #[test]
     fn test_parse_xrp_amount() {
         // Create a test XRP amount byte array
@@ -515,7 +872,7 @@ mod tests {
     fn test_parse_invalid_amount() {
         // A byte array whose length is not 48 is a caller/input error, reported as
         // `InvalidParams` (not an internal invariant trip).
-        let expected = InvalidParams as i32;
+        let expected = InvalidParams.code();
 
         // Test with an empty byte array
         assert_eq!(Amount::from_bytes(&[]).err().unwrap().code(), expected);
@@ -787,6 +1144,240 @@ mod tests {
         // No padding for IOU - uses all 48 bytes
     }
 
+    #[test]
+    fn test_xrp_whole_conversions() {
+        const TEN_XRP: Amount = Amount::xrp_from_whole(10);
+        assert_eq!(
+            TEN_XRP,
+            Amount::XRP {
+                num_drops: 10_000_000
+            }
+        );
+        assert_eq!(TEN_XRP.num_xrp(), Some(10));
+        assert_eq!(
+            Amount::XRP {
+                num_drops: 1_999_999
+            }
+            .num_xrp(),
+            Some(1)
+        );
+        assert_eq!(
+            Amount::XRP {
+                num_drops: -2_500_000
+            }
+            .num_xrp(),
+            Some(-2)
+        );
+
+        let mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
+        };
+        assert_eq!(mpt.num_xrp(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "XRP amount exceeds the total supply")]
+    fn test_xrp_from_whole_rejects_more_than_supply() {
+        let _ = Amount::xrp_from_whole(MAX_XRP + 1);
+    }
+
+    #[test]
+    fn test_checked_integer_conversions() {
+        use crate::host::error_codes::INVALID_PARAMS;
+
+        let mpt = |num_units, is_positive| Amount::MPT {
+            num_units,
+            is_positive,
+            mpt_id: MptId::from([0u8; 24]),
+        };
+        let iou = Amount::IOU {
+            amount: OpaqueFloat([0x80, 0, 0, 0, 0, 0, 0, 0]),
+            issuer: AccountID([0u8; 20]),
+            currency: Currency::from([0u8; 20]),
+        };
+
+        assert_eq!(
+            Amount::XRP { num_drops: -5 }.as_drops_checked().unwrap(),
+            -5
+        );
+        assert_eq!(
+            Amount::XRP {
+                num_drops: i64::MIN
+            }
+            .as_drops_checked()
+            .err()
+            .unwrap()
+            .code(),
+            INVALID_PARAMS
+        );
+        assert!(mpt(5, true).as_drops_checked().is_err());
+
+        assert_eq!(Amount::XRP { num_drops: 7 }.try_into_u64().unwrap(), 7);
+        assert_eq!(mpt(u64::MAX, true).try_into_u64().unwrap(), u64::MAX);
+        assert_eq!(mpt(0, false).try_into_u64().unwrap(), 0);
+        assert!(Amount::XRP { num_drops: -1 }.try_into_u64().is_err());
+        assert_eq!(
+            Amount::XRP {
+                num_drops: MAX_DROPS as i64
+            }
+            .try_into_u64()
+            .unwrap(),
+            MAX_DROPS
+        );
+        assert!(
+            Amount::XRP {
+                num_drops: MAX_DROPS as i64 + 1
+            }
+            .try_into_u64()
+            .is_err()
+        );
+        assert!(mpt(3, false).try_into_u64().is_err());
+        assert!(iou.try_into_u64().is_err());
+
+        assert_eq!(Amount::XRP { num_drops: -7 }.try_into_i64().unwrap(), -7);
+        let max_drops = MAX_DROPS as i64;
+        assert_eq!(
+            Amount::XRP {
+                num_drops: max_drops
+            }
+            .try_into_i64()
+            .unwrap(),
+            max_drops
+        );
+        assert!(
+            Amount::XRP {
+                num_drops: max_drops + 1
+            }
+            .try_into_i64()
+            .is_err()
+        );
+        assert_eq!(mpt(3, false).try_into_i64().unwrap(), -3);
+        assert_eq!(mpt(i64::MAX as u64, true).try_into_i64().unwrap(), i64::MAX);
+        assert!(mpt(i64::MAX as u64 + 1, true).try_into_i64().is_err());
+        assert!(iou.try_into_i64().is_err());
+    }
+
+    #[test]
+    fn test_same_asset() {
+        let iou = |currency: &[u8; 3], issuer: u8| {
+            let mut code = [0u8; 20];
+            code[12..15].copy_from_slice(currency);
+            Amount::IOU {
+                amount: OpaqueFloat([0x80, 0, 0, 0, 0, 0, 0, 0]),
+                issuer: AccountID([issuer; 20]),
+                currency: Currency::from(code),
+            }
+        };
+        let mpt = |sequence, is_positive| Amount::MPT {
+            num_units: 1,
+            is_positive,
+            mpt_id: MptId::new(sequence, AccountID([1; 20])),
+        };
+        let xrp = Amount::XRP { num_drops: -3 };
+
+        assert!(xrp.same_asset(&Amount::XRP { num_drops: 10 }));
+        assert!(iou(b"USD", 1).same_asset(&iou(b"USD", 1)));
+        assert!(!iou(b"USD", 1).same_asset(&iou(b"USD", 2)));
+        assert!(!iou(b"USD", 1).same_asset(&iou(b"EUR", 1)));
+        assert!(mpt(5, true).same_asset(&mpt(5, false)));
+        assert!(!mpt(5, true).same_asset(&mpt(6, true)));
+        assert!(!xrp.same_asset(&mpt(5, true)));
+        assert!(!iou(b"USD", 1).same_asset(&mpt(5, true)));
+
+        // An all-zero currency code is XRP; the letters "XRP" are not.
+        assert!(iou(&[0; 3], 9).same_asset(&xrp));
+        assert!(xrp.same_asset(&iou(&[0; 3], 1)));
+        assert!(!iou(b"XRP", 1).same_asset(&xrp));
+    }
+
+    #[test]
+    fn test_apply_transfer_rate_xrp_and_mpt() {
+        use crate::host::{
+            FLOAT_ROUNDING_MODES_DOWNWARD, FLOAT_ROUNDING_MODES_TO_NEAREST,
+            FLOAT_ROUNDING_MODES_TOWARDS_ZERO, FLOAT_ROUNDING_MODES_UPWARD,
+        };
+
+        let xrp = Amount::XRP { num_drops: 1_000 };
+        assert_eq!(
+            xrp.apply_transfer_rate(1_002_000_000, FLOAT_ROUNDING_MODES_TO_NEAREST)
+                .unwrap(),
+            Amount::XRP { num_drops: 1_002 }
+        );
+        assert_eq!(
+            xrp.apply_transfer_rate(0, FLOAT_ROUNDING_MODES_TO_NEAREST)
+                .unwrap(),
+            xrp
+        );
+
+        // 333 * 1.0015 = 333.4995
+        let mpt = Amount::MPT {
+            num_units: 333,
+            is_positive: true,
+            mpt_id: MptId::from([7u8; 24]),
+        };
+        let units = |mode| match mpt.apply_transfer_rate(1_001_500_000, mode).unwrap() {
+            Amount::MPT { num_units, .. } => num_units,
+            _ => unreachable!(),
+        };
+        assert_eq!(units(FLOAT_ROUNDING_MODES_TO_NEAREST), 333);
+        assert_eq!(units(FLOAT_ROUNDING_MODES_TOWARDS_ZERO), 333);
+        assert_eq!(units(FLOAT_ROUNDING_MODES_DOWNWARD), 333);
+        assert_eq!(units(FLOAT_ROUNDING_MODES_UPWARD), 334);
+
+        // -5 * 1.1 = -5.5: downward moves away from zero, half-even rounds to -6.
+        let negative = Amount::XRP { num_drops: -5 };
+        let drops = |mode| match negative.apply_transfer_rate(1_100_000_000, mode).unwrap() {
+            Amount::XRP { num_drops } => num_drops,
+            _ => unreachable!(),
+        };
+        assert_eq!(drops(FLOAT_ROUNDING_MODES_DOWNWARD), -6);
+        assert_eq!(drops(FLOAT_ROUNDING_MODES_UPWARD), -5);
+        assert_eq!(drops(FLOAT_ROUNDING_MODES_TO_NEAREST), -6);
+    }
+
+    #[test]
+    fn test_apply_transfer_rate_rejects_invalid_input() {
+        use crate::host::error_codes::INVALID_PARAMS;
+
+        let xrp = Amount::XRP { num_drops: 1 };
+        assert_eq!(
+            xrp.apply_transfer_rate(999_999_999, 0)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            xrp.apply_transfer_rate(2_000_000_001, 0)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            xrp.apply_transfer_rate(1_000_000_000, 9)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+
+        let huge = Amount::MPT {
+            num_units: u64::MAX,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
+        };
+        assert_eq!(
+            huge.apply_transfer_rate(1_500_000_000, 0)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+    }
+
     #[test]
     fn test_round_trip_edge_cases() {
         // Test XRP with maximum value that fits in 57 bits
@@ -1,8 +1,11 @@
 use crate::current_tx::CurrentTxFieldGetter;
 use crate::host::field_helpers::{get_variable_size_field, get_variable_size_field_optional};
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field};
+use crate::host::{
+    Error, Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field,
+};
 use crate::objects::LedgerObjectFieldGetter;
 use crate::sfield::SField;
+use core::ops::Range;
 
 /// Default blob size for general use (memos, etc.)
 pub const DEFAULT_BLOB_SIZE: usize = 1024;
@@ -11,6 +14,9 @@ pub const DEFAULT_BLOB_SIZE: usize = 1024;
 /// (see `maxSerializedCondition` in xrpld source code), so we do the same here.
 pub const CONDITION_BLOB_SIZE: usize = 128;
 
+/// The maximum number of bytes in a CredentialType (see `maxCredentialTypeLength` in xrpld source code).
+pub const CREDENTIAL_TYPE_BLOB_SIZE: usize = 64;
+
 pub const DOMAIN_BLOB_SIZE: usize = 256;
 
 /// The maximum number of bytes in a Fulfillment. Theoretically, the crypto-condition format allows for much larger
@@ -110,6 +116,136 @@ impl<const N: usize> Blob<N> {
     pub fn as_slice(&self) -> &[u8] {
         &self.data[..self.len]
     }
+
+    /// Compares the blob's contents against `expected` in constant time.
+    ///
+    /// Use this rather than `==` when checking a fulfillment, preimage or other secret supplied by
+    /// the transaction. See [`crypto::ct_eq`](crate::crypto::ct_eq).
+    #[inline]
+    pub fn ct_eq(&self, expected: &[u8]) -> bool {
+        crate::crypto::ct_eq(self.as_slice(), expected)
+    }
+
+    /// Returns true if the blob's contents are exactly `other`.
+    #[inline]
+    pub fn eq_slice(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+
+    /// Returns true if the blob's contents begin with `prefix`.
+    #[inline]
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.as_slice().starts_with(prefix)
+    }
+
+    /// Returns the offset of the first occurrence of `needle` in the blob's contents.
+    ///
+    /// An empty `needle` is found at offset 0.
+    #[inline]
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        find_bytes(self.as_slice(), needle)
+    }
+
+    /// Copies the bytes in `range` of the blob's contents to the start of `out`, returning how
+    /// many were copied.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `range` is reversed or ends past [`len`](Self::len), or `out` is
+    ///   shorter than the range
+    #[inline]
+    pub fn copy_into(&self, range: Range<usize>, out: &mut [u8]) -> Result<usize> {
+        copy_range(self.as_slice(), range, out)
+    }
+
+    /// Returns the blob's contents as text, for fields such as `MemoData`, `Domain` or `URI`
+    /// that usually hold it.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If the contents are not valid UTF-8
+    pub fn as_utf8(&self) -> Result<&str> {
+        match core::str::from_utf8(self.as_slice()) {
+            core::result::Result::Ok(text) => Result::Ok(text),
+            core::result::Result::Err(_) => Result::Err(Error::InvalidDecoding),
+        }
+    }
+
+    /// Returns true if every byte of the blob's contents is ASCII.
+    #[inline]
+    pub fn is_ascii(&self) -> bool {
+        self.as_slice().is_ascii()
+    }
+
+    /// Returns true if the blob's contents equal `expected` once ASCII letters in both are
+    /// lowercased, so a memo of `b"release"` or `b"Release"` matches `b"RELEASE"`.
+    ///
+    /// Bytes outside ASCII must match exactly.
+    #[inline]
+    pub fn as_ascii_lowercase_eq(&self, expected: &[u8]) -> bool {
+        self.as_slice().eq_ignore_ascii_case(expected)
+    }
+
+    /// Creates a blob from hex text, e.g. a memo typed as `52454C45415345` by a wallet that
+    /// hex-encodes its input. Either case of digit is accepted.
+    ///
+    /// ```
+    /// use xrpl_common_stdlib::types::blob::Blob;
+    ///
+    /// let command: Blob<16> = Blob::from_hex(b"52454c45415345").unwrap();
+    /// assert_eq!(command.as_utf8().unwrap(), "RELEASE");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If `hex` has an odd length or a character that is not a hex digit
+    /// * `BufferTooSmall` - If the decoded bytes do not fit in `N`
+    pub fn from_hex(hex: &[u8]) -> Result<Self> {
+        if hex.len() % 2 != 0 {
+            return Result::Err(Error::InvalidDecoding);
+        }
+        if hex.len() / 2 > N {
+            return Result::Err(Error::BufferTooSmall);
+        }
+        let mut blob = Self::new();
+        for (byte, pair) in blob.data.iter_mut().zip(hex.chunks_exact(2)) {
+            match (
+                crate::hex_char_to_nibble(pair[0]),
+                crate::hex_char_to_nibble(pair[1]),
+            ) {
+                (Some(high), Some(low)) => *byte = (high << 4) | low,
+                _ => return Result::Err(Error::InvalidDecoding),
+            }
+        }
+        blob.len = hex.len() / 2;
+        Result::Ok(blob)
+    }
+
+    /// Decodes the blob's contents as hex text; see [`from_hex`](Self::from_hex).
+    pub fn decode_hex(&self) -> Result<Self> {
+        Self::from_hex(self.as_slice())
+    }
+}
+
+/// Returns the offset of the first occurrence of `needle` in `haystack`.
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Copies `bytes[range]` to the start of `out`, failing instead of panicking on a bad range.
+pub(crate) fn copy_range(bytes: &[u8], range: Range<usize>, out: &mut [u8]) -> Result<usize> {
+    match (bytes.get(range), out.len()) {
+        (Some(src), out_len) if src.len() <= out_len => {
+            out[..src.len()].copy_from_slice(src);
+            Result::Ok(src.len())
+        }
+        _ => Result::Err(Error::InvalidParams),
+    }
 }
 
 impl<const N: usize> From<[u8; N]> for Blob<N> {
@@ -133,6 +269,9 @@ pub type StandardBlob = Blob<DEFAULT_BLOB_SIZE>;
 /// Type alias for 128-byte blob (for Condition fields)
 pub type ConditionBlob = Blob<CONDITION_BLOB_SIZE>;
 
+/// Type alias for 64-byte blob (for CredentialType fields)
+pub type CredentialTypeBlob = Blob<CREDENTIAL_TYPE_BLOB_SIZE>;
+
 /// Type alias for 256-byte blob (for Fulfillment fields)
 pub type FulfillmentBlob = Blob<FULFILLMENT_BLOB_SIZE>;
 
@@ -296,6 +435,15 @@ mod tests {
         assert_eq!(blob.as_slice(), &[1, 2, 3, 4, 5]);
     }
 
+    #[test]
+    fn test_ct_eq_ignores_unused_capacity() {
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3]);
+
+        assert!(blob.ct_eq(&[1, 2, 3]));
+        assert!(!blob.ct_eq(&[1, 2, 4]));
+        assert!(!blob.ct_eq(&[1, 2, 3, 0]));
+    }
+
     #[test]
     fn test_from_slice_with_empty_slice() {
         let data: &[u8] = &[];
@@ -464,4 +612,82 @@ mod tests {
         assert_eq!(blob.capacity(), WASM_BLOB_SIZE);
         assert_eq!(blob.capacity(), 4096);
     }
+
+    #[test]
+    fn test_search_helpers() {
+        let memo: Blob<16> = Blob::from_slice(b"cmd:release:42");
+
+        assert!(memo.eq_slice(b"cmd:release:42"));
+        assert!(!memo.eq_slice(b"cmd:release:4"));
+        assert!(memo.starts_with(b"cmd:"));
+        assert!(!memo.starts_with(b"release"));
+        assert_eq!(memo.find(b":"), Some(3));
+        assert_eq!(memo.find(b"42"), Some(12));
+        assert_eq!(memo.find(b""), Some(0));
+        assert_eq!(memo.find(b"43"), None);
+        // Unused capacity is zeroed but never matched.
+        assert_eq!(memo.find(&[0]), None);
+    }
+
+    #[test]
+    fn test_copy_into_checks_bounds() {
+        use crate::host::error_codes::INVALID_PARAMS;
+
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3, 4]);
+        let mut out = [0u8; 3];
+        assert_eq!(blob.copy_into(1..4, &mut out).unwrap(), 3);
+        assert_eq!(out, [2, 3, 4]);
+        assert_eq!(blob.copy_into(4..4, &mut out).unwrap(), 0);
+
+        // Past len (though within capacity), reversed, and larger than `out`
+        #[allow(clippy::reversed_empty_ranges)]
+        for range in [2..5, 3..2, 0..4] {
+            let result = blob.copy_into(range, &mut out);
+            assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+        }
+    }
+
+    #[test]
+    fn test_as_utf8() {
+        let text: Blob<16> = Blob::from_slice("café".as_bytes());
+        assert_eq!(text.as_utf8().unwrap(), "café");
+        assert!(!text.is_ascii());
+
+        let invalid: Blob<4> = Blob::from_slice(&[0x66, 0xFF]);
+        assert!(matches!(
+            invalid.as_utf8(),
+            Result::Err(Error::InvalidDecoding)
+        ));
+    }
+
+    #[test]
+    fn test_as_ascii_lowercase_eq() {
+        let memo: Blob<16> = Blob::from_slice(b"Release");
+        assert!(memo.is_ascii());
+        assert!(memo.as_ascii_lowercase_eq(b"RELEASE"));
+        assert!(memo.as_ascii_lowercase_eq(b"release"));
+        assert!(!memo.as_ascii_lowercase_eq(b"RELEASED"));
+        assert!(!memo.as_ascii_lowercase_eq(b"CANCEL"));
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let memo: Blob<16> = Blob::from_slice(b"43414E43454C");
+        let decoded = memo.decode_hex().unwrap();
+        assert_eq!(decoded.as_slice(), b"CANCEL");
+        assert_eq!(Blob::<4>::from_hex(b"").unwrap().len(), 0);
+
+        assert!(matches!(
+            Blob::<4>::from_hex(b"ABC"),
+            Result::Err(Error::InvalidDecoding)
+        ));
+        assert!(matches!(
+            Blob::<4>::from_hex(b"0G"),
+            Result::Err(Error::InvalidDecoding)
+        ));
+        assert!(matches!(
+            Blob::<2>::from_hex(b"000000"),
+            Result::Err(Error::BufferTooSmall)
+        ));
+    }
 }
@@ -1,21 +1,24 @@
-use crate::types::account_id::AccountID;
-
-/// The 20 bytes of Account Zero (rrrrrrrrrrrrrrrrrrrrrhoLvTp)
-pub const ACCOUNT_ZERO: AccountID = AccountID([0u8; 20]);
-
-/// The 20 bytes of Account One (rrrrrrrrrrrrrrrrrrrrBZbvji)
-pub const ACCOUNT_ONE: AccountID = {
-    // Create a mutable array *only* during compile-time evaluation
-    let mut arr = [0x00; 20];
-    arr[19] = 0x01;
-    // The final value of the block is the initialized array
-    AccountID(arr)
-};
+// Defined next to `AccountID`; re-exported here, where they have always been available.
+pub use crate::types::account_id::{ACCOUNT_ONE, ACCOUNT_ZERO};
 
 /// Indivisible unit of XRP
 pub const ONE_DROP: u64 = 1;
 
+/// Number of drops in one XRP
+pub const DROPS_PER_XRP: u64 = 1_000_000;
+
 /// 100 billion XRP
 pub const MAX_XRP: u64 = 100_000_000_000u64;
 /// Maximum possible drops of XRP
-pub const MAX_DROPS: u64 = MAX_XRP * 1_000_000;
+pub const MAX_DROPS: u64 = MAX_XRP * DROPS_PER_XRP;
+
+/// Converts whole XRP to drops. Overflows (panicking in debug and const contexts) only for
+/// values far above [`MAX_XRP`].
+pub const fn xrp_to_drops(xrp: u64) -> u64 {
+    xrp * DROPS_PER_XRP
+}
+
+/// Converts drops to whole XRP, discarding any fractional part.
+pub const fn drops_to_xrp(drops: u64) -> u64 {
+    drops / DROPS_PER_XRP
+}
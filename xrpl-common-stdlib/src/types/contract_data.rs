@@ -1,3 +1,7 @@
+use crate::host::Result;
+use crate::types::blob::{copy_range, find_bytes};
+use core::ops::Range;
+
 pub const XRPL_CONTRACT_DATA_SIZE: usize = 4096; //TODO size??
 
 /// A 4096-byte buffer for contract data on the XRP Ledger.
@@ -19,3 +23,92 @@ pub struct ContractData {
     /// The actual length of this contract data, if less than data.len()
     pub len: usize,
 }
+
+impl Default for ContractData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContractData {
+    /// Creates empty contract data.
+    pub const fn new() -> Self {
+        ContractData {
+            data: [0; XRPL_CONTRACT_DATA_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Returns a [`DataCursor`](crate::types::data_cursor::DataCursor) at the start of the data,
+    /// for writing.
+    pub fn cursor(&mut self) -> crate::types::data_cursor::DataCursor<'_> {
+        crate::types::data_cursor::DataCursor::new(self)
+    }
+
+    /// Returns a [`DataReader`](crate::types::data_cursor::DataReader) at the start of the data,
+    /// for reading only.
+    pub fn reader(&self) -> crate::types::data_cursor::DataReader<'_> {
+        crate::types::data_cursor::DataReader::new(self)
+    }
+
+    /// Returns the data actually stored (not including unused capacity).
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len.min(XRPL_CONTRACT_DATA_SIZE)]
+    }
+
+    /// Returns true if the stored data is exactly `other`.
+    #[inline]
+    pub fn eq_slice(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+
+    /// Returns true if the stored data begins with `prefix`, e.g. a format version tag.
+    #[inline]
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.as_slice().starts_with(prefix)
+    }
+
+    /// Returns the offset of the first occurrence of `needle` in the stored data.
+    ///
+    /// An empty `needle` is found at offset 0.
+    #[inline]
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        find_bytes(self.as_slice(), needle)
+    }
+
+    /// Copies the bytes in `range` of the stored data to the start of `out`, returning how many
+    /// were copied.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `range` is reversed or ends past `len`, or `out` is shorter than the
+    ///   range
+    #[inline]
+    pub fn copy_into(&self, range: Range<usize>, out: &mut [u8]) -> Result<usize> {
+        copy_range(self.as_slice(), range, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helpers_only_see_stored_bytes() {
+        let mut data = ContractData {
+            data: [0; XRPL_CONTRACT_DATA_SIZE],
+            len: 6,
+        };
+        data.data[..6].copy_from_slice(b"v1\x00abc");
+
+        assert_eq!(data.as_slice().len(), 6);
+        assert!(data.starts_with(b"v1"));
+        assert_eq!(data.find(b"abc"), Some(3));
+        assert_eq!(data.find(&[0, 0]), None);
+
+        let mut out = [0u8; 3];
+        assert_eq!(data.copy_into(3..6, &mut out).unwrap(), 3);
+        assert!(data.copy_into(3..7, &mut out).is_err());
+    }
+}
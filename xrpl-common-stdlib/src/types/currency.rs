@@ -17,9 +17,10 @@ pub const STANDARD_CURRENCY_SIZE: usize = 3; // For standard currencies like USD
 /// ## Derived Traits
 ///
 /// - `Copy`: Efficient for this 20-byte struct, enabling implicit copying
-/// - `PartialEq, Eq`: Enable comparisons and use in hash-based collections
+/// - `PartialEq, Eq, Hash`: Enable comparisons and use in hash-based collections
+/// - `PartialOrd, Ord`: Byte-wise ordering, for sorted collections and binary search
 /// - `Debug, Clone`: Standard traits for development and consistency
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C)]
 pub struct Currency(pub [u8; CURRENCY_SIZE]);
 
@@ -145,6 +146,24 @@ mod tests {
         assert_ne!(code1, code3);
     }
 
+    #[test]
+    fn test_currency_ordering_and_hash_derives() {
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |c: &Currency| {
+            let mut hasher = DefaultHasher::new();
+            c.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let usd = Currency::from(*b"\0\0\0\0\0\0\0\0\0\0\0\0USD\0\0\0\0\0");
+        let eur = Currency::from(*b"\0\0\0\0\0\0\0\0\0\0\0\0EUR\0\0\0\0\0");
+        assert!(eur < usd);
+        assert_eq!(hash(&usd), hash(&Currency::from(*usd.as_bytes())));
+        assert_ne!(hash(&usd), hash(&eur));
+    }
+
     #[test]
     fn test_currency_from_standard_bytes() {
         // Create a 3-byte array representing "USD"
@@ -0,0 +1,454 @@
+//! Typed, bounds-checked reading and writing of [`ContractData`].
+//!
+//! Contracts that keep state in the `Data` field usually lay it out as a sequence of
+//! fixed-size values. A [`DataCursor`] (for writing) or a [`DataReader`] (for reading only) tracks
+//! the position for them, so a layout reads as a list of calls instead of a list of offsets:
+//!
+//! ```
+//! use xrpl_common_stdlib::types::contract_data::ContractData;
+//! use xrpl_common_stdlib::types::data_cursor::DataCursor;
+//! use xrpl_common_stdlib::types::uint::Hash256;
+//!
+//! let mut data = ContractData::new();
+//! let mut writer = DataCursor::new(&mut data);
+//! writer.write_hash256(&Hash256::from([0xAB; 32])).unwrap();
+//! writer.write_u32_le(780_000_000).unwrap();
+//! assert_eq!(data.len, 36);
+//!
+//! let mut reader = data.reader();
+//! let counterpart = reader.read_hash256().unwrap();
+//! let deadline = reader.read_u32_le().unwrap();
+//! assert_eq!((counterpart, deadline), (Hash256::from([0xAB; 32]), 780_000_000));
+//! ```
+//!
+//! # Sections
+//!
+//! State that a module keeps in `Data` implements [`DataSection`], which reads and writes it at
+//! the cursor's position. Sections are stored one after another, and a tuple of sections is a
+//! section too, so a contract combines the state of several modules by choosing the order:
+//!
+//! ```
+//! use xrpl_common_stdlib::types::contract_data::ContractData;
+//! use xrpl_common_stdlib::types::data_cursor::{DataCursor, DataSection};
+//!
+//! #[derive(Debug, Default, PartialEq)]
+//! struct Counter(u32);
+//!
+//! impl DataSection for Counter {
+//!     fn read_section(cursor: &mut DataCursor) -> xrpl_common_stdlib::host::Result<Self> {
+//!         cursor.read_u32_le().map(Counter)
+//!     }
+//!
+//!     fn write_section(&self, cursor: &mut DataCursor) -> xrpl_common_stdlib::host::Result<()> {
+//!         cursor.write_u32_le(self.0)
+//!     }
+//! }
+//!
+//! let mut data = ContractData::new();
+//! DataCursor::new(&mut data).write_section(&(Counter(1), Counter(2))).unwrap();
+//!
+//! let mut reader = DataCursor::new(&mut data);
+//! let state: (Counter, Counter, Counter) = reader.read_section().unwrap();
+//! assert_eq!(state, (Counter(1), Counter(2), Counter(0)));
+//! ```
+//!
+//! A section past the end of the stored data reads as its `Default`, so new sections can be
+//! appended to a layout without migrating data written by an earlier version of the contract.
+//! In an escrow, `EscrowFinishContext::load_state` and `save_state` read and write the whole
+//! `Data` field this way.
+
+use crate::host::{Error, Result};
+use crate::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::types::contract_data::{ContractData, XRPL_CONTRACT_DATA_SIZE};
+use crate::types::uint::Hash256;
+
+/// A read/write position in a [`ContractData`].
+///
+/// Reads are bounded by the data's `len`. Writes overwrite from the current position and extend
+/// `len` when they go past it, so writing from a fresh cursor over existing data replaces its
+/// prefix and keeps the rest.
+#[derive(Debug)]
+pub struct DataCursor<'a> {
+    data: &'a mut ContractData,
+    pos: usize,
+}
+
+impl<'a> DataCursor<'a> {
+    /// Creates a cursor at the start of `data`.
+    pub fn new(data: &'a mut ContractData) -> Self {
+        DataCursor { data, pos: 0 }
+    }
+
+    /// The current offset from the start of the data.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of stored bytes after the current position.
+    pub fn remaining(&self) -> usize {
+        self.data.len.saturating_sub(self.pos)
+    }
+
+    /// Moves to offset `pos`.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `pos` is past the end of the stored data
+    pub fn seek(&mut self, pos: usize) -> Result<()> {
+        if pos > self.data.len {
+            return Result::Err(Error::InvalidParams);
+        }
+        self.pos = pos;
+        Result::Ok(())
+    }
+
+    /// Drops everything after the current position, e.g. after rewriting a shorter record.
+    pub fn truncate(&mut self) {
+        self.data.len = self.pos;
+    }
+
+    /// Writes `bytes` at the current position and advances past them.
+    ///
+    /// # Errors
+    ///
+    /// * `DataFieldTooLarge` - If the bytes do not fit in [`XRPL_CONTRACT_DATA_SIZE`]; nothing is
+    ///   written in that case
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        let end = self.pos + bytes.len();
+        if end > XRPL_CONTRACT_DATA_SIZE {
+            return Result::Err(Error::DataFieldTooLarge);
+        }
+        self.data.data[self.pos..end].copy_from_slice(bytes);
+        self.pos = end;
+        self.data.len = self.data.len.max(end);
+        Result::Ok(())
+    }
+
+    /// Writes one byte. See [`write_bytes`](Self::write_bytes).
+    pub fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.write_bytes(&[value])
+    }
+
+    /// Writes `value` as 4 little-endian bytes. See [`write_bytes`](Self::write_bytes).
+    pub fn write_u32_le(&mut self, value: u32) -> Result<()> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes `value` as 8 little-endian bytes. See [`write_bytes`](Self::write_bytes).
+    pub fn write_u64_le(&mut self, value: u64) -> Result<()> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    /// Writes the 32 bytes of `value`. See [`write_bytes`](Self::write_bytes).
+    pub fn write_hash256(&mut self, value: &Hash256) -> Result<()> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    /// Writes the 20 bytes of `value`. See [`write_bytes`](Self::write_bytes).
+    pub fn write_account_id(&mut self, value: &AccountID) -> Result<()> {
+        self.write_bytes(&value.0)
+    }
+
+    /// Reads the next `N` bytes and advances past them.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If fewer than `N` stored bytes remain; the position is unchanged
+    pub fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        read_at(self.data, &mut self.pos)
+    }
+
+    /// Reads one byte. See [`read_bytes`](Self::read_bytes).
+    pub fn read_u8(&mut self) -> Result<u8> {
+        self.read_bytes::<1>().map(|[value]| value)
+    }
+
+    /// Reads 4 little-endian bytes. See [`read_bytes`](Self::read_bytes).
+    pub fn read_u32_le(&mut self) -> Result<u32> {
+        self.read_bytes().map(u32::from_le_bytes)
+    }
+
+    /// Reads 8 little-endian bytes. See [`read_bytes`](Self::read_bytes).
+    pub fn read_u64_le(&mut self) -> Result<u64> {
+        self.read_bytes().map(u64::from_le_bytes)
+    }
+
+    /// Reads a 32-byte hash. See [`read_bytes`](Self::read_bytes).
+    pub fn read_hash256(&mut self) -> Result<Hash256> {
+        self.read_bytes::<32>().map(Hash256::from)
+    }
+
+    /// Reads a 20-byte account ID. See [`read_bytes`](Self::read_bytes).
+    pub fn read_account_id(&mut self) -> Result<AccountID> {
+        self.read_bytes::<ACCOUNT_ID_SIZE>().map(AccountID)
+    }
+
+    /// Reads a [`DataSection`] at the current position, or returns its `Default` if no stored
+    /// bytes remain.
+    pub fn read_section<S: DataSection>(&mut self) -> Result<S> {
+        if self.remaining() == 0 {
+            return Result::Ok(S::default());
+        }
+        S::read_section(self)
+    }
+
+    /// Writes a [`DataSection`] at the current position.
+    pub fn write_section<S: DataSection>(&mut self, section: &S) -> Result<()> {
+        section.write_section(self)
+    }
+}
+
+/// A read-only position in a [`ContractData`].
+///
+/// Reads the same layout as [`DataCursor`] through a shared borrow, for code that only inspects
+/// stored data. Reads are bounded by the data's `len`.
+#[derive(Debug, Clone)]
+pub struct DataReader<'a> {
+    data: &'a ContractData,
+    pos: usize,
+}
+
+impl<'a> DataReader<'a> {
+    /// Creates a reader at the start of `data`.
+    pub fn new(data: &'a ContractData) -> Self {
+        DataReader { data, pos: 0 }
+    }
+
+    /// The current offset from the start of the data.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of stored bytes after the current position.
+    pub fn remaining(&self) -> usize {
+        self.data.len.saturating_sub(self.pos)
+    }
+
+    /// Moves to offset `pos`.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `pos` is past the end of the stored data
+    pub fn seek(&mut self, pos: usize) -> Result<()> {
+        if pos > self.data.len {
+            return Result::Err(Error::InvalidParams);
+        }
+        self.pos = pos;
+        Result::Ok(())
+    }
+
+    /// Reads the next `N` bytes and advances past them.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If fewer than `N` stored bytes remain; the position is unchanged
+    pub fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        read_at(self.data, &mut self.pos)
+    }
+
+    /// Reads one byte. See [`read_bytes`](Self::read_bytes).
+    pub fn read_u8(&mut self) -> Result<u8> {
+        self.read_bytes::<1>().map(|[value]| value)
+    }
+
+    /// Reads 4 little-endian bytes. See [`read_bytes`](Self::read_bytes).
+    pub fn read_u32_le(&mut self) -> Result<u32> {
+        self.read_bytes().map(u32::from_le_bytes)
+    }
+
+    /// Reads 8 little-endian bytes. See [`read_bytes`](Self::read_bytes).
+    pub fn read_u64_le(&mut self) -> Result<u64> {
+        self.read_bytes().map(u64::from_le_bytes)
+    }
+
+    /// Reads a 32-byte hash. See [`read_bytes`](Self::read_bytes).
+    pub fn read_hash256(&mut self) -> Result<Hash256> {
+        self.read_bytes::<32>().map(Hash256::from)
+    }
+
+    /// Reads a 20-byte account ID. See [`read_bytes`](Self::read_bytes).
+    pub fn read_account_id(&mut self) -> Result<AccountID> {
+        self.read_bytes::<ACCOUNT_ID_SIZE>().map(AccountID)
+    }
+}
+
+fn read_at<const N: usize>(data: &ContractData, pos: &mut usize) -> Result<[u8; N]> {
+    if data.len.saturating_sub(*pos) < N {
+        return Result::Err(Error::InvalidDecoding);
+    }
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&data.data[*pos..*pos + N]);
+    *pos += N;
+    Result::Ok(bytes)
+}
+
+/// Contract state that occupies one section of the `Data` field.
+///
+/// See the [module docs](self#sections) for how sections combine.
+pub trait DataSection: Default {
+    /// Reads the section at the cursor's position.
+    ///
+    /// Only called when stored bytes remain: [`DataCursor::read_section`] returns the `Default`
+    /// of a section past the end of the data.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If the stored bytes are not a valid section
+    fn read_section(cursor: &mut DataCursor) -> Result<Self>;
+
+    /// Writes the section at the cursor's position.
+    ///
+    /// # Errors
+    ///
+    /// * `DataFieldTooLarge` - If the section does not fit in [`XRPL_CONTRACT_DATA_SIZE`]
+    fn write_section(&self, cursor: &mut DataCursor) -> Result<()>;
+}
+
+macro_rules! tuple_data_section {
+    ($($name:ident),+) => {
+        impl<$($name: DataSection),+> DataSection for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn read_section(cursor: &mut DataCursor) -> Result<Self> {
+                $(
+                    let $name = match cursor.read_section() {
+                        Result::Ok(section) => section,
+                        Result::Err(e) => return Result::Err(e),
+                    };
+                )+
+                Result::Ok(($($name,)+))
+            }
+
+            #[allow(non_snake_case)]
+            fn write_section(&self, cursor: &mut DataCursor) -> Result<()> {
+                let ($($name,)+) = self;
+                $(
+                    if let Result::Err(e) = cursor.write_section($name) {
+                        return Result::Err(e);
+                    }
+                )+
+                Result::Ok(())
+            }
+        }
+    };
+}
+
+tuple_data_section!(A, B);
+tuple_data_section!(A, B, C);
+tuple_data_section!(A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{DATA_FIELD_TOO_LARGE, INVALID_DECODING, INVALID_PARAMS};
+
+    #[test]
+    fn test_round_trip_tracks_position() {
+        let mut data = ContractData::new();
+        let mut cursor = DataCursor::new(&mut data);
+        cursor.write_u8(1).unwrap();
+        cursor.write_account_id(&AccountID([7; 20])).unwrap();
+        cursor.write_u64_le(u64::MAX - 1).unwrap();
+        assert_eq!(cursor.position(), 29);
+
+        cursor.seek(0).unwrap();
+        assert_eq!(cursor.remaining(), 29);
+        assert_eq!(cursor.read_u8().unwrap(), 1);
+        assert_eq!(cursor.read_account_id().unwrap(), AccountID([7; 20]));
+        assert_eq!(cursor.read_u64_le().unwrap(), u64::MAX - 1);
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[test]
+    fn test_overwrite_keeps_tail_until_truncated() {
+        let mut data = ContractData::new();
+        let mut cursor = DataCursor::new(&mut data);
+        cursor.write_u32_le(1).unwrap();
+        cursor.write_u32_le(2).unwrap();
+
+        let mut cursor = DataCursor::new(&mut data);
+        cursor.write_u32_le(3).unwrap();
+        assert_eq!(cursor.read_u32_le().unwrap(), 2);
+        cursor.seek(4).unwrap();
+        cursor.truncate();
+        assert_eq!(data.as_slice(), &3u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_bounds_errors_leave_cursor_unchanged() {
+        let mut data = ContractData::new();
+        let mut cursor = DataCursor::new(&mut data);
+        cursor
+            .write_bytes(&[0; XRPL_CONTRACT_DATA_SIZE - 2])
+            .unwrap();
+
+        let result = cursor.write_u32_le(1);
+        assert_eq!(result.err().unwrap().code(), DATA_FIELD_TOO_LARGE);
+        assert_eq!(cursor.position(), XRPL_CONTRACT_DATA_SIZE - 2);
+
+        let result = cursor.seek(XRPL_CONTRACT_DATA_SIZE);
+        assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+
+        cursor.seek(XRPL_CONTRACT_DATA_SIZE - 3).unwrap();
+        let result = cursor.read_u32_le();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+        assert_eq!(cursor.read_u8().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_reader_reads_what_the_cursor_wrote() {
+        let mut data = ContractData::new();
+        let mut cursor = DataCursor::new(&mut data);
+        cursor.write_hash256(&Hash256::from([3; 32])).unwrap();
+        cursor.write_u32_le(42).unwrap();
+
+        let mut reader = DataReader::new(&data);
+        assert_eq!(reader.read_hash256().unwrap(), Hash256::from([3; 32]));
+        assert_eq!(reader.read_u32_le().unwrap(), 42);
+        assert_eq!(reader.remaining(), 0);
+
+        let result = reader.read_u8();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+        reader.seek(32).unwrap();
+        assert_eq!(reader.read_u32_le().unwrap(), 42);
+        let result = reader.seek(37);
+        assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Flag(u8);
+
+    impl DataSection for Flag {
+        fn read_section(cursor: &mut DataCursor) -> Result<Self> {
+            cursor.read_u8().map(Flag)
+        }
+
+        fn write_section(&self, cursor: &mut DataCursor) -> Result<()> {
+            cursor.write_u8(self.0)
+        }
+    }
+
+    #[test]
+    fn test_tuple_sections_are_stored_in_order() {
+        let mut data = ContractData::new();
+        let state = (Flag(1), Flag(2), Flag(3), Flag(4));
+        DataCursor::new(&mut data).write_section(&state).unwrap();
+        assert_eq!(data.as_slice(), &[1, 2, 3, 4]);
+
+        let mut cursor = DataCursor::new(&mut data);
+        assert_eq!(
+            cursor.read_section::<(Flag, Flag, Flag, Flag)>().unwrap(),
+            state
+        );
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[test]
+    fn test_sections_past_the_end_are_default() {
+        let mut data = ContractData::new();
+        DataCursor::new(&mut data).write_section(&Flag(7)).unwrap();
+
+        let mut cursor = DataCursor::new(&mut data);
+        let state: (Flag, (Flag, Flag)) = cursor.read_section().unwrap();
+        assert_eq!(state, (Flag(7), (Flag(0), Flag(0))));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::host::{Error, Result};
+
+/// Identifies one of an account's price oracles (the `OracleDocumentID` of `OracleSet`).
+///
+/// An owner can publish several oracles, told apart by this number. The ledger stores it as a
+/// `UInt32`, so every `u32` is a valid ID; [`from_i64`](Self::from_i64) covers values that arrive
+/// as a wider or signed integer and rejects those that would otherwise be silently truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DocumentId(u32);
+
+impl DocumentId {
+    /// Creates a document ID. Usable in `const` items.
+    pub const fn new(id: u32) -> Self {
+        DocumentId(id)
+    }
+
+    /// Converts a signed integer, rejecting values outside `0..=u32::MAX` with `InvalidParams`.
+    pub fn from_i64(id: i64) -> Result<Self> {
+        match u32::try_from(id) {
+            Ok(id) => Result::Ok(DocumentId(id)),
+            Err(_) => Result::Err(Error::InvalidParams),
+        }
+    }
+
+    /// Returns the numeric ID.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the ID in the byte order the host's `oracle_keylet` expects.
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+}
+
+impl From<u32> for DocumentId {
+    fn from(id: u32) -> Self {
+        DocumentId(id)
+    }
+}
+
+impl From<DocumentId> for u32 {
+    fn from(id: DocumentId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_i64_rejects_values_that_do_not_fit() {
+        assert_eq!(DocumentId::from_i64(0).unwrap(), DocumentId::new(0));
+        assert_eq!(
+            DocumentId::from_i64(u32::MAX as i64).unwrap().get(),
+            u32::MAX
+        );
+        for invalid in [-1, i32::MIN as i64, u32::MAX as i64 + 1, i64::MAX] {
+            let result = DocumentId::from_i64(invalid);
+            assert_eq!(
+                result.err().unwrap().code(),
+                Error::InvalidParams.code(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_le_bytes() {
+        assert_eq!(DocumentId::new(0x0102_0304).to_le_bytes(), [4, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,137 @@
+//! Gas, the unit of the `ComputationAllowance` field.
+//!
+//! Every programmability trigger (EscrowFinish today, contract calls later) carries a
+//! `ComputationAllowance`, and the host charges gas against it for each WASM instruction and host
+//! call. Wrapping the value in [`Gas`] keeps it from being mixed up with the other `u32` fields of
+//! a transaction (sequence numbers, flags, tags).
+
+use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// An amount of gas.
+///
+/// Arithmetic follows `u32`: `+`, `-` and `*` panic on overflow in debug builds, and the
+/// `checked_*`/`saturating_*` methods are available where overflow is expected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Gas(pub u32);
+
+impl Gas {
+    /// No gas.
+    pub const ZERO: Gas = Gas(0);
+
+    /// Wraps a raw gas amount.
+    pub const fn new(amount: u32) -> Self {
+        Gas(amount)
+    }
+
+    /// Returns the raw gas amount.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Adds `rhs`, or returns `None` on overflow.
+    pub const fn checked_add(self, rhs: Gas) -> Option<Gas> {
+        match self.0.checked_add(rhs.0) {
+            Some(amount) => Some(Gas(amount)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs`, or returns `None` if `rhs` is larger.
+    pub const fn checked_sub(self, rhs: Gas) -> Option<Gas> {
+        match self.0.checked_sub(rhs.0) {
+            Some(amount) => Some(Gas(amount)),
+            None => None,
+        }
+    }
+
+    /// Adds `rhs`, stopping at `u32::MAX`.
+    pub const fn saturating_add(self, rhs: Gas) -> Gas {
+        Gas(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs`, stopping at zero.
+    pub const fn saturating_sub(self, rhs: Gas) -> Gas {
+        Gas(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl From<u32> for Gas {
+    fn from(amount: u32) -> Self {
+        Gas(amount)
+    }
+}
+
+impl From<Gas> for u32 {
+    fn from(gas: Gas) -> Self {
+        gas.0
+    }
+}
+
+impl Add for Gas {
+    type Output = Gas;
+
+    fn add(self, rhs: Gas) -> Gas {
+        Gas(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Gas {
+    fn add_assign(&mut self, rhs: Gas) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Gas {
+    type Output = Gas;
+
+    fn sub(self, rhs: Gas) -> Gas {
+        Gas(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Gas {
+    fn sub_assign(&mut self, rhs: Gas) {
+        self.0 -= rhs.0;
+    }
+}
+
+/// Scales a per-call cost by a number of calls.
+impl Mul<u32> for Gas {
+    type Output = Gas;
+
+    fn mul(self, rhs: u32) -> Gas {
+        Gas(self.0 * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut gas = Gas::new(100) + Gas(50) - Gas(30);
+        assert_eq!(gas, Gas(120));
+
+        gas += Gas(10);
+        gas -= Gas(20);
+        assert_eq!(gas * 3, Gas(330));
+        assert_eq!(u32::from(gas), 110);
+    }
+
+    #[test]
+    fn test_checked_and_saturating() {
+        assert_eq!(Gas(1).checked_sub(Gas(2)), None);
+        assert_eq!(Gas(u32::MAX).checked_add(Gas(1)), None);
+        assert_eq!(Gas(5).checked_add(Gas(1)), Some(Gas(6)));
+        assert_eq!(Gas(1).saturating_sub(Gas(2)), Gas::ZERO);
+        assert_eq!(Gas(u32::MAX).saturating_add(Gas(1)), Gas(u32::MAX));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Gas(1) < Gas(2));
+        assert_eq!(Gas(7).max(Gas(3)), Gas(7));
+    }
+}
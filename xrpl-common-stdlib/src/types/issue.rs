@@ -49,6 +49,14 @@ impl IouIssue {
     pub fn as_bytes(&self) -> &[u8] {
         &self._bytes
     }
+
+    pub fn issuer(&self) -> AccountID {
+        self.issuer
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
 }
 
 /// Struct to represent an Issue of type MPT. Exists so that other structs can restrict type
@@ -5,14 +5,22 @@ pub mod blob;
 pub mod constants;
 pub mod contract_data;
 pub mod currency;
+pub mod data_cursor;
+pub mod document_id;
+pub mod gas;
 pub mod issue;
 pub mod mpt_id;
 pub mod nft;
 pub mod object;
 pub mod opaque_float;
+pub mod pair;
 pub mod public_key;
+pub mod tick_size;
 pub mod transaction_type;
+pub mod transfer_rate;
 pub mod uint;
+pub mod vector256;
+pub mod xchain_bridge;
 
 // TODO: Move these to the `types` crate.
 // Relocated from the old top-level `src/types.rs`, which collided with this
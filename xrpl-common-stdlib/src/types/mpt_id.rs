@@ -12,9 +12,10 @@ pub const MPT_SEQUENCE_NUM_SIZE: usize = 4;
 /// ## Derived Traits
 ///
 /// - `Copy`: Efficient for this 24-byte struct, enabling implicit copying
-/// - `PartialEq, Eq`: Enable comparisons and use in hash-based collections
+/// - `PartialEq, Eq, Hash`: Enable comparisons and use in hash-based collections
+/// - `PartialOrd, Ord`: Byte-wise ordering, for sorted collections and binary search
 /// - `Debug, Clone`: Standard traits for development and consistency
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C)]
 pub struct MptId([u8; MPT_ID_SIZE]);
 
@@ -20,7 +20,7 @@
 use crate::host;
 use crate::host::{Error, Result};
 use crate::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
-use crate::types::blob::{URI_BLOB_SIZE, UriBlob};
+use crate::types::blob::{URI_BLOB_SIZE, UriBlob, find_bytes};
 
 /// Size of an NFTokenID in bytes (256 bits)
 pub const NFT_ID_SIZE: usize = 32;
@@ -310,11 +310,9 @@ impl NFToken {
     ///
     /// # Returns
     ///
-    /// * `Ok(UriBlob)` - The URI data (variable length, up to 256 bytes)
+    /// * `Ok(NftUri)` - The URI data (variable length, up to 256 bytes)
     /// * `Err(Error)` - If the NFT is not found or the host function fails
-    ///
-    ///
-    pub fn uri(&self, owner: &AccountID) -> Result<UriBlob> {
+    pub fn uri(&self, owner: &AccountID) -> Result<NftUri> {
         let mut uri_buf = [0u8; URI_BLOB_SIZE];
         let result = unsafe {
             host::get_nft(
@@ -328,12 +326,127 @@ impl NFToken {
         };
 
         match result {
-            code if code > 0 => Result::Ok(UriBlob::from(uri_buf)),
+            code if code > 0 => Result::Ok(NftUri(UriBlob {
+                data: uri_buf,
+                len: code as usize,
+            })),
             code => Result::Err(Error::from_code(code)),
         }
     }
 }
 
+/// The `URI` of an NFToken, as returned by [`NFToken::uri`].
+///
+/// The ledger stores the URI as opaque bytes. By convention they are the UTF-8 text of a URI,
+/// most often an `ipfs://` link or an `https://` gateway URL pointing at a JSON metadata
+/// document, and sometimes the JSON itself. These helpers check those conventions without
+/// manual byte inspection:
+///
+/// ```
+/// use xrpl_common_stdlib::types::blob::UriBlob;
+/// use xrpl_common_stdlib::types::nft::NftUri;
+///
+/// let uri = NftUri(UriBlob::from_slice(b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3/meta.json"));
+/// assert!(uri.is_ipfs());
+/// assert_eq!(uri.ipfs_path(), Some(&b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3/meta.json"[..]));
+/// assert_eq!(uri.scheme(), Some("ipfs"));
+/// assert!(uri.as_str().unwrap().ends_with(".json"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NftUri(pub UriBlob);
+
+impl NftUri {
+    /// Returns the URI bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Returns the URI as text, or `None` if it is not valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(self.as_bytes()).ok()
+    }
+
+    /// Returns the URI scheme (the part before the first `:`), or `None` if there is no valid one.
+    ///
+    /// A valid scheme is an ASCII letter followed by letters, digits, `+`, `-` or `.`. The scheme
+    /// is returned as stored; compare it with [`str::eq_ignore_ascii_case`].
+    pub fn scheme(&self) -> Option<&str> {
+        let bytes = self.as_bytes();
+        let end = bytes.iter().position(|&b| b == b':')?;
+        let scheme = &bytes[..end];
+        let valid = scheme.first().is_some_and(u8::is_ascii_alphabetic)
+            && scheme
+                .iter()
+                .all(|&b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
+        if !valid {
+            return None;
+        }
+        // An ASCII-only prefix is always valid UTF-8.
+        core::str::from_utf8(scheme).ok()
+    }
+
+    /// Returns true if the URI starts with `prefix`, ignoring ASCII case.
+    ///
+    /// Use this for schemes and hosts, which are case-insensitive:
+    /// `starts_with_ignore_case(b"https://")`.
+    pub fn starts_with_ignore_case(&self, prefix: &[u8]) -> bool {
+        let bytes = self.as_bytes();
+        bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+    }
+
+    /// Returns the rest of the URI after `prefix`, matched ignoring ASCII case, or `None` if it
+    /// does not start with `prefix`.
+    pub fn strip_prefix_ignore_case(&self, prefix: &[u8]) -> Option<&[u8]> {
+        if self.starts_with_ignore_case(prefix) {
+            Some(&self.as_bytes()[prefix.len()..])
+        } else {
+            None
+        }
+    }
+
+    /// Returns true for an `ipfs://` URI or an HTTP(S) gateway URL with an `/ipfs/` path.
+    pub fn is_ipfs(&self) -> bool {
+        self.ipfs_path().is_some()
+    }
+
+    /// Returns the content identifier and any path after it, e.g. `<cid>/metadata.json`, for the
+    /// URIs accepted by [`is_ipfs`](Self::is_ipfs).
+    pub fn ipfs_path(&self) -> Option<&[u8]> {
+        if let Some(path) = self.strip_prefix_ignore_case(b"ipfs://") {
+            // Tolerate the legacy `ipfs://ipfs/<cid>` form.
+            return Some(path.strip_prefix(b"ipfs/").unwrap_or(path));
+        }
+        if !self.is_http() {
+            return None;
+        }
+        let bytes = self.as_bytes();
+        find_bytes(bytes, b"/ipfs/").map(|at| &bytes[at + b"/ipfs/".len()..])
+    }
+
+    /// Returns true for an `http://` or `https://` URL.
+    pub fn is_http(&self) -> bool {
+        self.starts_with_ignore_case(b"https://") || self.starts_with_ignore_case(b"http://")
+    }
+
+    /// Returns true if the URI holds the metadata inline: a JSON object, optionally preceded by
+    /// whitespace, or a `data:application/json` URI.
+    pub fn is_inline_json(&self) -> bool {
+        self.starts_with_ignore_case(b"data:application/json")
+            || self
+                .as_bytes()
+                .iter()
+                .find(|b| !b.is_ascii_whitespace())
+                .is_some_and(|&b| b == b'{')
+    }
+}
+
+impl From<NftUri> for UriBlob {
+    fn from(uri: NftUri) -> Self {
+        uri.0
+    }
+}
+
 impl From<[u8; NFT_ID_SIZE]> for NFToken {
     fn from(value: [u8; NFT_ID_SIZE]) -> Self {
         NFToken(value)
@@ -662,6 +775,51 @@ mod tests {
         let result = nft.uri(&owner);
         assert!(result.is_ok());
         let uri = result.unwrap();
-        assert!(uri.len <= URI_BLOB_SIZE);
+        assert_eq!(uri.as_bytes().len(), expected_uri_len as usize);
+    }
+
+    fn nft_uri(bytes: &[u8]) -> NftUri {
+        NftUri(UriBlob::from_slice(bytes))
+    }
+
+    #[test]
+    fn test_nft_uri_ipfs_forms() {
+        let cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+        for uri in [
+            &b"ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"[..],
+            b"IPFS://ipfs/QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+            b"https://gateway.example/ipfs/QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+        ] {
+            let uri = nft_uri(uri);
+            assert!(uri.is_ipfs());
+            assert_eq!(uri.ipfs_path(), Some(&cid[..]));
+        }
+
+        assert!(!nft_uri(b"https://example.com/meta.json").is_ipfs());
+        assert!(!nft_uri(b"ar://abc/ipfs/def").is_ipfs());
+    }
+
+    #[test]
+    fn test_nft_uri_scheme_and_text() {
+        let uri = nft_uri(b"HTTPS://example.com/1.json");
+        assert_eq!(uri.scheme(), Some("HTTPS"));
+        assert!(uri.is_http());
+        assert_eq!(uri.as_str(), Some("HTTPS://example.com/1.json"));
+        assert_eq!(
+            uri.strip_prefix_ignore_case(b"https://"),
+            Some(&b"example.com/1.json"[..])
+        );
+
+        assert_eq!(nft_uri(b"1ab:rest").scheme(), None);
+        assert_eq!(nft_uri(b"no-scheme").scheme(), None);
+        assert_eq!(nft_uri(&[0xFF, 0xFE]).as_str(), None);
+    }
+
+    #[test]
+    fn test_nft_uri_inline_json() {
+        assert!(nft_uri(b"  {\"name\":\"x\"}").is_inline_json());
+        assert!(nft_uri(b"data:application/json;base64,e30=").is_inline_json());
+        assert!(!nft_uri(b"ipfs://Qm").is_inline_json());
+        assert!(!nft_uri(b"").is_inline_json());
     }
 }
@@ -1,3 +1,10 @@
+use crate::host::error_codes::match_result_code_with_expected_bytes;
+use crate::host::{
+    Error, Result, float_compare, float_divide, float_from_int, float_from_mant_exp,
+    float_multiply, float_pow, float_subtract,
+};
+use core::cmp::Ordering;
+
 /// Opaque 64-bit representation of an XRPL fungible token (IOU) amount.
 ///
 /// This struct encapsulates the XRPL's custom floating-point format used for fungible tokens.
@@ -140,21 +147,492 @@ impl OpaqueFloat {
     //     }
 }
 
+impl OpaqueFloat {
+    /// Compares two floats numerically using the `float_compare` host function.
+    ///
+    /// The host reports `0` (equal), `1` (`self` is greater) or `2` (`self` is less); this
+    /// translates those codes into an [`Ordering`].
+    pub fn compare(&self, other: &Self) -> Result<Ordering> {
+        let result_code = unsafe {
+            float_compare(
+                self.0.as_ptr(),
+                self.0.len(),
+                other.0.as_ptr(),
+                other.0.len(),
+            )
+        };
+        match result_code {
+            0 => Result::Ok(Ordering::Equal),
+            1 => Result::Ok(Ordering::Greater),
+            2 => Result::Ok(Ordering::Less),
+            code if code < 0 => Result::Err(Error::from_code(code)),
+            _ => Result::Err(Error::InternalError),
+        }
+    }
+
+    /// Returns whether `self` is numerically less than `other`.
+    pub fn less_than(&self, other: &Self) -> Result<bool> {
+        self.compare(other)
+            .map(|ordering| ordering == Ordering::Less)
+    }
+
+    /// Returns the smaller of `self` and `other`, or `self` if they are equal.
+    pub fn min(self, other: Self) -> Result<Self> {
+        self.compare(&other).map(|ordering| match ordering {
+            Ordering::Greater => other,
+            _ => self,
+        })
+    }
+
+    /// Returns the larger of `self` and `other`, or `self` if they are equal.
+    pub fn max(self, other: Self) -> Result<Self> {
+        self.compare(&other).map(|ordering| match ordering {
+            Ordering::Less => other,
+            _ => self,
+        })
+    }
+
+    /// Restricts `self` to the inclusive range `[min, max]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidParams` if `min` is greater than `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Result<Self> {
+        match min.compare(&max) {
+            Result::Ok(Ordering::Greater) => return Result::Err(Error::InvalidParams),
+            Result::Ok(_) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+        self.max(min).and_then(|value| value.min(max))
+    }
+
+    /// Returns whether this float is numerically zero.
+    pub fn is_zero(&self) -> Result<bool> {
+        self.compare(&OpaqueFloat(FLOAT_ZERO))
+            .map(|ordering| ordering == Ordering::Equal)
+    }
+
+    /// Returns whether this float is strictly less than zero.
+    pub fn is_negative(&self) -> Result<bool> {
+        self.compare(&OpaqueFloat(FLOAT_ZERO))
+            .map(|ordering| ordering == Ordering::Less)
+    }
+}
+
+/// Arithmetic helpers. `rounding_mode` is one of the `host::FLOAT_ROUNDING_MODES_*` constants.
+impl OpaqueFloat {
+    /// Creates the float `mantissa * 10^exponent` via the `float_from_mant_exp` host function.
+    pub fn from_mant_exp(mantissa: i64, exponent: i32, rounding_mode: i32) -> Result<Self> {
+        let mut out = [0u8; FLOAT_SIZE];
+        let result_code = unsafe {
+            float_from_mant_exp(
+                mantissa,
+                exponent,
+                out.as_mut_ptr(),
+                FLOAT_SIZE,
+                rounding_mode,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, FLOAT_SIZE, || OpaqueFloat(out))
+    }
+
+    /// Creates the float for `value` via the `float_from_int` host function.
+    pub fn from_int(value: i64, rounding_mode: i32) -> Result<Self> {
+        let mut out = [0u8; FLOAT_SIZE];
+        let result_code =
+            unsafe { float_from_int(value, out.as_mut_ptr(), FLOAT_SIZE, rounding_mode) };
+        match_result_code_with_expected_bytes(result_code, FLOAT_SIZE, || OpaqueFloat(out))
+    }
+
+    /// Subtracts `other` from `self` via the `float_subtract` host function.
+    pub fn subtract(&self, other: &Self, rounding_mode: i32) -> Result<Self> {
+        let mut out = [0u8; FLOAT_SIZE];
+        let result_code = unsafe {
+            float_subtract(
+                self.0.as_ptr(),
+                FLOAT_SIZE,
+                other.0.as_ptr(),
+                FLOAT_SIZE,
+                out.as_mut_ptr(),
+                FLOAT_SIZE,
+                rounding_mode,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, FLOAT_SIZE, || OpaqueFloat(out))
+    }
+
+    /// Multiplies two floats via the `float_multiply` host function.
+    pub fn multiply(&self, other: &Self, rounding_mode: i32) -> Result<Self> {
+        let mut out = [0u8; FLOAT_SIZE];
+        let result_code = unsafe {
+            float_multiply(
+                self.0.as_ptr(),
+                FLOAT_SIZE,
+                other.0.as_ptr(),
+                FLOAT_SIZE,
+                out.as_mut_ptr(),
+                FLOAT_SIZE,
+                rounding_mode,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, FLOAT_SIZE, || OpaqueFloat(out))
+    }
+
+    /// Divides `self` by `other` via the `float_divide` host function.
+    ///
+    /// # Errors
+    ///
+    /// Returns the host error, e.g. when `other` is zero.
+    pub fn divide(&self, other: &Self, rounding_mode: i32) -> Result<Self> {
+        let mut out = [0u8; FLOAT_SIZE];
+        let result_code = unsafe {
+            float_divide(
+                self.0.as_ptr(),
+                FLOAT_SIZE,
+                other.0.as_ptr(),
+                FLOAT_SIZE,
+                out.as_mut_ptr(),
+                FLOAT_SIZE,
+                rounding_mode,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, FLOAT_SIZE, || OpaqueFloat(out))
+    }
+
+    /// Raises `self` to the power `exponent` via the `float_pow` host function.
+    pub fn pow(&self, exponent: i32, rounding_mode: i32) -> Result<Self> {
+        let mut out = [0u8; FLOAT_SIZE];
+        let result_code = unsafe {
+            float_pow(
+                self.0.as_ptr(),
+                FLOAT_SIZE,
+                exponent,
+                out.as_mut_ptr(),
+                FLOAT_SIZE,
+                rounding_mode,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, FLOAT_SIZE, || OpaqueFloat(out))
+    }
+
+    /// Returns `self * bps / 10_000`, i.e. `bps` basis points of this value.
+    pub fn mul_bps(&self, bps: u32, rounding_mode: i32) -> Result<Self> {
+        self.scale_pow10(bps, -4, rounding_mode)
+    }
+
+    /// Returns `percent`% of this value, i.e. `self * percent / 100`.
+    pub fn percent_of(&self, percent: u32, rounding_mode: i32) -> Result<Self> {
+        self.scale_pow10(percent, -2, rounding_mode)
+    }
+
+    /// Returns `self * factor * 10^exponent`. The factor is built exactly by the host, so only
+    /// the final multiplication rounds.
+    pub(crate) fn scale_pow10(
+        &self,
+        factor: u32,
+        exponent: i32,
+        rounding_mode: i32,
+    ) -> Result<Self> {
+        OpaqueFloat::from_mant_exp(factor as i64, exponent, rounding_mode)
+            .and_then(|factor| self.multiply(&factor, rounding_mode))
+    }
+}
+
 impl From<[u8; 8]> for OpaqueFloat {
     fn from(value: [u8; 8]) -> Self {
         OpaqueFloat(value)
     }
 }
 
+/// Longest text [`OpaqueFloat::write_decimal`] produces, in bytes.
+pub const FLOAT_DECIMAL_MAX_LEN: usize = 32;
+
+/// Most digits written before the decimal point, or after it, before switching to scientific
+/// notation.
+const PLAIN_MAX_DIGITS: i32 = 20;
+
+const MANTISSA_MASK: u64 = (1 << 54) - 1;
+const EXPONENT_BIAS: i32 = 97;
+
+/// Formatting for logs and debugging. The value is decoded from its bits in the contract; it is
+/// never fed back into arithmetic, which stays with the host functions.
+impl OpaqueFloat {
+    /// Writes the value in decimal into `out` and returns the number of bytes written.
+    ///
+    /// Values are written in plain notation (`1250.5`, `-0.0001`) when that takes at most 20
+    /// digits before or after the point, and in scientific notation (`1.5e30`, `2e-25`)
+    /// otherwise. Trailing zeros are dropped, and zero is written as `0`.
+    ///
+    /// ```
+    /// use xrpl_common_stdlib::types::opaque_float::{FLOAT_DECIMAL_MAX_LEN, FLOAT_ONE, OpaqueFloat};
+    ///
+    /// let mut out = [0u8; FLOAT_DECIMAL_MAX_LEN];
+    /// let len = OpaqueFloat(FLOAT_ONE).write_decimal(&mut out);
+    /// assert_eq!(&out[..len], b"1");
+    /// ```
+    pub fn write_decimal(&self, out: &mut [u8; FLOAT_DECIMAL_MAX_LEN]) -> usize {
+        let raw = u64::from_be_bytes(self.0);
+        let mut mantissa = raw & MANTISSA_MASK;
+        if mantissa == 0 {
+            out[0] = b'0';
+            return 1;
+        }
+        let mut exponent = ((raw >> 54) & 0xFF) as i32 - EXPONENT_BIAS;
+        while mantissa % 10 == 0 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+
+        // A 54-bit mantissa has at most 17 digits.
+        let mut digits = [0u8; 17];
+        let mut count = 0;
+        while mantissa > 0 {
+            digits[count] = b'0' + (mantissa % 10) as u8;
+            mantissa /= 10;
+            count += 1;
+        }
+        digits[..count].reverse();
+        let digits = &digits[..count];
+        // Number of digits before the decimal point.
+        let point = count as i32 + exponent;
+
+        let mut len = 0;
+        let mut push = |bytes: &[u8]| {
+            out[len..len + bytes.len()].copy_from_slice(bytes);
+            len += bytes.len();
+        };
+        if raw & (1 << 62) == 0 {
+            push(b"-");
+        }
+        if exponent >= 0 && point <= PLAIN_MAX_DIGITS {
+            push(digits);
+            for _ in 0..exponent {
+                push(b"0");
+            }
+        } else if exponent < 0 && point > 0 {
+            push(&digits[..point as usize]);
+            push(b".");
+            push(&digits[point as usize..]);
+        } else if exponent < 0 && -exponent <= PLAIN_MAX_DIGITS {
+            push(b"0.");
+            for _ in 0..-point {
+                push(b"0");
+            }
+            push(digits);
+        } else {
+            push(&digits[..1]);
+            if count > 1 {
+                push(b".");
+                push(&digits[1..]);
+            }
+            push(b"e");
+            let scale = point - 1;
+            if scale < 0 {
+                push(b"-");
+            }
+            let scale = scale.unsigned_abs();
+            if scale >= 10 {
+                push(&[b'0' + (scale / 10) as u8]);
+            }
+            push(&[b'0' + (scale % 10) as u8]);
+        }
+        len
+    }
+}
+
+/// Writes the value as [`OpaqueFloat::write_decimal`] does.
+impl core::fmt::Display for OpaqueFloat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut out = [0u8; FLOAT_DECIMAL_MAX_LEN];
+        let len = self.write_decimal(&mut out);
+        // Only ASCII digits, signs, `.` and `e` are written.
+        f.write_str(core::str::from_utf8(&out[..len]).unwrap_or_default())
+    }
+}
+
+/// Size in bytes of a serialized [`OpaqueFloat`].
+const FLOAT_SIZE: usize = 8;
+
+/// The number `0` in XRPL's custom float format.
+pub const FLOAT_ZERO: [u8; 8] = [0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
 /// The number `1` in XRPL's custom float format.
 pub const FLOAT_ONE: [u8; 8] = [0xD4, 0x83, 0x8D, 0x7E, 0xA4, 0xC6, 0x80, 0x00];
 
-/// The number `0` in XRPL's custom float format.
+/// The number `-1` in XRPL's custom float format.
 pub const FLOAT_NEGATIVE_ONE: [u8; 8] = [0x94, 0x83, 0x8D, 0x7E, 0xA4, 0xC6, 0x80, 0x00];
 
 #[cfg(test)]
 mod tests {
-    // use super::*;
+    use super::*;
+    use crate::host::error_codes::{
+        INVALID_FLOAT_COMPUTATION, INVALID_FLOAT_INPUT, INVALID_PARAMS,
+    };
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::{FLOAT_ROUNDING_MODES_DOWNWARD, FLOAT_ROUNDING_MODES_TO_NEAREST, setup_mock};
+    use mockall::predicate::{always, eq};
+
+    /// Mocks `float_compare` by comparing the first byte of each operand, which is enough to
+    /// order the small set of distinct test values below.
+    fn mock_compare_by_first_byte() -> MockHostBindings {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_compare().returning(|a, _, b, _| {
+            let (a, b) = unsafe { (*a, *b) };
+            match a.cmp(&b) {
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+                Ordering::Less => 2,
+            }
+        });
+        mock
+    }
+
+    const LOW: OpaqueFloat = OpaqueFloat([1; 8]);
+    const MID: OpaqueFloat = OpaqueFloat([2; 8]);
+    const HIGH: OpaqueFloat = OpaqueFloat([3; 8]);
+
+    #[test]
+    fn test_compare_translates_host_codes() {
+        let _guard = setup_mock(mock_compare_by_first_byte());
+
+        assert_eq!(MID.compare(&MID).unwrap(), Ordering::Equal);
+        assert_eq!(HIGH.compare(&MID).unwrap(), Ordering::Greater);
+        assert_eq!(LOW.compare(&MID).unwrap(), Ordering::Less);
+        assert!(LOW.less_than(&HIGH).unwrap());
+        assert!(!HIGH.less_than(&MID).unwrap());
+        assert!(!MID.less_than(&MID).unwrap());
+    }
+
+    #[test]
+    fn test_compare_propagates_host_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_compare()
+            .returning(|_, _, _, _| INVALID_FLOAT_INPUT);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            LOW.compare(&HIGH).err().unwrap().code(),
+            INVALID_FLOAT_INPUT
+        );
+        assert_eq!(
+            LOW.less_than(&HIGH).err().unwrap().code(),
+            INVALID_FLOAT_INPUT
+        );
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        let _guard = setup_mock(mock_compare_by_first_byte());
+
+        assert_eq!(LOW.min(HIGH).unwrap(), LOW);
+        assert_eq!(LOW.max(HIGH).unwrap(), HIGH);
+        assert_eq!(HIGH.clamp(LOW, MID).unwrap(), MID);
+        assert_eq!(LOW.clamp(MID, HIGH).unwrap(), MID);
+        assert_eq!(MID.clamp(LOW, HIGH).unwrap(), MID);
+        assert_eq!(MID.clamp(HIGH, LOW).err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_mul_bps_builds_exact_factor_then_multiplies() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_from_mant_exp()
+            .with(
+                eq(25),
+                eq(-4),
+                always(),
+                eq(8),
+                eq(FLOAT_ROUNDING_MODES_DOWNWARD),
+            )
+            .times(1)
+            .returning(|_, _, out, _, _| {
+                unsafe { core::ptr::write_bytes(out, 0x25, 8) };
+                8
+            });
+        mock.expect_float_multiply()
+            .with(
+                always(),
+                eq(8),
+                always(),
+                eq(8),
+                always(),
+                eq(8),
+                eq(FLOAT_ROUNDING_MODES_DOWNWARD),
+            )
+            .times(1)
+            .returning(|a, _, b, _, out, _, _| {
+                unsafe {
+                    assert_eq!(*(a as *const [u8; 8]), FLOAT_ONE);
+                    assert_eq!(*(b as *const [u8; 8]), [0x25; 8]);
+                    core::ptr::write_bytes(out, 0x77, 8);
+                }
+                8
+            });
+        let _guard = setup_mock(mock);
+
+        let result = OpaqueFloat(FLOAT_ONE)
+            .mul_bps(25, FLOAT_ROUNDING_MODES_DOWNWARD)
+            .unwrap();
+        assert_eq!(result, OpaqueFloat([0x77; 8]));
+    }
+
+    #[test]
+    fn test_percent_of_uses_hundredths() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_from_mant_exp()
+            .with(eq(15), eq(-2), always(), eq(8), always())
+            .times(1)
+            .returning(|_, _, _, _, _| 8);
+        mock.expect_float_multiply()
+            .times(1)
+            .returning(|_, _, _, _, _, _, _| 8);
+        let _guard = setup_mock(mock);
+
+        assert!(
+            OpaqueFloat(FLOAT_ONE)
+                .percent_of(15, FLOAT_ROUNDING_MODES_TO_NEAREST)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_multiply_propagates_host_error() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_multiply()
+            .returning(|_, _, _, _, _, _, _| INVALID_FLOAT_COMPUTATION);
+        let _guard = setup_mock(mock);
+
+        let result = LOW.multiply(&HIGH, FLOAT_ROUNDING_MODES_TO_NEAREST);
+        assert_eq!(result.err().unwrap().code(), INVALID_FLOAT_COMPUTATION);
+    }
+
+    #[test]
+    fn test_sign_checks_compare_against_zero() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_compare().returning(|a, _, b, _| {
+            let value = |ptr: *const u8| {
+                let bytes = unsafe { *(ptr as *const [u8; 8]) };
+                match bytes {
+                    FLOAT_ONE => 1,
+                    FLOAT_NEGATIVE_ONE => -1,
+                    _ => 0,
+                }
+            };
+            match value(a).cmp(&value(b)) {
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+                Ordering::Less => 2,
+            }
+        });
+        let _guard = setup_mock(mock);
+
+        let zero = OpaqueFloat(FLOAT_ZERO);
+        assert!(zero.is_zero().unwrap());
+        assert!(!zero.is_negative().unwrap());
+        assert!(OpaqueFloat(FLOAT_NEGATIVE_ONE).is_negative().unwrap());
+        assert!(!OpaqueFloat(FLOAT_ONE).is_negative().unwrap());
+        assert!(!OpaqueFloat(FLOAT_ONE).is_zero().unwrap());
+    }
+
     // #[test]
     // fn test_exponent_mantissa_roundtrip() {
     //     // Test with various exponent and mantissa values
@@ -272,4 +750,61 @@ mod tests {
     //     // The mantissa should now have bits set
     //     assert_ne!(float_with_mantissa.get_mantissa(), 0);
     // }
+
+    /// Builds the float `mantissa * 10^exponent` bit by bit, as the host would for a normalized
+    /// mantissa.
+    fn float(negative: bool, mantissa: u64, exponent: i32) -> OpaqueFloat {
+        let sign = if negative { 0 } else { 1 << 62 };
+        let raw = (1 << 63) | sign | (((exponent + EXPONENT_BIAS) as u64) << 54) | mantissa;
+        OpaqueFloat(raw.to_be_bytes())
+    }
+
+    fn decimal(value: OpaqueFloat) -> std::string::String {
+        std::format!("{value}")
+    }
+
+    #[test]
+    fn test_write_decimal_constants() {
+        assert_eq!(decimal(OpaqueFloat(FLOAT_ZERO)), "0");
+        assert_eq!(decimal(OpaqueFloat(FLOAT_ONE)), "1");
+        assert_eq!(decimal(OpaqueFloat(FLOAT_NEGATIVE_ONE)), "-1");
+    }
+
+    #[test]
+    fn test_write_decimal_plain_notation() {
+        let m = 1_000_000_000_000_000;
+        assert_eq!(decimal(float(false, 1_500_000_000_000_000, -15)), "1.5");
+        assert_eq!(
+            decimal(float(true, 1_234_567_800_000_000, -12)),
+            "-1234.5678"
+        );
+        assert_eq!(decimal(float(false, m, -18)), "0.001");
+        assert_eq!(decimal(float(false, m, 4)), "10000000000000000000");
+        assert_eq!(
+            decimal(float(false, 1_234_500_000_000_000, -31)),
+            "0.00000000000000012345"
+        );
+    }
+
+    #[test]
+    fn test_write_decimal_scientific_notation() {
+        let m = 1_000_000_000_000_000;
+        assert_eq!(decimal(float(false, m, 5)), "1e20");
+        assert_eq!(
+            decimal(float(false, 9_999_999_999_999_999, 65)),
+            "9.999999999999999e80"
+        );
+        assert_eq!(decimal(float(true, m, -96)), "-1e-81");
+        assert_eq!(
+            decimal(float(false, 1_230_000_000_000_000, -37)),
+            "1.23e-22"
+        );
+
+        let mut out = [0u8; FLOAT_DECIMAL_MAX_LEN];
+        let longest = float(true, (1 << 54) - 1, -96);
+        assert_eq!(
+            longest.write_decimal(&mut out),
+            "-1.8014398509481983e-80".len()
+        );
+    }
 }
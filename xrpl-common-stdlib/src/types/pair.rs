@@ -0,0 +1,193 @@
+//! Ordered pairs of assets, as used by price oracles and AMM pools.
+//!
+//! A price is only meaningful with its direction: an oracle's XRP/USD entry says how many USD one
+//! XRP is worth, and reading it as USD/XRP inverts it. A [`Pair`] keeps the two sides named, so a
+//! lookup states which direction it expects and can tell when it found the inverse instead:
+//!
+//! ```
+//! use xrpl_common_stdlib::currency;
+//! use xrpl_common_stdlib::types::account_id::AccountID;
+//! use xrpl_common_stdlib::types::issue::{IouIssue, Issue, XrpIssue};
+//! use xrpl_common_stdlib::types::pair::{Orientation, Pair};
+//!
+//! let xrp = Issue::XRP(XrpIssue {});
+//! let usd = Issue::IOU(IouIssue::new(AccountID([0x2B; 20]), currency!("USD")));
+//! let pair = Pair::new(xrp.clone(), usd.clone());
+//!
+//! assert!(pair.matches(&xrp, &usd));
+//! assert_eq!(pair.orientation(&usd, &xrp), Some(Orientation::Inverted));
+//! ```
+//!
+//! AMM pools have no direction: the same pool is found whichever asset is named first, and its
+//! `Asset`/`Asset2` fields keep the order used when it was created. [`Pair::canonical`] puts
+//! both sides in the order the ledger sorts them, so two pairs naming the same pool compare
+//! equal once canonicalized.
+
+use crate::types::currency::Currency;
+use crate::types::issue::Issue;
+use core::cmp::Ordering;
+
+/// How a pair of assets relates to a [`Pair`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Same base and quote.
+    Same,
+    /// Base and quote swapped; prices must be inverted.
+    Inverted,
+}
+
+/// A `base`/`quote` pair of assets. A price for the pair is the amount of `quote` one unit of
+/// `base` is worth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair {
+    pub base: Issue,
+    pub quote: Issue,
+}
+
+impl Pair {
+    pub fn new(base: Issue, quote: Issue) -> Self {
+        Pair { base, quote }
+    }
+
+    /// Returns the pair with base and quote swapped.
+    pub fn inverted(&self) -> Pair {
+        Pair::new(self.quote.clone(), self.base.clone())
+    }
+
+    /// Returns whether `base`/`quote` is this pair in the same direction.
+    pub fn matches(&self, base: &Issue, quote: &Issue) -> bool {
+        &self.base == base && &self.quote == quote
+    }
+
+    /// Returns how `base`/`quote` relates to this pair, or `None` if it names different assets.
+    pub fn orientation(&self, base: &Issue, quote: &Issue) -> Option<Orientation> {
+        if self.matches(base, quote) {
+            Some(Orientation::Same)
+        } else if &self.base == quote && &self.quote == base {
+            Some(Orientation::Inverted)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the two sides are already in ledger order; see [`canonical_cmp`].
+    pub fn is_canonical(&self) -> bool {
+        canonical_cmp(&self.base, &self.quote) != Ordering::Greater
+    }
+
+    /// Returns the pair with its sides in ledger order, and how that relates to `self`.
+    pub fn canonical(&self) -> (Pair, Orientation) {
+        if self.is_canonical() {
+            (self.clone(), Orientation::Same)
+        } else {
+            (self.inverted(), Orientation::Inverted)
+        }
+    }
+
+    /// Returns the currency codes identifying the pair in an oracle's `PriceDataSeries`, where
+    /// XRP is the all-zero code. Oracle entries name only currencies, so the issuers of IOUs are
+    /// dropped.
+    ///
+    /// Returns `None` if either side is an MPT, which has no currency code.
+    pub fn oracle_currencies(&self) -> Option<(Currency, Currency)> {
+        match (oracle_currency(&self.base), oracle_currency(&self.quote)) {
+            (Some(base), Some(quote)) => Some((base, quote)),
+            _ => None,
+        }
+    }
+}
+
+fn oracle_currency(issue: &Issue) -> Option<Currency> {
+    match issue {
+        Issue::XRP(_) => Some(Currency([0; 20])),
+        Issue::IOU(iou) => Some(iou.currency()),
+        Issue::MPT(_) => None,
+    }
+}
+
+/// Orders two assets the way the ledger does when it sorts the sides of an AMM pool.
+///
+/// XRP and IOUs compare by currency code, then issuer, with XRP's code and issuer all zero, so
+/// XRP sorts first. Both come before any MPT, and MPTs compare by their `MPTokenIssuanceID`.
+pub fn canonical_cmp(a: &Issue, b: &Issue) -> Ordering {
+    match (a, b) {
+        (Issue::MPT(a), Issue::MPT(b)) => a.mpt_id().as_bytes().cmp(b.mpt_id().as_bytes()),
+        (Issue::MPT(_), _) => Ordering::Greater,
+        (_, Issue::MPT(_)) => Ordering::Less,
+        // Both are laid out as currency then issuer; XRP's 20 zero bytes stand for both.
+        _ => {
+            let a_bytes = a.as_bytes();
+            let b_bytes = b.as_bytes();
+            let a_padded = a_bytes.iter().chain(core::iter::repeat(&0)).take(40);
+            let b_padded = b_bytes.iter().chain(core::iter::repeat(&0)).take(40);
+            a_padded.cmp(b_padded)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::account_id::AccountID;
+    use crate::types::issue::{IouIssue, MptIssue, XrpIssue};
+    use crate::types::mpt_id::MptId;
+
+    fn iou(code: &[u8; 3], issuer: u8) -> Issue {
+        Issue::IOU(IouIssue::new(
+            AccountID([issuer; 20]),
+            Currency::from(*code),
+        ))
+    }
+
+    fn mpt(seq: u32) -> Issue {
+        Issue::MPT(MptIssue::new(MptId::new(seq, AccountID([0x01; 20]))))
+    }
+
+    #[test]
+    fn test_orientation() {
+        let pair = Pair::new(Issue::XRP(XrpIssue {}), iou(b"USD", 0x11));
+        assert_eq!(
+            pair.orientation(&Issue::XRP(XrpIssue {}), &iou(b"USD", 0x11)),
+            Some(Orientation::Same)
+        );
+        assert_eq!(
+            pair.orientation(&iou(b"USD", 0x11), &Issue::XRP(XrpIssue {})),
+            Some(Orientation::Inverted)
+        );
+        assert_eq!(
+            pair.orientation(&Issue::XRP(XrpIssue {}), &iou(b"USD", 0x22)),
+            None
+        );
+        assert_eq!(
+            pair.oracle_currencies(),
+            Some((Currency([0; 20]), Currency::from(*b"USD")))
+        );
+        assert_eq!(
+            Pair::new(mpt(1), iou(b"USD", 0x11)).oracle_currencies(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        let xrp = Issue::XRP(XrpIssue {});
+        assert_eq!(canonical_cmp(&xrp, &iou(b"USD", 0x11)), Ordering::Less);
+        assert_eq!(
+            canonical_cmp(&iou(b"EUR", 0x99), &iou(b"USD", 0x11)),
+            Ordering::Less
+        );
+        assert_eq!(
+            canonical_cmp(&iou(b"USD", 0x22), &iou(b"USD", 0x11)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            canonical_cmp(&mpt(1), &iou(b"USD", 0x11)),
+            Ordering::Greater
+        );
+
+        let (canonical, orientation) = Pair::new(iou(b"USD", 0x11), xrp.clone()).canonical();
+        assert_eq!(canonical, Pair::new(xrp, iou(b"USD", 0x11)));
+        assert_eq!(orientation, Orientation::Inverted);
+        assert!(canonical.is_canonical());
+    }
+}
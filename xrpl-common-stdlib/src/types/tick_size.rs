@@ -0,0 +1,136 @@
+//! An issuer's `TickSize`: how many significant digits offer exchange rates keep.
+//!
+//! When an offer involving an issuer's tokens is placed, its exchange rate is rounded to the
+//! issuer's tick size (or, when both sides are issued tokens, to the smaller of the two issuers'
+//! tick sizes). A tick size of 16 means full precision: an issuer sets it by clearing the field
+//! (with `AccountSet` `TickSize` 0 or 16), so the ledger only ever stores 3 to 15.
+
+use crate::host::{Error, Result};
+
+/// A number of significant digits, from [`TickSize::MIN`] to [`TickSize::MAX`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct TickSize(u8);
+
+impl TickSize {
+    /// The smallest tick size an issuer can set.
+    pub const MIN: TickSize = TickSize(3);
+
+    /// Full precision: exchange rates are not rounded. This is also the tick size of an issuer
+    /// without a `TickSize`.
+    pub const MAX: TickSize = TickSize(16);
+
+    /// Validates a tick size.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `digits` is outside `[3, 16]`
+    pub const fn new(digits: u8) -> Result<Self> {
+        if digits < Self::MIN.0 || digits > Self::MAX.0 {
+            return Result::Err(Error::InvalidParams);
+        }
+        Result::Ok(TickSize(digits))
+    }
+
+    /// Returns the tick size for an issuer's optional `TickSize` field, treating a missing field
+    /// as [`MAX`](Self::MAX).
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If the field holds a value outside `[3, 15]`, which the ledger never
+    ///   stores
+    pub const fn from_field(field: Option<u8>) -> Result<Self> {
+        match field {
+            Some(digits) if digits < Self::MAX.0 => Self::new(digits),
+            Some(_) => Result::Err(Error::InvalidParams),
+            None => Result::Ok(Self::MAX),
+        }
+    }
+
+    /// Returns the tick size that applies to an offer between two assets: the smaller of the
+    /// issuers' tick sizes. Pass `None` for XRP or an issuer without a `TickSize`.
+    pub const fn for_pair(a: Option<TickSize>, b: Option<TickSize>) -> TickSize {
+        match (a, b) {
+            (Some(a), Some(b)) if b.0 < a.0 => b,
+            (Some(a), _) => a,
+            (None, Some(b)) => b,
+            (None, None) => Self::MAX,
+        }
+    }
+
+    /// Returns the number of significant digits.
+    pub const fn digits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether exchange rates are kept at full precision, i.e. the tick size is
+    /// [`MAX`](Self::MAX).
+    pub const fn is_full_precision(self) -> bool {
+        self.0 == Self::MAX.0
+    }
+}
+
+impl Default for TickSize {
+    fn default() -> Self {
+        Self::MAX
+    }
+}
+
+/// Fails with `InvalidParams` like [`TickSize::new`].
+impl TryFrom<u8> for TickSize {
+    type Error = Error;
+
+    fn try_from(digits: u8) -> core::result::Result<Self, Error> {
+        match Self::new(digits) {
+            Result::Ok(tick_size) => core::result::Result::Ok(tick_size),
+            Result::Err(e) => core::result::Result::Err(e),
+        }
+    }
+}
+
+impl From<TickSize> for u8 {
+    fn from(tick_size: TickSize) -> Self {
+        tick_size.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_range() {
+        assert!(TickSize::new(2).is_err());
+        assert_eq!(TickSize::new(3).unwrap(), TickSize::MIN);
+        assert_eq!(TickSize::try_from(15).unwrap().digits(), 15);
+        assert_eq!(TickSize::new(16).unwrap(), TickSize::MAX);
+        assert!(TickSize::try_from(17).is_err());
+    }
+
+    #[test]
+    fn test_only_sixteen_is_full_precision() {
+        assert!(TickSize::MAX.is_full_precision());
+        assert!(TickSize::default().is_full_precision());
+        assert!(!TickSize::new(15).unwrap().is_full_precision());
+        assert!(!TickSize::MIN.is_full_precision());
+    }
+
+    #[test]
+    fn test_from_field_accepts_stored_values() {
+        assert_eq!(TickSize::from_field(None).unwrap(), TickSize::MAX);
+        assert_eq!(TickSize::from_field(Some(3)).unwrap(), TickSize::MIN);
+        assert_eq!(TickSize::from_field(Some(15)).unwrap().digits(), 15);
+        assert!(TickSize::from_field(Some(16)).is_err());
+        assert!(TickSize::from_field(Some(2)).is_err());
+    }
+
+    #[test]
+    fn test_for_pair_takes_the_smaller() {
+        let five = TickSize::new(5).unwrap();
+        let eight = TickSize::new(8).unwrap();
+        assert_eq!(TickSize::for_pair(Some(eight), Some(five)), five);
+        assert_eq!(TickSize::for_pair(Some(five), Some(eight)), five);
+        assert_eq!(TickSize::for_pair(None, Some(eight)).digits(), 8);
+        assert_eq!(TickSize::for_pair(None, None), TickSize::MAX);
+    }
+}
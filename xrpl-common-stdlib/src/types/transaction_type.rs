@@ -73,6 +73,7 @@ pub enum TransactionType {
     DIDDelete = 50,
     OracleSet = 51,
     OracleDelete = 52,
+    Batch = 71,
     EnableAmendment = 100,
     SetFee = 101,
     UNLModify = 102,
@@ -137,6 +138,7 @@ impl From<i16> for TransactionType {
             50 => TransactionType::DIDDelete,
             51 => TransactionType::OracleSet,
             52 => TransactionType::OracleDelete,
+            71 => TransactionType::Batch,
             100 => TransactionType::EnableAmendment,
             101 => TransactionType::SetFee,
             102 => TransactionType::UNLModify,
@@ -281,6 +283,7 @@ mod tests {
         assert_eq!(TransactionType::from(50i16), TransactionType::DIDDelete);
         assert_eq!(TransactionType::from(51i16), TransactionType::OracleSet);
         assert_eq!(TransactionType::from(52i16), TransactionType::OracleDelete);
+        assert_eq!(TransactionType::from(71i16), TransactionType::Batch);
         assert_eq!(
             TransactionType::from(100i16),
             TransactionType::EnableAmendment
@@ -0,0 +1,191 @@
+//! An issuer's transfer fee, the `TransferRate` field of an AccountRoot.
+//!
+//! The ledger stores the rate as billionths of the amount sent: `1_000_000_000` means the
+//! recipient gets exactly what the sender pays, and `1_002_000_000` means the sender pays 0.2%
+//! more. [`TransferRate`] checks the valid range once and converts to the forms fee computations
+//! need, so the `10^9` scale doesn't have to be repeated at every use.
+
+use crate::host::{Error, FLOAT_ROUNDING_MODES_TO_NEAREST, Result};
+use crate::types::amount::{Amount, MAX_TRANSFER_RATE, TRANSFER_RATE_PARITY};
+use crate::types::opaque_float::OpaqueFloat;
+
+/// An issuer's transfer rate, in billionths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct TransferRate(u32);
+
+impl TransferRate {
+    /// No fee.
+    pub const PARITY: TransferRate = TransferRate(TRANSFER_RATE_PARITY);
+
+    /// The highest rate the ledger allows, a 100% fee.
+    pub const MAX: TransferRate = TransferRate(MAX_TRANSFER_RATE);
+
+    /// Validates a raw `TransferRate` value. `0`, which the ledger treats as an unset field, is
+    /// [`PARITY`](Self::PARITY).
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `billionths` is neither 0 nor in
+    ///   `[TRANSFER_RATE_PARITY, MAX_TRANSFER_RATE]`
+    pub const fn new(billionths: u32) -> Result<Self> {
+        match billionths {
+            0 => Result::Ok(Self::PARITY),
+            TRANSFER_RATE_PARITY..=MAX_TRANSFER_RATE => Result::Ok(TransferRate(billionths)),
+            _ => Result::Err(Error::InvalidParams),
+        }
+    }
+
+    /// Returns the rate for an issuer's optional `TransferRate` field, treating a missing field
+    /// as [`PARITY`](Self::PARITY).
+    pub const fn from_field(field: Option<u32>) -> Result<Self> {
+        match field {
+            Some(billionths) => Self::new(billionths),
+            None => Result::Ok(Self::PARITY),
+        }
+    }
+
+    /// Returns the rate for a fee of `fee_bps` basis points, e.g. `20` for 0.2%.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidParams` - If `fee_bps` is more than `10_000` (a 100% fee)
+    pub const fn from_fee_bps(fee_bps: u32) -> Result<Self> {
+        if fee_bps > 10_000 {
+            return Result::Err(Error::InvalidParams);
+        }
+        Result::Ok(TransferRate(TRANSFER_RATE_PARITY + fee_bps * 100_000))
+    }
+
+    /// Returns the rate in billionths, as stored in the ledger.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether the rate charges no fee.
+    pub const fn is_parity(self) -> bool {
+        self.0 == TRANSFER_RATE_PARITY
+    }
+
+    /// Returns the fee in billionths of the amount received, e.g. `2_000_000` for 0.2%.
+    pub const fn fee_billionths(self) -> u32 {
+        self.0 - TRANSFER_RATE_PARITY
+    }
+
+    /// Returns what the sender pays per unit received, e.g. `1.002` for a 0.2% fee.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the float host functions.
+    pub fn as_multiplier(self) -> Result<OpaqueFloat> {
+        OpaqueFloat::from_mant_exp(self.0 as i64, -9, FLOAT_ROUNDING_MODES_TO_NEAREST)
+    }
+
+    /// Returns the fee per unit received, e.g. `0.002` for a 0.2% fee.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the float host functions.
+    pub fn fee_fraction(self) -> Result<OpaqueFloat> {
+        OpaqueFloat::from_mant_exp(
+            self.fee_billionths() as i64,
+            -9,
+            FLOAT_ROUNDING_MODES_TO_NEAREST,
+        )
+    }
+
+    /// Returns what a sender pays for the recipient to receive `amount`. See
+    /// [`Amount::apply_transfer_rate`].
+    pub fn apply(self, amount: &Amount, rounding_mode: i32) -> Result<Amount> {
+        amount.apply_transfer_rate(self.0, rounding_mode)
+    }
+}
+
+impl Default for TransferRate {
+    fn default() -> Self {
+        Self::PARITY
+    }
+}
+
+/// Fails with `InvalidParams` like [`TransferRate::new`].
+impl TryFrom<u32> for TransferRate {
+    type Error = Error;
+
+    fn try_from(billionths: u32) -> core::result::Result<Self, Error> {
+        match Self::new(billionths) {
+            Result::Ok(rate) => core::result::Result::Ok(rate),
+            Result::Err(e) => core::result::Result::Err(e),
+        }
+    }
+}
+
+impl From<TransferRate> for u32 {
+    fn from(rate: TransferRate) -> Self {
+        rate.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::INVALID_PARAMS;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_new_validates_range() {
+        assert_eq!(TransferRate::new(0).unwrap(), TransferRate::PARITY);
+        assert_eq!(
+            TransferRate::new(1_002_000_000).unwrap().get(),
+            1_002_000_000
+        );
+        assert_eq!(
+            TransferRate::new(MAX_TRANSFER_RATE).unwrap(),
+            TransferRate::MAX
+        );
+        for invalid in [1, 999_999_999, MAX_TRANSFER_RATE + 1] {
+            assert_eq!(
+                TransferRate::new(invalid).err().unwrap().code(),
+                INVALID_PARAMS
+            );
+        }
+        assert!(TransferRate::try_from(5).is_err());
+        assert_eq!(
+            TransferRate::from_field(None).unwrap(),
+            TransferRate::PARITY
+        );
+    }
+
+    #[test]
+    fn test_fee_conversions() {
+        let rate = TransferRate::from_fee_bps(20).unwrap();
+        assert_eq!(u32::from(rate), 1_002_000_000);
+        assert_eq!(rate.fee_billionths(), 2_000_000);
+        assert!(!rate.is_parity());
+        assert!(TransferRate::default().is_parity());
+        assert_eq!(
+            TransferRate::from_fee_bps(10_000).unwrap(),
+            TransferRate::MAX
+        );
+        assert!(TransferRate::from_fee_bps(10_001).is_err());
+    }
+
+    #[test]
+    fn test_float_forms_use_billionths() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_float_from_mant_exp()
+            .with(eq(1_002_000_000), eq(-9), always(), eq(8), always())
+            .times(1)
+            .returning(|_, _, _, _, _| 8);
+        mock.expect_float_from_mant_exp()
+            .with(eq(2_000_000), eq(-9), always(), eq(8), always())
+            .times(1)
+            .returning(|_, _, _, _, _| 8);
+        let _guard = setup_mock(mock);
+
+        let rate = TransferRate::new(1_002_000_000).unwrap();
+        assert!(rate.as_multiplier().is_ok());
+        assert!(rate.fee_fraction().is_ok());
+    }
+}
@@ -20,14 +20,23 @@ use crate::sfield::SField;
 ///
 /// ## Derived Traits
 ///
-/// - `PartialEq, Eq`: Essential for comparisons and use in collections
+/// - `PartialEq, Eq, Hash`: Essential for comparisons and use in collections
+/// - `PartialOrd, Ord`: Byte-wise (big-endian numeric) ordering
 /// - `Debug, Clone`: Standard traits for development and consistency
 ///
-/// Note: `Copy` is intentionally not derived because `N` can be arbitrarily large.
+/// Note: `Copy` is intentionally not derived because `N` can be arbitrarily large. Only
+/// [`Hash256`], which is as cheap to copy as an `AccountID` and is kept in
+/// [`ArrayVec`](crate::collections::ArrayVec)s such as [`Vector256`](crate::types::vector256::Vector256),
+/// is `Copy`.
+///
+/// The derived `PartialEq` short-circuits on the first differing byte. When comparing against a
+/// secret (e.g. checking a preimage hash), use [`UInt::ct_eq`] instead.
 #[repr(C)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UInt<const N: usize>(pub [u8; N]);
 
+impl Copy for UInt<UINT256_SIZE> {}
+
 impl<const N: usize> From<[u8; N]> for UInt<N> {
     fn from(bytes: [u8; N]) -> Self {
         Self(bytes)
@@ -39,6 +48,13 @@ impl<const N: usize> UInt<N> {
     pub fn as_bytes(&self) -> &[u8; N] {
         &self.0
     }
+
+    /// Compares two values in time that depends only on `N`, not on where they differ.
+    ///
+    /// See [`crypto::ct_eq`](crate::crypto::ct_eq).
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        crate::crypto::ct_eq(&self.0, &other.0)
+    }
 }
 
 // Keep the existing constants for compatibility
@@ -265,6 +281,26 @@ mod tests {
         assert_ne!(uint1, uint3);
     }
 
+    #[test]
+    fn test_uint_ordering_is_bytewise() {
+        let low = UInt::<4>::from([0x00, 0xFF, 0xFF, 0xFF]);
+        let high = UInt::<4>::from([0x01, 0x00, 0x00, 0x00]);
+
+        assert!(low < high);
+        assert_eq!(low.clone().max(high.clone()), high);
+    }
+
+    #[test]
+    fn test_uint_ct_eq() {
+        let a = UInt::<32>::from([0xAA; 32]);
+        let mut last_differs = [0xAA; 32];
+        last_differs[31] = 0xAB;
+
+        assert!(a.ct_eq(&UInt::from([0xAA; 32])));
+        assert!(!a.ct_eq(&UInt::from(last_differs)));
+        assert!(!a.ct_eq(&UInt::from([0x00; 32])));
+    }
+
     #[test]
     #[allow(clippy::clone_on_copy)]
     fn test_uint_clone() {
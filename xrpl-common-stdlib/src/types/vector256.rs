@@ -0,0 +1,206 @@
+//! Whole-field reads of `Vector256` fields.
+//!
+//! A `Vector256` field (`CredentialIDs`, `Indexes`, `Amendments`, ...) is a list of 256-bit
+//! hashes, which the host returns as their 32-byte values concatenated. [`Vector256`] reads the
+//! whole list at once into an [`ArrayVec`]:
+//!
+//! ```rust,no_run
+//! use xrpl_common_stdlib::current_tx;
+//! use xrpl_common_stdlib::sfield;
+//!
+//! if let Some(ids) = current_tx::get_field_optional(sfield::CredentialIDs).unwrap() {
+//!     for id in ids.iter() {
+//!         let _ = id.as_bytes();
+//!     }
+//! }
+//! ```
+//!
+//! The generated `SField` constants use [`StandardVector256`], which holds
+//! [`DEFAULT_VECTOR256_CAPACITY`] entries. Reading a longer field fails with `BufferTooSmall`;
+//! for those (a ledger's `Hashes`, the `Amendments` singleton) read single entries with a
+//! [`Locator`](crate::fields::locator::Locator) instead, or name a larger capacity with
+//! `SField::<Vector256<N>, CODE>::new()`.
+
+use crate::collections::ArrayVec;
+use crate::current_tx::CurrentTxFieldGetter;
+use crate::host::error_codes::FIELD_NOT_FOUND;
+use crate::host::{
+    Error, Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field,
+};
+use crate::objects::LedgerObjectFieldGetter;
+use crate::sfield::SField;
+use crate::types::uint::{Hash256, UINT256_SIZE};
+
+/// Entries held by [`StandardVector256`]: enough for any `CredentialIDs` list (at most 8) and a
+/// full page of a directory's `Indexes` (at most 32).
+pub const DEFAULT_VECTOR256_CAPACITY: usize = 32;
+
+/// A list of up to `N` [`Hash256`] values read from a `Vector256` field.
+pub type Vector256<const N: usize> = ArrayVec<Hash256, N>;
+
+/// The type of the generated `Vector256` `SField` constants.
+pub type StandardVector256 = Vector256<DEFAULT_VECTOR256_CAPACITY>;
+
+/// Reads a field with `host_fn`, which writes it into a buffer of the given length and returns
+/// the host result code.
+fn read<const N: usize, F: FnOnce(*mut u8, usize) -> i32>(
+    host_fn: F,
+) -> Result<Option<Vector256<N>>> {
+    let mut vector = Vector256::<N>::new();
+    // `UInt` is `repr(C)` over a byte array, so the `N` slots are `N * 32` contiguous bytes and
+    // any bytes the host writes are valid entries.
+    let result_code = host_fn(vector.as_mut_ptr().cast(), N * UINT256_SIZE);
+    match result_code {
+        FIELD_NOT_FOUND => Result::Ok(None),
+        code if code < 0 => Result::Err(Error::from_code(code)),
+        code if code as usize % UINT256_SIZE != 0 => Result::Err(Error::InvalidDecoding),
+        code => {
+            // The host wrote `code` bytes into a buffer of `N` entries.
+            unsafe { vector.set_len((code as usize / UINT256_SIZE).min(N)) };
+            Result::Ok(Some(vector))
+        }
+    }
+}
+
+fn read_required<const N: usize, F: FnOnce(*mut u8, usize) -> i32>(
+    host_fn: F,
+) -> Result<Vector256<N>> {
+    match read(host_fn) {
+        Result::Ok(Some(vector)) => Result::Ok(vector),
+        Result::Ok(None) => Result::Err(Error::FieldNotFound),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Implementation of `LedgerObjectFieldGetter` for `Vector256` fields.
+///
+/// Fails with `BufferTooSmall` if the field has more than `N` entries, and with
+/// `InvalidDecoding` if the host returns a length that is not a multiple of 32 bytes.
+impl<const N: usize> LedgerObjectFieldGetter for Vector256<N> {
+    #[inline]
+    fn get_from_current_ledger_obj<const CODE: i32>(field: SField<Self, CODE>) -> Result<Self> {
+        read_required(|buf, size| unsafe {
+            get_current_ledger_obj_field(i32::from(field), buf, size)
+        })
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_optional<const CODE: i32>(
+        field: SField<Self, CODE>,
+    ) -> Result<Option<Self>> {
+        read(|buf, size| unsafe { get_current_ledger_obj_field(i32::from(field), buf, size) })
+    }
+
+    #[inline]
+    fn get_from_ledger_obj<const CODE: i32>(
+        register_num: i32,
+        field: SField<Self, CODE>,
+    ) -> Result<Self> {
+        read_required(|buf, size| unsafe {
+            get_ledger_obj_field(register_num, i32::from(field), buf, size)
+        })
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_optional<const CODE: i32>(
+        register_num: i32,
+        field: SField<Self, CODE>,
+    ) -> Result<Option<Self>> {
+        read(|buf, size| unsafe { get_ledger_obj_field(register_num, i32::from(field), buf, size) })
+    }
+}
+
+/// Implementation of `CurrentTxFieldGetter` for `Vector256` fields, with the same limits as the
+/// ledger object getters.
+impl<const N: usize> CurrentTxFieldGetter for Vector256<N> {
+    #[inline]
+    fn get_from_current_tx<const CODE: i32>(field: SField<Self, CODE>) -> Result<Self> {
+        read_required(|buf, size| unsafe { get_tx_field(i32::from(field), buf, size) })
+    }
+
+    #[inline]
+    fn get_from_current_tx_optional<const CODE: i32>(
+        field: SField<Self, CODE>,
+    ) -> Result<Option<Self>> {
+        read(|buf, size| unsafe { get_tx_field(i32::from(field), buf, size) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::current_tx;
+    use crate::host::error_codes::BUFFER_TOO_SMALL;
+    use crate::host::host_bindings_trait::MockHostBindings;
+    use crate::host::setup_mock;
+    use crate::objects::ledger_object;
+    use crate::sfield;
+    use mockall::predicate::{always, eq};
+
+    #[test]
+    fn test_reads_concatenated_entries() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(
+                eq(i32::from(sfield::CredentialIDs)),
+                always(),
+                eq(DEFAULT_VECTOR256_CAPACITY * UINT256_SIZE),
+            )
+            .times(1)
+            .returning(|_, out, _| {
+                unsafe {
+                    core::ptr::write_bytes(out, 0xAA, 32);
+                    core::ptr::write_bytes(out.add(32), 0xBB, 32);
+                }
+                64
+            });
+        let _guard = setup_mock(mock);
+
+        let ids = current_tx::get_field(sfield::CredentialIDs).unwrap();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids.get(0), Some(&Hash256::from([0xAA; 32])));
+        assert!(ids.contains(&Hash256::from([0xBB; 32])));
+        assert_eq!(ids.get(2), None);
+        assert_eq!(ids.iter().count(), 2);
+    }
+
+    const SMALL_INDEXES: SField<Vector256<2>, 1245185> = SField::new();
+
+    #[test]
+    fn test_missing_oversized_and_malformed_fields() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .with(eq(1), always(), always(), eq(2 * UINT256_SIZE))
+            .returning(|_, _, _, _| FIELD_NOT_FOUND);
+        mock.expect_get_ledger_obj_field()
+            .with(eq(2), always(), always(), always())
+            .returning(|_, _, _, _| BUFFER_TOO_SMALL);
+        mock.expect_get_ledger_obj_field()
+            .with(eq(3), always(), always(), always())
+            .returning(|_, _, _, _| 33);
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            ledger_object::get_field_optional(1, SMALL_INDEXES).unwrap(),
+            None
+        );
+        assert_eq!(
+            ledger_object::get_field(1, SMALL_INDEXES)
+                .err()
+                .unwrap()
+                .code(),
+            FIELD_NOT_FOUND
+        );
+        assert_eq!(
+            ledger_object::get_field(2, SMALL_INDEXES)
+                .err()
+                .unwrap()
+                .code(),
+            BUFFER_TOO_SMALL
+        );
+        assert!(matches!(
+            ledger_object::get_field(3, SMALL_INDEXES).err(),
+            Some(Error::InvalidDecoding)
+        ));
+    }
+}
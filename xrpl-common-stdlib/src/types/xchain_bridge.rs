@@ -0,0 +1,255 @@
+use crate::current_tx::CurrentTxFieldGetter;
+use crate::host::field_helpers::{get_variable_size_field, get_variable_size_field_optional};
+use crate::host::{
+    Error, Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field,
+    transpose_option,
+};
+use crate::objects::LedgerObjectFieldGetter;
+use crate::sfield::SField;
+use crate::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::types::currency::{CURRENCY_SIZE, Currency};
+use crate::types::issue::{IouIssue, Issue, XrpIssue};
+
+/// Largest serialized `XChainBridge`: two length-prefixed accounts and two IOU issues.
+pub const XCHAIN_BRIDGE_MAX_SIZE: usize = 2 * (1 + ACCOUNT_ID_SIZE) + 2 * (CURRENCY_SIZE + 20);
+
+/// Selects one side of a bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainType {
+    /// The chain where the bridged asset is locked.
+    Locking,
+    /// The chain where the wrapped asset is issued.
+    Issuing,
+}
+
+impl ChainType {
+    /// The other side of the bridge.
+    pub fn other(self) -> Self {
+        match self {
+            ChainType::Locking => ChainType::Issuing,
+            ChainType::Issuing => ChainType::Locking,
+        }
+    }
+}
+
+/// The `XChainBridge` field: the door account and bridged issue on each chain.
+///
+/// Bridges only carry XRP or IOU issues, so an [`Issue::MPT`] never appears here.
+///
+/// ## Derived Traits
+///
+/// - `PartialEq, Eq`: Compare bridges, e.g. against one a contract expects
+/// - `Debug, Clone`: Standard traits for development and consistency
+///
+/// Note: `Copy` is intentionally not derived because each IOU issue is 40 bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XChainBridge {
+    pub locking_chain_door: AccountID,
+    pub locking_chain_issue: Issue,
+    pub issuing_chain_door: AccountID,
+    pub issuing_chain_issue: Issue,
+}
+
+impl XChainBridge {
+    /// The door account on the given side of the bridge.
+    pub fn door(&self, chain: ChainType) -> &AccountID {
+        match chain {
+            ChainType::Locking => &self.locking_chain_door,
+            ChainType::Issuing => &self.issuing_chain_door,
+        }
+    }
+
+    /// The bridged issue on the given side of the bridge.
+    pub fn issue(&self, chain: ChainType) -> &Issue {
+        match chain {
+            ChainType::Locking => &self.locking_chain_issue,
+            ChainType::Issuing => &self.issuing_chain_issue,
+        }
+    }
+
+    /// Decodes the field's serialized form: each door is a length-prefixed account, and each
+    /// issue is a currency code followed by the issuer unless the currency is XRP (all zeros).
+    ///
+    /// Returns `InvalidDecoding` if `bytes` is truncated, has trailing data, or a door's length
+    /// prefix is not 20.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut rest = bytes;
+        let locking_chain_door = take_door(&mut rest);
+        let locking_chain_issue = take_issue(&mut rest);
+        let issuing_chain_door = take_door(&mut rest);
+        let issuing_chain_issue = take_issue(&mut rest);
+        match (
+            locking_chain_door,
+            locking_chain_issue,
+            issuing_chain_door,
+            issuing_chain_issue,
+        ) {
+            (Some(lcd), Some(lci), Some(icd), Some(ici)) if rest.is_empty() => {
+                Result::Ok(XChainBridge {
+                    locking_chain_door: lcd,
+                    locking_chain_issue: lci,
+                    issuing_chain_door: icd,
+                    issuing_chain_issue: ici,
+                })
+            }
+            _ => Result::Err(Error::InvalidDecoding),
+        }
+    }
+}
+
+fn take<const N: usize>(rest: &mut &[u8]) -> Option<[u8; N]> {
+    let (head, tail) = rest.split_first_chunk::<N>()?;
+    *rest = tail;
+    Some(*head)
+}
+
+fn take_door(rest: &mut &[u8]) -> Option<AccountID> {
+    match take::<1>(rest)? {
+        [len] if len as usize == ACCOUNT_ID_SIZE => take::<ACCOUNT_ID_SIZE>(rest).map(AccountID),
+        _ => None,
+    }
+}
+
+fn take_issue(rest: &mut &[u8]) -> Option<Issue> {
+    let currency = take::<CURRENCY_SIZE>(rest)?;
+    if currency == [0; CURRENCY_SIZE] {
+        return Some(Issue::XRP(XrpIssue {}));
+    }
+    let issuer = take::<ACCOUNT_ID_SIZE>(rest)?;
+    Some(Issue::IOU(IouIssue::new(
+        AccountID(issuer),
+        Currency::from(currency),
+    )))
+}
+
+fn decode_buffer((buffer, len): ([u8; XCHAIN_BRIDGE_MAX_SIZE], usize)) -> Result<XChainBridge> {
+    XChainBridge::decode(&buffer[..len.min(XCHAIN_BRIDGE_MAX_SIZE)])
+}
+
+/// Implementation of `LedgerObjectFieldGetter` for the `XChainBridge` field of `Bridge` and
+/// `XChainOwnedClaimID` ledger objects.
+///
+/// # Buffer Management
+///
+/// Uses a buffer of [`XCHAIN_BRIDGE_MAX_SIZE`] bytes, large enough for a bridge with two IOU
+/// issues, and decodes however many bytes the host wrote.
+impl LedgerObjectFieldGetter for XChainBridge {
+    #[inline]
+    fn get_from_current_ledger_obj<const CODE: i32>(field: SField<Self, CODE>) -> Result<Self> {
+        get_variable_size_field::<XCHAIN_BRIDGE_MAX_SIZE, _>(field, |fc, buf, size| unsafe {
+            get_current_ledger_obj_field(fc, buf, size)
+        })
+        .and_then(decode_buffer)
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_optional<const CODE: i32>(
+        field: SField<Self, CODE>,
+    ) -> Result<Option<Self>> {
+        get_variable_size_field_optional::<XCHAIN_BRIDGE_MAX_SIZE, _>(
+            field,
+            |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
+        )
+        .and_then(|opt| transpose_option(opt.map(decode_buffer)))
+    }
+
+    #[inline]
+    fn get_from_ledger_obj<const CODE: i32>(
+        register_num: i32,
+        field: SField<Self, CODE>,
+    ) -> Result<Self> {
+        get_variable_size_field::<XCHAIN_BRIDGE_MAX_SIZE, _>(field, |fc, buf, size| unsafe {
+            get_ledger_obj_field(register_num, fc, buf, size)
+        })
+        .and_then(decode_buffer)
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_optional<const CODE: i32>(
+        register_num: i32,
+        field: SField<Self, CODE>,
+    ) -> Result<Option<Self>> {
+        get_variable_size_field_optional::<XCHAIN_BRIDGE_MAX_SIZE, _>(
+            field,
+            |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
+        )
+        .and_then(|opt| transpose_option(opt.map(decode_buffer)))
+    }
+}
+
+/// Implementation of `CurrentTxFieldGetter` for the `XChainBridge` field of XChain transactions.
+impl CurrentTxFieldGetter for XChainBridge {
+    #[inline]
+    fn get_from_current_tx<const CODE: i32>(field: SField<Self, CODE>) -> Result<Self> {
+        get_variable_size_field::<XCHAIN_BRIDGE_MAX_SIZE, _>(field, |fc, buf, size| unsafe {
+            get_tx_field(fc, buf, size)
+        })
+        .and_then(decode_buffer)
+    }
+
+    #[inline]
+    fn get_from_current_tx_optional<const CODE: i32>(
+        field: SField<Self, CODE>,
+    ) -> Result<Option<Self>> {
+        get_variable_size_field_optional::<XCHAIN_BRIDGE_MAX_SIZE, _>(
+            field,
+            |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
+        )
+        .and_then(|opt| transpose_option(opt.map(decode_buffer)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCKING_DOOR: [u8; 20] = [0x11; 20];
+    const ISSUING_DOOR: [u8; 20] = [0x22; 20];
+    const ISSUER: [u8; 20] = [0x33; 20];
+
+    /// An XRP-to-IOU bridge, serialized the way the host returns it.
+    fn xrp_to_iou_bytes() -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::new();
+        bytes.push(20);
+        bytes.extend_from_slice(&LOCKING_DOOR);
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.push(20);
+        bytes.extend_from_slice(&ISSUING_DOOR);
+        bytes.extend_from_slice(&Currency::from(*b"USD").0);
+        bytes.extend_from_slice(&ISSUER);
+        bytes
+    }
+
+    #[test]
+    fn test_decode_xrp_to_iou_bridge() {
+        let bridge = XChainBridge::decode(&xrp_to_iou_bytes()).unwrap();
+        assert_eq!(bridge.door(ChainType::Locking), &AccountID(LOCKING_DOOR));
+        assert_eq!(bridge.issue(ChainType::Locking), &Issue::XRP(XrpIssue {}));
+        assert_eq!(bridge.door(ChainType::Issuing), &AccountID(ISSUING_DOOR));
+        assert_eq!(
+            bridge.issue(ChainType::Issuing),
+            &Issue::IOU(IouIssue::new(AccountID(ISSUER), Currency::from(*b"USD")))
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_and_trailing_bytes() {
+        let bytes = xrp_to_iou_bytes();
+        let result = XChainBridge::decode(&bytes[..bytes.len() - 1]);
+        assert_eq!(result.err().unwrap().code(), Error::InvalidDecoding.code());
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(XChainBridge::decode(&trailing).is_err());
+
+        let mut bad_prefix = bytes;
+        bad_prefix[0] = 19;
+        assert!(XChainBridge::decode(&bad_prefix).is_err());
+    }
+
+    #[test]
+    fn test_chain_type_other() {
+        assert_eq!(ChainType::Locking.other(), ChainType::Issuing);
+        assert_eq!(ChainType::Issuing.other(), ChainType::Locking);
+    }
+}
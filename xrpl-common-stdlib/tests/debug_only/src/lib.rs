@@ -0,0 +1,12 @@
+#![no_std]
+
+use xrpl_common_stdlib::assert::debug_only;
+use xrpl_common_stdlib::host::trace::trace_num;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn finish() -> i32 {
+    debug_only! {
+        let _ = trace_num("debug only:", 1);
+    }
+    1
+}
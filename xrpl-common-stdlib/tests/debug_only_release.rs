@@ -0,0 +1,109 @@
+//! Checks that `debug_only!` blocks leave no trace in a release WASM.
+//!
+//! Builds the contract in `tests/debug_only` for `wasm32v1-none` with each profile and reads the
+//! host functions it imports. The block calls `trace_num`, so the import must be present in a dev
+//! build and in a release build with `keep-debug-only`, and absent from a plain release build.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/debug_only");
+
+/// Builds the fixture and returns the path of its `.wasm`.
+fn build(release: bool, keep_debug_only: bool) -> PathBuf {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!(
+        "debug_only_{}{}",
+        if release { "release" } else { "dev" },
+        if keep_debug_only { "_kept" } else { "" }
+    ));
+    let mut cargo = Command::new(env!("CARGO"));
+    cargo
+        .current_dir(FIXTURE)
+        .args(["build", "--target", "wasm32v1-none", "--target-dir"])
+        .arg(&target_dir);
+    if release {
+        cargo.arg("--release");
+    }
+    if keep_debug_only {
+        cargo.args(["--features", "keep-debug-only"]);
+    }
+    let status = cargo.status().expect("failed to run cargo");
+    assert!(status.success(), "building the debug_only fixture failed");
+    target_dir
+        .join("wasm32v1-none")
+        .join(if release { "release" } else { "debug" })
+        .join("debug_only_fixture.wasm")
+}
+
+fn read_leb_u32(bytes: &[u8], pos: &mut usize) -> u32 {
+    let mut value = 0u32;
+    let mut shift = 0;
+    loop {
+        let byte = bytes[*pos];
+        *pos += 1;
+        value |= u32::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+fn read_name(bytes: &[u8], pos: &mut usize) -> String {
+    let len = read_leb_u32(bytes, pos) as usize;
+    let name = String::from_utf8(bytes[*pos..*pos + len].to_vec()).expect("import name is UTF-8");
+    *pos += len;
+    name
+}
+
+/// Returns the `module.field` names of every import in a WASM module.
+fn imports(wasm: &Path) -> Vec<String> {
+    const IMPORT_SECTION: u8 = 2;
+    let bytes = std::fs::read(wasm).expect("failed to read the fixture WASM");
+    assert_eq!(&bytes[..4], b"\0asm", "not a WASM module");
+    let mut pos = 8;
+    while pos < bytes.len() {
+        let id = bytes[pos];
+        pos += 1;
+        let size = read_leb_u32(&bytes, &mut pos) as usize;
+        if id != IMPORT_SECTION {
+            pos += size;
+            continue;
+        }
+        let mut names = Vec::new();
+        for _ in 0..read_leb_u32(&bytes, &mut pos) {
+            let module = read_name(&bytes, &mut pos);
+            let field = read_name(&bytes, &mut pos);
+            match bytes[pos] {
+                // Function: type index.
+                0 => {
+                    pos += 1;
+                    read_leb_u32(&bytes, &mut pos);
+                }
+                kind => panic!("unexpected import kind {kind} for {module}.{field}"),
+            }
+            names.push(format!("{module}.{field}"));
+        }
+        return names;
+    }
+    Vec::new()
+}
+
+fn imports_trace_num(release: bool, keep_debug_only: bool) -> bool {
+    imports(&build(release, keep_debug_only))
+        .iter()
+        .any(|name| name == "host_lib.trace_num")
+}
+
+#[test]
+fn test_release_wasm_excludes_debug_only_imports() {
+    assert!(imports_trace_num(false, false), "dev build lost the trace");
+    assert!(
+        !imports_trace_num(true, false),
+        "release build kept the trace"
+    );
+    assert!(
+        imports_trace_num(true, true),
+        "keep-debug-only did not keep the trace"
+    );
+}
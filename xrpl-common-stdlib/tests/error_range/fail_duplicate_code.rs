@@ -0,0 +1,13 @@
+//! Two codes with the same number in one domain must fail to compile.
+
+use xrpl_common_stdlib::error_domain::error_range;
+
+error_range! {
+    mod ledger = 1 {
+        SEQUENCE = 1,
+        PARENT_TIME = 2,
+        PARENT_HASH = 2,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! Two domains with the same id would hand out the same codes, so they must fail to compile.
+
+use xrpl_common_stdlib::error_domain::error_range;
+
+error_range! {
+    mod ledger = 1 {
+        SEQUENCE = 1,
+    }
+    mod keylets = 1 {
+        ACCOUNT = 1,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! Domain 0 overlaps the host's error codes and must fail to compile.
+
+use xrpl_common_stdlib::error_domain::error_range;
+
+error_range! {
+    mod host_like = 0 {
+        FAILED = 1,
+    }
+}
+
+fn main() {}
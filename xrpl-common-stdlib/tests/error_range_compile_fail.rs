@@ -0,0 +1,10 @@
+//! Compile-fail tests proving that `error_range!` rejects colliding domains and codes.
+//!
+//! Regenerate snapshots with:
+//!   TRYBUILD=overwrite cargo test -p xrpl-common-stdlib --test error_range_compile_fail
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/error_range/fail_*.rs");
+}
@@ -1,5 +1,6 @@
 //! Happy-path integration tests for every compile-time literal macro re-exported
-//! from this crate (`r_address!`, `hash256!`, `pubkey!`, `currency!`, `blob!`).
+//! from this crate (`r_address!`, `hash256!`, `pubkey!`, `currency!`, `blob!`, `xrp!`,
+//! `amendment_id!`, `contract_metadata!`).
 //!
 //! Each test invokes the macro in a `const` binding and asserts the resulting
 //! struct contents. The point of these tests is to cover the boundaries the
@@ -11,12 +12,18 @@
 //!
 //! Compile-fail rejection paths live in `xrpl-macros/tests/compile_fail.rs`.
 
-use xrpl_common_stdlib::types::account_id::AccountID;
+use xrpl_common_stdlib::metadata::ContractMetadata;
+use xrpl_common_stdlib::types::account_id::{
+    ACCOUNT_ONE, ACCOUNT_ZERO, AccountID, GENESIS_ACCOUNT,
+};
+use xrpl_common_stdlib::types::amount::Amount;
 use xrpl_common_stdlib::types::blob::Blob;
 use xrpl_common_stdlib::types::currency::Currency;
 use xrpl_common_stdlib::types::public_key::PublicKey;
 use xrpl_common_stdlib::types::uint::Hash256;
-use xrpl_common_stdlib::{blob, currency, hash256, pubkey, r_address};
+use xrpl_common_stdlib::{
+    amendment_id, blob, contract_metadata, currency, hash256, pubkey, r_address, xrp,
+};
 
 #[test]
 fn r_address_expands_to_const_account_id() {
@@ -98,3 +105,46 @@ fn blob_expands_to_const_with_padded_capacity() {
     assert_eq!(B.as_slice(), &[0xDE, 0xAD, 0xBE, 0xEF]);
     assert_eq!(&B.data[4..], &[0u8; 4]);
 }
+
+#[test]
+fn xrp_expands_to_const_drops() {
+    const ONE_AND_A_HALF: Amount = xrp!(1.5);
+    const REFUND: Amount = xrp!(-0.000001);
+    assert_eq!(
+        ONE_AND_A_HALF,
+        Amount::XRP {
+            num_drops: 1_500_000
+        }
+    );
+    assert_eq!(REFUND, Amount::XRP { num_drops: -1 });
+    assert_eq!(xrp!(20), Amount::xrp_from_whole(20));
+}
+
+#[test]
+fn amendment_id_expands_to_const_id() {
+    const MULTI_SIGN: [u8; 32] = amendment_id!("MultiSign");
+    assert_eq!(
+        MULTI_SIGN,
+        hash256!("4C97EBA926031A7CF7D7B36FDE3ED66DDA5421192D63DE53FFB46E43B9DC8373").0
+    );
+}
+
+contract_metadata! { name: "macro_tests", version: "0.1.0", abi: 1 }
+
+#[test]
+fn contract_metadata_round_trips_through_parse() {
+    let metadata = ContractMetadata::parse(&CONTRACT_METADATA).unwrap();
+    assert_eq!(metadata.name, "macro_tests");
+    assert_eq!(metadata.version, "0.1.0");
+    assert_eq!(metadata.abi, 1);
+}
+
+#[test]
+fn r_address_matches_well_known_account_constants() {
+    assert_eq!(r_address!("rrrrrrrrrrrrrrrrrrrrrhoLvTp"), ACCOUNT_ZERO);
+    assert_eq!(r_address!("rrrrrrrrrrrrrrrrrrrrBZbvji"), ACCOUNT_ONE);
+    assert_eq!(
+        r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+        GENESIS_ACCOUNT
+    );
+}
@@ -0,0 +1,24 @@
+//! Tests for `sfield::name`. They live here because `src/sfield.rs` is generated and would lose
+//! an inline test module on the next regeneration.
+
+#![cfg(feature = "sfield-names")]
+
+use xrpl_common_stdlib::sfield;
+
+#[test]
+fn test_name_round_trips_field_codes() {
+    assert_eq!(sfield::name(sfield::Account.into()), Some("Account"));
+    assert_eq!(sfield::name(sfield::Flags.into()), Some("Flags"));
+    assert_eq!(
+        sfield::name(sfield::FinishFunction.into()),
+        Some("FinishFunction")
+    );
+    assert_eq!(sfield::name(sfield::Memos.into()), Some("Memos"));
+}
+
+#[test]
+fn test_name_rejects_unknown_and_sentinel_codes() {
+    assert_eq!(sfield::name(sfield::Invalid.into()), None);
+    assert_eq!(sfield::name(sfield::Generic.into()), None);
+    assert_eq!(sfield::name(0x7FFF_FFFF), None);
+}
@@ -0,0 +1,117 @@
+use xrpl_common_stdlib::ctx::{EntryContext, ExecutionContext, SmartFeatureContext};
+use xrpl_common_stdlib::host::Result;
+use xrpl_common_stdlib::host::chain::parent_ledger_time;
+use xrpl_common_stdlib::objects::escrow::EscrowReadFields;
+
+use crate::current_tx::escrow_cancel::EscrowCancel;
+use crate::ledger_objects::current_escrow::CurrentEscrow;
+
+/// [`ExecutionContext`] for an escrow cancel: the `EscrowCancel` transaction, the escrow being
+/// canceled, ledger info and a pool of `SLOTS` cache slots.
+pub type EscrowCancelExecutionContext<const SLOTS: usize = 8> =
+    ExecutionContext<EscrowCancel, CurrentEscrow, SLOTS>;
+
+/// Entry-point context for code that runs when a Smart Escrow is canceled.
+///
+/// Hosts only call `finish` today. This context is the counterpart of
+/// [`EscrowFinishContext`](crate::EscrowFinishContext) for the `cancel` export, so a contract can
+/// be written for it now with `#[entry_point(cancel)]`:
+///
+/// ```rust,ignore
+/// use xrpl_escrow_stdlib::host::Result;
+/// use xrpl_escrow_stdlib::{entry_point, EscrowCancelContext, FinishResult};
+///
+/// #[entry_point(cancel)]
+/// fn on_cancel(ctx: EscrowCancelContext) -> FinishResult {
+///     match ctx.cancel_after_passed() {
+///         Result::Ok(true) => FinishResult::succeed(),
+///         _ => FinishResult::reject(),
+///     }
+/// }
+/// ```
+///
+/// The return value is assumed to follow the `finish` convention, a positive value letting the
+/// cancel go ahead, until hosts define one.
+pub struct EscrowCancelContext {
+    tx: EscrowCancel,
+    escrow: CurrentEscrow,
+}
+
+impl Default for EscrowCancelContext {
+    fn default() -> Self {
+        Self {
+            tx: EscrowCancel,
+            escrow: CurrentEscrow,
+        }
+    }
+}
+
+impl EntryContext for EscrowCancelContext {
+    const ENTRY_POINT: &'static str = "cancel";
+}
+
+impl SmartFeatureContext for EscrowCancelContext {
+    type Tx = EscrowCancel;
+    fn tx(&self) -> &Self::Tx {
+        &self.tx
+    }
+}
+
+impl EscrowCancelContext {
+    /// Returns a reference to the escrow being canceled.
+    pub fn escrow(&self) -> &CurrentEscrow {
+        &self.escrow
+    }
+
+    /// Creates the [`EscrowCancelExecutionContext`] to pass to helper functions.
+    pub fn execution<const SLOTS: usize>(&self) -> EscrowCancelExecutionContext<SLOTS> {
+        ExecutionContext::new(self.tx, self.escrow)
+    }
+
+    /// Returns whether the escrow's `CancelAfter` has passed as of the parent ledger's close
+    /// time; see [`EscrowReadFields::can_cancel`].
+    pub fn cancel_after_passed(&self) -> Result<bool> {
+        parent_ledger_time().and_then(|now| self.escrow.can_cancel(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::{always, eq};
+    use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
+    use xrpl_common_stdlib::host::setup_mock;
+    use xrpl_common_stdlib::sfield;
+
+    #[test]
+    fn tx_and_escrow_accessors() {
+        let ctx = EscrowCancelContext::default();
+        let _tx: &EscrowCancel = ctx.tx();
+        let _escrow: &CurrentEscrow = ctx.escrow();
+        let execution: EscrowCancelExecutionContext = ctx.execution();
+        assert_eq!(*execution.tx(), EscrowCancel);
+    }
+
+    #[test]
+    fn cancel_after_passed_compares_against_parent_close_time() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_parent_ledger_time().returning(|out, _| {
+            unsafe { core::ptr::copy_nonoverlapping(2_000u32.to_le_bytes().as_ptr(), out, 4) };
+            4
+        });
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::CancelAfter)), always(), eq(4))
+            .times(1)
+            .returning(|_, out, _| {
+                unsafe { core::ptr::copy_nonoverlapping(1_999u32.to_le_bytes().as_ptr(), out, 4) };
+                4
+            });
+        let _guard = setup_mock(mock);
+
+        assert!(
+            EscrowCancelContext::default()
+                .cancel_after_passed()
+                .unwrap()
+        );
+    }
+}
@@ -1,8 +1,16 @@
-use xrpl_common_stdlib::ctx::SmartFeatureContext;
+use xrpl_common_stdlib::ctx::{EntryContext, ExecutionContext, SmartFeatureContext};
 use xrpl_common_stdlib::host;
+use xrpl_common_stdlib::types::contract_data::ContractData;
+use xrpl_common_stdlib::types::data_cursor::{DataCursor, DataSection};
 
 use crate::current_tx::escrow_finish::EscrowFinish;
 use crate::ledger_objects::current_escrow::CurrentEscrow;
+use crate::ledger_objects::traits::CurrentEscrowFields;
+
+/// [`ExecutionContext`] for an escrow finish: the `EscrowFinish` transaction, the escrow being
+/// finished, ledger info and a pool of `SLOTS` cache slots.
+pub type EscrowExecutionContext<const SLOTS: usize = 8> =
+    ExecutionContext<EscrowFinish, CurrentEscrow, SLOTS>;
 
 /// Entry-point context for a Smart Escrow finish operation.
 ///
@@ -12,8 +20,8 @@ use crate::ledger_objects::current_escrow::CurrentEscrow;
 /// (e.g., [`update_data`](EscrowFinishContext::update_data)) are exposed as
 /// safe inherent methods; no `unsafe` code is needed in user crates.
 ///
-/// The `#[smart_escrow]` macro constructs this via `Default::default()` and
-/// passes it to the user function.
+/// The `#[smart_escrow]` macro (or `#[entry_point(finish)]`) constructs this via
+/// `Default::default()` and passes it to the user function.
 pub struct EscrowFinishContext {
     tx: EscrowFinish,
     escrow: CurrentEscrow,
@@ -28,6 +36,10 @@ impl Default for EscrowFinishContext {
     }
 }
 
+impl EntryContext for EscrowFinishContext {
+    const ENTRY_POINT: &'static str = "finish";
+}
+
 impl SmartFeatureContext for EscrowFinishContext {
     type Tx = EscrowFinish;
     fn tx(&self) -> &Self::Tx {
@@ -41,6 +53,11 @@ impl EscrowFinishContext {
         &self.escrow
     }
 
+    /// Creates the [`EscrowExecutionContext`] to pass to helper functions.
+    pub fn execution<const SLOTS: usize>(&self) -> EscrowExecutionContext<SLOTS> {
+        ExecutionContext::new(self.tx, self.escrow)
+    }
+
     /// **[host fn]** Write new data to the Smart Escrow object.
     pub fn update_data(&self, data: &[u8]) -> host::Result<()> {
         let n = unsafe { host::update_data(data.as_ptr(), data.len()) };
@@ -49,14 +66,97 @@ impl EscrowFinishContext {
         }
         host::Result::Ok(())
     }
+
+    /// **[host fn]** Reads the contract state kept in the escrow's `Data` field.
+    ///
+    /// A missing `Data` field, and any section past the end of the stored bytes, reads as its
+    /// `Default` (see [`DataSection`]). To keep state from several modules in `Data`, load a
+    /// tuple of their sections.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidDecoding` - If `Data` does not hold a valid `S`, or holds bytes after it
+    /// * Any error returned while reading the `Data` field
+    pub fn load_state<S: DataSection>(&self) -> host::Result<S> {
+        let mut data = match self.escrow.get_data() {
+            host::Result::Ok(data) => data,
+            host::Result::Err(host::Error::FieldNotFound) => ContractData::new(),
+            host::Result::Err(e) => return host::Result::Err(e),
+        };
+        let mut cursor = DataCursor::new(&mut data);
+        cursor.read_section().and_then(|state| {
+            if cursor.remaining() == 0 {
+                host::Result::Ok(state)
+            } else {
+                host::Result::Err(host::Error::InvalidDecoding)
+            }
+        })
+    }
+
+    /// **[host fn]** Replaces the escrow's `Data` field with `state`.
+    ///
+    /// # Errors
+    ///
+    /// * `DataFieldTooLarge` - If `state` does not fit in the `Data` field
+    /// * Any error returned while writing the `Data` field
+    pub fn save_state<S: DataSection>(&self, state: &S) -> host::Result<()> {
+        let mut data = ContractData::new();
+        DataCursor::new(&mut data)
+            .write_section(state)
+            .and_then(|()| self.update_data(data.as_slice()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mockall::predicate::{always, eq};
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
     use xrpl_common_stdlib::host::Error;
+    use xrpl_common_stdlib::host::error_codes::{FIELD_NOT_FOUND, INVALID_DECODING};
+    use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
+    use xrpl_common_stdlib::host::setup_mock;
+    use xrpl_common_stdlib::sfield;
     use xrpl_stdlib_test_utils::EscrowScenario;
 
+    #[derive(Debug, Default, PartialEq)]
+    struct Counter(u32);
+
+    impl DataSection for Counter {
+        fn read_section(cursor: &mut DataCursor) -> host::Result<Self> {
+            cursor.read_u32_le().map(Counter)
+        }
+
+        fn write_section(&self, cursor: &mut DataCursor) -> host::Result<()> {
+            cursor.write_u32_le(self.0)
+        }
+    }
+
+    /// Wires the escrow's `Data` field to `stored` (missing if `None`) and captures whatever is
+    /// passed to `update_data`.
+    fn mock_data(stored: Option<Vec<u8>>) -> (MockHostBindings, Arc<Mutex<Option<Vec<u8>>>>) {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::Data)), always(), always())
+            .returning(move |_, ptr, _| match &stored {
+                Some(bytes) => {
+                    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len()) };
+                    bytes.len() as i32
+                }
+                None => FIELD_NOT_FOUND,
+            });
+
+        let written = Arc::new(Mutex::new(None));
+        let sink = written.clone();
+        mock.expect_update_data().returning(move |ptr, len| {
+            let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+            *sink.lock().unwrap() = Some(bytes.to_vec());
+            len as i32
+        });
+        (mock, written)
+    }
+
     #[test]
     fn default_constructs() {
         let _ctx = EscrowFinishContext::default();
@@ -69,6 +169,14 @@ mod tests {
         let _escrow: &CurrentEscrow = ctx.escrow();
     }
 
+    #[test]
+    fn execution_bundles_tx_and_escrow() {
+        let ctx = EscrowFinishContext::default();
+        let execution: EscrowExecutionContext = ctx.execution();
+        assert_eq!(*execution.tx(), EscrowFinish);
+        assert_eq!(*execution.object(), CurrentEscrow);
+    }
+
     #[test]
     fn update_data_returns_ok_on_success() {
         let _guard = EscrowScenario::builder()
@@ -88,4 +196,48 @@ mod tests {
         let ctx = EscrowFinishContext::default();
         assert!(ctx.update_data(b"payload").is_err());
     }
+
+    #[test]
+    fn load_state_of_missing_data_is_default() {
+        let (mock, _written) = mock_data(None);
+        let _guard = setup_mock(mock);
+
+        let ctx = EscrowFinishContext::default();
+        assert_eq!(ctx.load_state::<Counter>().unwrap(), Counter(0));
+    }
+
+    #[test]
+    fn save_state_writes_sections_in_order() {
+        let (mock, written) = mock_data(None);
+        let _guard = setup_mock(mock);
+
+        let ctx = EscrowFinishContext::default();
+        ctx.save_state(&(Counter(1), Counter(2))).unwrap();
+
+        let mut expected = 1u32.to_le_bytes().to_vec();
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        assert_eq!(written.lock().unwrap().as_deref(), Some(&expected[..]));
+    }
+
+    #[test]
+    fn load_state_reads_appended_sections_as_default() {
+        let (mock, _written) = mock_data(Some(5u32.to_le_bytes().to_vec()));
+        let _guard = setup_mock(mock);
+
+        let ctx = EscrowFinishContext::default();
+        assert_eq!(
+            ctx.load_state::<(Counter, Counter)>().unwrap(),
+            (Counter(5), Counter(0))
+        );
+    }
+
+    #[test]
+    fn load_state_rejects_trailing_bytes() {
+        let (mock, _written) = mock_data(Some(vec![1, 0, 0, 0, 0xFF]));
+        let _guard = setup_mock(mock);
+
+        let ctx = EscrowFinishContext::default();
+        let result = ctx.load_state::<Counter>();
+        assert_eq!(result.err().unwrap().code(), INVALID_DECODING);
+    }
 }
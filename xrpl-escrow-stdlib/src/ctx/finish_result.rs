@@ -1,3 +1,6 @@
+use xrpl_common_stdlib::host::Error;
+use xrpl_common_stdlib::host::context::ContextError;
+
 /// Return type for Smart Feature entry points.
 ///
 /// Wraps the `i32` the host inspects after a Smart Feature's WASM function
@@ -77,6 +80,23 @@ impl From<i32> for FinishResult {
     }
 }
 
+/// Blocks the Smart Feature with the error's (negative) code, so a host error can be returned
+/// as-is: `return e.into();`
+impl From<Error> for FinishResult {
+    fn from(error: Error) -> Self {
+        Self(error.code())
+    }
+}
+
+/// Blocks the Smart Feature with the error's contract-defined code, or its host code if it has
+/// none. An error always blocks: a positive contract-defined code, which would otherwise allow the
+/// transaction, becomes `0`.
+impl From<ContextError> for FinishResult {
+    fn from(error: ContextError) -> Self {
+        Self(error.code().min(0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +172,40 @@ mod tests {
             host_error_code
         );
     }
+
+    // --- From<Error> (used by `ensure!`) ---
+
+    #[test]
+    fn from_error_rejects_with_its_code() {
+        assert_eq!(
+            i32::from(FinishResult::from(Error::FieldNotFound)),
+            Error::FieldNotFound.code()
+        );
+    }
+
+    #[test]
+    fn from_context_error_prefers_the_context_code() {
+        assert_eq!(
+            i32::from(FinishResult::from(ContextError::new(
+                -301,
+                Error::FieldNotFound
+            ))),
+            -301
+        );
+        assert_eq!(
+            i32::from(FinishResult::from(ContextError::from(Error::FieldNotFound))),
+            Error::FieldNotFound.code()
+        );
+    }
+
+    #[test]
+    fn from_context_error_with_positive_code_rejects() {
+        assert_eq!(
+            i32::from(FinishResult::from(ContextError::new(
+                301,
+                Error::FieldNotFound
+            ))),
+            0
+        );
+    }
 }
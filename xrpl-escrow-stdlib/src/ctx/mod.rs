@@ -1,2 +1,3 @@
+pub mod escrow_cancel;
 pub mod escrow_finish;
 pub mod finish_result;
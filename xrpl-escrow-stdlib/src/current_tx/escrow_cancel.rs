@@ -0,0 +1,20 @@
+//! # EscrowCancel
+//!
+//! This module provides functionality for handling EscrowCancel transactions within the
+//! XRPL Programmability environment.
+
+use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
+
+use crate::current_tx::traits::EscrowCancelFields;
+
+/// Represents an EscrowCancel transaction in the XRPL Programmability environment.
+///
+/// Like [`EscrowFinish`](crate::current_tx::escrow_finish::EscrowFinish), this is a zero-sized
+/// marker: common fields come from `TransactionCommonFields` and the escrow reference (Owner,
+/// OfferSequence) from `EscrowCancelFields`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct EscrowCancel;
+
+impl TransactionCommonFields for EscrowCancel {}
+
+impl EscrowCancelFields for EscrowCancel {}
@@ -9,5 +9,6 @@
 //! let account = tx.get_account().unwrap_or_panic();
 //! let _flags = tx.get_flags().unwrap_or_panic();
 //! ```
+pub mod escrow_cancel;
 pub mod escrow_finish;
 pub mod traits;
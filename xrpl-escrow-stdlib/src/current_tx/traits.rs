@@ -1,12 +1,17 @@
 //! Escrow-finish-specific transaction field accessor trait.
 
-use xrpl_common_stdlib::current_tx::get_field;
 use xrpl_common_stdlib::current_tx::traits::TransactionCommonFields;
+use xrpl_common_stdlib::current_tx::{get_field, get_field_optional};
 use xrpl_common_stdlib::host::error_codes::match_result_code_optional;
 use xrpl_common_stdlib::host::{Result, get_tx_field};
+use xrpl_common_stdlib::keylets::escrow_keylet;
+use xrpl_common_stdlib::objects::slot::Slot;
 use xrpl_common_stdlib::sfield;
 use xrpl_common_stdlib::types::account_id::AccountID;
 use xrpl_common_stdlib::types::blob::{ConditionBlob, FulfillmentBlob};
+use xrpl_common_stdlib::types::vector256::StandardVector256;
+
+use crate::ledger_objects::escrow::Escrow;
 
 /// Trait providing access to fields specific to EscrowFinish transactions.
 ///
@@ -53,6 +58,28 @@ pub trait EscrowFinishFields: TransactionCommonFields {
         get_field(sfield::OfferSequence)
     }
 
+    /// Loads the escrow this transaction finishes, identified by `Owner` and `OfferSequence`.
+    ///
+    /// The escrow is cached in a new host slot, so its fields can be compared with the current
+    /// escrow's (e.g. to check that the finish really targets the escrow running this contract).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Escrow>` where:
+    /// * `Ok(Escrow)` - A handle to the cached escrow
+    /// * `Err(Error)` - If `Owner` or `OfferSequence` cannot be read, `LedgerObjNotFound` if
+    ///   no such escrow exists, or another host error (e.g. `SlotsFull`)
+    fn target_escrow(&self) -> Result<Escrow> {
+        let owner = match self.get_owner() {
+            Result::Ok(owner) => owner,
+            Result::Err(e) => return Result::Err(e),
+        };
+        self.get_offer_sequence()
+            .and_then(|sequence| escrow_keylet(&owner, sequence))
+            .and_then(|keylet| Slot::cache(&keylet))
+            .map(|slot| Escrow::new(slot.num()))
+    }
+
     /// Retrieves the cryptographic condition from the current EscrowFinish transaction.
     ///
     /// This optional field contains the cryptographic condition in full crypto-condition format.
@@ -124,6 +151,42 @@ pub trait EscrowFinishFields: TransactionCommonFields {
             (result_code > 0).then_some(buffer)
         })
     }
+
+    /// Retrieves the IDs of the credentials presented with the current EscrowFinish transaction.
+    ///
+    /// `CredentialIDs` holds at most 8 entries, so the whole list is read at once into a
+    /// [`StandardVector256`]. To read a single entry, use a [`Locator`] to `CredentialIDs` and the
+    /// entry's index with `current_tx::get_nested_field`.
+    ///
+    /// [`Locator`]: xrpl_common_stdlib::fields::locator::Locator
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<StandardVector256>>` where:
+    /// * `Ok(Some(ids))` - The credential IDs, in transaction order
+    /// * `Ok(None)` - If no credentials were presented
+    /// * `Err(Error)` - If the field cannot be retrieved
+    fn get_credential_ids(&self) -> Result<Option<StandardVector256>> {
+        get_field_optional(sfield::CredentialIDs)
+    }
+}
+
+/// Trait providing access to fields specific to EscrowCancel transactions.
+///
+/// An EscrowCancel returns an expired escrow's funds to its owner. It names the escrow the same
+/// way an EscrowFinish does, by owner and the sequence of the creating transaction.
+pub trait EscrowCancelFields: TransactionCommonFields {
+    /// Retrieves the owner account (the escrow's creator) from the current EscrowCancel
+    /// transaction.
+    fn get_owner(&self) -> Result<AccountID> {
+        get_field(sfield::Owner)
+    }
+
+    /// Retrieves the sequence number of the EscrowCreate transaction that created the escrow
+    /// being canceled.
+    fn get_offer_sequence(&self) -> Result<u32> {
+        get_field(sfield::OfferSequence)
+    }
 }
 
 #[cfg(test)]
@@ -158,6 +221,7 @@ mod tests {
             use xrpl_common_stdlib::host::setup_mock;
             use xrpl_common_stdlib::sfield;
             use xrpl_common_stdlib::types::blob::{CONDITION_BLOB_SIZE, FULFILLMENT_BLOB_SIZE};
+            use xrpl_common_stdlib::types::uint::Hash256;
 
             use mockall::predicate::{always, eq};
             use xrpl_common_stdlib::sfield::{Condition, Fulfillment};
@@ -185,6 +249,30 @@ mod tests {
                 assert_eq!(fulfillment.unwrap().len, FULFILLMENT_BLOB_SIZE);
             }
 
+            #[test]
+            fn test_get_credential_ids() {
+                let mut mock = MockHostBindings::new();
+                mock.expect_get_tx_field()
+                    .with(eq(sfield::CredentialIDs), always(), always())
+                    .times(1)
+                    .returning(|_, out, _| {
+                        unsafe { core::ptr::write_bytes(out, 0xCC, 64) };
+                        64
+                    });
+                mock.expect_get_tx_field()
+                    .with(eq(sfield::CredentialIDs), always(), always())
+                    .times(1)
+                    .returning(|_, _, _| FIELD_NOT_FOUND);
+                let _guard = setup_mock(mock);
+
+                let escrow = EscrowFinish;
+
+                let ids = escrow.get_credential_ids().unwrap().unwrap();
+                assert_eq!(ids.len(), 2);
+                assert_eq!(ids.get(1), Some(&Hash256::from([0xCC; 32])));
+                assert!(escrow.get_credential_ids().unwrap().is_none());
+            }
+
             #[test]
             fn test_optional_fields_return_none_when_zero_length() {
                 let mut mock = MockHostBindings::new();
@@ -444,5 +532,63 @@ mod tests {
                 assert_eq!(offer_seq_result.err().unwrap().code(), INVALID_FIELD);
             }
         }
+
+        mod target_escrow {
+            use crate::current_tx::escrow_finish::EscrowFinish;
+            use crate::current_tx::traits::EscrowFinishFields;
+            use crate::ledger_objects::escrow::Escrow;
+            use mockall::predicate::{always, eq};
+            use xrpl_common_stdlib::host::error_codes::LEDGER_OBJ_NOT_FOUND;
+            use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
+            use xrpl_common_stdlib::host::setup_mock;
+            use xrpl_common_stdlib::sfield;
+
+            fn mock_finish(cache_result: i32) -> MockHostBindings {
+                let mut mock = MockHostBindings::new();
+                mock.expect_get_tx_field()
+                    .with(eq(sfield::Owner), always(), eq(20))
+                    .returning(|_, ptr, len| {
+                        unsafe { core::ptr::write_bytes(ptr, 0xAA, len) };
+                        20
+                    });
+                mock.expect_get_tx_field()
+                    .with(eq(sfield::OfferSequence), always(), eq(4))
+                    .returning(|_, ptr, _| {
+                        let seq = 7u32.to_le_bytes();
+                        unsafe { core::ptr::copy_nonoverlapping(seq.as_ptr(), ptr, 4) };
+                        4
+                    });
+                mock.expect_escrow_keylet()
+                    .withf(|owner, owner_len, seq, seq_len, _, _| {
+                        *owner_len == 20
+                            && *seq_len == 4
+                            && unsafe { core::slice::from_raw_parts(*owner, 20) } == [0xAA; 20]
+                            && unsafe { core::slice::from_raw_parts(*seq, 4) } == 7u32.to_le_bytes()
+                    })
+                    .times(1)
+                    .returning(|_, _, _, _, out, _| {
+                        unsafe { core::ptr::write_bytes(out, 0xE5, 32) };
+                        32
+                    });
+                mock.expect_cache_ledger_obj()
+                    .with(always(), eq(32), eq(0))
+                    .times(1)
+                    .returning(move |_, _, _| cache_result);
+                mock
+            }
+
+            #[test]
+            fn test_target_escrow_caches_owner_and_offer_sequence() {
+                let _guard = setup_mock(mock_finish(3));
+                assert_eq!(EscrowFinish.target_escrow().unwrap(), Escrow::new(3));
+            }
+
+            #[test]
+            fn test_target_escrow_not_found() {
+                let _guard = setup_mock(mock_finish(LEDGER_OBJ_NOT_FOUND));
+                let result = EscrowFinish.target_escrow();
+                assert_eq!(result.err().unwrap().code(), LEDGER_OBJ_NOT_FOUND);
+            }
+        }
     }
 }
@@ -0,0 +1,160 @@
+//! The short path through a Smart Escrow.
+//!
+//! Most escrow conditions need the same handful of values: who funded the escrow, who receives
+//! it, how much it holds, what time it is, and the escrow's stored data. [`SmartEscrow`] puts
+//! those behind one type, so a first contract needs no field traits or `sfield` constants:
+//!
+//! ```rust,ignore
+//! use xrpl_escrow_stdlib::host::Result;
+//! use xrpl_escrow_stdlib::{entry_point, FinishResult, SmartEscrow};
+//!
+//! const UNLOCK_TIME: u32 = 800_000_000;
+//!
+//! #[entry_point(finish)]
+//! fn finish(escrow: SmartEscrow) -> FinishResult {
+//!     match escrow.now() {
+//!         Result::Ok(now) if now >= UNLOCK_TIME => FinishResult::succeed(),
+//!         _ => FinishResult::reject(),
+//!     }
+//! }
+//! ```
+//!
+//! Everything else is still reachable: [`SmartEscrow::tx`] and [`SmartEscrow::escrow`] return
+//! the same handles as [`EscrowFinishContext`], for use with the field traits.
+
+use xrpl_common_stdlib::ctx::{EntryContext, LedgerInfo, SmartFeatureContext};
+use xrpl_common_stdlib::host::Result;
+use xrpl_common_stdlib::objects::escrow::EscrowReadFields;
+use xrpl_common_stdlib::types::account_id::AccountID;
+use xrpl_common_stdlib::types::amount::Amount;
+use xrpl_common_stdlib::types::contract_data::ContractData;
+
+use crate::ctx::escrow_finish::EscrowFinishContext;
+use crate::current_tx::escrow_finish::EscrowFinish;
+use crate::ledger_objects::current_escrow::CurrentEscrow;
+
+/// The escrow being finished, the `EscrowFinish` transaction and ledger info, with the queries
+/// most contracts need.
+///
+/// Ledger header values are read from the host at most once, as in
+/// [`ExecutionContext`](xrpl_common_stdlib::ctx::ExecutionContext).
+#[derive(Default)]
+pub struct SmartEscrow {
+    ctx: EscrowFinishContext,
+    ledger: LedgerInfo,
+}
+
+impl EntryContext for SmartEscrow {
+    const ENTRY_POINT: &'static str = EscrowFinishContext::ENTRY_POINT;
+}
+
+impl SmartFeatureContext for SmartEscrow {
+    type Tx = EscrowFinish;
+    fn tx(&self) -> &Self::Tx {
+        self.ctx.tx()
+    }
+}
+
+impl From<EscrowFinishContext> for SmartEscrow {
+    fn from(ctx: EscrowFinishContext) -> Self {
+        SmartEscrow {
+            ctx,
+            ledger: LedgerInfo::new(),
+        }
+    }
+}
+
+impl SmartEscrow {
+    /// Creates the facade with the given ledger info, e.g. [`LedgerInfo::fixed`] in tests.
+    pub fn with_ledger(ledger: LedgerInfo) -> Self {
+        SmartEscrow {
+            ctx: EscrowFinishContext::default(),
+            ledger,
+        }
+    }
+
+    /// The escrow being finished, for reads the facade does not cover.
+    pub fn escrow(&self) -> &CurrentEscrow {
+        self.ctx.escrow()
+    }
+
+    /// Ledger header values.
+    pub fn ledger(&self) -> &LedgerInfo {
+        &self.ledger
+    }
+
+    /// The account that created and funded the escrow (its `Account`).
+    pub fn sender(&self) -> Result<AccountID> {
+        self.escrow().get_account()
+    }
+
+    /// The account the escrowed amount is paid to if the escrow finishes (its `Destination`).
+    pub fn recipient(&self) -> Result<AccountID> {
+        self.escrow().get_destination()
+    }
+
+    /// The amount held in the escrow.
+    pub fn amount(&self) -> Result<Amount> {
+        self.escrow().get_amount()
+    }
+
+    /// The parent ledger's close time, in seconds since the Ripple Epoch. This is the time the
+    /// ledger itself compares `FinishAfter` and `CancelAfter` against.
+    pub fn now(&self) -> Result<u32> {
+        self.ledger.parent_time()
+    }
+
+    /// The data stored on the escrow.
+    pub fn data(&self) -> Result<ContractData> {
+        self.escrow().get_data()
+    }
+
+    /// Replaces the data stored on the escrow; see [`EscrowFinishContext::update_data`].
+    pub fn set_data(&self, data: &[u8]) -> Result<()> {
+        self.ctx.update_data(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::{always, eq};
+    use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
+    use xrpl_common_stdlib::host::setup_mock;
+    use xrpl_common_stdlib::sfield;
+    use xrpl_stdlib_test_utils::EscrowScenario;
+
+    #[test]
+    fn reads_parties_and_time() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::Account)), always(), eq(20))
+            .times(1)
+            .returning(|_, out, len| {
+                unsafe { core::ptr::write_bytes(out, 0x11, len) };
+                20
+            });
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::Destination)), always(), eq(20))
+            .times(1)
+            .returning(|_, out, len| {
+                unsafe { core::ptr::write_bytes(out, 0x22, len) };
+                20
+            });
+        let _guard = setup_mock(mock);
+
+        let escrow = SmartEscrow::with_ledger(LedgerInfo::fixed(5, 1_234, [0; 32], 10));
+        assert_eq!(escrow.sender().unwrap(), AccountID([0x11; 20]));
+        assert_eq!(escrow.recipient().unwrap(), AccountID([0x22; 20]));
+        assert_eq!(escrow.now().unwrap(), 1_234);
+    }
+
+    #[test]
+    fn set_data_updates_the_escrow() {
+        let _guard = EscrowScenario::builder()
+            .with_update_data_returns(Ok(()))
+            .install();
+
+        assert!(SmartEscrow::default().set_data(b"state").is_ok());
+    }
+}
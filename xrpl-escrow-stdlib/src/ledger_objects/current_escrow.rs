@@ -1,15 +1,170 @@
+use xrpl_common_stdlib::current_tx::get_field;
+use xrpl_common_stdlib::host::Result;
+use xrpl_common_stdlib::keylets::{KeyletBytes, escrow_keylet};
+use xrpl_common_stdlib::objects::escrow::EscrowReadFields;
 use xrpl_common_stdlib::objects::traits::CurrentLedgerObjectCommonFields;
+use xrpl_common_stdlib::sfield;
+use xrpl_common_stdlib::types::account_id::AccountID;
+use xrpl_common_stdlib::types::amount::Amount;
+use xrpl_common_stdlib::types::blob::{ConditionBlob, WasmBlob};
+use xrpl_common_stdlib::types::contract_data::ContractData;
+use xrpl_common_stdlib::types::uint::Hash256;
 
 use crate::ledger_objects::traits::CurrentEscrowFields;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct CurrentEscrow;
 
-impl CurrentLedgerObjectCommonFields for CurrentEscrow {}
+impl CurrentLedgerObjectCommonFields for CurrentEscrow {
+    /// Recomputes the escrow's keylet from the `Owner` and `OfferSequence` of the EscrowFinish or
+    /// EscrowCancel being applied to it; the escrow object itself does not record the sequence
+    /// it was created with.
+    fn get_keylet(&self) -> Result<KeyletBytes> {
+        let owner: AccountID = match get_field(sfield::Owner) {
+            Result::Ok(owner) => owner,
+            Result::Err(e) => return Result::Err(e),
+        };
+        get_field(sfield::OfferSequence).and_then(|sequence| escrow_keylet(&owner, sequence))
+    }
+}
 
 impl CurrentEscrowFields for CurrentEscrow {}
 
+impl EscrowReadFields for CurrentEscrow {
+    fn get_account(&self) -> Result<AccountID> {
+        CurrentEscrowFields::get_account(self)
+    }
+
+    fn get_amount(&self) -> Result<Amount> {
+        CurrentEscrowFields::get_amount(self)
+    }
+
+    fn get_cancel_after(&self) -> Result<Option<u32>> {
+        CurrentEscrowFields::get_cancel_after(self)
+    }
+
+    fn get_condition(&self) -> Result<Option<ConditionBlob>> {
+        CurrentEscrowFields::get_condition(self)
+    }
+
+    fn get_destination(&self) -> Result<AccountID> {
+        CurrentEscrowFields::get_destination(self)
+    }
+
+    fn get_destination_node(&self) -> Result<Option<u64>> {
+        CurrentEscrowFields::get_destination_node(self)
+    }
+
+    fn get_destination_tag(&self) -> Result<Option<u32>> {
+        CurrentEscrowFields::get_destination_tag(self)
+    }
+
+    fn get_finish_after(&self) -> Result<Option<u32>> {
+        CurrentEscrowFields::get_finish_after(self)
+    }
+
+    fn get_owner_node(&self) -> Result<u64> {
+        CurrentEscrowFields::get_owner_node(self)
+    }
+
+    fn get_previous_txn_id(&self) -> Result<Hash256> {
+        CurrentEscrowFields::get_previous_txn_id(self)
+    }
+
+    fn get_previous_txn_lgr_seq(&self) -> Result<u32> {
+        CurrentEscrowFields::get_previous_txn_lgr_seq(self)
+    }
+
+    fn get_source_tag(&self) -> Result<Option<u32>> {
+        CurrentEscrowFields::get_source_tag(self)
+    }
+
+    fn get_transfer_rate(&self) -> Result<Option<u32>> {
+        CurrentEscrowFields::get_transfer_rate(self)
+    }
+
+    fn get_finish_function(&self) -> Result<Option<WasmBlob>> {
+        CurrentEscrowFields::get_finish_function(self)
+    }
+
+    fn get_data(&self) -> Result<ContractData> {
+        CurrentEscrowFields::get_data(self)
+    }
+}
+
 #[inline]
 pub fn get_current_escrow() -> CurrentEscrow {
     CurrentEscrow
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger_objects::escrow::Escrow;
+    use mockall::predicate::{always, eq};
+    use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
+    use xrpl_common_stdlib::host::setup_mock;
+
+    fn destination_of<E: EscrowReadFields>(escrow: &E) -> AccountID {
+        escrow.get_destination().unwrap()
+    }
+
+    #[test]
+    fn test_escrow_read_fields_is_generic_over_current_and_cached() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_current_ledger_obj_field()
+            .with(eq(i32::from(sfield::Destination)), always(), eq(20))
+            .times(1)
+            .returning(|_, ptr, len| {
+                unsafe { core::ptr::write_bytes(ptr, 0xAA, len) };
+                len as i32
+            });
+        mock.expect_get_ledger_obj_field()
+            .with(eq(2), eq(i32::from(sfield::Destination)), always(), eq(20))
+            .times(1)
+            .returning(|_, _, ptr, len| {
+                unsafe { core::ptr::write_bytes(ptr, 0xBB, len) };
+                len as i32
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(destination_of(&CurrentEscrow), AccountID([0xAA; 20]));
+        assert_eq!(destination_of(&Escrow::new(2)), AccountID([0xBB; 20]));
+    }
+
+    #[test]
+    fn test_get_index_uses_transaction_owner_and_offer_sequence() {
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::Owner)), always(), eq(20))
+            .times(1)
+            .returning(|_, ptr, len| {
+                unsafe { core::ptr::write_bytes(ptr, 0xAA, len) };
+                20
+            });
+        mock.expect_get_tx_field()
+            .with(eq(i32::from(sfield::OfferSequence)), always(), eq(4))
+            .times(1)
+            .returning(|_, ptr, _| {
+                let seq = 9u32.to_le_bytes();
+                unsafe { core::ptr::copy_nonoverlapping(seq.as_ptr(), ptr, 4) };
+                4
+            });
+        mock.expect_escrow_keylet()
+            .withf(|_, _, seq, seq_len, _, _| {
+                *seq_len == 4
+                    && unsafe { core::slice::from_raw_parts(*seq, 4) } == 9u32.to_le_bytes()
+            })
+            .times(1)
+            .returning(|_, _, _, _, out, _| {
+                unsafe { core::ptr::write_bytes(out, 0xE5, 32) };
+                32
+            });
+        let _guard = setup_mock(mock);
+
+        assert_eq!(
+            CurrentEscrow.get_index().unwrap(),
+            Hash256::from([0xE5; 32])
+        );
+    }
+}
@@ -19,6 +19,13 @@ impl Escrow {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl xrpl_common_stdlib::objects::json::ToJson for Escrow {
+    fn to_json_string(&self) -> std::string::String {
+        xrpl_common_stdlib::objects::json::escrow_json(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -28,4 +35,50 @@ mod tests {
         let escrow = Escrow::new(42);
         assert_eq!(escrow.slot_num, 42);
     }
+
+    #[test]
+    fn test_to_json_string_leaves_out_absent_fields() {
+        use xrpl_common_stdlib::host::error_codes::FIELD_NOT_FOUND;
+        use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
+        use xrpl_common_stdlib::host::setup_mock;
+        use xrpl_common_stdlib::objects::json::ToJson;
+        use xrpl_common_stdlib::sfield;
+
+        let optional = [
+            sfield::Condition.into(),
+            sfield::CancelAfter.into(),
+            sfield::FinishAfter.into(),
+            sfield::SourceTag.into(),
+            sfield::DestinationTag.into(),
+            sfield::DestinationNode.into(),
+            sfield::FinishFunction.into(),
+        ];
+        let mut mock = MockHostBindings::new();
+        mock.expect_get_ledger_obj_field()
+            .returning(move |_, field: i32, out, len| {
+                if optional.contains(&field) {
+                    FIELD_NOT_FOUND
+                } else if field == i32::from(sfield::Amount) {
+                    let bytes = (0x4000_0000_0000_0000u64 | 5).to_be_bytes();
+                    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, 8) };
+                    8
+                } else if field == i32::from(sfield::Data) {
+                    0
+                } else {
+                    unsafe { core::ptr::write_bytes(out, 0x11, len) };
+                    len as i32
+                }
+            });
+        let _guard = setup_mock(mock);
+
+        let json = Escrow::new(3).to_json_string();
+        assert!(json.contains("\"Amount\":\"5\""), "{json}");
+        assert!(
+            json.contains("\"OwnerNode\":\"1111111111111111\""),
+            "{json}"
+        );
+        assert!(json.contains("\"Data\":\"\""), "{json}");
+        assert!(!json.contains("FinishFunction"), "{json}");
+        assert!(!json.contains("error"), "{json}");
+    }
 }
@@ -69,6 +69,22 @@ pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
         current_ledger_object::get_field_optional(sfield::DestinationTag)
     }
 
+    /// Checks that the escrow carries the destination tag `expected`.
+    ///
+    /// # Errors
+    ///
+    /// * `FieldNotFound` - The escrow has no destination tag
+    /// * `InvalidParams` - The escrow has a different destination tag
+    /// * Any error returned while reading `DestinationTag`
+    fn require_destination_tag(&self, expected: u32) -> Result<()> {
+        match self.get_destination_tag() {
+            Ok(Some(tag)) if tag == expected => Ok(()),
+            Ok(Some(_)) => Err(Error::InvalidParams),
+            Ok(None) => Err(Error::FieldNotFound),
+            Err(e) => Err(e),
+        }
+    }
+
     /// The time, in seconds since the Ripple Epoch, after which this escrow can be finished. Any
     /// EscrowFinish transaction before this time fails. (Specifically, this is compared with the
     /// close time of the previous validated ledger.)
@@ -99,6 +115,12 @@ pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
         current_ledger_object::get_field_optional(sfield::SourceTag)
     }
 
+    /// The issuer's transfer rate when the escrow was created, for IOU escrows. Absent when the
+    /// rate was parity or the escrow holds XRP or an MPT.
+    fn get_transfer_rate(&self) -> Result<Option<u32>> {
+        current_ledger_object::get_field_optional(sfield::TransferRate)
+    }
+
     /// The WASM code that is executing.
     fn get_finish_function(&self) -> Result<Option<WasmBlob>> {
         current_ledger_object::get_field_optional(sfield::FinishFunction)
@@ -154,7 +176,9 @@ pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
 mod tests {
     use super::*;
     use mockall::predicate::{always, eq};
-    use xrpl_common_stdlib::host::error_codes::{FIELD_NOT_FOUND, INTERNAL_ERROR, INVALID_FIELD};
+    use xrpl_common_stdlib::host::error_codes::{
+        FIELD_NOT_FOUND, INTERNAL_ERROR, INVALID_FIELD, INVALID_PARAMS,
+    };
     use xrpl_common_stdlib::host::host_bindings_trait::MockHostBindings;
     use xrpl_common_stdlib::objects::LedgerObjectFieldGetter;
     use xrpl_common_stdlib::sfield::SField;
@@ -178,6 +202,7 @@ mod tests {
         use super::*;
         use crate::ledger_objects::current_escrow::CurrentEscrow;
         use xrpl_common_stdlib::host::setup_mock;
+        use xrpl_common_stdlib::objects::view::LedgerObjectView;
         use xrpl_common_stdlib::types::blob::CONDITION_BLOB_SIZE;
         use xrpl_common_stdlib::types::blob::WASM_BLOB_SIZE;
 
@@ -324,6 +349,45 @@ mod tests {
             assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
         }
 
+        #[test]
+        fn test_previous_txn_reads_hash_and_ledger_seq() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_field()
+                .with(eq(sfield::PreviousTxnID), always(), eq(32))
+                .times(1)
+                .returning(|_, ptr, _| {
+                    unsafe { core::ptr::write_bytes(ptr, 0xAB, 32) };
+                    32
+                });
+            mock.expect_get_current_ledger_obj_field()
+                .with(eq(sfield::PreviousTxnLgrSeq), always(), eq(4))
+                .times(1)
+                .returning(|_, ptr, _| {
+                    unsafe { core::ptr::copy_nonoverlapping(77u32.to_le_bytes().as_ptr(), ptr, 4) };
+                    4
+                });
+
+            let _guard = setup_mock(mock);
+
+            let previous = CurrentEscrow.view().previous_txn().unwrap();
+            assert_eq!(previous.id, Hash256::from([0xAB; 32]));
+            assert_eq!(previous.ledger_seq, 77);
+        }
+
+        #[test]
+        fn test_previous_txn_propagates_error() {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_field()
+                .with(eq(sfield::PreviousTxnID), always(), eq(32))
+                .times(1)
+                .returning(|_, _, _| INTERNAL_ERROR);
+
+            let _guard = setup_mock(mock);
+
+            let result = CurrentEscrow.view().previous_txn();
+            assert_eq!(result.err().unwrap().code(), INTERNAL_ERROR);
+        }
+
         #[test]
         fn test_get_data_returns_error_on_internal_error() {
             let mut mock = MockHostBindings::new();
@@ -360,5 +424,38 @@ mod tests {
             assert!(result.is_err());
             assert_eq!(result.err().unwrap().code(), INVALID_FIELD);
         }
+
+        fn mock_destination_tag(tag: Option<u32>) -> MockHostBindings {
+            let mut mock = MockHostBindings::new();
+            mock.expect_get_current_ledger_obj_field()
+                .with(eq(sfield::DestinationTag), always(), eq(4))
+                .times(1)
+                .returning(move |_, ptr, _| match tag {
+                    Some(tag) => {
+                        unsafe { ptr.copy_from_nonoverlapping(tag.to_le_bytes().as_ptr(), 4) };
+                        4
+                    }
+                    None => FIELD_NOT_FOUND,
+                });
+            mock
+        }
+
+        #[test]
+        fn test_require_destination_tag() {
+            {
+                let _guard = setup_mock(mock_destination_tag(Some(42)));
+                assert!(CurrentEscrow.require_destination_tag(42).is_ok());
+            }
+            {
+                let _guard = setup_mock(mock_destination_tag(Some(43)));
+                let result = CurrentEscrow.require_destination_tag(42);
+                assert_eq!(result.err().unwrap().code(), INVALID_PARAMS);
+            }
+            {
+                let _guard = setup_mock(mock_destination_tag(None));
+                let result = CurrentEscrow.require_destination_tag(42);
+                assert_eq!(result.err().unwrap().code(), FIELD_NOT_FOUND);
+            }
+        }
     }
 }
@@ -6,9 +6,13 @@ extern crate std;
 
 pub mod ctx;
 pub mod current_tx;
+pub mod facade;
 pub mod ledger_objects;
+pub mod rand;
 
-pub use ctx::escrow_finish::EscrowFinishContext;
+pub use ctx::escrow_cancel::{EscrowCancelContext, EscrowCancelExecutionContext};
+pub use ctx::escrow_finish::{EscrowExecutionContext, EscrowFinishContext};
 pub use ctx::finish_result::FinishResult;
+pub use facade::SmartEscrow;
 
 pub use xrpl_common_stdlib::*;
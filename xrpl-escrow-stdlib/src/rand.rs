@@ -0,0 +1,31 @@
+//! Deterministic pseudo-randomness for Smart Escrows.
+//!
+//! [`ledger_entropy`] binds the value to the escrow being finished. Read the limits described in
+//! [`xrpl_common_stdlib::rand`] before relying on it.
+
+use xrpl_common_stdlib::host::Result;
+use xrpl_common_stdlib::keylets::escrow_keylet;
+pub use xrpl_common_stdlib::rand::ledger_entropy_for;
+
+use crate::current_tx::escrow_finish::EscrowFinish;
+use crate::current_tx::traits::EscrowFinishFields;
+
+/// Derives 32 bytes of entropy from the parent ledger hash, the keylet of the escrow being
+/// finished, and the `EscrowFinish` transaction's account and sequence.
+///
+/// ```no_run
+/// use xrpl_escrow_stdlib::rand::ledger_entropy;
+///
+/// // Break a tie between two equally entitled destinations.
+/// let first_wins = ledger_entropy().unwrap()[0] & 1 == 0;
+/// # let _ = first_wins;
+/// ```
+pub fn ledger_entropy() -> Result<[u8; 32]> {
+    let tx = EscrowFinish;
+    tx.get_owner()
+        .and_then(|owner| {
+            tx.get_offer_sequence()
+                .and_then(|seq| escrow_keylet(&owner, seq))
+        })
+        .and_then(|keylet| ledger_entropy_for(&keylet))
+}
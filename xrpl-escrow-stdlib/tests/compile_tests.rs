@@ -6,4 +6,6 @@ fn compile_fail_tests() {
     // const { assert!() } failures. A single pass test switches it to `cargo build`.
     t.pass("tests/finish_result/pass/*.rs");
     t.compile_fail("tests/finish_result/compile_fail/*.rs");
+    t.pass("tests/entry_point/pass/*.rs");
+    t.compile_fail("tests/entry_point/compile_fail/*.rs");
 }
@@ -0,0 +1,9 @@
+use xrpl_escrow_stdlib::{EscrowFinishContext, entry_point};
+
+// EscrowFinishContext belongs to `finish`, not `cancel`.
+#[entry_point(cancel)]
+fn on_cancel(_ctx: EscrowFinishContext) -> i32 {
+    1
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use xrpl_common_stdlib::ctx::EntryContext;
+use xrpl_escrow_stdlib::{EscrowCancelContext, EscrowFinishContext, FinishResult, entry_point};
+
+// An entry point the library has no context for yet.
+#[derive(Default)]
+struct ReadyContext;
+
+impl EntryContext for ReadyContext {
+    const ENTRY_POINT: &'static str = "ready";
+}
+
+#[entry_point(ready)]
+fn on_ready(_ctx: ReadyContext) -> i32 {
+    1
+}
+
+#[entry_point(finish)]
+fn on_finish(_ctx: EscrowFinishContext) -> FinishResult {
+    FinishResult::succeed()
+}
+
+#[entry_point(cancel)]
+fn on_cancel(_ctx: EscrowCancelContext) -> FinishResult {
+    FinishResult::reject()
+}
+
+fn main() {
+    assert_eq!(ready(), 1);
+}
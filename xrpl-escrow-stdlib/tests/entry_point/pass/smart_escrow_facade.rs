@@ -0,0 +1,12 @@
+use xrpl_escrow_stdlib::host::Result;
+use xrpl_escrow_stdlib::{FinishResult, SmartEscrow, entry_point};
+
+#[entry_point(finish)]
+fn on_finish(escrow: SmartEscrow) -> FinishResult {
+    match escrow.now() {
+        Result::Ok(now) if now > 0 => FinishResult::succeed(),
+        _ => FinishResult::reject(),
+    }
+}
+
+fn main() {}
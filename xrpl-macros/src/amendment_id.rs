@@ -0,0 +1,80 @@
+//! `amendment_id!` — compile-time amendment name → 32-byte amendment ID (`[u8; 32]`).
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let name_lit = syn::parse2::<LitStr>(input)?;
+    let name = name_lit.value();
+
+    let bytes = amendment_id(&name).map_err(|reason| {
+        syn::Error::new(name_lit.span(), format!("Invalid amendment name: {reason}"))
+    })?;
+
+    let bytes_tokens = bytes.iter().map(|b| quote! {#b});
+    // A plain array rather than `Hash256`, so the macro also works inside `xrpl-common-stdlib`
+    // itself and the result can go straight to `amendment_enabled`.
+    let expanded = quote! {
+        [#(#bytes_tokens),*]
+    };
+    Ok(expanded)
+}
+
+/// rippled derives an amendment's ID from its name: the first half of the name's SHA-512.
+fn amendment_id(name: &str) -> Result<[u8; 32], &'static str> {
+    use sha2::{Digest, Sha512};
+
+    if name.is_empty() {
+        return Err("expected a non-empty name");
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err("expected only ASCII letters, digits and underscores");
+    }
+    let digest = Sha512::digest(name.as_bytes());
+    let mut id = [0u8; 32];
+    id.copy_from_slice(&digest[..32]);
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{amendment_id, expand};
+    use quote::quote;
+
+    #[test]
+    fn hashes_name_like_rippled() {
+        // The ID of the `MultiSign` amendment as rippled reports it.
+        let expected = [
+            0x4C, 0x97, 0xEB, 0xA9, 0x26, 0x03, 0x1A, 0x7C, 0xF7, 0xD7, 0xB3, 0x6F, 0xDE, 0x3E,
+            0xD6, 0x6D, 0xDA, 0x54, 0x21, 0x19, 0x2D, 0x63, 0xDE, 0x53, 0xFF, 0xB4, 0x6E, 0x43,
+            0xB9, 0xDC, 0x83, 0x73,
+        ];
+        assert_eq!(amendment_id("MultiSign").unwrap(), expected);
+    }
+
+    #[test]
+    fn rejects_empty_name() {
+        let err = amendment_id("").unwrap_err();
+        assert_eq!(err, "expected a non-empty name");
+    }
+
+    #[test]
+    fn rejects_non_identifier_characters() {
+        let err = amendment_id("Multi Sign").unwrap_err();
+        assert_eq!(err, "expected only ASCII letters, digits and underscores");
+    }
+
+    #[test]
+    fn expand_emits_tokens_for_valid_name() {
+        let input = quote! { "SmartEscrow" };
+        assert!(expand(input).is_ok());
+    }
+
+    #[test]
+    fn expand_errors_on_empty_name() {
+        let input = quote! { "" };
+        let err = expand(input).unwrap_err();
+        assert!(err.to_string().contains("expected a non-empty name"));
+    }
+}
@@ -0,0 +1,134 @@
+//! `contract_metadata!` — describes a contract in a custom WASM section.
+//!
+//! `contract_metadata! { name: "escrow", version: "1.2.0", abi: 1 }` emits a `CONTRACT_METADATA`
+//! static holding `name=escrow\nversion=1.2.0\nabi=1\n`, placed in the `xrpl_metadata` custom
+//! section on `wasm32`. `xrpl_common_stdlib::metadata` parses the payload.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitInt, LitStr, Token};
+
+/// Must match `xrpl_common_stdlib::metadata::SECTION_NAME`.
+const SECTION_NAME: &str = "xrpl_metadata";
+
+struct MetadataInput {
+    name: LitStr,
+    version: LitStr,
+    abi: LitInt,
+}
+
+fn parse_key(input: ParseStream, expected: &str) -> syn::Result<()> {
+    let key: Ident = input.parse()?;
+    if key != expected {
+        return Err(syn::Error::new(
+            key.span(),
+            format!("expected `{expected}` (fields are `name`, `version`, `abi`, in that order)"),
+        ));
+    }
+    input.parse::<Token![:]>()?;
+    Ok(())
+}
+
+impl Parse for MetadataInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        parse_key(input, "name")?;
+        let name: LitStr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        parse_key(input, "version")?;
+        let version: LitStr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        parse_key(input, "abi")?;
+        let abi: LitInt = input.parse()?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { name, version, abi })
+    }
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let MetadataInput { name, version, abi } = syn::parse2::<MetadataInput>(input)?;
+
+    let name_value = name.value();
+    check_value("name", &name_value).map_err(|msg| syn::Error::new(name.span(), msg))?;
+    let version_value = version.value();
+    check_value("version", &version_value).map_err(|msg| syn::Error::new(version.span(), msg))?;
+    let abi_value = abi.base10_parse::<u32>()?;
+
+    let payload = encode(&name_value, &version_value, abi_value);
+    let len = payload.len();
+    let payload = syn::LitByteStr::new(&payload, proc_macro2::Span::call_site());
+
+    let expanded = quote! {
+        /// Contract metadata written by `contract_metadata!`; see `xrpl_common_stdlib::metadata`.
+        #[used]
+        #[cfg_attr(target_arch = "wasm32", unsafe(link_section = #SECTION_NAME))]
+        pub static CONTRACT_METADATA: [u8; #len] = *#payload;
+    };
+    Ok(expanded)
+}
+
+fn check_value(field: &str, value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err(format!("contract {field} must not be empty"));
+    }
+    if value.contains('\n') {
+        return Err(format!("contract {field} must not contain a newline"));
+    }
+    Ok(())
+}
+
+fn encode(name: &str, version: &str, abi: u32) -> Vec<u8> {
+    format!("name={name}\nversion={version}\nabi={abi}\n").into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_value, encode, expand};
+    use quote::quote;
+
+    #[test]
+    fn encodes_one_field_per_line() {
+        assert_eq!(
+            encode("escrow", "1.2.0", 1),
+            b"name=escrow\nversion=1.2.0\nabi=1\n"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_and_multiline_values() {
+        assert!(
+            check_value("name", "")
+                .unwrap_err()
+                .contains("must not be empty")
+        );
+        assert!(
+            check_value("version", "1\n2")
+                .unwrap_err()
+                .contains("newline")
+        );
+        assert!(check_value("name", "a=b").is_ok());
+    }
+
+    #[test]
+    fn expand_emits_tokens_for_valid_input() {
+        let input = quote! { name: "escrow", version: "1.2.0", abi: 1, };
+        let tokens = expand(input).unwrap().to_string();
+        assert!(tokens.contains("CONTRACT_METADATA"));
+        assert!(tokens.contains("xrpl_metadata"));
+    }
+
+    #[test]
+    fn expand_errors_on_fields_out_of_order() {
+        let input = quote! { version: "1.2.0", name: "escrow", abi: 1 };
+        let err = expand(input).unwrap_err();
+        assert!(err.to_string().contains("expected `name`"));
+    }
+
+    #[test]
+    fn expand_errors_on_abi_out_of_range() {
+        let input = quote! { name: "escrow", version: "1.2.0", abi: 4294967296 };
+        assert!(expand(input).is_err());
+    }
+}
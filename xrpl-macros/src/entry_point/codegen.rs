@@ -8,24 +8,44 @@ use super::parse::EntryFn;
 use super::validate::ReturnKind;
 
 pub(crate) struct CodegenConfig {
-    pub export_symbol: &'static str,
-    pub ctx_path: TokenStream,
+    pub export_symbol: String,
+    /// Context type to construct, or `None` to use the function's parameter type and check at
+    /// compile time that its `EntryContext::ENTRY_POINT` names `export_symbol`.
+    pub ctx_path: Option<TokenStream>,
 }
 
 pub(crate) fn emit(entry: &EntryFn, kind: &ReturnKind, cfg: &CodegenConfig) -> TokenStream {
     let user_fn = &entry.func;
     let fn_name = &entry.func.sig.ident;
-    let export = Ident::new(cfg.export_symbol, Span::call_site());
-    let ctx = &cfg.ctx_path;
+    let export = Ident::new(&cfg.export_symbol, Span::call_site());
 
     let call = match kind {
-        ReturnKind::FinishResult => quote! { i32::from(#fn_name(ctx)) },
+        ReturnKind::FinishResult | ReturnKind::IntoI32 => quote! { i32::from(#fn_name(ctx)) },
         ReturnKind::I32 => quote! { #fn_name(ctx) },
     };
 
+    let (ctx, check) = match &cfg.ctx_path {
+        Some(path) => (path.clone(), TokenStream::new()),
+        None => {
+            let ty = param_type(entry);
+            let symbol = &cfg.export_symbol;
+            (
+                quote!(<#ty>),
+                quote! {
+                    const _: () = ::xrpl_common_stdlib::ctx::check_entry_point(
+                        <#ty as ::xrpl_common_stdlib::ctx::EntryContext>::ENTRY_POINT,
+                        #symbol,
+                    );
+                },
+            )
+        }
+    };
+
     quote! {
         #user_fn
 
+        #check
+
         #[unsafe(no_mangle)]
         pub extern "C" fn #export() -> i32 {
             let ctx = #ctx::default();
@@ -33,3 +53,11 @@ pub(crate) fn emit(entry: &EntryFn, kind: &ReturnKind, cfg: &CodegenConfig) -> T
         }
     }
 }
+
+/// The type of the single parameter, which `validate` has already checked exists.
+fn param_type(entry: &EntryFn) -> &syn::Type {
+    match entry.func.sig.inputs.first() {
+        Some(syn::FnArg::Typed(pat_type)) => &pat_type.ty,
+        _ => unreachable!("validated entry-point functions take one typed parameter"),
+    }
+}
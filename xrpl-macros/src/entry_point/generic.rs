@@ -0,0 +1,54 @@
+//! `#[entry_point(<export>)]` attribute macro.
+//!
+//! Unlike `#[smart_escrow]`, the export symbol comes from the attribute and the context type from
+//! the function's parameter, so entry points the host adds later need no new macro. The generated
+//! code checks at compile time that the context's `EntryContext::ENTRY_POINT` names the same
+//! export.
+
+use proc_macro::TokenStream;
+use syn::Ident;
+
+use super::codegen::{CodegenConfig, emit};
+use super::parse::parse_entry_fn;
+use super::validate::{ValidationRules, validate};
+
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr2: proc_macro2::TokenStream = attr.into();
+    if attr2.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "#[entry_point] needs the name of the export, e.g. `#[entry_point(finish)]`",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let export: Ident = match syn::parse2(attr2) {
+        Ok(ident) => ident,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let entry = match parse_entry_fn(item) {
+        Ok(e) => e,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let kind = match validate(
+        &entry,
+        &ValidationRules {
+            expected_ctx_type: None,
+        },
+    ) {
+        Ok(k) => k,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    emit(
+        &entry,
+        &kind,
+        &CodegenConfig {
+            export_symbol: export.to_string(),
+            ctx_path: None,
+        },
+    )
+    .into()
+}
@@ -8,8 +8,13 @@
 //! three stages together with macro-specific rules and export symbols. Adding a
 //! third entry-point macro means adding a new orchestrator file and a new
 //! `#[proc_macro_attribute]` shim in `lib.rs` — the pipeline itself is unchanged.
+//!
+//! [`generic`] backs `#[entry_point(<export>)]`, which takes the export symbol as
+//! an argument and any context type implementing `EntryContext`, so new host
+//! entry points don't need a dedicated macro at all.
 
 pub(crate) mod codegen;
+pub(crate) mod generic;
 pub(crate) mod parse;
 pub(crate) mod smart_contract;
 pub(crate) mod smart_escrow;
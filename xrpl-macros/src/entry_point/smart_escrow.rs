@@ -23,7 +23,7 @@ pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
     let kind = match validate(
         &entry,
         &ValidationRules {
-            expected_ctx_type: "EscrowFinishContext",
+            expected_ctx_type: Some("EscrowFinishContext"),
         },
     ) {
         Ok(k) => k,
@@ -34,8 +34,8 @@ pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
         &entry,
         &kind,
         &CodegenConfig {
-            export_symbol: "finish",
-            ctx_path: quote!(::xrpl_escrow_stdlib::EscrowFinishContext),
+            export_symbol: "finish".to_string(),
+            ctx_path: Some(quote!(::xrpl_escrow_stdlib::EscrowFinishContext)),
         },
     )
     .into()
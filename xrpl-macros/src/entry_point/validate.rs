@@ -3,30 +3,34 @@
 use super::parse::EntryFn;
 
 pub(crate) struct ValidationRules<'a> {
-    pub expected_ctx_type: &'a str,
+    /// Name of the only context type the macro accepts, or `None` to accept any type (checked
+    /// against the export symbol by the generated code instead).
+    pub expected_ctx_type: Option<&'a str>,
 }
 
 pub(crate) enum ReturnKind {
     FinishResult,
     I32,
+    /// Any other type; converted with `i32::from`. Only accepted without an `expected_ctx_type`.
+    IntoI32,
 }
 
 pub(crate) fn validate(entry: &EntryFn, rules: &ValidationRules<'_>) -> syn::Result<ReturnKind> {
     check_params(entry, rules)?;
-    classify_return_type(entry)
+    classify_return_type(entry, rules)
 }
 
 fn check_params(entry: &EntryFn, rules: &ValidationRules<'_>) -> syn::Result<()> {
     let inputs = &entry.func.sig.inputs;
 
     if inputs.is_empty() {
-        return Err(syn::Error::new_spanned(
-            &entry.func.sig.ident,
-            format!(
-                "entry-point function must take exactly one parameter of type `{}`",
-                rules.expected_ctx_type
-            ),
-        ));
+        let msg = match rules.expected_ctx_type {
+            Some(expected) => {
+                format!("entry-point function must take exactly one parameter of type `{expected}`")
+            }
+            None => "entry-point function must take exactly one context parameter".to_string(),
+        };
+        return Err(syn::Error::new_spanned(&entry.func.sig.ident, msg));
     }
 
     if inputs.len() > 1 {
@@ -47,32 +51,44 @@ fn check_params(entry: &EntryFn, rules: &ValidationRules<'_>) -> syn::Result<()>
         }
     };
 
+    let Some(expected_ctx_type) = rules.expected_ctx_type else {
+        return Ok(());
+    };
     let matches = match ty {
         syn::Type::Path(type_path) => type_path
             .path
             .segments
             .last()
-            .is_some_and(|seg| seg.ident == rules.expected_ctx_type),
+            .is_some_and(|seg| seg.ident == expected_ctx_type),
         _ => false,
     };
 
     if !matches {
         return Err(syn::Error::new_spanned(
             ty,
-            format!("parameter type must be `{}`", rules.expected_ctx_type),
+            format!("parameter type must be `{expected_ctx_type}`"),
         ));
     }
 
     Ok(())
 }
 
-fn classify_return_type(entry: &EntryFn) -> syn::Result<ReturnKind> {
+fn classify_return_type(entry: &EntryFn, rules: &ValidationRules<'_>) -> syn::Result<ReturnKind> {
     match &entry.func.sig.output {
+        syn::ReturnType::Default if rules.expected_ctx_type.is_none() => {
+            Err(syn::Error::new_spanned(
+                &entry.func.sig.ident,
+                "entry-point function must return a value convertible to `i32`",
+            ))
+        }
         syn::ReturnType::Default => Err(syn::Error::new_spanned(
             &entry.func.sig.ident,
             "entry-point function must return `FinishResult` or `i32`",
         )),
-        syn::ReturnType::Type(_, ty) => classify_return(ty),
+        syn::ReturnType::Type(_, ty) => match classify_return(ty) {
+            Err(_) if rules.expected_ctx_type.is_none() => Ok(ReturnKind::IntoI32),
+            kind => kind,
+        },
     }
 }
 
@@ -4,22 +4,27 @@
 //! Logic, helpers, and unit tests live in the per-macro files.
 //!
 //! - **Typed-constant macros** (`r_address!`, `hash256!`, `pubkey!`,
-//!   `currency!`, `blob!`): validate at compile time and emit a typed XRPL
-//!   value. `hex_util` holds decode helpers shared across these macros.
-//! - **Entry-point macros** (`#[smart_escrow]`, `#[smart_contract]`): wrap
+//!   `currency!`, `blob!`, `xrp!`, `amendment_id!`): validate at compile time and emit a
+//!   typed XRPL value. `hex_util` holds decode helpers shared across these macros.
+//! - **`contract_metadata!`**: embeds a description of the contract in a custom WASM section
+//!   for tooling to read back.
+//! - **Entry-point macros** (`#[smart_escrow]`, `#[smart_contract]`, `#[entry_point]`): wrap
 //!   user functions in the `extern "C"` symbols the XRPL host calls. All
 //!   three stages — parse, validate, codegen — live in `entry_point/` and are
 //!   shared between the two macros so adding a third follows the same pattern.
 
 use proc_macro::TokenStream;
 
+mod amendment_id;
 mod blob;
+mod contract_metadata;
 mod currency;
 mod entry_point;
 mod hash256;
 mod hex_util;
 mod pubkey;
 mod r_address;
+mod xrp;
 
 /// Converts an XRPL classic address (r-address) to a 20-byte [`AccountID`] at compile time.
 ///
@@ -65,6 +70,31 @@ pub fn hash256(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Converts an amendment name to its 32-byte amendment ID at compile time.
+///
+/// rippled derives an amendment's ID from its name (the SHA-512Half of the name), so hashing at
+/// compile time saves the contract a host call per lookup. The result is a `[u8; 32]`, ready for
+/// `amendment_enabled`. Empty names and names with characters other than ASCII letters, digits
+/// and underscores are compile errors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use xrpl_common_stdlib::amendment_id;
+/// use xrpl_common_stdlib::host::chain::amendment_enabled;
+///
+/// const TOKEN_ESCROW: [u8; 32] = amendment_id!("TokenEscrow");
+///
+/// let enabled = amendment_enabled(&TOKEN_ESCROW);
+/// ```
+#[proc_macro]
+pub fn amendment_id(input: TokenStream) -> TokenStream {
+    match amendment_id::expand(input.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Converts a 66-character hex string to a 33-byte [`PublicKey`] at compile time.
 ///
 /// The first two hex characters must be a valid XRPL public key prefix:
@@ -145,6 +175,54 @@ pub fn blob(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Converts a decimal XRP literal to an [`Amount::XRP`] (in drops) at compile time.
+///
+/// The literal is converted digit by digit, so `xrp!(0.1)` is exactly 100,000 drops with no
+/// floating-point rounding. A leading `-` is allowed. More than 6 decimal places (a fraction of a
+/// drop), exponent notation, type suffixes, or amounts above the 100 billion XRP supply are
+/// compile errors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use xrpl_common_stdlib::xrp;
+/// use xrpl_common_stdlib::types::amount::Amount;
+///
+/// const MIN_DEPOSIT: Amount = xrp!(1.5); // Amount::XRP { num_drops: 1_500_000 }
+/// ```
+#[proc_macro]
+pub fn xrp(input: TokenStream) -> TokenStream {
+    match xrp::expand(input.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Describes the contract in an `xrpl_metadata` custom section of the WASM binary.
+///
+/// Takes a name, a version and the host ABI version the contract targets, in that order. It
+/// expands to a `pub static CONTRACT_METADATA` holding one `key=value` line per field, so it can
+/// be used at most once per module (once per crate, in practice). Tooling reads the section back
+/// with `xrpl_common_stdlib::metadata::ContractMetadata::parse`; the static is kept even though
+/// nothing in the contract refers to it.
+///
+/// Empty or multi-line names and versions, and an `abi` that is not a `u32`, are compile errors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use xrpl_common_stdlib::contract_metadata;
+///
+/// contract_metadata! { name: "vesting_escrow", version: "1.2.0", abi: 1 }
+/// ```
+#[proc_macro]
+pub fn contract_metadata(input: TokenStream) -> TokenStream {
+    match contract_metadata::expand(input.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Wraps a Smart Escrow finish function in the `extern "C" fn finish()` entry point
 /// the XRPL host calls when an `EscrowFinish` transaction invokes the feature.
 ///
@@ -212,6 +290,32 @@ pub fn smart_escrow(attr: TokenStream, item: TokenStream) -> TokenStream {
     entry_point::smart_escrow::expand(attr, item)
 }
 
+/// Exports a function as any host entry point, e.g. `#[entry_point(finish)]` or
+/// `#[entry_point(cancel)]`.
+///
+/// The attribute names the `extern "C"` symbol to export. The annotated function must take exactly
+/// one parameter, whose type implements `xrpl_common_stdlib::ctx::EntryContext` (and therefore
+/// `Default`), and return `i32` or a type `i32` implements `From` for (such as `FinishResult`).
+/// It is a compile error if the context's `ENTRY_POINT` is not the exported symbol, so a context
+/// cannot be wired to an entry point it was not written for.
+///
+/// `#[smart_escrow]` remains the shorthand for `finish`; this macro covers the rest, including
+/// entry points that hosts add later, for which a contract can define its own context type.
+/// The generated check refers to `xrpl_common_stdlib`, so the contract must depend on it directly.
+///
+/// ```rust,ignore
+/// use xrpl_escrow_stdlib::{entry_point, EscrowFinishContext, FinishResult};
+///
+/// #[entry_point(finish)]
+/// fn run(_ctx: EscrowFinishContext) -> FinishResult {
+///     FinishResult::succeed()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn entry_point(attr: TokenStream, item: TokenStream) -> TokenStream {
+    entry_point::generic::expand(attr, item)
+}
+
 /// Wraps a Smart Contract entry function in the appropriate `extern "C"` export.
 #[proc_macro_attribute]
 pub fn smart_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
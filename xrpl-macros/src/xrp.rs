@@ -0,0 +1,148 @@
+//! `xrp!` — compile-time decimal XRP literal → `Amount::XRP` in drops.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Lit, Token};
+
+/// Drops in one XRP.
+const DROPS_PER_XRP: u64 = 1_000_000;
+/// Number of decimal places representable in drops.
+const MAX_DECIMALS: usize = 6;
+/// 100 billion XRP — the total supply, and therefore the largest meaningful amount.
+const MAX_DROPS: u64 = 100_000_000_000 * DROPS_PER_XRP;
+
+struct XrpInput {
+    negative: bool,
+    lit: Lit,
+}
+
+impl Parse for XrpInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+        let lit: Lit = input.parse()?;
+        match lit {
+            Lit::Int(_) | Lit::Float(_) => Ok(XrpInput { negative, lit }),
+            other => Err(syn::Error::new(other.span(), "expected numeric literal")),
+        }
+    }
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let XrpInput { negative, lit } = syn::parse2::<XrpInput>(input)?;
+    let (digits, suffix) = match &lit {
+        Lit::Int(int) => (int.base10_digits(), int.suffix()),
+        Lit::Float(float) => (float.base10_digits(), float.suffix()),
+        _ => unreachable!("XrpInput only accepts numeric literals"),
+    };
+    if !suffix.is_empty() {
+        return Err(syn::Error::new(
+            lit.span(),
+            "Invalid XRP amount: type suffixes are not allowed",
+        ));
+    }
+
+    let drops = parse_drops(digits)
+        .map_err(|reason| syn::Error::new(lit.span(), format!("Invalid XRP amount: {reason}")))?;
+    // MAX_DROPS fits comfortably in i64, so the cast cannot wrap.
+    let num_drops = if negative {
+        -(drops as i64)
+    } else {
+        drops as i64
+    };
+
+    Ok(quote! {
+        ::xrpl_common_stdlib::types::amount::Amount::XRP { num_drops: #num_drops }
+    })
+}
+
+/// Converts a base-10 literal such as `"1.5"` into drops without going through `f64`.
+fn parse_drops(digits: &str) -> Result<u64, &'static str> {
+    if digits.contains(['e', 'E']) {
+        return Err("exponent notation is not supported");
+    }
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if fraction.len() > MAX_DECIMALS {
+        return Err("more than 6 decimal places (smaller than one drop)");
+    }
+
+    let whole: u64 = whole.parse().map_err(|_| "exceeds the XRP supply")?;
+    let mut fraction_drops: u64 = 0;
+    for (i, c) in fraction.chars().enumerate() {
+        let digit = c.to_digit(10).ok_or("non-digit character")? as u64;
+        fraction_drops += digit * 10u64.pow((MAX_DECIMALS - 1 - i) as u32);
+    }
+
+    whole
+        .checked_mul(DROPS_PER_XRP)
+        .and_then(|drops| drops.checked_add(fraction_drops))
+        .filter(|&drops| drops <= MAX_DROPS)
+        .ok_or("exceeds the XRP supply")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_DROPS, expand, parse_drops};
+    use quote::quote;
+
+    #[test]
+    fn parses_whole_xrp() {
+        assert_eq!(parse_drops("25").unwrap(), 25_000_000);
+    }
+
+    #[test]
+    fn parses_fractional_xrp() {
+        assert_eq!(parse_drops("1.5").unwrap(), 1_500_000);
+        assert_eq!(parse_drops("0.000001").unwrap(), 1);
+        assert_eq!(parse_drops("2.").unwrap(), 2_000_000);
+    }
+
+    #[test]
+    fn parses_total_supply() {
+        assert_eq!(parse_drops("100000000000").unwrap(), MAX_DROPS);
+    }
+
+    #[test]
+    fn rejects_sub_drop_precision() {
+        let err = parse_drops("0.0000001").unwrap_err();
+        assert_eq!(err, "more than 6 decimal places (smaller than one drop)");
+    }
+
+    #[test]
+    fn rejects_more_than_supply() {
+        assert_eq!(
+            parse_drops("100000000000.000001").unwrap_err(),
+            "exceeds the XRP supply"
+        );
+        assert_eq!(
+            parse_drops("99999999999999999999").unwrap_err(),
+            "exceeds the XRP supply"
+        );
+    }
+
+    #[test]
+    fn rejects_exponent() {
+        assert_eq!(
+            parse_drops("1e3").unwrap_err(),
+            "exponent notation is not supported"
+        );
+    }
+
+    #[test]
+    fn expand_accepts_negative_amounts() {
+        let tokens = expand(quote! { -1.25 }).unwrap().to_string();
+        assert!(tokens.contains("- 1250000i64"), "{tokens}");
+    }
+
+    #[test]
+    fn expand_errors_on_suffix() {
+        let err = expand(quote! { 1u64 }).unwrap_err();
+        assert!(err.to_string().contains("type suffixes are not allowed"));
+    }
+
+    #[test]
+    fn expand_errors_on_string_literal() {
+        let err = expand(quote! { "1.5" }).unwrap_err();
+        assert!(err.to_string().contains("expected numeric literal"));
+    }
+}
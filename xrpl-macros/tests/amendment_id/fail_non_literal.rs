@@ -0,0 +1,10 @@
+use xrpl_macros::amendment_id;
+
+// The macro only accepts string literals, never runtime expressions.
+fn convert(name: &str) {
+    amendment_id!(name);
+}
+
+fn main() {
+    convert("TokenEscrow");
+}
@@ -1,7 +1,7 @@
 //! Compile-fail tests for every macro in `xrpl-macros`.
 //!
 //! **Typed-constant macros** (`r_address!`, `hash256!`, `pubkey!`, `currency!`,
-//! `blob!`): trybuild covers `fail_non_literal` — the parser-level error that
+//! `blob!`, `xrp!`, `amendment_id!`): trybuild covers `fail_non_literal` — the parser-level error that
 //! has no decode-function equivalent. All other rejection paths (wrong length,
 //! bad prefix, XRP reserved, capacity overflow, …) are unit-tested directly
 //! against the per-macro `decode_*` / `check_*` helpers, which is faster and
@@ -21,5 +21,8 @@ fn compile_fail() {
     t.compile_fail("tests/pubkey/fail_*.rs");
     t.compile_fail("tests/currency/fail_*.rs");
     t.compile_fail("tests/blob/fail_*.rs");
+    t.compile_fail("tests/xrp/fail_*.rs");
+    t.compile_fail("tests/amendment_id/fail_*.rs");
     t.compile_fail("tests/smart_escrow/fail_*.rs");
+    t.compile_fail("tests/entry_point/fail_*.rs");
 }
@@ -0,0 +1,9 @@
+use xrpl_macros::entry_point;
+
+// The export symbol is required; there is no default entry point.
+#[entry_point]
+fn run(ctx: u32) -> i32 {
+    ctx as i32
+}
+
+fn main() {}
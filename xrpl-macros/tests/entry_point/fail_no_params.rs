@@ -0,0 +1,8 @@
+use xrpl_macros::entry_point;
+
+#[entry_point(ready)]
+fn run() -> i32 {
+    1
+}
+
+fn main() {}
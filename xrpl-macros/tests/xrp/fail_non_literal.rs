@@ -0,0 +1,10 @@
+use xrpl_macros::xrp;
+
+// The macro only accepts numeric literals, never runtime expressions.
+fn convert(amount: f64) {
+    xrp!(amount);
+}
+
+fn main() {
+    convert(1.5);
+}
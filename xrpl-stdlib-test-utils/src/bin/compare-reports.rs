@@ -0,0 +1,43 @@
+//! Diffs two observation reports.
+//!
+//! ```text
+//! XRPL_REPORT_DIR=/tmp/reports cargo test -p trace_escrow_finish     # in e2e-tests/
+//! cargo run -p xrpl-stdlib-test-utils --bin compare-reports -- \
+//!     /tmp/reports/trace_escrow_finish.report rippled.log
+//! ```
+//!
+//! Either file may be a saved report or a rippled log containing `@report` trace lines. Exits
+//! with status 1 if the reports differ and 2 on usage or I/O errors.
+
+use std::process::ExitCode;
+
+use xrpl_stdlib_test_utils::report::{compare, parse};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [expected_path, actual_path] = args.as_slice() else {
+        eprintln!("usage: compare-reports <expected> <actual>");
+        return ExitCode::from(2);
+    };
+
+    let mut reports = Vec::new();
+    for path in [expected_path, actual_path] {
+        match std::fs::read_to_string(path) {
+            Ok(text) => reports.push(parse(&text)),
+            Err(e) => {
+                eprintln!("cannot read {path}: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let differences = compare(&reports[0], &reports[1]);
+    if differences.is_empty() {
+        println!("reports match ({} observations)", reports[0].values.len());
+        return ExitCode::SUCCESS;
+    }
+    for difference in &differences {
+        println!("{difference}");
+    }
+    ExitCode::FAILURE
+}
@@ -0,0 +1,40 @@
+//! Prints the `contract_metadata!` of a compiled contract.
+//!
+//! ```text
+//! cargo run -p xrpl-stdlib-test-utils --bin contract-metadata -- path/to/contract.wasm
+//! ```
+//!
+//! Exits with status 1 if the module has no readable metadata and 2 on usage or I/O errors.
+
+use std::process::ExitCode;
+
+use xrpl_stdlib_test_utils::metadata::read_contract_metadata;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [path] = args.as_slice() else {
+        eprintln!("usage: contract-metadata <contract.wasm>");
+        return ExitCode::from(2);
+    };
+
+    let wasm = match std::fs::read(path) {
+        Ok(wasm) => wasm,
+        Err(e) => {
+            eprintln!("cannot read {path}: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match read_contract_metadata(&wasm) {
+        Some(metadata) => {
+            println!("name: {}", metadata.name);
+            println!("version: {}", metadata.version);
+            println!("abi: {}", metadata.abi);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("{path} has no contract metadata");
+            ExitCode::FAILURE
+        }
+    }
+}
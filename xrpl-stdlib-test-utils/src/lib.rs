@@ -1,13 +1,21 @@
 //! Test harness for XRPL WebAssembly smart contracts.
 //!
 //! `MockHostBindings` (re-exported via [`mock_common`]) is defined inline in
-//! `xrpl-wasm-stdlib` because `mockall::automock` generates it next to the `HostBindings`
-//! trait. This crate is the author-facing entry point on top of it: a plain re-export for the
-//! raw mock, plus domain-specific scenario builders (see [`mock_escrow`]) that translate
-//! escrow facts into mock expectations. Always a dev-dependency; never compiled to WASM.
+//! `xrpl-wasm-stdlib` because `mockall` generates it next to the host capability traits. This
+//! crate is the author-facing entry point on top of it: a plain re-export for the raw mock, plus
+//! domain-specific scenario builders (see [`mock_escrow`]) that translate escrow facts into mock
+//! expectations. [`report`] and the `compare-reports` binary diff the observation reports that
+//! e2e contracts emit, so a contract's run on the stub host can be checked against its run on
+//! rippled. [`metadata`] and the `contract-metadata` binary read `contract_metadata!` back out of
+//! a compiled contract. [`example_runner`] calls a contract's exported `finish` against a
+//! `FixtureHost`, which is how this crate's integration tests cover the examples. Always a
+//! dev-dependency; never compiled to WASM.
 
+pub mod example_runner;
+pub mod metadata;
 pub mod mock_common;
 pub mod mock_escrow;
+pub mod report;
 
 pub use mock_common::*;
 pub use mock_escrow::*;
@@ -0,0 +1,106 @@
+//! Reading `contract_metadata!` back out of a compiled contract.
+//!
+//! [`read_contract_metadata`] takes the bytes of a `.wasm` file and returns the
+//! [`ContractMetadata`] stored in its `xrpl_metadata` custom section. The `contract-metadata`
+//! binary prints it for a file on disk.
+
+use xrpl_common_stdlib::metadata::{ContractMetadata, SECTION_NAME};
+
+const WASM_MAGIC: &[u8; 4] = b"\0asm";
+const CUSTOM_SECTION_ID: u8 = 0;
+
+/// Returns the metadata embedded in `wasm`, or `None` if the module has no metadata section or it
+/// cannot be parsed.
+pub fn read_contract_metadata(wasm: &[u8]) -> Option<ContractMetadata<'_>> {
+    custom_section(wasm, SECTION_NAME).and_then(ContractMetadata::parse)
+}
+
+/// Returns the payload of the first custom section called `name`, or `None` if there is none or
+/// `wasm` is not a well-formed module.
+pub fn custom_section<'a>(wasm: &'a [u8], name: &str) -> Option<&'a [u8]> {
+    if wasm.get(..4)? != WASM_MAGIC {
+        return None;
+    }
+    // Skip the magic number and the 4-byte version.
+    let mut rest = wasm.get(8..)?;
+    while let Some((&id, after_id)) = rest.split_first() {
+        let (size, after_size) = read_leb128(after_id)?;
+        let body = after_size.get(..size)?;
+        rest = &after_size[size..];
+
+        if id == CUSTOM_SECTION_ID {
+            let (name_len, after_name_len) = read_leb128(body)?;
+            if after_name_len.get(..name_len)? == name.as_bytes() {
+                return Some(&after_name_len[name_len..]);
+            }
+        }
+    }
+    None
+}
+
+/// Decodes an unsigned LEB128 `u32`, returning it and the bytes after it.
+fn read_leb128(bytes: &[u8]) -> Option<(usize, &[u8])> {
+    let mut value: u32 = 0;
+    for (i, &byte) in bytes.iter().enumerate().take(5) {
+        value |= ((byte & 0x7F) as u32).checked_shl(7 * i as u32)?;
+        if byte & 0x80 == 0 {
+            return Some((value as usize, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut body = vec![name.len() as u8];
+        body.extend_from_slice(name.as_bytes());
+        body.extend_from_slice(payload);
+        let mut section = vec![CUSTOM_SECTION_ID, body.len() as u8];
+        section.extend(body);
+        section
+    }
+
+    fn module(sections: &[Vec<u8>]) -> Vec<u8> {
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        // An empty type section, to check that non-custom sections are skipped.
+        wasm.extend_from_slice(&[1, 1, 0]);
+        for section in sections {
+            wasm.extend_from_slice(section);
+        }
+        wasm
+    }
+
+    #[test]
+    fn test_reads_metadata_section() {
+        let wasm = module(&[
+            custom("name", b"ignored"),
+            custom(SECTION_NAME, b"name=escrow\nversion=1.2.0\nabi=1\n"),
+        ]);
+        let metadata = read_contract_metadata(&wasm).unwrap();
+        assert_eq!(metadata.name, "escrow");
+        assert_eq!(metadata.version, "1.2.0");
+        assert_eq!(metadata.abi, 1);
+    }
+
+    #[test]
+    fn test_missing_or_malformed_modules() {
+        assert_eq!(read_contract_metadata(&module(&[])), None);
+        assert_eq!(read_contract_metadata(b"not wasm"), None);
+
+        let mut truncated = module(&[custom(SECTION_NAME, b"name=a\nversion=1\nabi=1\n")]);
+        truncated.pop();
+        assert_eq!(read_contract_metadata(&truncated), None);
+    }
+
+    #[test]
+    fn test_leb128_multi_byte() {
+        assert_eq!(
+            read_leb128(&[0xE5, 0x8E, 0x26, 9]),
+            Some((624_485, &[9u8][..]))
+        );
+        assert_eq!(read_leb128(&[0x80, 0x80]), None);
+    }
+}
@@ -1,11 +1,21 @@
 //! Re-exports of the mock host-bindings machinery that lives inline in `xrpl-wasm-stdlib`.
 //!
-//! `mockall::automock` generates `MockHostBindings` right next to the `HostBindings` trait
-//! definition, so the type itself can't live in this crate. What lives here instead is the
-//! author-facing entry point: import from `xrpl_stdlib_test_utils` instead of reaching into
-//! `xrpl_common_stdlib::host::*` directly.
+//! `MockHostBindings`, `ComposedHost` and the per-capability mocks (`MockTrace`, ...) are
+//! generated right next to the capability traits they implement, so the types themselves can't
+//! live in this crate. What lives here instead is the author-facing entry point: import from
+//! `xrpl_stdlib_test_utils` instead of reaching into `xrpl_common_stdlib::host::*` directly.
 
-pub use xrpl_common_stdlib::host::host_bindings_trait::{HostBindings, MockHostBindings};
+pub use xrpl_common_stdlib::host::host_bindings_trait::fixture::{
+    FixtureBytes, FixtureHost, FixtureRng,
+};
+pub use xrpl_common_stdlib::host::host_bindings_trait::record::{
+    CallLog, HostCallRecord, RecordingHost, ReplayHost,
+};
+pub use xrpl_common_stdlib::host::host_bindings_trait::{
+    ComposedHost, Crypto, Float, HostBindings, Keylets, LedgerHeader, LedgerObjects, MockCrypto,
+    MockFloat, MockHostBindings, MockKeylets, MockLedgerHeader, MockLedgerObjects, MockNft,
+    MockTrace, MockTxFields, Nft, Trace, TxFields,
+};
 pub use xrpl_common_stdlib::host::{
     MockGuard, apply_default_expectations, create_default_mock, setup_mock,
 };
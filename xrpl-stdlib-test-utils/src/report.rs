@@ -0,0 +1,167 @@
+//! Reading and diffing observation reports.
+//!
+//! E2E contracts record the values they read with `test_utils::report::Report` (in `e2e-tests/`)
+//! and emit them as `@report <name>=<value>` trace lines. Running a contract natively against the
+//! stub host and on a rippled node therefore yields two reports, and [`compare`] lists the
+//! observations on which they disagree. The `compare-reports` binary wraps this for the command
+//! line.
+//!
+//! [`parse`] accepts any text containing report lines — a saved `.report` file or a raw rippled
+//! log — and ignores everything else on and around those lines.
+
+use std::collections::BTreeMap;
+
+/// Prefix of every report line; must match `test_utils::report::MARKER`.
+pub const MARKER: &str = "@report ";
+
+/// Name under which a report records that it dropped observations.
+pub const OVERFLOW: &str = "!overflow";
+
+/// The observations of one run, keyed by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Observations {
+    /// Observed values, as written by the contract (hex or decimal).
+    pub values: BTreeMap<String, String>,
+    /// Whether the contract ran out of report space.
+    pub overflowed: bool,
+}
+
+/// One way in which two reports disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// Both runs observed `name`, with different values.
+    Value {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+    /// Only the expected run observed `name`.
+    Missing { name: String },
+    /// Only the actual run observed `name`.
+    Unexpected { name: String },
+    /// A report dropped observations, so the comparison is incomplete.
+    Overflowed { in_expected: bool },
+}
+
+impl core::fmt::Display for Difference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Difference::Value {
+                name,
+                expected,
+                actual,
+            } => write!(f, "{name}: expected {expected}, got {actual}"),
+            Difference::Missing { name } => write!(f, "{name}: missing"),
+            Difference::Unexpected { name } => write!(f, "{name}: not in the expected report"),
+            Difference::Overflowed { in_expected } => {
+                let which = if *in_expected { "expected" } else { "actual" };
+                write!(f, "the {which} report overflowed")
+            }
+        }
+    }
+}
+
+/// Extracts the report lines from `text`.
+///
+/// If a name is observed more than once, the first value is kept, so a contract that traces in a
+/// loop is compared on its first iteration.
+pub fn parse(text: &str) -> Observations {
+    let mut observations = Observations::default();
+    for line in text.lines() {
+        let Some(start) = line.find(MARKER) else {
+            continue;
+        };
+        let entry = line[start + MARKER.len()..]
+            .split_whitespace()
+            .next()
+            .unwrap_or("");
+        if entry == OVERFLOW {
+            observations.overflowed = true;
+        } else if let Some((name, value)) = entry.split_once('=') {
+            observations
+                .values
+                .entry(name.to_string())
+                .or_insert_with(|| value.to_string());
+        }
+    }
+    observations
+}
+
+/// Lists the differences between two reports, in name order. Hex values are compared without
+/// regard to case.
+pub fn compare(expected: &Observations, actual: &Observations) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    if expected.overflowed {
+        differences.push(Difference::Overflowed { in_expected: true });
+    }
+    if actual.overflowed {
+        differences.push(Difference::Overflowed { in_expected: false });
+    }
+
+    for (name, expected_value) in &expected.values {
+        match actual.values.get(name) {
+            Some(actual_value) if actual_value.eq_ignore_ascii_case(expected_value) => {}
+            Some(actual_value) => differences.push(Difference::Value {
+                name: name.clone(),
+                expected: expected_value.clone(),
+                actual: actual_value.clone(),
+            }),
+            None => differences.push(Difference::Missing { name: name.clone() }),
+        }
+    }
+    for name in actual.values.keys() {
+        if !expected.values.contains_key(name) {
+            differences.push(Difference::Unexpected { name: name.clone() });
+        }
+    }
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_picks_report_lines_out_of_a_log() {
+        let log = "\
+2026-Oct-15 WASM:TRC $$$$$ STARTING WASM EXECUTION $$$$$
+2026-Oct-15 WASM:TRC @report Account=0A0B
+2026-Oct-15 WASM:TRC   Sequence: 7
+2026-Oct-15 WASM:TRC @report Sequence=7
+@report Sequence=8
+@report !overflow
+";
+        let observations = parse(log);
+        assert_eq!(observations.values.len(), 2);
+        assert_eq!(observations.values["Account"], "0A0B");
+        assert_eq!(observations.values["Sequence"], "7");
+        assert!(observations.overflowed);
+    }
+
+    #[test]
+    fn test_compare_reports_each_kind_of_difference() {
+        let expected = parse("@report A=0a0b\n@report B=1\n@report C=2\n");
+        let actual = parse("@report A=0A0B\n@report B=3\n@report D=4\n@report !overflow\n");
+
+        let differences = compare(&expected, &actual);
+        assert_eq!(
+            differences,
+            [
+                Difference::Overflowed { in_expected: false },
+                Difference::Value {
+                    name: "B".to_string(),
+                    expected: "1".to_string(),
+                    actual: "3".to_string(),
+                },
+                Difference::Missing {
+                    name: "C".to_string()
+                },
+                Difference::Unexpected {
+                    name: "D".to_string()
+                },
+            ]
+        );
+        assert_eq!(differences[1].to_string(), "B: expected 1, got 3");
+        assert!(compare(&expected, &expected).is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+//! `examples/smart-escrows/hello_world` run against the fixture host.
+
+#[path = "../../examples/smart-escrows/hello_world/src/lib.rs"]
+mod contract;
+
+use xrpl_stdlib_test_utils::FixtureHost;
+use xrpl_stdlib_test_utils::example_runner::{Outcome, run_finish};
+
+#[test]
+fn finishes_unconditionally() {
+    assert_eq!(
+        run_finish(contract::finish, FixtureHost::new()),
+        Outcome::Finish
+    );
+}
@@ -0,0 +1,39 @@
+//! `examples/smart-escrows/kyc` run against the fixture host.
+//!
+//! The fixture host's keylet stubs don't derive real keylets, so these tests only say whether the
+//! credential lookup hits or misses; that the right credential is looked up is covered by the
+//! e2e tests.
+
+#[path = "../../examples/smart-escrows/kyc/src/lib.rs"]
+mod contract;
+
+use xrpl_common_stdlib::host::error_codes::FIELD_NOT_FOUND;
+use xrpl_common_stdlib::r_address;
+use xrpl_common_stdlib::sfield;
+use xrpl_common_stdlib::types::account_id::AccountID;
+use xrpl_stdlib_test_utils::FixtureHost;
+use xrpl_stdlib_test_utils::example_runner::{Outcome, run_finish};
+
+const DESTINATION: AccountID = r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+
+#[test]
+fn finishes_when_the_destination_credential_exists() {
+    let host = FixtureHost::new().with_bytes(sfield::Destination.into(), DESTINATION.0);
+    assert_eq!(run_finish(contract::finish, host), Outcome::Finish);
+}
+
+#[test]
+fn rejects_when_the_destination_credential_is_missing() {
+    let host = FixtureHost::new()
+        .with_bytes(sfield::Destination.into(), DESTINATION.0)
+        .with_keylet_misses();
+    assert_eq!(run_finish(contract::finish, host), Outcome::Reject);
+}
+
+#[test]
+fn propagates_a_missing_destination() {
+    assert_eq!(
+        run_finish(contract::finish, FixtureHost::new()),
+        Outcome::Error(FIELD_NOT_FOUND)
+    );
+}
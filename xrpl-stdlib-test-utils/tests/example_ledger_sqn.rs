@@ -0,0 +1,21 @@
+//! `examples/smart-escrows/ledger_sqn` run against the fixture host.
+
+#[path = "../../examples/smart-escrows/ledger_sqn/src/lib.rs"]
+mod contract;
+
+use xrpl_stdlib_test_utils::FixtureHost;
+use xrpl_stdlib_test_utils::example_runner::{Outcome, run_finish};
+
+#[test]
+fn rejects_before_ledger_5() {
+    let host = FixtureHost::new().with_ledger_sqn(4);
+    assert_eq!(run_finish(contract::finish, host), Outcome::Reject);
+}
+
+#[test]
+fn finishes_from_ledger_5() {
+    for ledger_sqn in [5, 6, 90_000_000] {
+        let host = FixtureHost::new().with_ledger_sqn(ledger_sqn);
+        assert_eq!(run_finish(contract::finish, host), Outcome::Finish);
+    }
+}
@@ -0,0 +1,42 @@
+//! `examples/smart-escrows/notary` run against the fixture host.
+
+#[path = "../../examples/smart-escrows/notary/src/lib.rs"]
+mod contract;
+
+use xrpl_common_stdlib::host::error_codes::FIELD_NOT_FOUND;
+use xrpl_common_stdlib::r_address;
+use xrpl_common_stdlib::sfield;
+use xrpl_common_stdlib::types::account_id::AccountID;
+use xrpl_stdlib_test_utils::FixtureHost;
+use xrpl_stdlib_test_utils::example_runner::{Outcome, run_finish};
+
+const NOTARY: AccountID = r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+const SOMEONE_ELSE: AccountID = r_address!("rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY");
+
+fn submitted_by(account: AccountID) -> FixtureHost {
+    FixtureHost::new().with_bytes(sfield::Account.into(), account.0)
+}
+
+#[test]
+fn finishes_when_the_notary_submits() {
+    assert_eq!(
+        run_finish(contract::finish, submitted_by(NOTARY)),
+        Outcome::Finish
+    );
+}
+
+#[test]
+fn rejects_other_accounts() {
+    assert_eq!(
+        run_finish(contract::finish, submitted_by(SOMEONE_ELSE)),
+        Outcome::Reject
+    );
+}
+
+#[test]
+fn propagates_a_missing_account() {
+    assert_eq!(
+        run_finish(contract::finish, FixtureHost::new()),
+        Outcome::Error(FIELD_NOT_FOUND)
+    );
+}
@@ -0,0 +1,19 @@
+//! `examples/smart-escrows/oracle` run against the fixture host.
+//!
+//! The fixture host has no nested or array fields, so the oracle never has a `PriceDataSeries`
+//! here; reading actual prices is covered by the e2e tests.
+
+#[path = "../../examples/smart-escrows/oracle/src/lib.rs"]
+mod contract;
+
+use xrpl_common_stdlib::host::error_codes::FIELD_NOT_FOUND;
+use xrpl_stdlib_test_utils::FixtureHost;
+use xrpl_stdlib_test_utils::example_runner::{Outcome, run_finish};
+
+#[test]
+fn propagates_a_missing_price_series() {
+    assert_eq!(
+        run_finish(contract::finish, FixtureHost::new()),
+        Outcome::Error(FIELD_NOT_FOUND)
+    );
+}